@@ -1,8204 +1,233 @@
-use crate::wavetable::BandLimitedWaveTables;
+//! Built-in wavetables, synthesized from the Fourier series of their shapes
 
-pub static WAVETABLES: [[f32; BandLimitedWaveTables::FRAME_LEN]; 4] = [
+#[cfg(test)]
+pub mod baked;
+
+use super::*;
+use core::f32::consts::PI;
+use realfft::num_complex::Complex32;
+use wavetable::Normalize;
+
+/// Every partial below Nyquist, for full bandwidth shapes
+const NUM_HARMONICS: usize = BandLimitedWaveTables::FRAME_LEN / 2 - 1;
+
+pub const PWM_SWEEP_FRAMES: usize = 64;
+pub const SAW_TO_SQUARE_FRAMES: usize = 32;
+
+/// The harmonic each drawbar adds, from 8' to 1', the sub-harmonic ones (16' and 5⅓') are left out
+pub const DRAWBAR_HARMONICS: [usize; 7] = [1, 2, 3, 4, 5, 6, 8];
+
+/// Drawbar positions, from `0` (pushed in) to `8` (pulled out), one frame per registration
+pub const DRAWBAR_REGISTRATIONS: [[u8; DRAWBAR_HARMONICS.len()]; 7] = [
+    [8, 0, 0, 0, 0, 0, 0],
+    [8, 8, 0, 0, 0, 0, 0],
+    [8, 8, 8, 0, 0, 0, 0],
+    [8, 8, 8, 8, 0, 0, 0],
+    [8, 8, 8, 8, 8, 0, 0],
+    [8, 8, 8, 8, 8, 8, 0],
+    [8, 8, 8, 8, 8, 8, 8],
+];
+
+/// A resonance of the vocal tract
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Formant {
+    /// In Hz
+    pub frequency: f32,
+    /// In Hz, between the points 3 dB below the peak
+    pub bandwidth: f32,
+    /// In dB, at the peak
+    pub gain_db: f32,
+}
+
+impl Formant {
+    pub const fn new(frequency: f32, bandwidth: f32, gain_db: f32) -> Self {
+        Self {
+            frequency,
+            bandwidth,
+            gain_db,
+        }
+    }
+
+    /// Linear gain, at `frequency`, in Hz
+    pub fn response(&self, frequency: f32) -> f32 {
+        let detuning = 2. * (frequency - self.frequency) / self.bandwidth;
+        10f32.powf(self.gain_db / 20.) / detuning.mul_add(detuning, 1.).sqrt()
+    }
+
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        let lerp = |a: f32, b: f32| (b - a).mul_add(t, a);
+
+        Self::new(
+            lerp(self.frequency, other.frequency),
+            lerp(self.bandwidth, other.bandwidth),
+            lerp(self.gain_db, other.gain_db),
+        )
+    }
+}
+
+/// The first three formants of a tenor's A, E, I, O and U, in that order
+pub const VOWEL_FORMANTS: [[Formant; 3]; 5] = [
+    [
+        Formant::new(650., 80., 0.),
+        Formant::new(1080., 90., -6.),
+        Formant::new(2650., 120., -7.),
+    ],
     [
-        0.0,
-        0.003_067_956_8,
-        0.006_135_884_7,
-        0.009_203_754,
-        0.012_271_538,
-        0.015_339_206,
-        0.018_406_73,
-        0.021_474_08,
-        0.024_541_229,
-        0.027_608_145,
-        0.030_674_804,
-        0.033_741_172,
-        0.036_807_224,
-        0.039_872_926,
-        0.042_938_26,
-        0.046_003_18,
-        0.049_067_676,
-        0.052_131_705,
-        0.055_195_246,
-        0.058_258_265,
-        0.061_320_737,
-        0.064_382_63,
-        0.067_443_92,
-        0.070_504_576,
-        0.073_564_57,
-        0.076_623_864,
-        0.079_682_44,
-        0.082_740_26,
-        0.085_797_31,
-        0.088_853_55,
-        0.091_908_954,
-        0.094_963_5,
-        0.098_017_14,
-        0.101_069_86,
-        0.104_121_63,
-        0.107_172_42,
-        0.110_222_206,
-        0.113_270_95,
-        0.116_318_63,
-        0.119_365_215,
-        0.122_410_68,
-        0.125_454_98,
-        0.128_498_1,
-        0.131_540_03,
-        0.134_580_7,
-        0.137_620_12,
-        0.140_658_24,
-        0.143_695_03,
-        0.146_730_47,
-        0.149_764_54,
-        0.152_797_19,
-        0.155_828_4,
-        0.158_858_15,
-        0.161_886_4,
-        0.164_913_12,
-        0.167_938_29,
-        0.170_961_89,
-        0.173_983_87,
-        0.177_004_22,
-        0.180_022_9,
-        0.183_039_89,
-        0.186_055_15,
-        0.189_068_66,
-        0.192_080_4,
-        0.195_090_32,
-        0.198_098_4,
-        0.201_104_64,
-        0.204_108_97,
-        0.207_111_37,
-        0.210_111_84,
-        0.213_110_31,
-        0.216_106_8,
-        0.219_101_24,
-        0.222_093_63,
-        0.225_083_92,
-        0.228_072_08,
-        0.231_058_1,
-        0.234_041_96,
-        0.237_023_6,
-        0.240_003_02,
-        0.242_980_18,
-        0.245_955_05,
-        0.248_927_61,
-        0.251_897_8,
-        0.254_865_65,
-        0.257_831_1,
-        0.260_794_1,
-        0.263_754_67,
-        0.266_712_75,
-        0.269_668_3,
-        0.272_621_36,
-        0.275_571_82,
-        0.278_519_7,
-        0.281_464_93,
-        0.284_407_53,
-        0.287_347_47,
-        0.290_284_66,
-        0.293_219_15,
-        0.296_150_9,
-        0.299_079_84,
-        0.302_005_95,
-        0.304_929_23,
-        0.307_849_65,
-        0.310_767_14,
-        0.313_681_75,
-        0.316_593_38,
-        0.319_502_03,
-        0.322_407_7,
-        0.325_310_3,
-        0.328_209_85,
-        0.331_106_3,
-        0.333_999_66,
-        0.336_889_86,
-        0.339_776_87,
-        0.342_660_73,
-        0.345_541_33,
-        0.348_418_68,
-        0.351_292_76,
-        0.354_163_53,
-        0.357_030_96,
-        0.359_895_05,
-        0.362_755_72,
-        0.365_612_98,
-        0.368_466_82,
-        0.371_317_2,
-        0.374_164_07,
-        0.377_007_42,
-        0.379_847_2,
-        0.382_683_43,
-        0.385_516_05,
-        0.388_345_03,
-        0.391_170_38,
-        0.393_992_04,
-        0.396_81,
-        0.399_624_2,
-        0.402_434_65,
-        0.405_241_3,
-        0.408_044_16,
-        0.410_843_16,
-        0.413_638_32,
-        0.416_429_55,
-        0.419_216_9,
-        0.422_000_26,
-        0.424_779_68,
-        0.427_555_08,
-        0.430_326_5,
-        0.433_093_82,
-        0.435_857_1,
-        0.438_616_25,
-        0.441_371_26,
-        0.444_122_14,
-        0.446_868_84,
-        0.449_611_34,
-        0.452_349_57,
-        0.455_083_58,
-        0.457_813_3,
-        0.460_538_72,
-        0.463_259_8,
-        0.465_976_5,
-        0.468_688_82,
-        0.471_396_74,
-        0.474_100_2,
-        0.476_799_22,
-        0.479_493_77,
-        0.482_183_78,
-        0.484_869_24,
-        0.487_550_17,
-        0.490_226_48,
-        0.492_898_2,
-        0.495_565_27,
-        0.498_227_66,
-        0.500_885_37,
-        0.503_538_37,
-        0.506_186_66,
-        0.508_830_13,
-        0.511_468_8,
-        0.514_102_76,
-        0.516_731_8,
-        0.519_356,
-        0.521_975_3,
-        0.524_589_66,
-        0.527_199_15,
-        0.529_803_63,
-        0.532_403_1,
-        0.534_997_64,
-        0.537_587_05,
-        0.540_171_44,
-        0.542_750_8,
-        0.545_325,
-        0.547_894_06,
-        0.550_457_95,
-        0.553_016_7,
-        0.555_570_24,
-        0.558_118_5,
-        0.560_661_55,
-        0.563_199_34,
-        0.565_731_8,
-        0.568_258_94,
-        0.570_780_75,
-        0.573_297_14,
-        0.575_808_17,
-        0.578_313_77,
-        0.580_813_94,
-        0.583_308_64,
-        0.585_797_85,
-        0.588_281_6,
-        0.590_759_7,
-        0.593_232_3,
-        0.595_699_3,
-        0.598_160_7,
-        0.600_616_46,
-        0.603_066_6,
-        0.605_511_07,
-        0.607_949_8,
-        0.610_382_8,
-        0.612_810_1,
-        0.615_231_6,
-        0.617_647_3,
-        0.620_057_2,
-        0.622_461_26,
-        0.624_859_5,
-        0.627_251_8,
-        0.629_638_25,
-        0.632_018_74,
-        0.634_393_3,
-        0.636_761_84,
-        0.639_124_45,
-        0.641_481_04,
-        0.643_831_55,
-        0.646_176_04,
-        0.648_514_4,
-        0.650_846_66,
-        0.653_172_85,
-        0.655_492_84,
-        0.657_806_7,
-        0.660_114_35,
-        0.662_415_8,
-        0.664_711,
-        0.666_999_94,
-        0.669_282_6,
-        0.671_559,
-        0.673_829,
-        0.676_092_7,
-        0.678_350_03,
-        0.680_601,
-        0.682_845_53,
-        0.685_083_7,
-        0.687_315_34,
-        0.689_540_57,
-        0.691_759_2,
-        0.693_971_46,
-        0.696_177_1,
-        0.698_376_24,
-        0.700_568_8,
-        0.702_754_74,
-        0.704_934_06,
-        0.707_106_77,
-        0.709_272_8,
-        0.711_432_2,
-        0.713_584_84,
-        0.715_730_85,
-        0.717_870_06,
-        0.720_002_53,
-        0.722_128_2,
-        0.724_247_1,
-        0.726_359_1,
-        0.728_464_37,
-        0.730_562_75,
-        0.732_654_3,
-        0.734_738_9,
-        0.736_816_6,
-        0.738_887_3,
-        0.740_951_1,
-        0.743_007_96,
-        0.745_057_76,
-        0.747_100_6,
-        0.749_136_4,
-        0.751_165_15,
-        0.753_186_8,
-        0.755_201_4,
-        0.757_208_8,
-        0.759_209_2,
-        0.761_202_4,
-        0.763_188_4,
-        0.765_167_24,
-        0.767_138_9,
-        0.769_103_35,
-        0.771_060_5,
-        0.773_010_43,
-        0.774_953_1,
-        0.776_888_5,
-        0.778_816_5,
-        0.780_737_2,
-        0.782_650_6,
-        0.784_556_57,
-        0.786_455_2,
-        0.788_346_4,
-        0.790_230_2,
-        0.792_106_57,
-        0.793_975_5,
-        0.795_836_9,
-        0.797_690_87,
-        0.799_537_24,
-        0.801_376_16,
-        0.803_207_5,
-        0.805_031_36,
-        0.806_847_6,
-        0.808_656_16,
-        0.810_457_17,
-        0.812_250_6,
-        0.814_036_3,
-        0.815_814_44,
-        0.817_584_8,
-        0.819_347_5,
-        0.821_102_5,
-        0.822_849_8,
-        0.824_589_3,
-        0.826_321_07,
-        0.828_045_07,
-        0.829_761_2,
-        0.831_469_6,
-        0.833_170_2,
-        0.834_862_9,
-        0.836_547_73,
-        0.838_224_7,
-        0.839_893_8,
-        0.841_555,
-        0.843_208_25,
-        0.844_853_6,
-        0.846_490_9,
-        0.848_120_33,
-        0.849_741_76,
-        0.851_355_2,
-        0.852_960_6,
-        0.854_558,
-        0.856_147_35,
-        0.857_728_6,
-        0.859_301_8,
-        0.860_866_96,
-        0.862_423_96,
-        0.863_972_84,
-        0.865_513_6,
-        0.867_046_24,
-        0.868_570_7,
-        0.870_086_97,
-        0.871_595_1,
-        0.873_095,
-        0.874_586_64,
-        0.876_070_1,
-        0.877_545_3,
-        0.879_012_2,
-        0.880_470_9,
-        0.881_921_3,
-        0.883_363_37,
-        0.884_797_1,
-        0.886_222_54,
-        0.887_639_64,
-        0.889_048_34,
-        0.890_448_75,
-        0.891_840_7,
-        0.893_224_3,
-        0.894_599_5,
-        0.895_966_23,
-        0.897_324_56,
-        0.898_674_5,
-        0.900_015_9,
-        0.901_348_8,
-        0.902_673_3,
-        0.903_989_3,
-        0.905_296_74,
-        0.906_595_7,
-        0.907_886_1,
-        0.909_168,
-        0.910_441_3,
-        0.911_706_03,
-        0.912_962_2,
-        0.914_209_8,
-        0.915_448_7,
-        0.916_679_1,
-        0.917_900_8,
-        0.919_113_9,
-        0.920_318_3,
-        0.921_514_03,
-        0.922_701_1,
-        0.923_879_5,
-        0.925_049_25,
-        0.926_210_2,
-        0.927_362_5,
-        0.928_506_1,
-        0.929_640_9,
-        0.930_766_94,
-        0.931_884_3,
-        0.932_992_8,
-        0.934_092_5,
-        0.935_183_5,
-        0.936_265_65,
-        0.937_339,
-        0.938_403_55,
-        0.939_459_2,
-        0.940_506_04,
-        0.941_544_06,
-        0.942_573_2,
-        0.943_593_44,
-        0.944_604_8,
-        0.945_607_3,
-        0.946_600_9,
-        0.947_585_6,
-        0.948_561_4,
-        0.949_528_16,
-        0.950_486_06,
-        0.951_435,
-        0.952_375,
-        0.953_306,
-        0.954_228_1,
-        0.955_141_2,
-        0.956_045_27,
-        0.956_940_35,
-        0.957_826_44,
-        0.958_703_46,
-        0.959_571_54,
-        0.960_430_5,
-        0.961_280_47,
-        0.962_121_4,
-        0.962_953_27,
-        0.963_776_05,
-        0.964_589_8,
-        0.965_394_44,
-        0.966_19,
-        0.966_976_46,
-        0.967_753_8,
-        0.968_522_1,
-        0.969_281_26,
-        0.970_031_26,
-        0.970_772_15,
-        0.971_503_9,
-        0.972_226_5,
-        0.972_939_97,
-        0.973_644_26,
-        0.974_339_37,
-        0.975_025_36,
-        0.975_702_1,
-        0.976_369_74,
-        0.977_028_13,
-        0.977_677_35,
-        0.978_317_4,
-        0.978_948_2,
-        0.979_569_8,
-        0.980_182_1,
-        0.980_785_25,
-        0.981_379_2,
-        0.981_963_9,
-        0.982_539_3,
-        0.983_105_5,
-        0.983_662_4,
-        0.984_210_1,
-        0.984_748_5,
-        0.985_277_65,
-        0.985_797_5,
-        0.986_308_1,
-        0.986_809_4,
-        0.987_301_4,
-        0.987_784_15,
-        0.988_257_6,
-        0.988_721_67,
-        0.989_176_5,
-        0.989_622,
-        0.990_058_2,
-        0.990_485_1,
-        0.990_902_66,
-        0.991_310_83,
-        0.991_709_77,
-        0.992_099_3,
-        0.992_479_56,
-        0.992_850_4,
-        0.993_211_9,
-        0.993_564_1,
-        0.993_907,
-        0.994_240_46,
-        0.994_564_6,
-        0.994_879_3,
-        0.995_184_7,
-        0.995_480_8,
-        0.995_767_4,
-        0.996_044_7,
-        0.996_312_6,
-        0.996_571_1,
-        0.996_820_3,
-        0.997_060_06,
-        0.997_290_43,
-        0.997_511_45,
-        0.997_723_04,
-        0.997_925_3,
-        0.998_118_1,
-        0.998_301_57,
-        0.998_475_55,
-        0.998_640_24,
-        0.998_795_45,
-        0.998_941_3,
-        0.999_077_74,
-        0.999_204_75,
-        0.999_322_35,
-        0.999_430_6,
-        0.999_529_4,
-        0.999_618_8,
-        0.999_698_8,
-        0.999_769_4,
-        0.999_830_6,
-        0.999_882_34,
-        0.999_924_7,
-        0.999_957_6,
-        0.999_981_16,
-        0.999_995_3,
-        1.0,
-        0.999_995_3,
-        0.999_981_16,
-        0.999_957_6,
-        0.999_924_7,
-        0.999_882_34,
-        0.999_830_6,
-        0.999_769_4,
-        0.999_698_8,
-        0.999_618_8,
-        0.999_529_4,
-        0.999_430_6,
-        0.999_322_35,
-        0.999_204_75,
-        0.999_077_74,
-        0.998_941_3,
-        0.998_795_45,
-        0.998_640_24,
-        0.998_475_55,
-        0.998_301_57,
-        0.998_118_1,
-        0.997_925_3,
-        0.997_723_04,
-        0.997_511_45,
-        0.997_290_43,
-        0.997_060_06,
-        0.996_820_3,
-        0.996_571_1,
-        0.996_312_6,
-        0.996_044_7,
-        0.995_767_4,
-        0.995_480_8,
-        0.995_184_7,
-        0.994_879_3,
-        0.994_564_6,
-        0.994_240_46,
-        0.993_907,
-        0.993_564_1,
-        0.993_211_9,
-        0.992_850_4,
-        0.992_479_56,
-        0.992_099_3,
-        0.991_709_77,
-        0.991_310_83,
-        0.990_902_66,
-        0.990_485_1,
-        0.990_058_2,
-        0.989_622,
-        0.989_176_5,
-        0.988_721_67,
-        0.988_257_6,
-        0.987_784_15,
-        0.987_301_4,
-        0.986_809_4,
-        0.986_308_1,
-        0.985_797_5,
-        0.985_277_65,
-        0.984_748_5,
-        0.984_210_1,
-        0.983_662_4,
-        0.983_105_5,
-        0.982_539_3,
-        0.981_963_9,
-        0.981_379_2,
-        0.980_785_25,
-        0.980_182_1,
-        0.979_569_8,
-        0.978_948_2,
-        0.978_317_4,
-        0.977_677_35,
-        0.977_028_13,
-        0.976_369_74,
-        0.975_702_1,
-        0.975_025_36,
-        0.974_339_37,
-        0.973_644_26,
-        0.972_939_97,
-        0.972_226_5,
-        0.971_503_9,
-        0.970_772_15,
-        0.970_031_26,
-        0.969_281_26,
-        0.968_522_1,
-        0.967_753_8,
-        0.966_976_46,
-        0.966_19,
-        0.965_394_44,
-        0.964_589_8,
-        0.963_776_05,
-        0.962_953_27,
-        0.962_121_4,
-        0.961_280_47,
-        0.960_430_5,
-        0.959_571_54,
-        0.958_703_46,
-        0.957_826_44,
-        0.956_940_35,
-        0.956_045_27,
-        0.955_141_2,
-        0.954_228_1,
-        0.953_306,
-        0.952_375,
-        0.951_435,
-        0.950_486_06,
-        0.949_528_16,
-        0.948_561_4,
-        0.947_585_6,
-        0.946_600_9,
-        0.945_607_3,
-        0.944_604_8,
-        0.943_593_44,
-        0.942_573_2,
-        0.941_544_06,
-        0.940_506_04,
-        0.939_459_2,
-        0.938_403_55,
-        0.937_339,
-        0.936_265_65,
-        0.935_183_5,
-        0.934_092_5,
-        0.932_992_8,
-        0.931_884_3,
-        0.930_766_94,
-        0.929_640_9,
-        0.928_506_1,
-        0.927_362_5,
-        0.926_210_2,
-        0.925_049_25,
-        0.923_879_5,
-        0.922_701_1,
-        0.921_514_03,
-        0.920_318_3,
-        0.919_113_9,
-        0.917_900_8,
-        0.916_679_1,
-        0.915_448_7,
-        0.914_209_8,
-        0.912_962_2,
-        0.911_706_03,
-        0.910_441_3,
-        0.909_168,
-        0.907_886_1,
-        0.906_595_7,
-        0.905_296_74,
-        0.903_989_3,
-        0.902_673_3,
-        0.901_348_8,
-        0.900_015_9,
-        0.898_674_5,
-        0.897_324_56,
-        0.895_966_23,
-        0.894_599_5,
-        0.893_224_3,
-        0.891_840_7,
-        0.890_448_75,
-        0.889_048_34,
-        0.887_639_64,
-        0.886_222_54,
-        0.884_797_1,
-        0.883_363_37,
-        0.881_921_3,
-        0.880_470_9,
-        0.879_012_2,
-        0.877_545_3,
-        0.876_070_1,
-        0.874_586_64,
-        0.873_095,
-        0.871_595_1,
-        0.870_086_97,
-        0.868_570_7,
-        0.867_046_24,
-        0.865_513_6,
-        0.863_972_84,
-        0.862_423_96,
-        0.860_866_96,
-        0.859_301_8,
-        0.857_728_6,
-        0.856_147_35,
-        0.854_558,
-        0.852_960_6,
-        0.851_355_2,
-        0.849_741_76,
-        0.848_120_33,
-        0.846_490_9,
-        0.844_853_6,
-        0.843_208_25,
-        0.841_555,
-        0.839_893_8,
-        0.838_224_7,
-        0.836_547_73,
-        0.834_862_9,
-        0.833_170_2,
-        0.831_469_6,
-        0.829_761_2,
-        0.828_045_07,
-        0.826_321_07,
-        0.824_589_3,
-        0.822_849_8,
-        0.821_102_5,
-        0.819_347_5,
-        0.817_584_8,
-        0.815_814_44,
-        0.814_036_3,
-        0.812_250_6,
-        0.810_457_17,
-        0.808_656_16,
-        0.806_847_6,
-        0.805_031_36,
-        0.803_207_5,
-        0.801_376_16,
-        0.799_537_24,
-        0.797_690_87,
-        0.795_836_9,
-        0.793_975_5,
-        0.792_106_57,
-        0.790_230_2,
-        0.788_346_4,
-        0.786_455_2,
-        0.784_556_57,
-        0.782_650_6,
-        0.780_737_2,
-        0.778_816_5,
-        0.776_888_5,
-        0.774_953_1,
-        0.773_010_43,
-        0.771_060_5,
-        0.769_103_35,
-        0.767_138_9,
-        0.765_167_24,
-        0.763_188_4,
-        0.761_202_4,
-        0.759_209_2,
-        0.757_208_8,
-        0.755_201_4,
-        0.753_186_8,
-        0.751_165_15,
-        0.749_136_4,
-        0.747_100_6,
-        0.745_057_76,
-        0.743_007_96,
-        0.740_951_1,
-        0.738_887_3,
-        0.736_816_6,
-        0.734_738_9,
-        0.732_654_3,
-        0.730_562_75,
-        0.728_464_37,
-        0.726_359_1,
-        0.724_247_1,
-        0.722_128_2,
-        0.720_002_53,
-        0.717_870_06,
-        0.715_730_85,
-        0.713_584_84,
-        0.711_432_2,
-        0.709_272_8,
-        0.707_106_77,
-        0.704_934_06,
-        0.702_754_74,
-        0.700_568_8,
-        0.698_376_24,
-        0.696_177_1,
-        0.693_971_46,
-        0.691_759_2,
-        0.689_540_57,
-        0.687_315_34,
-        0.685_083_7,
-        0.682_845_53,
-        0.680_601,
-        0.678_350_03,
-        0.676_092_7,
-        0.673_829,
-        0.671_559,
-        0.669_282_6,
-        0.666_999_94,
-        0.664_711,
-        0.662_415_8,
-        0.660_114_35,
-        0.657_806_7,
-        0.655_492_84,
-        0.653_172_85,
-        0.650_846_66,
-        0.648_514_4,
-        0.646_176_04,
-        0.643_831_55,
-        0.641_481_04,
-        0.639_124_45,
-        0.636_761_84,
-        0.634_393_3,
-        0.632_018_74,
-        0.629_638_25,
-        0.627_251_8,
-        0.624_859_5,
-        0.622_461_26,
-        0.620_057_2,
-        0.617_647_3,
-        0.615_231_6,
-        0.612_810_1,
-        0.610_382_8,
-        0.607_949_8,
-        0.605_511_07,
-        0.603_066_6,
-        0.600_616_46,
-        0.598_160_7,
-        0.595_699_3,
-        0.593_232_3,
-        0.590_759_7,
-        0.588_281_6,
-        0.585_797_85,
-        0.583_308_64,
-        0.580_813_94,
-        0.578_313_77,
-        0.575_808_17,
-        0.573_297_14,
-        0.570_780_75,
-        0.568_258_94,
-        0.565_731_8,
-        0.563_199_34,
-        0.560_661_55,
-        0.558_118_5,
-        0.555_570_24,
-        0.553_016_7,
-        0.550_457_95,
-        0.547_894_06,
-        0.545_325,
-        0.542_750_8,
-        0.540_171_44,
-        0.537_587_05,
-        0.534_997_64,
-        0.532_403_1,
-        0.529_803_63,
-        0.527_199_15,
-        0.524_589_66,
-        0.521_975_3,
-        0.519_356,
-        0.516_731_8,
-        0.514_102_76,
-        0.511_468_8,
-        0.508_830_13,
-        0.506_186_66,
-        0.503_538_37,
-        0.500_885_37,
-        0.498_227_66,
-        0.495_565_27,
-        0.492_898_2,
-        0.490_226_48,
-        0.487_550_17,
-        0.484_869_24,
-        0.482_183_78,
-        0.479_493_77,
-        0.476_799_22,
-        0.474_100_2,
-        0.471_396_74,
-        0.468_688_82,
-        0.465_976_5,
-        0.463_259_8,
-        0.460_538_72,
-        0.457_813_3,
-        0.455_083_58,
-        0.452_349_57,
-        0.449_611_34,
-        0.446_868_84,
-        0.444_122_14,
-        0.441_371_26,
-        0.438_616_25,
-        0.435_857_1,
-        0.433_093_82,
-        0.430_326_5,
-        0.427_555_08,
-        0.424_779_68,
-        0.422_000_26,
-        0.419_216_9,
-        0.416_429_55,
-        0.413_638_32,
-        0.410_843_16,
-        0.408_044_16,
-        0.405_241_3,
-        0.402_434_65,
-        0.399_624_2,
-        0.396_81,
-        0.393_992_04,
-        0.391_170_38,
-        0.388_345_03,
-        0.385_516_05,
-        0.382_683_43,
-        0.379_847_2,
-        0.377_007_42,
-        0.374_164_07,
-        0.371_317_2,
-        0.368_466_82,
-        0.365_612_98,
-        0.362_755_72,
-        0.359_895_05,
-        0.357_030_96,
-        0.354_163_53,
-        0.351_292_76,
-        0.348_418_68,
-        0.345_541_33,
-        0.342_660_73,
-        0.339_776_87,
-        0.336_889_86,
-        0.333_999_66,
-        0.331_106_3,
-        0.328_209_85,
-        0.325_310_3,
-        0.322_407_7,
-        0.319_502_03,
-        0.316_593_38,
-        0.313_681_75,
-        0.310_767_14,
-        0.307_849_65,
-        0.304_929_23,
-        0.302_005_95,
-        0.299_079_84,
-        0.296_150_9,
-        0.293_219_15,
-        0.290_284_66,
-        0.287_347_47,
-        0.284_407_53,
-        0.281_464_93,
-        0.278_519_7,
-        0.275_571_82,
-        0.272_621_36,
-        0.269_668_3,
-        0.266_712_75,
-        0.263_754_67,
-        0.260_794_1,
-        0.257_831_1,
-        0.254_865_65,
-        0.251_897_8,
-        0.248_927_61,
-        0.245_955_05,
-        0.242_980_18,
-        0.240_003_02,
-        0.237_023_6,
-        0.234_041_96,
-        0.231_058_1,
-        0.228_072_08,
-        0.225_083_92,
-        0.222_093_63,
-        0.219_101_24,
-        0.216_106_8,
-        0.213_110_31,
-        0.210_111_84,
-        0.207_111_37,
-        0.204_108_97,
-        0.201_104_64,
-        0.198_098_4,
-        0.195_090_32,
-        0.192_080_4,
-        0.189_068_66,
-        0.186_055_15,
-        0.183_039_89,
-        0.180_022_9,
-        0.177_004_22,
-        0.173_983_87,
-        0.170_961_89,
-        0.167_938_29,
-        0.164_913_12,
-        0.161_886_4,
-        0.158_858_15,
-        0.155_828_4,
-        0.152_797_19,
-        0.149_764_54,
-        0.146_730_47,
-        0.143_695_03,
-        0.140_658_24,
-        0.137_620_12,
-        0.134_580_7,
-        0.131_540_03,
-        0.128_498_1,
-        0.125_454_98,
-        0.122_410_68,
-        0.119_365_215,
-        0.116_318_63,
-        0.113_270_95,
-        0.110_222_206,
-        0.107_172_42,
-        0.104_121_63,
-        0.101_069_86,
-        0.098_017_14,
-        0.094_963_5,
-        0.091_908_954,
-        0.088_853_55,
-        0.085_797_31,
-        0.082_740_26,
-        0.079_682_44,
-        0.076_623_864,
-        0.073_564_57,
-        0.070_504_576,
-        0.067_443_92,
-        0.064_382_63,
-        0.061_320_737,
-        0.058_258_265,
-        0.055_195_246,
-        0.052_131_705,
-        0.049_067_676,
-        0.046_003_18,
-        0.042_938_26,
-        0.039_872_926,
-        0.036_807_224,
-        0.033_741_172,
-        0.030_674_804,
-        0.027_608_145,
-        0.024_541_229,
-        0.021_474_08,
-        0.018_406_73,
-        0.015_339_206,
-        0.012_271_538,
-        0.009_203_754,
-        0.006_135_884_7,
-        0.003_067_956_8,
-        0.0,
-        -0.003_067_956_8,
-        -0.006_135_884_7,
-        -0.009_203_754,
-        -0.012_271_538,
-        -0.015_339_206,
-        -0.018_406_73,
-        -0.021_474_08,
-        -0.024_541_229,
-        -0.027_608_145,
-        -0.030_674_804,
-        -0.033_741_172,
-        -0.036_807_224,
-        -0.039_872_926,
-        -0.042_938_26,
-        -0.046_003_18,
-        -0.049_067_676,
-        -0.052_131_705,
-        -0.055_195_246,
-        -0.058_258_265,
-        -0.061_320_737,
-        -0.064_382_63,
-        -0.067_443_92,
-        -0.070_504_576,
-        -0.073_564_57,
-        -0.076_623_864,
-        -0.079_682_44,
-        -0.082_740_26,
-        -0.085_797_31,
-        -0.088_853_55,
-        -0.091_908_954,
-        -0.094_963_5,
-        -0.098_017_14,
-        -0.101_069_86,
-        -0.104_121_63,
-        -0.107_172_42,
-        -0.110_222_206,
-        -0.113_270_95,
-        -0.116_318_63,
-        -0.119_365_215,
-        -0.122_410_68,
-        -0.125_454_98,
-        -0.128_498_1,
-        -0.131_540_03,
-        -0.134_580_7,
-        -0.137_620_12,
-        -0.140_658_24,
-        -0.143_695_03,
-        -0.146_730_47,
-        -0.149_764_54,
-        -0.152_797_19,
-        -0.155_828_4,
-        -0.158_858_15,
-        -0.161_886_4,
-        -0.164_913_12,
-        -0.167_938_29,
-        -0.170_961_89,
-        -0.173_983_87,
-        -0.177_004_22,
-        -0.180_022_9,
-        -0.183_039_89,
-        -0.186_055_15,
-        -0.189_068_66,
-        -0.192_080_4,
-        -0.195_090_32,
-        -0.198_098_4,
-        -0.201_104_64,
-        -0.204_108_97,
-        -0.207_111_37,
-        -0.210_111_84,
-        -0.213_110_31,
-        -0.216_106_8,
-        -0.219_101_24,
-        -0.222_093_63,
-        -0.225_083_92,
-        -0.228_072_08,
-        -0.231_058_1,
-        -0.234_041_96,
-        -0.237_023_6,
-        -0.240_003_02,
-        -0.242_980_18,
-        -0.245_955_05,
-        -0.248_927_61,
-        -0.251_897_8,
-        -0.254_865_65,
-        -0.257_831_1,
-        -0.260_794_1,
-        -0.263_754_67,
-        -0.266_712_75,
-        -0.269_668_3,
-        -0.272_621_36,
-        -0.275_571_82,
-        -0.278_519_7,
-        -0.281_464_93,
-        -0.284_407_53,
-        -0.287_347_47,
-        -0.290_284_66,
-        -0.293_219_15,
-        -0.296_150_9,
-        -0.299_079_84,
-        -0.302_005_95,
-        -0.304_929_23,
-        -0.307_849_65,
-        -0.310_767_14,
-        -0.313_681_75,
-        -0.316_593_38,
-        -0.319_502_03,
-        -0.322_407_7,
-        -0.325_310_3,
-        -0.328_209_85,
-        -0.331_106_3,
-        -0.333_999_66,
-        -0.336_889_86,
-        -0.339_776_87,
-        -0.342_660_73,
-        -0.345_541_33,
-        -0.348_418_68,
-        -0.351_292_76,
-        -0.354_163_53,
-        -0.357_030_96,
-        -0.359_895_05,
-        -0.362_755_72,
-        -0.365_612_98,
-        -0.368_466_82,
-        -0.371_317_2,
-        -0.374_164_07,
-        -0.377_007_42,
-        -0.379_847_2,
-        -0.382_683_43,
-        -0.385_516_05,
-        -0.388_345_03,
-        -0.391_170_38,
-        -0.393_992_04,
-        -0.396_81,
-        -0.399_624_2,
-        -0.402_434_65,
-        -0.405_241_3,
-        -0.408_044_16,
-        -0.410_843_16,
-        -0.413_638_32,
-        -0.416_429_55,
-        -0.419_216_9,
-        -0.422_000_26,
-        -0.424_779_68,
-        -0.427_555_08,
-        -0.430_326_5,
-        -0.433_093_82,
-        -0.435_857_1,
-        -0.438_616_25,
-        -0.441_371_26,
-        -0.444_122_14,
-        -0.446_868_84,
-        -0.449_611_34,
-        -0.452_349_57,
-        -0.455_083_58,
-        -0.457_813_3,
-        -0.460_538_72,
-        -0.463_259_8,
-        -0.465_976_5,
-        -0.468_688_82,
-        -0.471_396_74,
-        -0.474_100_2,
-        -0.476_799_22,
-        -0.479_493_77,
-        -0.482_183_78,
-        -0.484_869_24,
-        -0.487_550_17,
-        -0.490_226_48,
-        -0.492_898_2,
-        -0.495_565_27,
-        -0.498_227_66,
-        -0.500_885_37,
-        -0.503_538_37,
-        -0.506_186_66,
-        -0.508_830_13,
-        -0.511_468_8,
-        -0.514_102_76,
-        -0.516_731_8,
-        -0.519_356,
-        -0.521_975_3,
-        -0.524_589_66,
-        -0.527_199_15,
-        -0.529_803_63,
-        -0.532_403_1,
-        -0.534_997_64,
-        -0.537_587_05,
-        -0.540_171_44,
-        -0.542_750_8,
-        -0.545_325,
-        -0.547_894_06,
-        -0.550_457_95,
-        -0.553_016_7,
-        -0.555_570_24,
-        -0.558_118_5,
-        -0.560_661_55,
-        -0.563_199_34,
-        -0.565_731_8,
-        -0.568_258_94,
-        -0.570_780_75,
-        -0.573_297_14,
-        -0.575_808_17,
-        -0.578_313_77,
-        -0.580_813_94,
-        -0.583_308_64,
-        -0.585_797_85,
-        -0.588_281_6,
-        -0.590_759_7,
-        -0.593_232_3,
-        -0.595_699_3,
-        -0.598_160_7,
-        -0.600_616_46,
-        -0.603_066_6,
-        -0.605_511_07,
-        -0.607_949_8,
-        -0.610_382_8,
-        -0.612_810_1,
-        -0.615_231_6,
-        -0.617_647_3,
-        -0.620_057_2,
-        -0.622_461_26,
-        -0.624_859_5,
-        -0.627_251_8,
-        -0.629_638_25,
-        -0.632_018_74,
-        -0.634_393_3,
-        -0.636_761_84,
-        -0.639_124_45,
-        -0.641_481_04,
-        -0.643_831_55,
-        -0.646_176_04,
-        -0.648_514_4,
-        -0.650_846_66,
-        -0.653_172_85,
-        -0.655_492_84,
-        -0.657_806_7,
-        -0.660_114_35,
-        -0.662_415_8,
-        -0.664_711,
-        -0.666_999_94,
-        -0.669_282_6,
-        -0.671_559,
-        -0.673_829,
-        -0.676_092_7,
-        -0.678_350_03,
-        -0.680_601,
-        -0.682_845_53,
-        -0.685_083_7,
-        -0.687_315_34,
-        -0.689_540_57,
-        -0.691_759_2,
-        -0.693_971_46,
-        -0.696_177_1,
-        -0.698_376_24,
-        -0.700_568_8,
-        -0.702_754_74,
-        -0.704_934_06,
-        -0.707_106_77,
-        -0.709_272_8,
-        -0.711_432_2,
-        -0.713_584_84,
-        -0.715_730_85,
-        -0.717_870_06,
-        -0.720_002_53,
-        -0.722_128_2,
-        -0.724_247_1,
-        -0.726_359_1,
-        -0.728_464_37,
-        -0.730_562_75,
-        -0.732_654_3,
-        -0.734_738_9,
-        -0.736_816_6,
-        -0.738_887_3,
-        -0.740_951_1,
-        -0.743_007_96,
-        -0.745_057_76,
-        -0.747_100_6,
-        -0.749_136_4,
-        -0.751_165_15,
-        -0.753_186_8,
-        -0.755_201_4,
-        -0.757_208_8,
-        -0.759_209_2,
-        -0.761_202_4,
-        -0.763_188_4,
-        -0.765_167_24,
-        -0.767_138_9,
-        -0.769_103_35,
-        -0.771_060_5,
-        -0.773_010_43,
-        -0.774_953_1,
-        -0.776_888_5,
-        -0.778_816_5,
-        -0.780_737_2,
-        -0.782_650_6,
-        -0.784_556_57,
-        -0.786_455_2,
-        -0.788_346_4,
-        -0.790_230_2,
-        -0.792_106_57,
-        -0.793_975_5,
-        -0.795_836_9,
-        -0.797_690_87,
-        -0.799_537_24,
-        -0.801_376_16,
-        -0.803_207_5,
-        -0.805_031_36,
-        -0.806_847_6,
-        -0.808_656_16,
-        -0.810_457_17,
-        -0.812_250_6,
-        -0.814_036_3,
-        -0.815_814_44,
-        -0.817_584_8,
-        -0.819_347_5,
-        -0.821_102_5,
-        -0.822_849_8,
-        -0.824_589_3,
-        -0.826_321_07,
-        -0.828_045_07,
-        -0.829_761_2,
-        -0.831_469_6,
-        -0.833_170_2,
-        -0.834_862_9,
-        -0.836_547_73,
-        -0.838_224_7,
-        -0.839_893_8,
-        -0.841_555,
-        -0.843_208_25,
-        -0.844_853_6,
-        -0.846_490_9,
-        -0.848_120_33,
-        -0.849_741_76,
-        -0.851_355_2,
-        -0.852_960_6,
-        -0.854_558,
-        -0.856_147_35,
-        -0.857_728_6,
-        -0.859_301_8,
-        -0.860_866_96,
-        -0.862_423_96,
-        -0.863_972_84,
-        -0.865_513_6,
-        -0.867_046_24,
-        -0.868_570_7,
-        -0.870_086_97,
-        -0.871_595_1,
-        -0.873_095,
-        -0.874_586_64,
-        -0.876_070_1,
-        -0.877_545_3,
-        -0.879_012_2,
-        -0.880_470_9,
-        -0.881_921_3,
-        -0.883_363_37,
-        -0.884_797_1,
-        -0.886_222_54,
-        -0.887_639_64,
-        -0.889_048_34,
-        -0.890_448_75,
-        -0.891_840_7,
-        -0.893_224_3,
-        -0.894_599_5,
-        -0.895_966_23,
-        -0.897_324_56,
-        -0.898_674_5,
-        -0.900_015_9,
-        -0.901_348_8,
-        -0.902_673_3,
-        -0.903_989_3,
-        -0.905_296_74,
-        -0.906_595_7,
-        -0.907_886_1,
-        -0.909_168,
-        -0.910_441_3,
-        -0.911_706_03,
-        -0.912_962_2,
-        -0.914_209_8,
-        -0.915_448_7,
-        -0.916_679_1,
-        -0.917_900_8,
-        -0.919_113_9,
-        -0.920_318_3,
-        -0.921_514_03,
-        -0.922_701_1,
-        -0.923_879_5,
-        -0.925_049_25,
-        -0.926_210_2,
-        -0.927_362_5,
-        -0.928_506_1,
-        -0.929_640_9,
-        -0.930_766_94,
-        -0.931_884_3,
-        -0.932_992_8,
-        -0.934_092_5,
-        -0.935_183_5,
-        -0.936_265_65,
-        -0.937_339,
-        -0.938_403_55,
-        -0.939_459_2,
-        -0.940_506_04,
-        -0.941_544_06,
-        -0.942_573_2,
-        -0.943_593_44,
-        -0.944_604_8,
-        -0.945_607_3,
-        -0.946_600_9,
-        -0.947_585_6,
-        -0.948_561_4,
-        -0.949_528_16,
-        -0.950_486_06,
-        -0.951_435,
-        -0.952_375,
-        -0.953_306,
-        -0.954_228_1,
-        -0.955_141_2,
-        -0.956_045_27,
-        -0.956_940_35,
-        -0.957_826_44,
-        -0.958_703_46,
-        -0.959_571_54,
-        -0.960_430_5,
-        -0.961_280_47,
-        -0.962_121_4,
-        -0.962_953_27,
-        -0.963_776_05,
-        -0.964_589_8,
-        -0.965_394_44,
-        -0.966_19,
-        -0.966_976_46,
-        -0.967_753_8,
-        -0.968_522_1,
-        -0.969_281_26,
-        -0.970_031_26,
-        -0.970_772_15,
-        -0.971_503_9,
-        -0.972_226_5,
-        -0.972_939_97,
-        -0.973_644_26,
-        -0.974_339_37,
-        -0.975_025_36,
-        -0.975_702_1,
-        -0.976_369_74,
-        -0.977_028_13,
-        -0.977_677_35,
-        -0.978_317_4,
-        -0.978_948_2,
-        -0.979_569_8,
-        -0.980_182_1,
-        -0.980_785_25,
-        -0.981_379_2,
-        -0.981_963_9,
-        -0.982_539_3,
-        -0.983_105_5,
-        -0.983_662_4,
-        -0.984_210_1,
-        -0.984_748_5,
-        -0.985_277_65,
-        -0.985_797_5,
-        -0.986_308_1,
-        -0.986_809_4,
-        -0.987_301_4,
-        -0.987_784_15,
-        -0.988_257_6,
-        -0.988_721_67,
-        -0.989_176_5,
-        -0.989_622,
-        -0.990_058_2,
-        -0.990_485_1,
-        -0.990_902_66,
-        -0.991_310_83,
-        -0.991_709_77,
-        -0.992_099_3,
-        -0.992_479_56,
-        -0.992_850_4,
-        -0.993_211_9,
-        -0.993_564_1,
-        -0.993_907,
-        -0.994_240_46,
-        -0.994_564_6,
-        -0.994_879_3,
-        -0.995_184_7,
-        -0.995_480_8,
-        -0.995_767_4,
-        -0.996_044_7,
-        -0.996_312_6,
-        -0.996_571_1,
-        -0.996_820_3,
-        -0.997_060_06,
-        -0.997_290_43,
-        -0.997_511_45,
-        -0.997_723_04,
-        -0.997_925_3,
-        -0.998_118_1,
-        -0.998_301_57,
-        -0.998_475_55,
-        -0.998_640_24,
-        -0.998_795_45,
-        -0.998_941_3,
-        -0.999_077_74,
-        -0.999_204_75,
-        -0.999_322_35,
-        -0.999_430_6,
-        -0.999_529_4,
-        -0.999_618_8,
-        -0.999_698_8,
-        -0.999_769_4,
-        -0.999_830_6,
-        -0.999_882_34,
-        -0.999_924_7,
-        -0.999_957_6,
-        -0.999_981_16,
-        -0.999_995_3,
-        -1.0,
-        -0.999_995_3,
-        -0.999_981_16,
-        -0.999_957_6,
-        -0.999_924_7,
-        -0.999_882_34,
-        -0.999_830_6,
-        -0.999_769_4,
-        -0.999_698_8,
-        -0.999_618_8,
-        -0.999_529_4,
-        -0.999_430_6,
-        -0.999_322_35,
-        -0.999_204_75,
-        -0.999_077_74,
-        -0.998_941_3,
-        -0.998_795_45,
-        -0.998_640_24,
-        -0.998_475_55,
-        -0.998_301_57,
-        -0.998_118_1,
-        -0.997_925_3,
-        -0.997_723_04,
-        -0.997_511_45,
-        -0.997_290_43,
-        -0.997_060_06,
-        -0.996_820_3,
-        -0.996_571_1,
-        -0.996_312_6,
-        -0.996_044_7,
-        -0.995_767_4,
-        -0.995_480_8,
-        -0.995_184_7,
-        -0.994_879_3,
-        -0.994_564_6,
-        -0.994_240_46,
-        -0.993_907,
-        -0.993_564_1,
-        -0.993_211_9,
-        -0.992_850_4,
-        -0.992_479_56,
-        -0.992_099_3,
-        -0.991_709_77,
-        -0.991_310_83,
-        -0.990_902_66,
-        -0.990_485_1,
-        -0.990_058_2,
-        -0.989_622,
-        -0.989_176_5,
-        -0.988_721_67,
-        -0.988_257_6,
-        -0.987_784_15,
-        -0.987_301_4,
-        -0.986_809_4,
-        -0.986_308_1,
-        -0.985_797_5,
-        -0.985_277_65,
-        -0.984_748_5,
-        -0.984_210_1,
-        -0.983_662_4,
-        -0.983_105_5,
-        -0.982_539_3,
-        -0.981_963_9,
-        -0.981_379_2,
-        -0.980_785_25,
-        -0.980_182_1,
-        -0.979_569_8,
-        -0.978_948_2,
-        -0.978_317_4,
-        -0.977_677_35,
-        -0.977_028_13,
-        -0.976_369_74,
-        -0.975_702_1,
-        -0.975_025_36,
-        -0.974_339_37,
-        -0.973_644_26,
-        -0.972_939_97,
-        -0.972_226_5,
-        -0.971_503_9,
-        -0.970_772_15,
-        -0.970_031_26,
-        -0.969_281_26,
-        -0.968_522_1,
-        -0.967_753_8,
-        -0.966_976_46,
-        -0.966_19,
-        -0.965_394_44,
-        -0.964_589_8,
-        -0.963_776_05,
-        -0.962_953_27,
-        -0.962_121_4,
-        -0.961_280_47,
-        -0.960_430_5,
-        -0.959_571_54,
-        -0.958_703_46,
-        -0.957_826_44,
-        -0.956_940_35,
-        -0.956_045_27,
-        -0.955_141_2,
-        -0.954_228_1,
-        -0.953_306,
-        -0.952_375,
-        -0.951_435,
-        -0.950_486_06,
-        -0.949_528_16,
-        -0.948_561_4,
-        -0.947_585_6,
-        -0.946_600_9,
-        -0.945_607_3,
-        -0.944_604_8,
-        -0.943_593_44,
-        -0.942_573_2,
-        -0.941_544_06,
-        -0.940_506_04,
-        -0.939_459_2,
-        -0.938_403_55,
-        -0.937_339,
-        -0.936_265_65,
-        -0.935_183_5,
-        -0.934_092_5,
-        -0.932_992_8,
-        -0.931_884_3,
-        -0.930_766_94,
-        -0.929_640_9,
-        -0.928_506_1,
-        -0.927_362_5,
-        -0.926_210_2,
-        -0.925_049_25,
-        -0.923_879_5,
-        -0.922_701_1,
-        -0.921_514_03,
-        -0.920_318_3,
-        -0.919_113_9,
-        -0.917_900_8,
-        -0.916_679_1,
-        -0.915_448_7,
-        -0.914_209_8,
-        -0.912_962_2,
-        -0.911_706_03,
-        -0.910_441_3,
-        -0.909_168,
-        -0.907_886_1,
-        -0.906_595_7,
-        -0.905_296_74,
-        -0.903_989_3,
-        -0.902_673_3,
-        -0.901_348_8,
-        -0.900_015_9,
-        -0.898_674_5,
-        -0.897_324_56,
-        -0.895_966_23,
-        -0.894_599_5,
-        -0.893_224_3,
-        -0.891_840_7,
-        -0.890_448_75,
-        -0.889_048_34,
-        -0.887_639_64,
-        -0.886_222_54,
-        -0.884_797_1,
-        -0.883_363_37,
-        -0.881_921_3,
-        -0.880_470_9,
-        -0.879_012_2,
-        -0.877_545_3,
-        -0.876_070_1,
-        -0.874_586_64,
-        -0.873_095,
-        -0.871_595_1,
-        -0.870_086_97,
-        -0.868_570_7,
-        -0.867_046_24,
-        -0.865_513_6,
-        -0.863_972_84,
-        -0.862_423_96,
-        -0.860_866_96,
-        -0.859_301_8,
-        -0.857_728_6,
-        -0.856_147_35,
-        -0.854_558,
-        -0.852_960_6,
-        -0.851_355_2,
-        -0.849_741_76,
-        -0.848_120_33,
-        -0.846_490_9,
-        -0.844_853_6,
-        -0.843_208_25,
-        -0.841_555,
-        -0.839_893_8,
-        -0.838_224_7,
-        -0.836_547_73,
-        -0.834_862_9,
-        -0.833_170_2,
-        -0.831_469_6,
-        -0.829_761_2,
-        -0.828_045_07,
-        -0.826_321_07,
-        -0.824_589_3,
-        -0.822_849_8,
-        -0.821_102_5,
-        -0.819_347_5,
-        -0.817_584_8,
-        -0.815_814_44,
-        -0.814_036_3,
-        -0.812_250_6,
-        -0.810_457_17,
-        -0.808_656_16,
-        -0.806_847_6,
-        -0.805_031_36,
-        -0.803_207_5,
-        -0.801_376_16,
-        -0.799_537_24,
-        -0.797_690_87,
-        -0.795_836_9,
-        -0.793_975_5,
-        -0.792_106_57,
-        -0.790_230_2,
-        -0.788_346_4,
-        -0.786_455_2,
-        -0.784_556_57,
-        -0.782_650_6,
-        -0.780_737_2,
-        -0.778_816_5,
-        -0.776_888_5,
-        -0.774_953_1,
-        -0.773_010_43,
-        -0.771_060_5,
-        -0.769_103_35,
-        -0.767_138_9,
-        -0.765_167_24,
-        -0.763_188_4,
-        -0.761_202_4,
-        -0.759_209_2,
-        -0.757_208_8,
-        -0.755_201_4,
-        -0.753_186_8,
-        -0.751_165_15,
-        -0.749_136_4,
-        -0.747_100_6,
-        -0.745_057_76,
-        -0.743_007_96,
-        -0.740_951_1,
-        -0.738_887_3,
-        -0.736_816_6,
-        -0.734_738_9,
-        -0.732_654_3,
-        -0.730_562_75,
-        -0.728_464_37,
-        -0.726_359_1,
-        -0.724_247_1,
-        -0.722_128_2,
-        -0.720_002_53,
-        -0.717_870_06,
-        -0.715_730_85,
-        -0.713_584_84,
-        -0.711_432_2,
-        -0.709_272_8,
-        -0.707_106_77,
-        -0.704_934_06,
-        -0.702_754_74,
-        -0.700_568_8,
-        -0.698_376_24,
-        -0.696_177_1,
-        -0.693_971_46,
-        -0.691_759_2,
-        -0.689_540_57,
-        -0.687_315_34,
-        -0.685_083_7,
-        -0.682_845_53,
-        -0.680_601,
-        -0.678_350_03,
-        -0.676_092_7,
-        -0.673_829,
-        -0.671_559,
-        -0.669_282_6,
-        -0.666_999_94,
-        -0.664_711,
-        -0.662_415_8,
-        -0.660_114_35,
-        -0.657_806_7,
-        -0.655_492_84,
-        -0.653_172_85,
-        -0.650_846_66,
-        -0.648_514_4,
-        -0.646_176_04,
-        -0.643_831_55,
-        -0.641_481_04,
-        -0.639_124_45,
-        -0.636_761_84,
-        -0.634_393_3,
-        -0.632_018_74,
-        -0.629_638_25,
-        -0.627_251_8,
-        -0.624_859_5,
-        -0.622_461_26,
-        -0.620_057_2,
-        -0.617_647_3,
-        -0.615_231_6,
-        -0.612_810_1,
-        -0.610_382_8,
-        -0.607_949_8,
-        -0.605_511_07,
-        -0.603_066_6,
-        -0.600_616_46,
-        -0.598_160_7,
-        -0.595_699_3,
-        -0.593_232_3,
-        -0.590_759_7,
-        -0.588_281_6,
-        -0.585_797_85,
-        -0.583_308_64,
-        -0.580_813_94,
-        -0.578_313_77,
-        -0.575_808_17,
-        -0.573_297_14,
-        -0.570_780_75,
-        -0.568_258_94,
-        -0.565_731_8,
-        -0.563_199_34,
-        -0.560_661_55,
-        -0.558_118_5,
-        -0.555_570_24,
-        -0.553_016_7,
-        -0.550_457_95,
-        -0.547_894_06,
-        -0.545_325,
-        -0.542_750_8,
-        -0.540_171_44,
-        -0.537_587_05,
-        -0.534_997_64,
-        -0.532_403_1,
-        -0.529_803_63,
-        -0.527_199_15,
-        -0.524_589_66,
-        -0.521_975_3,
-        -0.519_356,
-        -0.516_731_8,
-        -0.514_102_76,
-        -0.511_468_8,
-        -0.508_830_13,
-        -0.506_186_66,
-        -0.503_538_37,
-        -0.500_885_37,
-        -0.498_227_66,
-        -0.495_565_27,
-        -0.492_898_2,
-        -0.490_226_48,
-        -0.487_550_17,
-        -0.484_869_24,
-        -0.482_183_78,
-        -0.479_493_77,
-        -0.476_799_22,
-        -0.474_100_2,
-        -0.471_396_74,
-        -0.468_688_82,
-        -0.465_976_5,
-        -0.463_259_8,
-        -0.460_538_72,
-        -0.457_813_3,
-        -0.455_083_58,
-        -0.452_349_57,
-        -0.449_611_34,
-        -0.446_868_84,
-        -0.444_122_14,
-        -0.441_371_26,
-        -0.438_616_25,
-        -0.435_857_1,
-        -0.433_093_82,
-        -0.430_326_5,
-        -0.427_555_08,
-        -0.424_779_68,
-        -0.422_000_26,
-        -0.419_216_9,
-        -0.416_429_55,
-        -0.413_638_32,
-        -0.410_843_16,
-        -0.408_044_16,
-        -0.405_241_3,
-        -0.402_434_65,
-        -0.399_624_2,
-        -0.396_81,
-        -0.393_992_04,
-        -0.391_170_38,
-        -0.388_345_03,
-        -0.385_516_05,
-        -0.382_683_43,
-        -0.379_847_2,
-        -0.377_007_42,
-        -0.374_164_07,
-        -0.371_317_2,
-        -0.368_466_82,
-        -0.365_612_98,
-        -0.362_755_72,
-        -0.359_895_05,
-        -0.357_030_96,
-        -0.354_163_53,
-        -0.351_292_76,
-        -0.348_418_68,
-        -0.345_541_33,
-        -0.342_660_73,
-        -0.339_776_87,
-        -0.336_889_86,
-        -0.333_999_66,
-        -0.331_106_3,
-        -0.328_209_85,
-        -0.325_310_3,
-        -0.322_407_7,
-        -0.319_502_03,
-        -0.316_593_38,
-        -0.313_681_75,
-        -0.310_767_14,
-        -0.307_849_65,
-        -0.304_929_23,
-        -0.302_005_95,
-        -0.299_079_84,
-        -0.296_150_9,
-        -0.293_219_15,
-        -0.290_284_66,
-        -0.287_347_47,
-        -0.284_407_53,
-        -0.281_464_93,
-        -0.278_519_7,
-        -0.275_571_82,
-        -0.272_621_36,
-        -0.269_668_3,
-        -0.266_712_75,
-        -0.263_754_67,
-        -0.260_794_1,
-        -0.257_831_1,
-        -0.254_865_65,
-        -0.251_897_8,
-        -0.248_927_61,
-        -0.245_955_05,
-        -0.242_980_18,
-        -0.240_003_02,
-        -0.237_023_6,
-        -0.234_041_96,
-        -0.231_058_1,
-        -0.228_072_08,
-        -0.225_083_92,
-        -0.222_093_63,
-        -0.219_101_24,
-        -0.216_106_8,
-        -0.213_110_31,
-        -0.210_111_84,
-        -0.207_111_37,
-        -0.204_108_97,
-        -0.201_104_64,
-        -0.198_098_4,
-        -0.195_090_32,
-        -0.192_080_4,
-        -0.189_068_66,
-        -0.186_055_15,
-        -0.183_039_89,
-        -0.180_022_9,
-        -0.177_004_22,
-        -0.173_983_87,
-        -0.170_961_89,
-        -0.167_938_29,
-        -0.164_913_12,
-        -0.161_886_4,
-        -0.158_858_15,
-        -0.155_828_4,
-        -0.152_797_19,
-        -0.149_764_54,
-        -0.146_730_47,
-        -0.143_695_03,
-        -0.140_658_24,
-        -0.137_620_12,
-        -0.134_580_7,
-        -0.131_540_03,
-        -0.128_498_1,
-        -0.125_454_98,
-        -0.122_410_68,
-        -0.119_365_215,
-        -0.116_318_63,
-        -0.113_270_95,
-        -0.110_222_206,
-        -0.107_172_42,
-        -0.104_121_63,
-        -0.101_069_86,
-        -0.098_017_14,
-        -0.094_963_5,
-        -0.091_908_954,
-        -0.088_853_55,
-        -0.085_797_31,
-        -0.082_740_26,
-        -0.079_682_44,
-        -0.076_623_864,
-        -0.073_564_57,
-        -0.070_504_576,
-        -0.067_443_92,
-        -0.064_382_63,
-        -0.061_320_737,
-        -0.058_258_265,
-        -0.055_195_246,
-        -0.052_131_705,
-        -0.049_067_676,
-        -0.046_003_18,
-        -0.042_938_26,
-        -0.039_872_926,
-        -0.036_807_224,
-        -0.033_741_172,
-        -0.030_674_804,
-        -0.027_608_145,
-        -0.024_541_229,
-        -0.021_474_08,
-        -0.018_406_73,
-        -0.015_339_206,
-        -0.012_271_538,
-        -0.009_203_754,
-        -0.006_135_884_7,
-        -0.003_067_956_8,
+        Formant::new(400., 70., 0.),
+        Formant::new(1700., 80., -14.),
+        Formant::new(2600., 100., -12.),
     ],
     [
-        0.0,
-        0.001_953_125,
-        0.003_906_25,
-        0.005_859_375,
-        0.007_812_5,
-        0.009_765_625,
-        0.011_718_75,
-        0.013_671_875,
-        0.015_625,
-        0.017_578_125,
-        0.019_531_25,
-        0.021_484_375,
-        0.023_437_5,
-        0.025_390_625,
-        0.027_343_75,
-        0.029_296_875,
-        0.031_25,
-        0.033_203_125,
-        0.035_156_25,
-        0.037_109_375,
-        0.039_062_5,
-        0.041_015_625,
-        0.042_968_75,
-        0.044_921_875,
-        0.046_875,
-        0.048_828_125,
-        0.050_781_25,
-        0.052_734_375,
-        0.054_687_5,
-        0.056_640_625,
-        0.058_593_75,
-        0.060_546_875,
-        0.062_5,
-        0.064_453_125,
-        0.066_406_25,
-        0.068_359_375,
-        0.070_312_5,
-        0.072_265_625,
-        0.074_218_75,
-        0.076_171_875,
-        0.078_125,
-        0.080_078_125,
-        0.082_031_25,
-        0.083_984_375,
-        0.085_937_5,
-        0.087_890_625,
-        0.089_843_75,
-        0.091_796_875,
-        0.093_75,
-        0.095_703_125,
-        0.097_656_25,
-        0.099_609_375,
-        0.101_562_5,
-        0.103_515_625,
-        0.105_468_75,
-        0.107_421_875,
-        0.109_375,
-        0.111_328_125,
-        0.113_281_25,
-        0.115_234_375,
-        0.117_187_5,
-        0.119_140_625,
-        0.121_093_75,
-        0.123_046_875,
-        0.125,
-        0.126_953_13,
-        0.128_906_25,
-        0.130_859_38,
-        0.132_812_5,
-        0.134_765_63,
-        0.136_718_75,
-        0.138_671_88,
-        0.140_625,
-        0.142_578_13,
-        0.144_531_25,
-        0.146_484_38,
-        0.148_437_5,
-        0.150_390_63,
-        0.152_343_75,
-        0.154_296_88,
-        0.156_25,
-        0.158_203_13,
-        0.160_156_25,
-        0.162_109_38,
-        0.164_062_5,
-        0.166_015_63,
-        0.167_968_75,
-        0.169_921_88,
-        0.171_875,
-        0.173_828_13,
-        0.175_781_25,
-        0.177_734_38,
-        0.179_687_5,
-        0.181_640_63,
-        0.183_593_75,
-        0.185_546_88,
-        0.187_5,
-        0.189_453_13,
-        0.191_406_25,
-        0.193_359_38,
-        0.195_312_5,
-        0.197_265_63,
-        0.199_218_75,
-        0.201_171_88,
-        0.203_125,
-        0.205_078_13,
-        0.207_031_25,
-        0.208_984_38,
-        0.210_937_5,
-        0.212_890_63,
-        0.214_843_75,
-        0.216_796_88,
-        0.218_75,
-        0.220_703_13,
-        0.222_656_25,
-        0.224_609_38,
-        0.226_562_5,
-        0.228_515_63,
-        0.230_468_75,
-        0.232_421_88,
-        0.234_375,
-        0.236_328_13,
-        0.238_281_25,
-        0.240_234_38,
-        0.242_187_5,
-        0.244_140_63,
-        0.246_093_75,
-        0.248_046_88,
-        0.25,
-        0.251_953_13,
-        0.253_906_25,
-        0.255_859_38,
-        0.257_812_5,
-        0.259_765_63,
-        0.261_718_75,
-        0.263_671_88,
-        0.265_625,
-        0.267_578_13,
-        0.269_531_25,
-        0.271_484_38,
-        0.273_437_5,
-        0.275_390_63,
-        0.277_343_75,
-        0.279_296_88,
-        0.281_25,
-        0.283_203_13,
-        0.285_156_25,
-        0.287_109_38,
-        0.289_062_5,
-        0.291_015_63,
-        0.292_968_75,
-        0.294_921_88,
-        0.296_875,
-        0.298_828_13,
-        0.300_781_25,
-        0.302_734_38,
-        0.304_687_5,
-        0.306_640_63,
-        0.308_593_75,
-        0.310_546_88,
-        0.312_5,
-        0.314_453_13,
-        0.316_406_25,
-        0.318_359_38,
-        0.320_312_5,
-        0.322_265_63,
-        0.324_218_75,
-        0.326_171_88,
-        0.328_125,
-        0.330_078_13,
-        0.332_031_25,
-        0.333_984_38,
-        0.335_937_5,
-        0.337_890_63,
-        0.339_843_75,
-        0.341_796_88,
-        0.343_75,
-        0.345_703_13,
-        0.347_656_25,
-        0.349_609_38,
-        0.351_562_5,
-        0.353_515_63,
-        0.355_468_75,
-        0.357_421_88,
-        0.359_375,
-        0.361_328_13,
-        0.363_281_25,
-        0.365_234_38,
-        0.367_187_5,
-        0.369_140_63,
-        0.371_093_75,
-        0.373_046_88,
-        0.375,
-        0.376_953_13,
-        0.378_906_25,
-        0.380_859_38,
-        0.382_812_5,
-        0.384_765_63,
-        0.386_718_75,
-        0.388_671_88,
-        0.390_625,
-        0.392_578_13,
-        0.394_531_25,
-        0.396_484_38,
-        0.398_437_5,
-        0.400_390_63,
-        0.402_343_75,
-        0.404_296_88,
-        0.406_25,
-        0.408_203_13,
-        0.410_156_25,
-        0.412_109_38,
-        0.414_062_5,
-        0.416_015_63,
-        0.417_968_75,
-        0.419_921_88,
-        0.421_875,
-        0.423_828_13,
-        0.425_781_25,
-        0.427_734_38,
-        0.429_687_5,
-        0.431_640_63,
-        0.433_593_75,
-        0.435_546_88,
-        0.437_5,
-        0.439_453_13,
-        0.441_406_25,
-        0.443_359_38,
-        0.445_312_5,
-        0.447_265_63,
-        0.449_218_75,
-        0.451_171_88,
-        0.453_125,
-        0.455_078_13,
-        0.457_031_25,
-        0.458_984_38,
-        0.460_937_5,
-        0.462_890_63,
-        0.464_843_75,
-        0.466_796_88,
-        0.468_75,
-        0.470_703_13,
-        0.472_656_25,
-        0.474_609_38,
-        0.476_562_5,
-        0.478_515_63,
-        0.480_468_75,
-        0.482_421_88,
-        0.484_375,
-        0.486_328_13,
-        0.488_281_25,
-        0.490_234_38,
-        0.492_187_5,
-        0.494_140_63,
-        0.496_093_75,
-        0.498_046_88,
-        0.5,
-        0.501_953_1,
-        0.503_906_25,
-        0.505_859_4,
-        0.507_812_5,
-        0.509_765_6,
-        0.511_718_75,
-        0.513_671_9,
-        0.515_625,
-        0.517_578_1,
-        0.519_531_25,
-        0.521_484_4,
-        0.523_437_5,
-        0.525_390_6,
-        0.527_343_75,
-        0.529_296_9,
-        0.531_25,
-        0.533_203_1,
-        0.535_156_25,
-        0.537_109_4,
-        0.539_062_5,
-        0.541_015_6,
-        0.542_968_75,
-        0.544_921_9,
-        0.546_875,
-        0.548_828_1,
-        0.550_781_25,
-        0.552_734_4,
-        0.554_687_5,
-        0.556_640_6,
-        0.558_593_75,
-        0.560_546_9,
-        0.562_5,
-        0.564_453_1,
-        0.566_406_25,
-        0.568_359_4,
-        0.570_312_5,
-        0.572_265_6,
-        0.574_218_75,
-        0.576_171_9,
-        0.578_125,
-        0.580_078_1,
-        0.582_031_25,
-        0.583_984_4,
-        0.585_937_5,
-        0.587_890_6,
-        0.589_843_75,
-        0.591_796_9,
-        0.593_75,
-        0.595_703_1,
-        0.597_656_25,
-        0.599_609_4,
-        0.601_562_5,
-        0.603_515_6,
-        0.605_468_75,
-        0.607_421_9,
-        0.609_375,
-        0.611_328_1,
-        0.613_281_25,
-        0.615_234_4,
-        0.617_187_5,
-        0.619_140_6,
-        0.621_093_75,
-        0.623_046_9,
-        0.625,
-        0.626_953_1,
-        0.628_906_25,
-        0.630_859_4,
-        0.632_812_5,
-        0.634_765_6,
-        0.636_718_75,
-        0.638_671_9,
-        0.640_625,
-        0.642_578_1,
-        0.644_531_25,
-        0.646_484_4,
-        0.648_437_5,
-        0.650_390_6,
-        0.652_343_75,
-        0.654_296_9,
-        0.656_25,
-        0.658_203_1,
-        0.660_156_25,
-        0.662_109_4,
-        0.664_062_5,
-        0.666_015_6,
-        0.667_968_75,
-        0.669_921_9,
-        0.671_875,
-        0.673_828_1,
-        0.675_781_25,
-        0.677_734_4,
-        0.679_687_5,
-        0.681_640_6,
-        0.683_593_75,
-        0.685_546_9,
-        0.687_5,
-        0.689_453_1,
-        0.691_406_25,
-        0.693_359_4,
-        0.695_312_5,
-        0.697_265_6,
-        0.699_218_75,
-        0.701_171_9,
-        0.703_125,
-        0.705_078_1,
-        0.707_031_25,
-        0.708_984_4,
-        0.710_937_5,
-        0.712_890_6,
-        0.714_843_75,
-        0.716_796_9,
-        0.718_75,
-        0.720_703_1,
-        0.722_656_25,
-        0.724_609_4,
-        0.726_562_5,
-        0.728_515_6,
-        0.730_468_75,
-        0.732_421_9,
-        0.734_375,
-        0.736_328_1,
-        0.738_281_25,
-        0.740_234_4,
-        0.742_187_5,
-        0.744_140_6,
-        0.746_093_75,
-        0.748_046_9,
-        0.75,
-        0.751_953_1,
-        0.753_906_25,
-        0.755_859_4,
-        0.757_812_5,
-        0.759_765_6,
-        0.761_718_75,
-        0.763_671_9,
-        0.765_625,
-        0.767_578_1,
-        0.769_531_25,
-        0.771_484_4,
-        0.773_437_5,
-        0.775_390_6,
-        0.777_343_75,
-        0.779_296_9,
-        0.781_25,
-        0.783_203_1,
-        0.785_156_25,
-        0.787_109_4,
-        0.789_062_5,
-        0.791_015_6,
-        0.792_968_75,
-        0.794_921_9,
-        0.796_875,
-        0.798_828_1,
-        0.800_781_25,
-        0.802_734_4,
-        0.804_687_5,
-        0.806_640_6,
-        0.808_593_75,
-        0.810_546_9,
-        0.812_5,
-        0.814_453_1,
-        0.816_406_25,
-        0.818_359_4,
-        0.820_312_5,
-        0.822_265_6,
-        0.824_218_75,
-        0.826_171_9,
-        0.828_125,
-        0.830_078_1,
-        0.832_031_25,
-        0.833_984_4,
-        0.835_937_5,
-        0.837_890_6,
-        0.839_843_75,
-        0.841_796_9,
-        0.843_75,
-        0.845_703_1,
-        0.847_656_25,
-        0.849_609_4,
-        0.851_562_5,
-        0.853_515_6,
-        0.855_468_75,
-        0.857_421_9,
-        0.859_375,
-        0.861_328_1,
-        0.863_281_25,
-        0.865_234_4,
-        0.867_187_5,
-        0.869_140_6,
-        0.871_093_75,
-        0.873_046_9,
-        0.875,
-        0.876_953_1,
-        0.878_906_25,
-        0.880_859_4,
-        0.882_812_5,
-        0.884_765_6,
-        0.886_718_75,
-        0.888_671_9,
-        0.890_625,
-        0.892_578_1,
-        0.894_531_25,
-        0.896_484_4,
-        0.898_437_5,
-        0.900_390_7,
-        0.902_343_7,
-        0.904_296_93,
-        0.906_249_94,
-        0.908_203_2,
-        0.910_156_2,
-        0.912_109_43,
-        0.914_062_44,
-        0.916_015_7,
-        0.917_968_7,
-        0.919_921_93,
-        0.921_874_94,
-        0.923_828_2,
-        0.925_781_2,
-        0.927_734_43,
-        0.929_687_44,
-        0.931_640_7,
-        0.933_593_7,
-        0.935_546_93,
-        0.937_499_94,
-        0.939_453_2,
-        0.941_406_2,
-        0.943_359_5,
-        0.945_312_4,
-        0.947_265_74,
-        0.949_218_63,
-        0.951_172,
-        0.953_124_9,
-        0.955_078_3,
-        0.957_031_1,
-        0.958_984_55,
-        0.960_937_3,
-        0.962_890_86,
-        0.964_843_5,
-        0.966_797_2,
-        0.968_749_7,
-        0.970_703_5,
-        0.972_655_83,
-        0.974_609_85,
-        0.976_561_96,
-        0.978_516_3,
-        0.980_468,
-        0.982_422_8,
-        0.984_373_75,
-        0.986_329_73,
-        0.988_279_1,
-        0.990_237_4,
-        0.992_182_8,
-        0.994_148_6,
-        0.996_077_54,
-        0.998_093_07,
-        0.999_604_2,
-        0.998_093_07,
-        0.996_077_54,
-        0.994_148_6,
-        0.992_182_8,
-        0.990_237_4,
-        0.988_279_1,
-        0.986_329_73,
-        0.984_373_75,
-        0.982_422_8,
-        0.980_468,
-        0.978_516_3,
-        0.976_561_96,
-        0.974_609_85,
-        0.972_655_83,
-        0.970_703_5,
-        0.968_749_7,
-        0.966_797_2,
-        0.964_843_5,
-        0.962_890_86,
-        0.960_937_3,
-        0.958_984_55,
-        0.957_031_1,
-        0.955_078_3,
-        0.953_124_9,
-        0.951_172,
-        0.949_218_63,
-        0.947_265_74,
-        0.945_312_4,
-        0.943_359_5,
-        0.941_406_2,
-        0.939_453_2,
-        0.937_499_94,
-        0.935_546_93,
-        0.933_593_7,
-        0.931_640_7,
-        0.929_687_44,
-        0.927_734_43,
-        0.925_781_2,
-        0.923_828_2,
-        0.921_874_94,
-        0.919_921_93,
-        0.917_968_7,
-        0.916_015_7,
-        0.914_062_44,
-        0.912_109_43,
-        0.910_156_2,
-        0.908_203_2,
-        0.906_249_94,
-        0.904_296_93,
-        0.902_343_7,
-        0.900_390_7,
-        0.898_437_5,
-        0.896_484_4,
-        0.894_531_25,
-        0.892_578_1,
-        0.890_625,
-        0.888_671_9,
-        0.886_718_75,
-        0.884_765_6,
-        0.882_812_5,
-        0.880_859_4,
-        0.878_906_25,
-        0.876_953_1,
-        0.875,
-        0.873_046_9,
-        0.871_093_75,
-        0.869_140_6,
-        0.867_187_5,
-        0.865_234_4,
-        0.863_281_25,
-        0.861_328_1,
-        0.859_375,
-        0.857_421_9,
-        0.855_468_75,
-        0.853_515_6,
-        0.851_562_5,
-        0.849_609_4,
-        0.847_656_25,
-        0.845_703_1,
-        0.843_75,
-        0.841_796_9,
-        0.839_843_75,
-        0.837_890_6,
-        0.835_937_5,
-        0.833_984_4,
-        0.832_031_25,
-        0.830_078_1,
-        0.828_125,
-        0.826_171_9,
-        0.824_218_75,
-        0.822_265_6,
-        0.820_312_5,
-        0.818_359_4,
-        0.816_406_25,
-        0.814_453_1,
-        0.812_5,
-        0.810_546_9,
-        0.808_593_75,
-        0.806_640_6,
-        0.804_687_5,
-        0.802_734_4,
-        0.800_781_25,
-        0.798_828_1,
-        0.796_875,
-        0.794_921_9,
-        0.792_968_75,
-        0.791_015_6,
-        0.789_062_5,
-        0.787_109_4,
-        0.785_156_25,
-        0.783_203_1,
-        0.781_25,
-        0.779_296_9,
-        0.777_343_75,
-        0.775_390_6,
-        0.773_437_5,
-        0.771_484_4,
-        0.769_531_25,
-        0.767_578_1,
-        0.765_625,
-        0.763_671_9,
-        0.761_718_75,
-        0.759_765_6,
-        0.757_812_5,
-        0.755_859_4,
-        0.753_906_25,
-        0.751_953_1,
-        0.75,
-        0.748_046_9,
-        0.746_093_75,
-        0.744_140_6,
-        0.742_187_5,
-        0.740_234_4,
-        0.738_281_25,
-        0.736_328_1,
-        0.734_375,
-        0.732_421_9,
-        0.730_468_75,
-        0.728_515_6,
-        0.726_562_5,
-        0.724_609_4,
-        0.722_656_25,
-        0.720_703_1,
-        0.718_75,
-        0.716_796_9,
-        0.714_843_75,
-        0.712_890_6,
-        0.710_937_5,
-        0.708_984_4,
-        0.707_031_25,
-        0.705_078_1,
-        0.703_125,
-        0.701_171_9,
-        0.699_218_75,
-        0.697_265_6,
-        0.695_312_5,
-        0.693_359_4,
-        0.691_406_25,
-        0.689_453_1,
-        0.687_5,
-        0.685_546_9,
-        0.683_593_75,
-        0.681_640_6,
-        0.679_687_5,
-        0.677_734_4,
-        0.675_781_25,
-        0.673_828_1,
-        0.671_875,
-        0.669_921_9,
-        0.667_968_75,
-        0.666_015_6,
-        0.664_062_5,
-        0.662_109_4,
-        0.660_156_25,
-        0.658_203_1,
-        0.656_25,
-        0.654_296_9,
-        0.652_343_75,
-        0.650_390_6,
-        0.648_437_5,
-        0.646_484_4,
-        0.644_531_25,
-        0.642_578_1,
-        0.640_625,
-        0.638_671_9,
-        0.636_718_75,
-        0.634_765_6,
-        0.632_812_5,
-        0.630_859_4,
-        0.628_906_25,
-        0.626_953_1,
-        0.625,
-        0.623_046_9,
-        0.621_093_75,
-        0.619_140_6,
-        0.617_187_5,
-        0.615_234_4,
-        0.613_281_25,
-        0.611_328_1,
-        0.609_375,
-        0.607_421_9,
-        0.605_468_75,
-        0.603_515_6,
-        0.601_562_5,
-        0.599_609_4,
-        0.597_656_25,
-        0.595_703_1,
-        0.593_75,
-        0.591_796_9,
-        0.589_843_75,
-        0.587_890_6,
-        0.585_937_5,
-        0.583_984_4,
-        0.582_031_25,
-        0.580_078_1,
-        0.578_125,
-        0.576_171_9,
-        0.574_218_75,
-        0.572_265_6,
-        0.570_312_5,
-        0.568_359_4,
-        0.566_406_25,
-        0.564_453_1,
-        0.562_5,
-        0.560_546_9,
-        0.558_593_75,
-        0.556_640_6,
-        0.554_687_5,
-        0.552_734_4,
-        0.550_781_25,
-        0.548_828_1,
-        0.546_875,
-        0.544_921_9,
-        0.542_968_75,
-        0.541_015_6,
-        0.539_062_5,
-        0.537_109_4,
-        0.535_156_25,
-        0.533_203_1,
-        0.531_25,
-        0.529_296_9,
-        0.527_343_75,
-        0.525_390_6,
-        0.523_437_5,
-        0.521_484_4,
-        0.519_531_25,
-        0.517_578_1,
-        0.515_625,
-        0.513_671_9,
-        0.511_718_75,
-        0.509_765_6,
-        0.507_812_5,
-        0.505_859_4,
-        0.503_906_25,
-        0.501_953_1,
-        0.5,
-        0.498_046_88,
-        0.496_093_75,
-        0.494_140_63,
-        0.492_187_5,
-        0.490_234_38,
-        0.488_281_25,
-        0.486_328_13,
-        0.484_375,
-        0.482_421_88,
-        0.480_468_75,
-        0.478_515_63,
-        0.476_562_5,
-        0.474_609_38,
-        0.472_656_25,
-        0.470_703_13,
-        0.468_75,
-        0.466_796_88,
-        0.464_843_75,
-        0.462_890_63,
-        0.460_937_5,
-        0.458_984_38,
-        0.457_031_25,
-        0.455_078_13,
-        0.453_125,
-        0.451_171_88,
-        0.449_218_75,
-        0.447_265_63,
-        0.445_312_5,
-        0.443_359_38,
-        0.441_406_25,
-        0.439_453_13,
-        0.437_5,
-        0.435_546_88,
-        0.433_593_75,
-        0.431_640_63,
-        0.429_687_5,
-        0.427_734_38,
-        0.425_781_25,
-        0.423_828_13,
-        0.421_875,
-        0.419_921_88,
-        0.417_968_75,
-        0.416_015_63,
-        0.414_062_5,
-        0.412_109_38,
-        0.410_156_25,
-        0.408_203_13,
-        0.406_25,
-        0.404_296_88,
-        0.402_343_75,
-        0.400_390_63,
-        0.398_437_5,
-        0.396_484_38,
-        0.394_531_25,
-        0.392_578_13,
-        0.390_625,
-        0.388_671_88,
-        0.386_718_75,
-        0.384_765_63,
-        0.382_812_5,
-        0.380_859_38,
-        0.378_906_25,
-        0.376_953_13,
-        0.375,
-        0.373_046_88,
-        0.371_093_75,
-        0.369_140_63,
-        0.367_187_5,
-        0.365_234_38,
-        0.363_281_25,
-        0.361_328_13,
-        0.359_375,
-        0.357_421_88,
-        0.355_468_75,
-        0.353_515_63,
-        0.351_562_5,
-        0.349_609_38,
-        0.347_656_25,
-        0.345_703_13,
-        0.343_75,
-        0.341_796_88,
-        0.339_843_75,
-        0.337_890_63,
-        0.335_937_5,
-        0.333_984_38,
-        0.332_031_25,
-        0.330_078_13,
-        0.328_125,
-        0.326_171_88,
-        0.324_218_75,
-        0.322_265_63,
-        0.320_312_5,
-        0.318_359_38,
-        0.316_406_25,
-        0.314_453_13,
-        0.312_5,
-        0.310_546_88,
-        0.308_593_75,
-        0.306_640_63,
-        0.304_687_5,
-        0.302_734_38,
-        0.300_781_25,
-        0.298_828_13,
-        0.296_875,
-        0.294_921_88,
-        0.292_968_75,
-        0.291_015_63,
-        0.289_062_5,
-        0.287_109_38,
-        0.285_156_25,
-        0.283_203_13,
-        0.281_25,
-        0.279_296_88,
-        0.277_343_75,
-        0.275_390_63,
-        0.273_437_5,
-        0.271_484_38,
-        0.269_531_25,
-        0.267_578_13,
-        0.265_625,
-        0.263_671_88,
-        0.261_718_75,
-        0.259_765_63,
-        0.257_812_5,
-        0.255_859_38,
-        0.253_906_25,
-        0.251_953_13,
-        0.25,
-        0.248_046_88,
-        0.246_093_75,
-        0.244_140_63,
-        0.242_187_5,
-        0.240_234_38,
-        0.238_281_25,
-        0.236_328_13,
-        0.234_375,
-        0.232_421_88,
-        0.230_468_75,
-        0.228_515_63,
-        0.226_562_5,
-        0.224_609_38,
-        0.222_656_25,
-        0.220_703_13,
-        0.218_75,
-        0.216_796_88,
-        0.214_843_75,
-        0.212_890_63,
-        0.210_937_5,
-        0.208_984_38,
-        0.207_031_25,
-        0.205_078_13,
-        0.203_125,
-        0.201_171_88,
-        0.199_218_75,
-        0.197_265_63,
-        0.195_312_5,
-        0.193_359_38,
-        0.191_406_25,
-        0.189_453_13,
-        0.187_5,
-        0.185_546_88,
-        0.183_593_75,
-        0.181_640_63,
-        0.179_687_5,
-        0.177_734_38,
-        0.175_781_25,
-        0.173_828_13,
-        0.171_875,
-        0.169_921_88,
-        0.167_968_75,
-        0.166_015_63,
-        0.164_062_5,
-        0.162_109_38,
-        0.160_156_25,
-        0.158_203_13,
-        0.156_25,
-        0.154_296_88,
-        0.152_343_75,
-        0.150_390_63,
-        0.148_437_5,
-        0.146_484_38,
-        0.144_531_25,
-        0.142_578_13,
-        0.140_625,
-        0.138_671_88,
-        0.136_718_75,
-        0.134_765_63,
-        0.132_812_5,
-        0.130_859_38,
-        0.128_906_25,
-        0.126_953_13,
-        0.125,
-        0.123_046_875,
-        0.121_093_75,
-        0.119_140_625,
-        0.117_187_5,
-        0.115_234_375,
-        0.113_281_25,
-        0.111_328_125,
-        0.109_375,
-        0.107_421_875,
-        0.105_468_75,
-        0.103_515_625,
-        0.101_562_5,
-        0.099_609_375,
-        0.097_656_25,
-        0.095_703_125,
-        0.093_75,
-        0.091_796_875,
-        0.089_843_75,
-        0.087_890_625,
-        0.085_937_5,
-        0.083_984_375,
-        0.082_031_25,
-        0.080_078_125,
-        0.078_125,
-        0.076_171_875,
-        0.074_218_75,
-        0.072_265_625,
-        0.070_312_5,
-        0.068_359_375,
-        0.066_406_25,
-        0.064_453_125,
-        0.062_5,
-        0.060_546_875,
-        0.058_593_75,
-        0.056_640_625,
-        0.054_687_5,
-        0.052_734_375,
-        0.050_781_25,
-        0.048_828_125,
-        0.046_875,
-        0.044_921_875,
-        0.042_968_75,
-        0.041_015_625,
-        0.039_062_5,
-        0.037_109_375,
-        0.035_156_25,
-        0.033_203_125,
-        0.031_25,
-        0.029_296_875,
-        0.027_343_75,
-        0.025_390_625,
-        0.023_437_5,
-        0.021_484_375,
-        0.019_531_25,
-        0.017_578_125,
-        0.015_625,
-        0.013_671_875,
-        0.011_718_75,
-        0.009_765_625,
-        0.007_812_5,
-        0.005_859_375,
-        0.003_906_25,
-        0.001_953_125,
-        0.0,
-        -0.001_953_125,
-        -0.003_906_25,
-        -0.005_859_375,
-        -0.007_812_5,
-        -0.009_765_625,
-        -0.011_718_75,
-        -0.013_671_875,
-        -0.015_625,
-        -0.017_578_125,
-        -0.019_531_25,
-        -0.021_484_375,
-        -0.023_437_5,
-        -0.025_390_625,
-        -0.027_343_75,
-        -0.029_296_875,
-        -0.031_25,
-        -0.033_203_125,
-        -0.035_156_25,
-        -0.037_109_375,
-        -0.039_062_5,
-        -0.041_015_625,
-        -0.042_968_75,
-        -0.044_921_875,
-        -0.046_875,
-        -0.048_828_125,
-        -0.050_781_25,
-        -0.052_734_375,
-        -0.054_687_5,
-        -0.056_640_625,
-        -0.058_593_75,
-        -0.060_546_875,
-        -0.062_5,
-        -0.064_453_125,
-        -0.066_406_25,
-        -0.068_359_375,
-        -0.070_312_5,
-        -0.072_265_625,
-        -0.074_218_75,
-        -0.076_171_875,
-        -0.078_125,
-        -0.080_078_125,
-        -0.082_031_25,
-        -0.083_984_375,
-        -0.085_937_5,
-        -0.087_890_625,
-        -0.089_843_75,
-        -0.091_796_875,
-        -0.093_75,
-        -0.095_703_125,
-        -0.097_656_25,
-        -0.099_609_375,
-        -0.101_562_5,
-        -0.103_515_625,
-        -0.105_468_75,
-        -0.107_421_875,
-        -0.109_375,
-        -0.111_328_125,
-        -0.113_281_25,
-        -0.115_234_375,
-        -0.117_187_5,
-        -0.119_140_625,
-        -0.121_093_75,
-        -0.123_046_875,
-        -0.125,
-        -0.126_953_13,
-        -0.128_906_25,
-        -0.130_859_38,
-        -0.132_812_5,
-        -0.134_765_63,
-        -0.136_718_75,
-        -0.138_671_88,
-        -0.140_625,
-        -0.142_578_13,
-        -0.144_531_25,
-        -0.146_484_38,
-        -0.148_437_5,
-        -0.150_390_63,
-        -0.152_343_75,
-        -0.154_296_88,
-        -0.156_25,
-        -0.158_203_13,
-        -0.160_156_25,
-        -0.162_109_38,
-        -0.164_062_5,
-        -0.166_015_63,
-        -0.167_968_75,
-        -0.169_921_88,
-        -0.171_875,
-        -0.173_828_13,
-        -0.175_781_25,
-        -0.177_734_38,
-        -0.179_687_5,
-        -0.181_640_63,
-        -0.183_593_75,
-        -0.185_546_88,
-        -0.187_5,
-        -0.189_453_13,
-        -0.191_406_25,
-        -0.193_359_38,
-        -0.195_312_5,
-        -0.197_265_63,
-        -0.199_218_75,
-        -0.201_171_88,
-        -0.203_125,
-        -0.205_078_13,
-        -0.207_031_25,
-        -0.208_984_38,
-        -0.210_937_5,
-        -0.212_890_63,
-        -0.214_843_75,
-        -0.216_796_88,
-        -0.218_75,
-        -0.220_703_13,
-        -0.222_656_25,
-        -0.224_609_38,
-        -0.226_562_5,
-        -0.228_515_63,
-        -0.230_468_75,
-        -0.232_421_88,
-        -0.234_375,
-        -0.236_328_13,
-        -0.238_281_25,
-        -0.240_234_38,
-        -0.242_187_5,
-        -0.244_140_63,
-        -0.246_093_75,
-        -0.248_046_88,
-        -0.25,
-        -0.251_953_13,
-        -0.253_906_25,
-        -0.255_859_38,
-        -0.257_812_5,
-        -0.259_765_63,
-        -0.261_718_75,
-        -0.263_671_88,
-        -0.265_625,
-        -0.267_578_13,
-        -0.269_531_25,
-        -0.271_484_38,
-        -0.273_437_5,
-        -0.275_390_63,
-        -0.277_343_75,
-        -0.279_296_88,
-        -0.281_25,
-        -0.283_203_13,
-        -0.285_156_25,
-        -0.287_109_38,
-        -0.289_062_5,
-        -0.291_015_63,
-        -0.292_968_75,
-        -0.294_921_88,
-        -0.296_875,
-        -0.298_828_13,
-        -0.300_781_25,
-        -0.302_734_38,
-        -0.304_687_5,
-        -0.306_640_63,
-        -0.308_593_75,
-        -0.310_546_88,
-        -0.312_5,
-        -0.314_453_13,
-        -0.316_406_25,
-        -0.318_359_38,
-        -0.320_312_5,
-        -0.322_265_63,
-        -0.324_218_75,
-        -0.326_171_88,
-        -0.328_125,
-        -0.330_078_13,
-        -0.332_031_25,
-        -0.333_984_38,
-        -0.335_937_5,
-        -0.337_890_63,
-        -0.339_843_75,
-        -0.341_796_88,
-        -0.343_75,
-        -0.345_703_13,
-        -0.347_656_25,
-        -0.349_609_38,
-        -0.351_562_5,
-        -0.353_515_63,
-        -0.355_468_75,
-        -0.357_421_88,
-        -0.359_375,
-        -0.361_328_13,
-        -0.363_281_25,
-        -0.365_234_38,
-        -0.367_187_5,
-        -0.369_140_63,
-        -0.371_093_75,
-        -0.373_046_88,
-        -0.375,
-        -0.376_953_13,
-        -0.378_906_25,
-        -0.380_859_38,
-        -0.382_812_5,
-        -0.384_765_63,
-        -0.386_718_75,
-        -0.388_671_88,
-        -0.390_625,
-        -0.392_578_13,
-        -0.394_531_25,
-        -0.396_484_38,
-        -0.398_437_5,
-        -0.400_390_63,
-        -0.402_343_75,
-        -0.404_296_88,
-        -0.406_25,
-        -0.408_203_13,
-        -0.410_156_25,
-        -0.412_109_38,
-        -0.414_062_5,
-        -0.416_015_63,
-        -0.417_968_75,
-        -0.419_921_88,
-        -0.421_875,
-        -0.423_828_13,
-        -0.425_781_25,
-        -0.427_734_38,
-        -0.429_687_5,
-        -0.431_640_63,
-        -0.433_593_75,
-        -0.435_546_88,
-        -0.437_5,
-        -0.439_453_13,
-        -0.441_406_25,
-        -0.443_359_38,
-        -0.445_312_5,
-        -0.447_265_63,
-        -0.449_218_75,
-        -0.451_171_88,
-        -0.453_125,
-        -0.455_078_13,
-        -0.457_031_25,
-        -0.458_984_38,
-        -0.460_937_5,
-        -0.462_890_63,
-        -0.464_843_75,
-        -0.466_796_88,
-        -0.468_75,
-        -0.470_703_13,
-        -0.472_656_25,
-        -0.474_609_38,
-        -0.476_562_5,
-        -0.478_515_63,
-        -0.480_468_75,
-        -0.482_421_88,
-        -0.484_375,
-        -0.486_328_13,
-        -0.488_281_25,
-        -0.490_234_38,
-        -0.492_187_5,
-        -0.494_140_63,
-        -0.496_093_75,
-        -0.498_046_88,
-        -0.5,
-        -0.501_953_1,
-        -0.503_906_25,
-        -0.505_859_4,
-        -0.507_812_5,
-        -0.509_765_6,
-        -0.511_718_75,
-        -0.513_671_9,
-        -0.515_625,
-        -0.517_578_1,
-        -0.519_531_25,
-        -0.521_484_4,
-        -0.523_437_5,
-        -0.525_390_6,
-        -0.527_343_75,
-        -0.529_296_9,
-        -0.531_25,
-        -0.533_203_1,
-        -0.535_156_25,
-        -0.537_109_4,
-        -0.539_062_5,
-        -0.541_015_6,
-        -0.542_968_75,
-        -0.544_921_9,
-        -0.546_875,
-        -0.548_828_1,
-        -0.550_781_25,
-        -0.552_734_4,
-        -0.554_687_5,
-        -0.556_640_6,
-        -0.558_593_75,
-        -0.560_546_9,
-        -0.562_5,
-        -0.564_453_1,
-        -0.566_406_25,
-        -0.568_359_4,
-        -0.570_312_5,
-        -0.572_265_6,
-        -0.574_218_75,
-        -0.576_171_9,
-        -0.578_125,
-        -0.580_078_1,
-        -0.582_031_25,
-        -0.583_984_4,
-        -0.585_937_5,
-        -0.587_890_6,
-        -0.589_843_75,
-        -0.591_796_9,
-        -0.593_75,
-        -0.595_703_1,
-        -0.597_656_25,
-        -0.599_609_4,
-        -0.601_562_5,
-        -0.603_515_6,
-        -0.605_468_75,
-        -0.607_421_9,
-        -0.609_375,
-        -0.611_328_1,
-        -0.613_281_25,
-        -0.615_234_4,
-        -0.617_187_5,
-        -0.619_140_6,
-        -0.621_093_75,
-        -0.623_046_9,
-        -0.625,
-        -0.626_953_1,
-        -0.628_906_25,
-        -0.630_859_4,
-        -0.632_812_5,
-        -0.634_765_6,
-        -0.636_718_75,
-        -0.638_671_9,
-        -0.640_625,
-        -0.642_578_1,
-        -0.644_531_25,
-        -0.646_484_4,
-        -0.648_437_5,
-        -0.650_390_6,
-        -0.652_343_75,
-        -0.654_296_9,
-        -0.656_25,
-        -0.658_203_1,
-        -0.660_156_25,
-        -0.662_109_4,
-        -0.664_062_5,
-        -0.666_015_6,
-        -0.667_968_75,
-        -0.669_921_9,
-        -0.671_875,
-        -0.673_828_1,
-        -0.675_781_25,
-        -0.677_734_4,
-        -0.679_687_5,
-        -0.681_640_6,
-        -0.683_593_75,
-        -0.685_546_9,
-        -0.687_5,
-        -0.689_453_1,
-        -0.691_406_25,
-        -0.693_359_4,
-        -0.695_312_5,
-        -0.697_265_6,
-        -0.699_218_75,
-        -0.701_171_9,
-        -0.703_125,
-        -0.705_078_1,
-        -0.707_031_25,
-        -0.708_984_4,
-        -0.710_937_5,
-        -0.712_890_6,
-        -0.714_843_75,
-        -0.716_796_9,
-        -0.718_75,
-        -0.720_703_1,
-        -0.722_656_25,
-        -0.724_609_4,
-        -0.726_562_5,
-        -0.728_515_6,
-        -0.730_468_75,
-        -0.732_421_9,
-        -0.734_375,
-        -0.736_328_1,
-        -0.738_281_25,
-        -0.740_234_4,
-        -0.742_187_5,
-        -0.744_140_6,
-        -0.746_093_75,
-        -0.748_046_9,
-        -0.75,
-        -0.751_953_1,
-        -0.753_906_25,
-        -0.755_859_4,
-        -0.757_812_5,
-        -0.759_765_6,
-        -0.761_718_75,
-        -0.763_671_9,
-        -0.765_625,
-        -0.767_578_1,
-        -0.769_531_25,
-        -0.771_484_4,
-        -0.773_437_5,
-        -0.775_390_6,
-        -0.777_343_75,
-        -0.779_296_9,
-        -0.781_25,
-        -0.783_203_1,
-        -0.785_156_25,
-        -0.787_109_4,
-        -0.789_062_5,
-        -0.791_015_6,
-        -0.792_968_75,
-        -0.794_921_9,
-        -0.796_875,
-        -0.798_828_1,
-        -0.800_781_25,
-        -0.802_734_4,
-        -0.804_687_5,
-        -0.806_640_6,
-        -0.808_593_75,
-        -0.810_546_9,
-        -0.812_5,
-        -0.814_453_1,
-        -0.816_406_25,
-        -0.818_359_4,
-        -0.820_312_5,
-        -0.822_265_6,
-        -0.824_218_75,
-        -0.826_171_9,
-        -0.828_125,
-        -0.830_078_1,
-        -0.832_031_25,
-        -0.833_984_4,
-        -0.835_937_5,
-        -0.837_890_6,
-        -0.839_843_75,
-        -0.841_796_9,
-        -0.843_75,
-        -0.845_703_1,
-        -0.847_656_25,
-        -0.849_609_4,
-        -0.851_562_5,
-        -0.853_515_6,
-        -0.855_468_75,
-        -0.857_421_9,
-        -0.859_375,
-        -0.861_328_1,
-        -0.863_281_25,
-        -0.865_234_4,
-        -0.867_187_5,
-        -0.869_140_6,
-        -0.871_093_75,
-        -0.873_046_9,
-        -0.875,
-        -0.876_953_1,
-        -0.878_906_25,
-        -0.880_859_4,
-        -0.882_812_5,
-        -0.884_765_6,
-        -0.886_718_75,
-        -0.888_671_9,
-        -0.890_625,
-        -0.892_578_1,
-        -0.894_531_25,
-        -0.896_484_4,
-        -0.898_437_5,
-        -0.900_390_7,
-        -0.902_343_7,
-        -0.904_296_93,
-        -0.906_249_94,
-        -0.908_203_2,
-        -0.910_156_2,
-        -0.912_109_43,
-        -0.914_062_44,
-        -0.916_015_7,
-        -0.917_968_7,
-        -0.919_921_93,
-        -0.921_874_94,
-        -0.923_828_2,
-        -0.925_781_2,
-        -0.927_734_43,
-        -0.929_687_44,
-        -0.931_640_7,
-        -0.933_593_7,
-        -0.935_546_93,
-        -0.937_499_94,
-        -0.939_453_2,
-        -0.941_406_2,
-        -0.943_359_5,
-        -0.945_312_4,
-        -0.947_265_74,
-        -0.949_218_63,
-        -0.951_172,
-        -0.953_124_9,
-        -0.955_078_3,
-        -0.957_031_1,
-        -0.958_984_55,
-        -0.960_937_3,
-        -0.962_890_86,
-        -0.964_843_5,
-        -0.966_797_2,
-        -0.968_749_7,
-        -0.970_703_5,
-        -0.972_655_83,
-        -0.974_609_85,
-        -0.976_561_96,
-        -0.978_516_3,
-        -0.980_468,
-        -0.982_422_8,
-        -0.984_373_75,
-        -0.986_329_73,
-        -0.988_279_1,
-        -0.990_237_4,
-        -0.992_182_8,
-        -0.994_148_6,
-        -0.996_077_54,
-        -0.998_093_07,
-        -0.999_604_2,
-        -0.998_093_07,
-        -0.996_077_54,
-        -0.994_148_6,
-        -0.992_182_8,
-        -0.990_237_4,
-        -0.988_279_1,
-        -0.986_329_73,
-        -0.984_373_75,
-        -0.982_422_8,
-        -0.980_468,
-        -0.978_516_3,
-        -0.976_561_96,
-        -0.974_609_85,
-        -0.972_655_83,
-        -0.970_703_5,
-        -0.968_749_7,
-        -0.966_797_2,
-        -0.964_843_5,
-        -0.962_890_86,
-        -0.960_937_3,
-        -0.958_984_55,
-        -0.957_031_1,
-        -0.955_078_3,
-        -0.953_124_9,
-        -0.951_172,
-        -0.949_218_63,
-        -0.947_265_74,
-        -0.945_312_4,
-        -0.943_359_5,
-        -0.941_406_2,
-        -0.939_453_2,
-        -0.937_499_94,
-        -0.935_546_93,
-        -0.933_593_7,
-        -0.931_640_7,
-        -0.929_687_44,
-        -0.927_734_43,
-        -0.925_781_2,
-        -0.923_828_2,
-        -0.921_874_94,
-        -0.919_921_93,
-        -0.917_968_7,
-        -0.916_015_7,
-        -0.914_062_44,
-        -0.912_109_43,
-        -0.910_156_2,
-        -0.908_203_2,
-        -0.906_249_94,
-        -0.904_296_93,
-        -0.902_343_7,
-        -0.900_390_7,
-        -0.898_437_5,
-        -0.896_484_4,
-        -0.894_531_25,
-        -0.892_578_1,
-        -0.890_625,
-        -0.888_671_9,
-        -0.886_718_75,
-        -0.884_765_6,
-        -0.882_812_5,
-        -0.880_859_4,
-        -0.878_906_25,
-        -0.876_953_1,
-        -0.875,
-        -0.873_046_9,
-        -0.871_093_75,
-        -0.869_140_6,
-        -0.867_187_5,
-        -0.865_234_4,
-        -0.863_281_25,
-        -0.861_328_1,
-        -0.859_375,
-        -0.857_421_9,
-        -0.855_468_75,
-        -0.853_515_6,
-        -0.851_562_5,
-        -0.849_609_4,
-        -0.847_656_25,
-        -0.845_703_1,
-        -0.843_75,
-        -0.841_796_9,
-        -0.839_843_75,
-        -0.837_890_6,
-        -0.835_937_5,
-        -0.833_984_4,
-        -0.832_031_25,
-        -0.830_078_1,
-        -0.828_125,
-        -0.826_171_9,
-        -0.824_218_75,
-        -0.822_265_6,
-        -0.820_312_5,
-        -0.818_359_4,
-        -0.816_406_25,
-        -0.814_453_1,
-        -0.812_5,
-        -0.810_546_9,
-        -0.808_593_75,
-        -0.806_640_6,
-        -0.804_687_5,
-        -0.802_734_4,
-        -0.800_781_25,
-        -0.798_828_1,
-        -0.796_875,
-        -0.794_921_9,
-        -0.792_968_75,
-        -0.791_015_6,
-        -0.789_062_5,
-        -0.787_109_4,
-        -0.785_156_25,
-        -0.783_203_1,
-        -0.781_25,
-        -0.779_296_9,
-        -0.777_343_75,
-        -0.775_390_6,
-        -0.773_437_5,
-        -0.771_484_4,
-        -0.769_531_25,
-        -0.767_578_1,
-        -0.765_625,
-        -0.763_671_9,
-        -0.761_718_75,
-        -0.759_765_6,
-        -0.757_812_5,
-        -0.755_859_4,
-        -0.753_906_25,
-        -0.751_953_1,
-        -0.75,
-        -0.748_046_9,
-        -0.746_093_75,
-        -0.744_140_6,
-        -0.742_187_5,
-        -0.740_234_4,
-        -0.738_281_25,
-        -0.736_328_1,
-        -0.734_375,
-        -0.732_421_9,
-        -0.730_468_75,
-        -0.728_515_6,
-        -0.726_562_5,
-        -0.724_609_4,
-        -0.722_656_25,
-        -0.720_703_1,
-        -0.718_75,
-        -0.716_796_9,
-        -0.714_843_75,
-        -0.712_890_6,
-        -0.710_937_5,
-        -0.708_984_4,
-        -0.707_031_25,
-        -0.705_078_1,
-        -0.703_125,
-        -0.701_171_9,
-        -0.699_218_75,
-        -0.697_265_6,
-        -0.695_312_5,
-        -0.693_359_4,
-        -0.691_406_25,
-        -0.689_453_1,
-        -0.687_5,
-        -0.685_546_9,
-        -0.683_593_75,
-        -0.681_640_6,
-        -0.679_687_5,
-        -0.677_734_4,
-        -0.675_781_25,
-        -0.673_828_1,
-        -0.671_875,
-        -0.669_921_9,
-        -0.667_968_75,
-        -0.666_015_6,
-        -0.664_062_5,
-        -0.662_109_4,
-        -0.660_156_25,
-        -0.658_203_1,
-        -0.656_25,
-        -0.654_296_9,
-        -0.652_343_75,
-        -0.650_390_6,
-        -0.648_437_5,
-        -0.646_484_4,
-        -0.644_531_25,
-        -0.642_578_1,
-        -0.640_625,
-        -0.638_671_9,
-        -0.636_718_75,
-        -0.634_765_6,
-        -0.632_812_5,
-        -0.630_859_4,
-        -0.628_906_25,
-        -0.626_953_1,
-        -0.625,
-        -0.623_046_9,
-        -0.621_093_75,
-        -0.619_140_6,
-        -0.617_187_5,
-        -0.615_234_4,
-        -0.613_281_25,
-        -0.611_328_1,
-        -0.609_375,
-        -0.607_421_9,
-        -0.605_468_75,
-        -0.603_515_6,
-        -0.601_562_5,
-        -0.599_609_4,
-        -0.597_656_25,
-        -0.595_703_1,
-        -0.593_75,
-        -0.591_796_9,
-        -0.589_843_75,
-        -0.587_890_6,
-        -0.585_937_5,
-        -0.583_984_4,
-        -0.582_031_25,
-        -0.580_078_1,
-        -0.578_125,
-        -0.576_171_9,
-        -0.574_218_75,
-        -0.572_265_6,
-        -0.570_312_5,
-        -0.568_359_4,
-        -0.566_406_25,
-        -0.564_453_1,
-        -0.562_5,
-        -0.560_546_9,
-        -0.558_593_75,
-        -0.556_640_6,
-        -0.554_687_5,
-        -0.552_734_4,
-        -0.550_781_25,
-        -0.548_828_1,
-        -0.546_875,
-        -0.544_921_9,
-        -0.542_968_75,
-        -0.541_015_6,
-        -0.539_062_5,
-        -0.537_109_4,
-        -0.535_156_25,
-        -0.533_203_1,
-        -0.531_25,
-        -0.529_296_9,
-        -0.527_343_75,
-        -0.525_390_6,
-        -0.523_437_5,
-        -0.521_484_4,
-        -0.519_531_25,
-        -0.517_578_1,
-        -0.515_625,
-        -0.513_671_9,
-        -0.511_718_75,
-        -0.509_765_6,
-        -0.507_812_5,
-        -0.505_859_4,
-        -0.503_906_25,
-        -0.501_953_1,
-        -0.5,
-        -0.498_046_88,
-        -0.496_093_75,
-        -0.494_140_63,
-        -0.492_187_5,
-        -0.490_234_38,
-        -0.488_281_25,
-        -0.486_328_13,
-        -0.484_375,
-        -0.482_421_88,
-        -0.480_468_75,
-        -0.478_515_63,
-        -0.476_562_5,
-        -0.474_609_38,
-        -0.472_656_25,
-        -0.470_703_13,
-        -0.468_75,
-        -0.466_796_88,
-        -0.464_843_75,
-        -0.462_890_63,
-        -0.460_937_5,
-        -0.458_984_38,
-        -0.457_031_25,
-        -0.455_078_13,
-        -0.453_125,
-        -0.451_171_88,
-        -0.449_218_75,
-        -0.447_265_63,
-        -0.445_312_5,
-        -0.443_359_38,
-        -0.441_406_25,
-        -0.439_453_13,
-        -0.437_5,
-        -0.435_546_88,
-        -0.433_593_75,
-        -0.431_640_63,
-        -0.429_687_5,
-        -0.427_734_38,
-        -0.425_781_25,
-        -0.423_828_13,
-        -0.421_875,
-        -0.419_921_88,
-        -0.417_968_75,
-        -0.416_015_63,
-        -0.414_062_5,
-        -0.412_109_38,
-        -0.410_156_25,
-        -0.408_203_13,
-        -0.406_25,
-        -0.404_296_88,
-        -0.402_343_75,
-        -0.400_390_63,
-        -0.398_437_5,
-        -0.396_484_38,
-        -0.394_531_25,
-        -0.392_578_13,
-        -0.390_625,
-        -0.388_671_88,
-        -0.386_718_75,
-        -0.384_765_63,
-        -0.382_812_5,
-        -0.380_859_38,
-        -0.378_906_25,
-        -0.376_953_13,
-        -0.375,
-        -0.373_046_88,
-        -0.371_093_75,
-        -0.369_140_63,
-        -0.367_187_5,
-        -0.365_234_38,
-        -0.363_281_25,
-        -0.361_328_13,
-        -0.359_375,
-        -0.357_421_88,
-        -0.355_468_75,
-        -0.353_515_63,
-        -0.351_562_5,
-        -0.349_609_38,
-        -0.347_656_25,
-        -0.345_703_13,
-        -0.343_75,
-        -0.341_796_88,
-        -0.339_843_75,
-        -0.337_890_63,
-        -0.335_937_5,
-        -0.333_984_38,
-        -0.332_031_25,
-        -0.330_078_13,
-        -0.328_125,
-        -0.326_171_88,
-        -0.324_218_75,
-        -0.322_265_63,
-        -0.320_312_5,
-        -0.318_359_38,
-        -0.316_406_25,
-        -0.314_453_13,
-        -0.312_5,
-        -0.310_546_88,
-        -0.308_593_75,
-        -0.306_640_63,
-        -0.304_687_5,
-        -0.302_734_38,
-        -0.300_781_25,
-        -0.298_828_13,
-        -0.296_875,
-        -0.294_921_88,
-        -0.292_968_75,
-        -0.291_015_63,
-        -0.289_062_5,
-        -0.287_109_38,
-        -0.285_156_25,
-        -0.283_203_13,
-        -0.281_25,
-        -0.279_296_88,
-        -0.277_343_75,
-        -0.275_390_63,
-        -0.273_437_5,
-        -0.271_484_38,
-        -0.269_531_25,
-        -0.267_578_13,
-        -0.265_625,
-        -0.263_671_88,
-        -0.261_718_75,
-        -0.259_765_63,
-        -0.257_812_5,
-        -0.255_859_38,
-        -0.253_906_25,
-        -0.251_953_13,
-        -0.25,
-        -0.248_046_88,
-        -0.246_093_75,
-        -0.244_140_63,
-        -0.242_187_5,
-        -0.240_234_38,
-        -0.238_281_25,
-        -0.236_328_13,
-        -0.234_375,
-        -0.232_421_88,
-        -0.230_468_75,
-        -0.228_515_63,
-        -0.226_562_5,
-        -0.224_609_38,
-        -0.222_656_25,
-        -0.220_703_13,
-        -0.218_75,
-        -0.216_796_88,
-        -0.214_843_75,
-        -0.212_890_63,
-        -0.210_937_5,
-        -0.208_984_38,
-        -0.207_031_25,
-        -0.205_078_13,
-        -0.203_125,
-        -0.201_171_88,
-        -0.199_218_75,
-        -0.197_265_63,
-        -0.195_312_5,
-        -0.193_359_38,
-        -0.191_406_25,
-        -0.189_453_13,
-        -0.187_5,
-        -0.185_546_88,
-        -0.183_593_75,
-        -0.181_640_63,
-        -0.179_687_5,
-        -0.177_734_38,
-        -0.175_781_25,
-        -0.173_828_13,
-        -0.171_875,
-        -0.169_921_88,
-        -0.167_968_75,
-        -0.166_015_63,
-        -0.164_062_5,
-        -0.162_109_38,
-        -0.160_156_25,
-        -0.158_203_13,
-        -0.156_25,
-        -0.154_296_88,
-        -0.152_343_75,
-        -0.150_390_63,
-        -0.148_437_5,
-        -0.146_484_38,
-        -0.144_531_25,
-        -0.142_578_13,
-        -0.140_625,
-        -0.138_671_88,
-        -0.136_718_75,
-        -0.134_765_63,
-        -0.132_812_5,
-        -0.130_859_38,
-        -0.128_906_25,
-        -0.126_953_13,
-        -0.125,
-        -0.123_046_875,
-        -0.121_093_75,
-        -0.119_140_625,
-        -0.117_187_5,
-        -0.115_234_375,
-        -0.113_281_25,
-        -0.111_328_125,
-        -0.109_375,
-        -0.107_421_875,
-        -0.105_468_75,
-        -0.103_515_625,
-        -0.101_562_5,
-        -0.099_609_375,
-        -0.097_656_25,
-        -0.095_703_125,
-        -0.093_75,
-        -0.091_796_875,
-        -0.089_843_75,
-        -0.087_890_625,
-        -0.085_937_5,
-        -0.083_984_375,
-        -0.082_031_25,
-        -0.080_078_125,
-        -0.078_125,
-        -0.076_171_875,
-        -0.074_218_75,
-        -0.072_265_625,
-        -0.070_312_5,
-        -0.068_359_375,
-        -0.066_406_25,
-        -0.064_453_125,
-        -0.062_5,
-        -0.060_546_875,
-        -0.058_593_75,
-        -0.056_640_625,
-        -0.054_687_5,
-        -0.052_734_375,
-        -0.050_781_25,
-        -0.048_828_125,
-        -0.046_875,
-        -0.044_921_875,
-        -0.042_968_75,
-        -0.041_015_625,
-        -0.039_062_5,
-        -0.037_109_375,
-        -0.035_156_25,
-        -0.033_203_125,
-        -0.031_25,
-        -0.029_296_875,
-        -0.027_343_75,
-        -0.025_390_625,
-        -0.023_437_5,
-        -0.021_484_375,
-        -0.019_531_25,
-        -0.017_578_125,
-        -0.015_625,
-        -0.013_671_875,
-        -0.011_718_75,
-        -0.009_765_625,
-        -0.007_812_5,
-        -0.005_859_375,
-        -0.003_906_25,
-        -0.001_953_125,
+        Formant::new(290., 40., 0.),
+        Formant::new(1870., 90., -15.),
+        Formant::new(2800., 100., -18.),
     ],
     [
-        0.0,
-        0.000_977_039_3,
-        0.001_952_171_3,
-        0.002_931_118,
-        0.003_904_342_7,
-        0.004_885_196_7,
-        0.005_856_514,
-        0.006_839_275_4,
-        0.007_808_685_3,
-        0.008_793_354,
-        0.009_760_857,
-        0.010_747_434,
-        0.011_713_027,
-        0.012_701_512,
-        0.013_665_198,
-        0.014_655_591,
-        0.015_617_369,
-        0.016_609_67,
-        0.017_569_54,
-        0.018_563_75,
-        0.019_521_71,
-        0.020_517_83,
-        0.021_473_88,
-        0.022_471_908,
-        0.023_426_05,
-        0.024_425_989,
-        0.025_378_22,
-        0.026_380_07,
-        0.027_330_391,
-        0.028_334_15,
-        0.029_282_56,
-        0.030_288_23,
-        0.031_234_728,
-        0.032_242_313,
-        0.033_186_898,
-        0.034_196_39,
-        0.035_139_065,
-        0.036_150_474,
-        0.037_091_233,
-        0.038_104_557,
-        0.039_043_404,
-        0.040_058_64,
-        0.040_995_57,
-        0.042_012_72,
-        0.042_947_736,
-        0.043_966_804,
-        0.044_899_903,
-        0.045_920_886,
-        0.046_852_07,
-        0.047_874_972,
-        0.048_804_235,
-        0.049_829_055,
-        0.050_756_402,
-        0.051_783_14,
-        0.052_708_566,
-        0.053_737_227,
-        0.054_660_73,
-        0.055_691_313,
-        0.056_612_894,
-        0.057_645_4,
-        0.058_565_058,
-        0.059_599_485,
-        0.060_517_22,
-        0.061_553_57,
-        0.062_469_386,
-        0.063_507_66,
-        0.064_421_55,
-        0.065_461_75,
-        0.066_373_706,
-        0.067_415_84,
-        0.068_325_87,
-        0.069_369_93,
-        0.070_278_026,
-        0.071_324_02,
-        0.072_230_19,
-        0.073_278_11,
-        0.074_182_35,
-        0.075_232_2,
-        0.076_134_5,
-        0.077_186_294,
-        0.078_086_66,
-        0.079_140_39,
-        0.080_038_816,
-        0.081_094_48,
-        0.081_990_97,
-        0.083_048_575,
-        0.083_943_13,
-        0.085_002_67,
-        0.085_895_285,
-        0.086_956_77,
-        0.087_847_434,
-        0.088_910_86,
-        0.089_799_59,
-        0.090_864_964,
-        0.091_751_74,
-        0.092_819_06,
-        0.093_703_89,
-        0.094_773_16,
-        0.095_656_04,
-        0.096_727_26,
-        0.097_608_19,
-        0.098_681_36,
-        0.099_560_335,
-        0.100_635_46,
-        0.101_512_484,
-        0.102_589_57,
-        0.103_464_63,
-        0.104_543_67,
-        0.105_416_775,
-        0.106_497_78,
-        0.107_368_916,
-        0.108_451_88,
-        0.109_321_065,
-        0.110_405_99,
-        0.111_273_21,
-        0.112_360_1,
-        0.113_225_35,
-        0.114_314_206,
-        0.115_177_48,
-        0.116_268_32,
-        0.117_129_624,
-        0.118_222_43,
-        0.119_081_765,
-        0.120_176_55,
-        0.121_033_9,
-        0.122_130_66,
-        0.122_986_03,
-        0.124_084_77,
-        0.124_938_17,
-        0.126_038_9,
-        0.126_890_3,
-        0.127_993,
-        0.128_842_43,
-        0.129_947_13,
-        0.130_794_57,
-        0.131_901_25,
-        0.132_746_7,
-        0.133_855_37,
-        0.134_698_82,
-        0.135_809_5,
-        0.136_650_95,
-        0.137_763_62,
-        0.138_603_08,
-        0.139_717_74,
-        0.140_555_2,
-        0.141_671_87,
-        0.142_507_31,
-        0.143_625_99,
-        0.144_459_44,
-        0.145_580_13,
-        0.146_411_55,
-        0.147_534_25,
-        0.148_363_68,
-        0.149_488_39,
-        0.150_315_79,
-        0.151_442_53,
-        0.152_267_9,
-        0.153_396_65,
-        0.154_220_03,
-        0.155_350_79,
-        0.156_172_14,
-        0.157_304_93,
-        0.158_124_25,
-        0.159_259_07,
-        0.160_076_35,
-        0.161_213_22,
-        0.162_028_46,
-        0.163_167_36,
-        0.163_980_57,
-        0.165_121_5,
-        0.165_932_67,
-        0.167_075_65,
-        0.167_884_78,
-        0.169_029_8,
-        0.169_836_88,
-        0.170_983_96,
-        0.171_788_98,
-        0.172_938_1,
-        0.173_741_07,
-        0.174_892_25,
-        0.175_693_17,
-        0.176_846_41,
-        0.177_645_27,
-        0.178_800_57,
-        0.179_597_35,
-        0.180_754_72,
-        0.181_549_44,
-        0.182_708_89,
-        0.183_501_53,
-        0.184_663_04,
-        0.185_453_62,
-        0.186_617_21,
-        0.187_405_7,
-        0.188_571_38,
-        0.189_357_79,
-        0.190_525_55,
-        0.191_309_87,
-        0.192_479_71,
-        0.193_261_95,
-        0.194_433_88,
-        0.195_214_02,
-        0.196_388_07,
-        0.197_166_1,
-        0.198_342_23,
-        0.199_118_17,
-        0.200_296_42,
-        0.201_070_23,
-        0.202_250_6,
-        0.203_022_32,
-        0.204_204_78,
-        0.204_974_37,
-        0.206_158_97,
-        0.206_926_44,
-        0.208_113_15,
-        0.208_878_5,
-        0.210_067_35,
-        0.210_830_55,
-        0.212_021_53,
-        0.212_782_62,
-        0.213_975_73,
-        0.214_734_67,
-        0.215_929_93,
-        0.216_686_73,
-        0.217_884_12,
-        0.218_638_78,
-        0.219_838_32,
-        0.220_590_83,
-        0.221_792_53,
-        0.222_542_87,
-        0.223_746_73,
-        0.224_494_92,
-        0.225_700_94,
-        0.226_446_96,
-        0.227_655_16,
-        0.228_399,
-        0.229_609_37,
-        0.230_351_03,
-        0.231_563_58,
-        0.232_303_07,
-        0.233_517_8,
-        0.234_255_09,
-        0.235_472_02,
-        0.236_207_13,
-        0.237_426_24,
-        0.238_159_15,
-        0.239_380_46,
-        0.240_111_17,
-        0.241_334_69,
-        0.242_063_2,
-        0.243_288_93,
-        0.244_015_2,
-        0.245_243_16,
-        0.245_967_22,
-        0.247_197_4,
-        0.247_919_23,
-        0.249_151_63,
-        0.249_871_24,
-        0.251_105_87,
-        0.251_823_25,
-        0.253_060_13,
-        0.253_775_24,
-        0.255_014_36,
-        0.255_727_26,
-        0.256_968_62,
-        0.257_679_25,
-        0.258_922_87,
-        0.259_631_25,
-        0.260_877_13,
-        0.261_583_24,
-        0.262_831_4,
-        0.263_535_23,
-        0.264_785_65,
-        0.265_487_22,
-        0.266_739_93,
-        0.267_439_2,
-        0.268_694_2,
-        0.269_391_18,
-        0.270_648_48,
-        0.271_343_14,
-        0.272_602_74,
-        0.273_295_13,
-        0.274_557_02,
-        0.275_247_1,
-        0.276_511_3,
-        0.277_199_06,
-        0.278_465_6,
-        0.279_151_02,
-        0.280_419_9,
-        0.281_103,
-        0.282_374_17,
-        0.283_054_95,
-        0.284_328_46,
-        0.285_006_88,
-        0.286_282_75,
-        0.286_958_84,
-        0.288_237_07,
-        0.288_910_78,
-        0.290_191_38,
-        0.290_862_74,
-        0.292_145_67,
-        0.292_814_67,
-        0.294_1,
-        0.294_766_6,
-        0.296_054_3,
-        0.296_718_54,
-        0.298_008_62,
-        0.298_670_47,
-        0.299_962_97,
-        0.300_622_37,
-        0.301_917_28,
-        0.302_574_3,
-        0.303_871_6,
-        0.304_526_2,
-        0.305_825_95,
-        0.306_478_14,
-        0.307_780_3,
-        0.308_430_05,
-        0.309_734_64,
-        0.310_381_95,
-        0.311_689,
-        0.312_333_85,
-        0.313_643_34,
-        0.314_285_76,
-        0.315_597_68,
-        0.316_237_63,
-        0.317_552_03,
-        0.318_189_53,
-        0.319_506_4,
-        0.320_141_4,
-        0.321_460_78,
-        0.322_093_28,
-        0.323_415_16,
-        0.324_045_18,
-        0.325_369_5,
-        0.325_997_02,
-        0.327_323_9,
-        0.327_948_9,
-        0.329_278_3,
-        0.329_900_77,
-        0.331_232_67,
-        0.331_852_6,
-        0.333_187_07,
-        0.333_804_5,
-        0.335_141_48,
-        0.335_756_33,
-        0.337_095_86,
-        0.337_708_18,
-        0.339_050_26,
-        0.339_660_02,
-        0.341_004_7,
-        0.341_611_86,
-        0.342_959_1,
-        0.343_563_68,
-        0.344_913_5,
-        0.345_515_52,
-        0.346_867_95,
-        0.347_467_33,
-        0.348_822_39,
-        0.349_419_15,
-        0.350_776_82,
-        0.351_370_96,
-        0.352_731_26,
-        0.353_322_77,
-        0.354_685_7,
-        0.355_274_56,
-        0.356_640_16,
-        0.357_226_37,
-        0.358_594_63,
-        0.359_178_16,
-        0.360_549_06,
-        0.361_129_94,
-        0.362_503_53,
-        0.363_081_72,
-        0.364_458_02,
-        0.365_033_5,
-        0.366_412_5,
-        0.366_985_26,
-        0.368_366_96,
-        0.368_937_05,
-        0.370_321_45,
-        0.370_888_8,
-        0.372_275_95,
-        0.372_840_55,
-        0.374_230_44,
-        0.374_792_3,
-        0.376_184_97,
-        0.376_744_03,
-        0.378_139_47,
-        0.378_695_8,
-        0.380_094,
-        0.380_647_5,
-        0.382_048_52,
-        0.382_599_23,
-        0.384_003_04,
-        0.384_550_96,
-        0.385_957_57,
-        0.386_502_65,
-        0.387_912_1,
-        0.388_454_38,
-        0.389_866_65,
-        0.390_406_07,
-        0.391_821_2,
-        0.392_357_77,
-        0.393_775_76,
-        0.394_309_46,
-        0.395_730_32,
-        0.396_261_16,
-        0.397_684_9,
-        0.398_212_82,
-        0.399_639_46,
-        0.400_164_48,
-        0.401_594_04,
-        0.402_116_15,
-        0.403_548_66,
-        0.404_067_8,
-        0.405_503_24,
-        0.406_019_45,
-        0.407_457_86,
-        0.407_971_1,
-        0.409_412_44,
-        0.409_922_75,
-        0.411_367_06,
-        0.411_874_35,
-        0.413_321_7,
-        0.413_826,
-        0.415_276_32,
-        0.415_777_6,
-        0.417_230_96,
-        0.417_729_23,
-        0.419_185_6,
-        0.419_680_8,
-        0.421_140_25,
-        0.421_632_4,
-        0.423_094_93,
-        0.423_583_98,
-        0.425_049_57,
-        0.425_535_6,
-        0.427_004_25,
-        0.427_487_13,
-        0.428_958_95,
-        0.429_438_7,
-        0.430_913_63,
-        0.431_390_26,
-        0.432_868_33,
-        0.433_341_83,
-        0.434_823_04,
-        0.435_293_35,
-        0.436_777_74,
-        0.437_244_9,
-        0.438_732_48,
-        0.439_196_4,
-        0.440_687_2,
-        0.441_147_92,
-        0.442_641_94,
-        0.443_099_44,
-        0.444_596_68,
-        0.445_050_95,
-        0.446_551_44,
-        0.447_002_44,
-        0.448_506_2,
-        0.448_953_93,
-        0.450_460_97,
-        0.450_905_38,
-        0.452_415_73,
-        0.452_856_87,
-        0.454_370_53,
-        0.454_808_32,
-        0.456_325_32,
-        0.456_759_75,
-        0.458_280_15,
-        0.458_711_2,
-        0.460_234_94,
-        0.460_662_63,
-        0.462_189_76,
-        0.462_614_06,
-        0.464_144_62,
-        0.464_565_46,
-        0.466_099_44,
-        0.466_516_88,
-        0.468_054_3,
-        0.468_468_25,
-        0.470_009_18,
-        0.470_419_65,
-        0.471_964_03,
-        0.472_371,
-        0.473_918_9,
-        0.474_322_38,
-        0.475_873_83,
-        0.476_273_75,
-        0.477_828_7,
-        0.478_225_08,
-        0.479_783_62,
-        0.480_176_42,
-        0.481_738_54,
-        0.482_127_73,
-        0.483_693_48,
-        0.484_079_03,
-        0.485_648_42,
-        0.486_030_34,
-        0.487_603_37,
-        0.487_981_65,
-        0.489_558_34,
-        0.489_932_92,
-        0.491_513_3,
-        0.491_884_2,
-        0.493_468_3,
-        0.493_835_45,
-        0.495_423_32,
-        0.495_786_7,
-        0.497_378_32,
-        0.497_737_9,
-        0.499_333_32,
-        0.499_689_16,
-        0.501_288_35,
-        0.501_640_4,
-        0.503_243_4,
-        0.503_591_54,
-        0.505_198_5,
-        0.505_542_76,
-        0.507_153_5,
-        0.507_493_9,
-        0.509_108_6,
-        0.509_445_1,
-        0.511_063_7,
-        0.511_396_2,
-        0.513_018_8,
-        0.513_347_4,
-        0.514_973_94,
-        0.515_298_5,
-        0.516_929_1,
-        0.517_249_64,
-        0.518_884_2,
-        0.519_200_74,
-        0.520_839_33,
-        0.521_151_8,
-        0.522_794_54,
-        0.523_102_9,
-        0.524_749_7,
-        0.525_053_9,
-        0.526_704_9,
-        0.527_004_96,
-        0.528_660_1,
-        0.528_956,
-        0.530_615_3,
-        0.530_907_03,
-        0.532_570_6,
-        0.532_858,
-        0.534_525_9,
-        0.534_809,
-        0.536_481_14,
-        0.536_76,
-        0.538_436_4,
-        0.538_710_95,
-        0.540_391_7,
-        0.540_661_9,
-        0.542_347,
-        0.542_612_85,
-        0.544_302_34,
-        0.544_563_8,
-        0.546_257_7,
-        0.546_514_63,
-        0.548_213_06,
-        0.548_465_55,
-        0.550_168_4,
-        0.550_416_4,
-        0.552_123_8,
-        0.552_367_2,
-        0.554_079_23,
-        0.554_318_1,
-        0.556_034_6,
-        0.556_268_9,
-        0.557_990_1,
-        0.558_219_7,
-        0.559_945_5,
-        0.560_170_5,
-        0.561_901_03,
-        0.562_121_2,
-        0.563_856_54,
-        0.564_071_95,
-        0.565_812_05,
-        0.566_022_7,
-        0.567_767_56,
-        0.567_973_43,
-        0.569_723_1,
-        0.569_924_1,
-        0.571_678_7,
-        0.571_874_8,
-        0.573_634_27,
-        0.573_825_4,
-        0.575_589_9,
-        0.575_776_04,
-        0.577_545_5,
-        0.577_726_66,
-        0.579_501_15,
-        0.579_677_3,
-        0.581_456_8,
-        0.581_627_85,
-        0.583_412_47,
-        0.583_578_4,
-        0.585_368_2,
-        0.585_528_97,
-        0.587_323_9,
-        0.587_479_5,
-        0.589_279_65,
-        0.589_43,
-        0.591_235_46,
-        0.591_380_4,
-        0.593_191_2,
-        0.593_330_86,
-        0.595_147,
-        0.595_281_3,
-        0.597_102_9,
-        0.597_231_7,
-        0.599_058_75,
-        0.599_182_07,
-        0.601_014_6,
-        0.601_132_45,
-        0.602_970_54,
-        0.603_082_8,
-        0.604_926_47,
-        0.605_033_1,
-        0.606_882_4,
-        0.606_983_36,
-        0.608_838_4,
-        0.608_933_6,
-        0.610_794_37,
-        0.610_883_9,
-        0.612_750_4,
-        0.612_834_1,
-        0.614_706_46,
-        0.614_784_24,
-        0.616_662_56,
-        0.616_734_45,
-        0.618_618_67,
-        0.618_684_53,
-        0.620_574_8,
-        0.620_634_7,
-        0.622_530_94,
-        0.622_584_76,
-        0.624_487_1,
-        0.624_534_8,
-        0.626_443_3,
-        0.626_484_8,
-        0.628_399_55,
-        0.628_434_8,
-        0.630_355_83,
-        0.630_384_74,
-        0.632_312_1,
-        0.632_334_7,
-        0.634_268_46,
-        0.634_284_6,
-        0.636_224_87,
-        0.636_234_46,
-        0.638_181_2,
-        0.638_184_3,
-        0.640_137_7,
-        0.640_134_1,
-        0.642_094_14,
-        0.642_083_9,
-        0.644_050_6,
-        0.644_033_6,
-        0.646_007_1,
-        0.645_983_34,
-        0.647_963_7,
-        0.647_933,
-        0.649_920_3,
-        0.649_882_7,
-        0.651_876_9,
-        0.651_832_3,
-        0.653_833_57,
-        0.653_781_83,
-        0.655_790_27,
-        0.655_731_4,
-        0.657_747,
-        0.657_680_87,
-        0.659_703_8,
-        0.659_630_36,
-        0.661_660_55,
-        0.661_579_8,
-        0.663_617_43,
-        0.663_529_16,
-        0.665_574_3,
-        0.665_478_47,
-        0.667_531_25,
-        0.667_427_8,
-        0.669_488_2,
-        0.669_377,
-        0.671_445_2,
-        0.671_326_3,
-        0.673_402_25,
-        0.673_275_5,
-        0.675_359_37,
-        0.675_224_6,
-        0.677_316_5,
-        0.677_173_7,
-        0.679_273_66,
-        0.679_122_75,
-        0.681_230_9,
-        0.681_071_7,
-        0.683_188_14,
-        0.683_020_65,
-        0.685_145_5,
-        0.684_969_6,
-        0.687_102_85,
-        0.686_918_44,
-        0.689_060_3,
-        0.688_867_3,
-        0.691_017_75,
-        0.690_816,
-        0.692_975_3,
-        0.692_764_7,
-        0.694_932_8,
-        0.694_713_35,
-        0.696_890_5,
-        0.696_661_95,
-        0.698_848_1,
-        0.698_610_54,
-        0.700_805_84,
-        0.700_559,
-        0.702_763_6,
-        0.702_507_44,
-        0.704_721_45,
-        0.704_455_85,
-        0.706_679_4,
-        0.706_404_15,
-        0.708_637_36,
-        0.708_352_4,
-        0.710_595_37,
-        0.710_300_6,
-        0.712_553_44,
-        0.712_248_74,
-        0.714_511_6,
-        0.714_196_8,
-        0.716_469_8,
-        0.716_144_8,
-        0.718_428_1,
-        0.718_092_74,
-        0.720_386_4,
-        0.720_040_6,
-        0.722_344_8,
-        0.721_988_44,
-        0.724_303_3,
-        0.723_936_2,
-        0.726_261_85,
-        0.725_883_84,
-        0.728_220_46,
-        0.727_831_4,
-        0.730_179_2,
-        0.729_778_95,
-        0.732_138,
-        0.731_726_4,
-        0.734_096_8,
-        0.733_673_75,
-        0.736_055_73,
-        0.735_621_04,
-        0.738_014_76,
-        0.737_568_2,
-        0.739_973_84,
-        0.739_515_3,
-        0.741_933_05,
-        0.741_462_35,
-        0.743_892_3,
-        0.743_409_3,
-        0.745_851_64,
-        0.745_356_14,
-        0.747_811_1,
-        0.747_302_9,
-        0.749_770_64,
-        0.749_249_5,
-        0.751_730_26,
-        0.751_196_1,
-        0.753_69,
-        0.753_142_6,
-        0.755_649_86,
-        0.755_088_9,
-        0.757_609_8,
-        0.757_035_2,
-        0.759_569_8,
-        0.758_981_35,
-        0.761_53,
-        0.760_927_4,
-        0.763_490_26,
-        0.762_873_35,
-        0.765_450_6,
-        0.764_819_15,
-        0.767_411_1,
-        0.766_764_9,
-        0.769_371_7,
-        0.768_710_43,
-        0.771_332_44,
-        0.770_655_93,
-        0.773_293_3,
-        0.772_601_25,
-        0.775_254_25,
-        0.774_546_44,
-        0.777_215_36,
-        0.776_491_5,
-        0.779_176_65,
-        0.778_436_4,
-        0.781_138,
-        0.780_381_26,
-        0.783_099_53,
-        0.782_325_86,
-        0.785_061_24,
-        0.784_270_4,
-        0.787_023_07,
-        0.786_214_7,
-        0.788_985,
-        0.788_158_95,
-        0.790_947_14,
-        0.790_102_96,
-        0.792_909_44,
-        0.792_046_84,
-        0.794_871_9,
-        0.793_990_55,
-        0.796_834_6,
-        0.795_934,
-        0.798_797_37,
-        0.797_877_4,
-        0.800_760_4,
-        0.799_820_54,
-        0.802_723_6,
-        0.801_763_53,
-        0.804_686_96,
-        0.803_706_3,
-        0.806_650_5,
-        0.805_648_86,
-        0.808_614_3,
-        0.807_591_2,
-        0.810_578_35,
-        0.809_533_36,
-        0.812_542_56,
-        0.811_475_3,
-        0.814_506_95,
-        0.813_416_96,
-        0.816_471_64,
-        0.815_358_4,
-        0.818_436_56,
-        0.817_299_6,
-        0.820_401_7,
-        0.819_240_57,
-        0.822_367_13,
-        0.821_181_3,
-        0.824_332_83,
-        0.823_121_7,
-        0.826_298_8,
-        0.825_061_86,
-        0.828_265,
-        0.827_001_75,
-        0.830_231_55,
-        0.828_941_3,
-        0.832_198_4,
-        0.830_880_6,
-        0.834_165_5,
-        0.832_819_5,
-        0.836_133,
-        0.834_758_1,
-        0.838_100_8,
-        0.836_696_4,
-        0.840_069,
-        0.838_634_3,
-        0.842_037_5,
-        0.840_571_9,
-        0.844_006_36,
-        0.842_509_03,
-        0.845_975_64,
-        0.844_445_8,
-        0.847_945_33,
-        0.846_382_14,
-        0.849_915_44,
-        0.848_318_1,
-        0.851_886,
-        0.850_253_6,
-        0.853_857,
-        0.852_188_6,
-        0.855_828_4,
-        0.854_123_2,
-        0.857_800_36,
-        0.856_057_2,
-        0.859_772_86,
-        0.857_990_74,
-        0.861_745_83,
-        0.859_923_7,
-        0.863_719_34,
-        0.861_856_16,
-        0.865_693_5,
-        0.863_787_95,
-        0.867_668_2,
-        0.865_719_2,
-        0.869_643_57,
-        0.867_649_73,
-        0.871_619_6,
-        0.869_579_6,
-        0.873_596_3,
-        0.871_508_84,
-        0.875_573_75,
-        0.873_437_3,
-        0.877_551_9,
-        0.875_364_96,
-        0.879_530_9,
-        0.877_291_8,
-        0.881_510_7,
-        0.879_217_8,
-        0.883_491_4,
-        0.881_143,
-        0.885_472_95,
-        0.883_067_13,
-        0.887_455_5,
-        0.884_990_33,
-        0.889_439_1,
-        0.886_912_5,
-        0.891_423_7,
-        0.888_833_6,
-        0.893_409_5,
-        0.890_753_45,
-        0.895_396_4,
-        0.892_672_2,
-        0.897_384_6,
-        0.894_589_54,
-        0.899_374_1,
-        0.896_505_6,
-        0.901_365_04,
-        0.898_420_2,
-        0.903_357_45,
-        0.900_333_3,
-        0.905_351_4,
-        0.902_244_75,
-        0.907_347,
-        0.904_154_5,
-        0.909_344_5,
-        0.906_062_36,
-        0.911_343_8,
-        0.907_968_3,
-        0.913_345_1,
-        0.909_872_2,
-        0.915_348_6,
-        0.911_773_8,
-        0.917_354_4,
-        0.913_673_04,
-        0.919_362_6,
-        0.915_569_8,
-        0.921_373_5,
-        0.917_463_7,
-        0.923_387_2,
-        0.919_354_8,
-        0.925_404,
-        0.921_242_65,
-        0.927_424_1,
-        0.923_127_1,
-        0.929_447_7,
-        0.925_007_8,
-        0.931_475_16,
-        0.926_884_6,
-        0.933_506_8,
-        0.928_757,
-        0.935_542_94,
-        0.930_624_66,
-        0.937_584_04,
-        0.932_487_2,
-        0.939_630_5,
-        0.934_344_05,
-        0.941_682_93,
-        0.936_194_7,
-        0.943_741_8,
-        0.938_038_6,
-        0.945_807_9,
-        0.939_874_95,
-        0.947_881_76,
-        0.941_703,
-        0.949_964_46,
-        0.943_521_86,
-        0.952_056_94,
-        0.945_330_3,
-        0.954_160_3,
-        0.947_127_3,
-        0.956_275_9,
-        0.948_911_3,
-        0.958_405_26,
-        0.950_680_6,
-        0.960_550_25,
-        0.952_433_2,
-        0.962_713_06,
-        0.954_166_77,
-        0.964_896_3,
-        0.955_878_44,
-        0.967_103_1,
-        0.957_564_7,
-        0.969_337_34,
-        0.959_221_4,
-        0.971_603_6,
-        0.960_843_27,
-        0.973_907_7,
-        0.962_423_74,
-        0.976_257_1,
-        0.963_954_7,
-        0.978_661_06,
-        0.965_425_3,
-        0.981_131_73,
-        0.966_821_9,
-        0.983_685,
-        0.968_125_8,
-        0.986_342_55,
-        0.969_311_95,
-        0.989_134_1,
-        0.970_344_9,
-        0.992_101_7,
-        0.971_173_8,
-        0.995_307_56,
-        0.971_722_36,
-        0.998_846_6,
-        0.971_870_8,
-        1.002_872,
-        0.971_419_9,
-        1.007_647_5,
-        0.970_012_3,
-        1.013_669_8,
-        0.966_937_24,
-        1.021_995,
-        0.960_552_04,
-        1.035_330_8,
-        0.946_033_7,
-        1.063_256_3,
-        0.900_870_5,
-        1.178_003_1,
-        0.0,
-        -1.178_003_1,
-        -0.900_870_5,
-        -1.063_256_3,
-        -0.946_033_7,
-        -1.035_330_8,
-        -0.960_552_04,
-        -1.021_995,
-        -0.966_937_24,
-        -1.013_669_8,
-        -0.970_012_3,
-        -1.007_647_5,
-        -0.971_419_9,
-        -1.002_872,
-        -0.971_870_8,
-        -0.998_846_6,
-        -0.971_722_36,
-        -0.995_307_56,
-        -0.971_173_8,
-        -0.992_101_7,
-        -0.970_344_9,
-        -0.989_134_1,
-        -0.969_311_95,
-        -0.986_342_55,
-        -0.968_125_8,
-        -0.983_685,
-        -0.966_821_9,
-        -0.981_131_73,
-        -0.965_425_3,
-        -0.978_661_06,
-        -0.963_954_7,
-        -0.976_257_1,
-        -0.962_423_74,
-        -0.973_907_7,
-        -0.960_843_27,
-        -0.971_603_6,
-        -0.959_221_4,
-        -0.969_337_34,
-        -0.957_564_7,
-        -0.967_103_1,
-        -0.955_878_44,
-        -0.964_896_3,
-        -0.954_166_77,
-        -0.962_713_06,
-        -0.952_433_2,
-        -0.960_550_25,
-        -0.950_680_6,
-        -0.958_405_26,
-        -0.948_911_3,
-        -0.956_275_9,
-        -0.947_127_3,
-        -0.954_160_3,
-        -0.945_330_3,
-        -0.952_056_94,
-        -0.943_521_86,
-        -0.949_964_46,
-        -0.941_703,
-        -0.947_881_76,
-        -0.939_874_95,
-        -0.945_807_9,
-        -0.938_038_6,
-        -0.943_741_8,
-        -0.936_194_7,
-        -0.941_682_93,
-        -0.934_344_05,
-        -0.939_630_5,
-        -0.932_487_2,
-        -0.937_584_04,
-        -0.930_624_66,
-        -0.935_542_94,
-        -0.928_757,
-        -0.933_506_8,
-        -0.926_884_6,
-        -0.931_475_16,
-        -0.925_007_8,
-        -0.929_447_7,
-        -0.923_127_1,
-        -0.927_424_1,
-        -0.921_242_65,
-        -0.925_404,
-        -0.919_354_8,
-        -0.923_387_2,
-        -0.917_463_7,
-        -0.921_373_5,
-        -0.915_569_8,
-        -0.919_362_6,
-        -0.913_673_04,
-        -0.917_354_4,
-        -0.911_773_8,
-        -0.915_348_6,
-        -0.909_872_2,
-        -0.913_345_1,
-        -0.907_968_3,
-        -0.911_343_8,
-        -0.906_062_36,
-        -0.909_344_5,
-        -0.904_154_5,
-        -0.907_347,
-        -0.902_244_75,
-        -0.905_351_4,
-        -0.900_333_3,
-        -0.903_357_45,
-        -0.898_420_2,
-        -0.901_365_04,
-        -0.896_505_6,
-        -0.899_374_1,
-        -0.894_589_54,
-        -0.897_384_6,
-        -0.892_672_2,
-        -0.895_396_4,
-        -0.890_753_45,
-        -0.893_409_5,
-        -0.888_833_6,
-        -0.891_423_7,
-        -0.886_912_5,
-        -0.889_439_1,
-        -0.884_990_33,
-        -0.887_455_5,
-        -0.883_067_13,
-        -0.885_472_95,
-        -0.881_143,
-        -0.883_491_4,
-        -0.879_217_8,
-        -0.881_510_7,
-        -0.877_291_8,
-        -0.879_530_9,
-        -0.875_364_96,
-        -0.877_551_9,
-        -0.873_437_3,
-        -0.875_573_75,
-        -0.871_508_84,
-        -0.873_596_3,
-        -0.869_579_6,
-        -0.871_619_6,
-        -0.867_649_73,
-        -0.869_643_57,
-        -0.865_719_2,
-        -0.867_668_2,
-        -0.863_787_95,
-        -0.865_693_5,
-        -0.861_856_16,
-        -0.863_719_34,
-        -0.859_923_7,
-        -0.861_745_83,
-        -0.857_990_74,
-        -0.859_772_86,
-        -0.856_057_2,
-        -0.857_800_36,
-        -0.854_123_2,
-        -0.855_828_4,
-        -0.852_188_6,
-        -0.853_857,
-        -0.850_253_6,
-        -0.851_886,
-        -0.848_318_1,
-        -0.849_915_44,
-        -0.846_382_14,
-        -0.847_945_33,
-        -0.844_445_8,
-        -0.845_975_64,
-        -0.842_509_03,
-        -0.844_006_36,
-        -0.840_571_9,
-        -0.842_037_5,
-        -0.838_634_3,
-        -0.840_069,
-        -0.836_696_4,
-        -0.838_100_8,
-        -0.834_758_1,
-        -0.836_133,
-        -0.832_819_5,
-        -0.834_165_5,
-        -0.830_880_6,
-        -0.832_198_4,
-        -0.828_941_3,
-        -0.830_231_55,
-        -0.827_001_75,
-        -0.828_265,
-        -0.825_061_86,
-        -0.826_298_8,
-        -0.823_121_7,
-        -0.824_332_83,
-        -0.821_181_3,
-        -0.822_367_13,
-        -0.819_240_57,
-        -0.820_401_7,
-        -0.817_299_6,
-        -0.818_436_56,
-        -0.815_358_4,
-        -0.816_471_64,
-        -0.813_416_96,
-        -0.814_506_95,
-        -0.811_475_3,
-        -0.812_542_56,
-        -0.809_533_36,
-        -0.810_578_35,
-        -0.807_591_2,
-        -0.808_614_3,
-        -0.805_648_86,
-        -0.806_650_5,
-        -0.803_706_3,
-        -0.804_686_96,
-        -0.801_763_53,
-        -0.802_723_6,
-        -0.799_820_54,
-        -0.800_760_4,
-        -0.797_877_4,
-        -0.798_797_37,
-        -0.795_934,
-        -0.796_834_6,
-        -0.793_990_55,
-        -0.794_871_9,
-        -0.792_046_84,
-        -0.792_909_44,
-        -0.790_102_96,
-        -0.790_947_14,
-        -0.788_158_95,
-        -0.788_985,
-        -0.786_214_7,
-        -0.787_023_07,
-        -0.784_270_4,
-        -0.785_061_24,
-        -0.782_325_86,
-        -0.783_099_53,
-        -0.780_381_26,
-        -0.781_138,
-        -0.778_436_4,
-        -0.779_176_65,
-        -0.776_491_5,
-        -0.777_215_36,
-        -0.774_546_44,
-        -0.775_254_25,
-        -0.772_601_25,
-        -0.773_293_3,
-        -0.770_655_93,
-        -0.771_332_44,
-        -0.768_710_43,
-        -0.769_371_7,
-        -0.766_764_9,
-        -0.767_411_1,
-        -0.764_819_15,
-        -0.765_450_6,
-        -0.762_873_35,
-        -0.763_490_26,
-        -0.760_927_4,
-        -0.761_53,
-        -0.758_981_35,
-        -0.759_569_8,
-        -0.757_035_2,
-        -0.757_609_8,
-        -0.755_088_9,
-        -0.755_649_86,
-        -0.753_142_6,
-        -0.753_69,
-        -0.751_196_1,
-        -0.751_730_26,
-        -0.749_249_5,
-        -0.749_770_64,
-        -0.747_302_9,
-        -0.747_811_1,
-        -0.745_356_14,
-        -0.745_851_64,
-        -0.743_409_3,
-        -0.743_892_3,
-        -0.741_462_35,
-        -0.741_933_05,
-        -0.739_515_3,
-        -0.739_973_84,
-        -0.737_568_2,
-        -0.738_014_76,
-        -0.735_621_04,
-        -0.736_055_73,
-        -0.733_673_75,
-        -0.734_096_8,
-        -0.731_726_4,
-        -0.732_138,
-        -0.729_778_95,
-        -0.730_179_2,
-        -0.727_831_4,
-        -0.728_220_46,
-        -0.725_883_84,
-        -0.726_261_85,
-        -0.723_936_2,
-        -0.724_303_3,
-        -0.721_988_44,
-        -0.722_344_8,
-        -0.720_040_6,
-        -0.720_386_4,
-        -0.718_092_74,
-        -0.718_428_1,
-        -0.716_144_8,
-        -0.716_469_8,
-        -0.714_196_8,
-        -0.714_511_6,
-        -0.712_248_74,
-        -0.712_553_44,
-        -0.710_300_6,
-        -0.710_595_37,
-        -0.708_352_4,
-        -0.708_637_36,
-        -0.706_404_15,
-        -0.706_679_4,
-        -0.704_455_85,
-        -0.704_721_45,
-        -0.702_507_44,
-        -0.702_763_6,
-        -0.700_559,
-        -0.700_805_84,
-        -0.698_610_54,
-        -0.698_848_1,
-        -0.696_661_95,
-        -0.696_890_5,
-        -0.694_713_35,
-        -0.694_932_8,
-        -0.692_764_7,
-        -0.692_975_3,
-        -0.690_816,
-        -0.691_017_75,
-        -0.688_867_3,
-        -0.689_060_3,
-        -0.686_918_44,
-        -0.687_102_85,
-        -0.684_969_6,
-        -0.685_145_5,
-        -0.683_020_65,
-        -0.683_188_14,
-        -0.681_071_7,
-        -0.681_230_9,
-        -0.679_122_75,
-        -0.679_273_66,
-        -0.677_173_7,
-        -0.677_316_5,
-        -0.675_224_6,
-        -0.675_359_37,
-        -0.673_275_5,
-        -0.673_402_25,
-        -0.671_326_3,
-        -0.671_445_2,
-        -0.669_377,
-        -0.669_488_2,
-        -0.667_427_8,
-        -0.667_531_25,
-        -0.665_478_47,
-        -0.665_574_3,
-        -0.663_529_16,
-        -0.663_617_43,
-        -0.661_579_8,
-        -0.661_660_55,
-        -0.659_630_36,
-        -0.659_703_8,
-        -0.657_680_87,
-        -0.657_747,
-        -0.655_731_4,
-        -0.655_790_27,
-        -0.653_781_83,
-        -0.653_833_57,
-        -0.651_832_3,
-        -0.651_876_9,
-        -0.649_882_7,
-        -0.649_920_3,
-        -0.647_933,
-        -0.647_963_7,
-        -0.645_983_34,
-        -0.646_007_1,
-        -0.644_033_6,
-        -0.644_050_6,
-        -0.642_083_9,
-        -0.642_094_14,
-        -0.640_134_1,
-        -0.640_137_7,
-        -0.638_184_3,
-        -0.638_181_2,
-        -0.636_234_46,
-        -0.636_224_87,
-        -0.634_284_6,
-        -0.634_268_46,
-        -0.632_334_7,
-        -0.632_312_1,
-        -0.630_384_74,
-        -0.630_355_83,
-        -0.628_434_8,
-        -0.628_399_55,
-        -0.626_484_8,
-        -0.626_443_3,
-        -0.624_534_8,
-        -0.624_487_1,
-        -0.622_584_76,
-        -0.622_530_94,
-        -0.620_634_7,
-        -0.620_574_8,
-        -0.618_684_53,
-        -0.618_618_67,
-        -0.616_734_45,
-        -0.616_662_56,
-        -0.614_784_24,
-        -0.614_706_46,
-        -0.612_834_1,
-        -0.612_750_4,
-        -0.610_883_9,
-        -0.610_794_37,
-        -0.608_933_6,
-        -0.608_838_4,
-        -0.606_983_36,
-        -0.606_882_4,
-        -0.605_033_1,
-        -0.604_926_47,
-        -0.603_082_8,
-        -0.602_970_54,
-        -0.601_132_45,
-        -0.601_014_6,
-        -0.599_182_07,
-        -0.599_058_75,
-        -0.597_231_7,
-        -0.597_102_9,
-        -0.595_281_3,
-        -0.595_147,
-        -0.593_330_86,
-        -0.593_191_2,
-        -0.591_380_4,
-        -0.591_235_46,
-        -0.589_43,
-        -0.589_279_65,
-        -0.587_479_5,
-        -0.587_323_9,
-        -0.585_528_97,
-        -0.585_368_2,
-        -0.583_578_4,
-        -0.583_412_47,
-        -0.581_627_85,
-        -0.581_456_8,
-        -0.579_677_3,
-        -0.579_501_15,
-        -0.577_726_66,
-        -0.577_545_5,
-        -0.575_776_04,
-        -0.575_589_9,
-        -0.573_825_4,
-        -0.573_634_27,
-        -0.571_874_8,
-        -0.571_678_7,
-        -0.569_924_1,
-        -0.569_723_1,
-        -0.567_973_43,
-        -0.567_767_56,
-        -0.566_022_7,
-        -0.565_812_05,
-        -0.564_071_95,
-        -0.563_856_54,
-        -0.562_121_2,
-        -0.561_901_03,
-        -0.560_170_5,
-        -0.559_945_5,
-        -0.558_219_7,
-        -0.557_990_1,
-        -0.556_268_9,
-        -0.556_034_6,
-        -0.554_318_1,
-        -0.554_079_23,
-        -0.552_367_2,
-        -0.552_123_8,
-        -0.550_416_4,
-        -0.550_168_4,
-        -0.548_465_55,
-        -0.548_213_06,
-        -0.546_514_63,
-        -0.546_257_7,
-        -0.544_563_8,
-        -0.544_302_34,
-        -0.542_612_85,
-        -0.542_347,
-        -0.540_661_9,
-        -0.540_391_7,
-        -0.538_710_95,
-        -0.538_436_4,
-        -0.536_76,
-        -0.536_481_14,
-        -0.534_809,
-        -0.534_525_9,
-        -0.532_858,
-        -0.532_570_6,
-        -0.530_907_03,
-        -0.530_615_3,
-        -0.528_956,
-        -0.528_660_1,
-        -0.527_004_96,
-        -0.526_704_9,
-        -0.525_053_9,
-        -0.524_749_7,
-        -0.523_102_9,
-        -0.522_794_54,
-        -0.521_151_8,
-        -0.520_839_33,
-        -0.519_200_74,
-        -0.518_884_2,
-        -0.517_249_64,
-        -0.516_929_1,
-        -0.515_298_5,
-        -0.514_973_94,
-        -0.513_347_4,
-        -0.513_018_8,
-        -0.511_396_2,
-        -0.511_063_7,
-        -0.509_445_1,
-        -0.509_108_6,
-        -0.507_493_9,
-        -0.507_153_5,
-        -0.505_542_76,
-        -0.505_198_5,
-        -0.503_591_54,
-        -0.503_243_4,
-        -0.501_640_4,
-        -0.501_288_35,
-        -0.499_689_16,
-        -0.499_333_32,
-        -0.497_737_9,
-        -0.497_378_32,
-        -0.495_786_7,
-        -0.495_423_32,
-        -0.493_835_45,
-        -0.493_468_3,
-        -0.491_884_2,
-        -0.491_513_3,
-        -0.489_932_92,
-        -0.489_558_34,
-        -0.487_981_65,
-        -0.487_603_37,
-        -0.486_030_34,
-        -0.485_648_42,
-        -0.484_079_03,
-        -0.483_693_48,
-        -0.482_127_73,
-        -0.481_738_54,
-        -0.480_176_42,
-        -0.479_783_62,
-        -0.478_225_08,
-        -0.477_828_7,
-        -0.476_273_75,
-        -0.475_873_83,
-        -0.474_322_38,
-        -0.473_918_9,
-        -0.472_371,
-        -0.471_964_03,
-        -0.470_419_65,
-        -0.470_009_18,
-        -0.468_468_25,
-        -0.468_054_3,
-        -0.466_516_88,
-        -0.466_099_44,
-        -0.464_565_46,
-        -0.464_144_62,
-        -0.462_614_06,
-        -0.462_189_76,
-        -0.460_662_63,
-        -0.460_234_94,
-        -0.458_711_2,
-        -0.458_280_15,
-        -0.456_759_75,
-        -0.456_325_32,
-        -0.454_808_32,
-        -0.454_370_53,
-        -0.452_856_87,
-        -0.452_415_73,
-        -0.450_905_38,
-        -0.450_460_97,
-        -0.448_953_93,
-        -0.448_506_2,
-        -0.447_002_44,
-        -0.446_551_44,
-        -0.445_050_95,
-        -0.444_596_68,
-        -0.443_099_44,
-        -0.442_641_94,
-        -0.441_147_92,
-        -0.440_687_2,
-        -0.439_196_4,
-        -0.438_732_48,
-        -0.437_244_9,
-        -0.436_777_74,
-        -0.435_293_35,
-        -0.434_823_04,
-        -0.433_341_83,
-        -0.432_868_33,
-        -0.431_390_26,
-        -0.430_913_63,
-        -0.429_438_7,
-        -0.428_958_95,
-        -0.427_487_13,
-        -0.427_004_25,
-        -0.425_535_6,
-        -0.425_049_57,
-        -0.423_583_98,
-        -0.423_094_93,
-        -0.421_632_4,
-        -0.421_140_25,
-        -0.419_680_8,
-        -0.419_185_6,
-        -0.417_729_23,
-        -0.417_230_96,
-        -0.415_777_6,
-        -0.415_276_32,
-        -0.413_826,
-        -0.413_321_7,
-        -0.411_874_35,
-        -0.411_367_06,
-        -0.409_922_75,
-        -0.409_412_44,
-        -0.407_971_1,
-        -0.407_457_86,
-        -0.406_019_45,
-        -0.405_503_24,
-        -0.404_067_8,
-        -0.403_548_66,
-        -0.402_116_15,
-        -0.401_594_04,
-        -0.400_164_48,
-        -0.399_639_46,
-        -0.398_212_82,
-        -0.397_684_9,
-        -0.396_261_16,
-        -0.395_730_32,
-        -0.394_309_46,
-        -0.393_775_76,
-        -0.392_357_77,
-        -0.391_821_2,
-        -0.390_406_07,
-        -0.389_866_65,
-        -0.388_454_38,
-        -0.387_912_1,
-        -0.386_502_65,
-        -0.385_957_57,
-        -0.384_550_96,
-        -0.384_003_04,
-        -0.382_599_23,
-        -0.382_048_52,
-        -0.380_647_5,
-        -0.380_094,
-        -0.378_695_8,
-        -0.378_139_47,
-        -0.376_744_03,
-        -0.376_184_97,
-        -0.374_792_3,
-        -0.374_230_44,
-        -0.372_840_55,
-        -0.372_275_95,
-        -0.370_888_8,
-        -0.370_321_45,
-        -0.368_937_05,
-        -0.368_366_96,
-        -0.366_985_26,
-        -0.366_412_5,
-        -0.365_033_5,
-        -0.364_458_02,
-        -0.363_081_72,
-        -0.362_503_53,
-        -0.361_129_94,
-        -0.360_549_06,
-        -0.359_178_16,
-        -0.358_594_63,
-        -0.357_226_37,
-        -0.356_640_16,
-        -0.355_274_56,
-        -0.354_685_7,
-        -0.353_322_77,
-        -0.352_731_26,
-        -0.351_370_96,
-        -0.350_776_82,
-        -0.349_419_15,
-        -0.348_822_39,
-        -0.347_467_33,
-        -0.346_867_95,
-        -0.345_515_52,
-        -0.344_913_5,
-        -0.343_563_68,
-        -0.342_959_1,
-        -0.341_611_86,
-        -0.341_004_7,
-        -0.339_660_02,
-        -0.339_050_26,
-        -0.337_708_18,
-        -0.337_095_86,
-        -0.335_756_33,
-        -0.335_141_48,
-        -0.333_804_5,
-        -0.333_187_07,
-        -0.331_852_6,
-        -0.331_232_67,
-        -0.329_900_77,
-        -0.329_278_3,
-        -0.327_948_9,
-        -0.327_323_9,
-        -0.325_997_02,
-        -0.325_369_5,
-        -0.324_045_18,
-        -0.323_415_16,
-        -0.322_093_28,
-        -0.321_460_78,
-        -0.320_141_4,
-        -0.319_506_4,
-        -0.318_189_53,
-        -0.317_552_03,
-        -0.316_237_63,
-        -0.315_597_68,
-        -0.314_285_76,
-        -0.313_643_34,
-        -0.312_333_85,
-        -0.311_689,
-        -0.310_381_95,
-        -0.309_734_64,
-        -0.308_430_05,
-        -0.307_780_3,
-        -0.306_478_14,
-        -0.305_825_95,
-        -0.304_526_2,
-        -0.303_871_6,
-        -0.302_574_3,
-        -0.301_917_28,
-        -0.300_622_37,
-        -0.299_962_97,
-        -0.298_670_47,
-        -0.298_008_62,
-        -0.296_718_54,
-        -0.296_054_3,
-        -0.294_766_6,
-        -0.294_1,
-        -0.292_814_67,
-        -0.292_145_67,
-        -0.290_862_74,
-        -0.290_191_38,
-        -0.288_910_78,
-        -0.288_237_07,
-        -0.286_958_84,
-        -0.286_282_75,
-        -0.285_006_88,
-        -0.284_328_46,
-        -0.283_054_95,
-        -0.282_374_17,
-        -0.281_103,
-        -0.280_419_9,
-        -0.279_151_02,
-        -0.278_465_6,
-        -0.277_199_06,
-        -0.276_511_3,
-        -0.275_247_1,
-        -0.274_557_02,
-        -0.273_295_13,
-        -0.272_602_74,
-        -0.271_343_14,
-        -0.270_648_48,
-        -0.269_391_18,
-        -0.268_694_2,
-        -0.267_439_2,
-        -0.266_739_93,
-        -0.265_487_22,
-        -0.264_785_65,
-        -0.263_535_23,
-        -0.262_831_4,
-        -0.261_583_24,
-        -0.260_877_13,
-        -0.259_631_25,
-        -0.258_922_87,
-        -0.257_679_25,
-        -0.256_968_62,
-        -0.255_727_26,
-        -0.255_014_36,
-        -0.253_775_24,
-        -0.253_060_13,
-        -0.251_823_25,
-        -0.251_105_87,
-        -0.249_871_24,
-        -0.249_151_63,
-        -0.247_919_23,
-        -0.247_197_4,
-        -0.245_967_22,
-        -0.245_243_16,
-        -0.244_015_2,
-        -0.243_288_93,
-        -0.242_063_2,
-        -0.241_334_69,
-        -0.240_111_17,
-        -0.239_380_46,
-        -0.238_159_15,
-        -0.237_426_24,
-        -0.236_207_13,
-        -0.235_472_02,
-        -0.234_255_09,
-        -0.233_517_8,
-        -0.232_303_07,
-        -0.231_563_58,
-        -0.230_351_03,
-        -0.229_609_37,
-        -0.228_399,
-        -0.227_655_16,
-        -0.226_446_96,
-        -0.225_700_94,
-        -0.224_494_92,
-        -0.223_746_73,
-        -0.222_542_87,
-        -0.221_792_53,
-        -0.220_590_83,
-        -0.219_838_32,
-        -0.218_638_78,
-        -0.217_884_12,
-        -0.216_686_73,
-        -0.215_929_93,
-        -0.214_734_67,
-        -0.213_975_73,
-        -0.212_782_62,
-        -0.212_021_53,
-        -0.210_830_55,
-        -0.210_067_35,
-        -0.208_878_5,
-        -0.208_113_15,
-        -0.206_926_44,
-        -0.206_158_97,
-        -0.204_974_37,
-        -0.204_204_78,
-        -0.203_022_32,
-        -0.202_250_6,
-        -0.201_070_23,
-        -0.200_296_42,
-        -0.199_118_17,
-        -0.198_342_23,
-        -0.197_166_1,
-        -0.196_388_07,
-        -0.195_214_02,
-        -0.194_433_88,
-        -0.193_261_95,
-        -0.192_479_71,
-        -0.191_309_87,
-        -0.190_525_55,
-        -0.189_357_79,
-        -0.188_571_38,
-        -0.187_405_7,
-        -0.186_617_21,
-        -0.185_453_62,
-        -0.184_663_04,
-        -0.183_501_53,
-        -0.182_708_89,
-        -0.181_549_44,
-        -0.180_754_72,
-        -0.179_597_35,
-        -0.178_800_57,
-        -0.177_645_27,
-        -0.176_846_41,
-        -0.175_693_17,
-        -0.174_892_25,
-        -0.173_741_07,
-        -0.172_938_1,
-        -0.171_788_98,
-        -0.170_983_96,
-        -0.169_836_88,
-        -0.169_029_8,
-        -0.167_884_78,
-        -0.167_075_65,
-        -0.165_932_67,
-        -0.165_121_5,
-        -0.163_980_57,
-        -0.163_167_36,
-        -0.162_028_46,
-        -0.161_213_22,
-        -0.160_076_35,
-        -0.159_259_07,
-        -0.158_124_25,
-        -0.157_304_93,
-        -0.156_172_14,
-        -0.155_350_79,
-        -0.154_220_03,
-        -0.153_396_65,
-        -0.152_267_9,
-        -0.151_442_53,
-        -0.150_315_79,
-        -0.149_488_39,
-        -0.148_363_68,
-        -0.147_534_25,
-        -0.146_411_55,
-        -0.145_580_13,
-        -0.144_459_44,
-        -0.143_625_99,
-        -0.142_507_31,
-        -0.141_671_87,
-        -0.140_555_2,
-        -0.139_717_74,
-        -0.138_603_08,
-        -0.137_763_62,
-        -0.136_650_95,
-        -0.135_809_5,
-        -0.134_698_82,
-        -0.133_855_37,
-        -0.132_746_7,
-        -0.131_901_25,
-        -0.130_794_57,
-        -0.129_947_13,
-        -0.128_842_43,
-        -0.127_993,
-        -0.126_890_3,
-        -0.126_038_9,
-        -0.124_938_17,
-        -0.124_084_77,
-        -0.122_986_03,
-        -0.122_130_66,
-        -0.121_033_9,
-        -0.120_176_55,
-        -0.119_081_765,
-        -0.118_222_43,
-        -0.117_129_624,
-        -0.116_268_32,
-        -0.115_177_48,
-        -0.114_314_206,
-        -0.113_225_35,
-        -0.112_360_1,
-        -0.111_273_21,
-        -0.110_405_99,
-        -0.109_321_065,
-        -0.108_451_88,
-        -0.107_368_916,
-        -0.106_497_78,
-        -0.105_416_775,
-        -0.104_543_67,
-        -0.103_464_63,
-        -0.102_589_57,
-        -0.101_512_484,
-        -0.100_635_46,
-        -0.099_560_335,
-        -0.098_681_36,
-        -0.097_608_19,
-        -0.096_727_26,
-        -0.095_656_04,
-        -0.094_773_16,
-        -0.093_703_89,
-        -0.092_819_06,
-        -0.091_751_74,
-        -0.090_864_964,
-        -0.089_799_59,
-        -0.088_910_86,
-        -0.087_847_434,
-        -0.086_956_77,
-        -0.085_895_285,
-        -0.085_002_67,
-        -0.083_943_13,
-        -0.083_048_575,
-        -0.081_990_97,
-        -0.081_094_48,
-        -0.080_038_816,
-        -0.079_140_39,
-        -0.078_086_66,
-        -0.077_186_294,
-        -0.076_134_5,
-        -0.075_232_2,
-        -0.074_182_35,
-        -0.073_278_11,
-        -0.072_230_19,
-        -0.071_324_02,
-        -0.070_278_026,
-        -0.069_369_93,
-        -0.068_325_87,
-        -0.067_415_84,
-        -0.066_373_706,
-        -0.065_461_75,
-        -0.064_421_55,
-        -0.063_507_66,
-        -0.062_469_386,
-        -0.061_553_57,
-        -0.060_517_22,
-        -0.059_599_485,
-        -0.058_565_058,
-        -0.057_645_4,
-        -0.056_612_894,
-        -0.055_691_313,
-        -0.054_660_73,
-        -0.053_737_227,
-        -0.052_708_566,
-        -0.051_783_14,
-        -0.050_756_402,
-        -0.049_829_055,
-        -0.048_804_235,
-        -0.047_874_972,
-        -0.046_852_07,
-        -0.045_920_886,
-        -0.044_899_903,
-        -0.043_966_804,
-        -0.042_947_736,
-        -0.042_012_72,
-        -0.040_995_57,
-        -0.040_058_64,
-        -0.039_043_404,
-        -0.038_104_557,
-        -0.037_091_233,
-        -0.036_150_474,
-        -0.035_139_065,
-        -0.034_196_39,
-        -0.033_186_898,
-        -0.032_242_313,
-        -0.031_234_728,
-        -0.030_288_23,
-        -0.029_282_56,
-        -0.028_334_15,
-        -0.027_330_391,
-        -0.026_380_07,
-        -0.025_378_22,
-        -0.024_425_989,
-        -0.023_426_05,
-        -0.022_471_908,
-        -0.021_473_88,
-        -0.020_517_83,
-        -0.019_521_71,
-        -0.018_563_75,
-        -0.017_569_54,
-        -0.016_609_67,
-        -0.015_617_369,
-        -0.014_655_591,
-        -0.013_665_198,
-        -0.012_701_512,
-        -0.011_713_027,
-        -0.010_747_434,
-        -0.009_760_857,
-        -0.008_793_354,
-        -0.007_808_685_3,
-        -0.006_839_275_4,
-        -0.005_856_514,
-        -0.004_885_196_7,
-        -0.003_904_342_7,
-        -0.002_931_118,
-        -0.001_952_171_3,
-        -0.000_977_039_3,
+        Formant::new(400., 40., 0.),
+        Formant::new(800., 80., -10.),
+        Formant::new(2600., 100., -12.),
     ],
     [
-        0.0,
-        1.178_980_1,
-        0.902_822_7,
-        1.066_187_4,
-        0.949_938_06,
-        1.040_216,
-        0.966_408_55,
-        1.028_834_2,
-        0.974_745_9,
-        1.022_463_2,
-        0.979_773_16,
-        1.018_395,
-        0.983_132_9,
-        1.015_573_5,
-        0.985_536,
-        1.013_502_1,
-        0.987_339_74,
-        1.011_917_2,
-        0.988_743_36,
-        1.010_665_4,
-        0.989_866_6,
-        1.009_651_9,
-        0.990_785_84,
-        1.008_814_5,
-        0.991_551_9,
-        1.008_111,
-        0.992_200_14,
-        1.007_511_7,
-        0.992_755_7,
-        1.006_995_2,
-        0.993_237_2,
-        1.006_545_3,
-        0.993_658_5,
-        1.006_15,
-        0.994_030_2,
-        1.005_8,
-        0.994_360_45,
-        1.005_487_8,
-        0.994_655_97,
-        1.005_207_7,
-        0.994_921_86,
-        1.004_954_9,
-        0.995_162_37,
-        1.004_725_8,
-        0.995_380_94,
-        1.004_517_1,
-        0.995_580_5,
-        1.004_326_1,
-        0.995_763_36,
-        1.004_150_9,
-        0.995_931_57,
-        1.003_989_3,
-        0.996_086_7,
-        1.003_840_1,
-        0.996_230_4,
-        1.003_701_7,
-        0.996_363_76,
-        1.003_573_1,
-        0.996_487_86,
-        1.003_453_3,
-        0.996_603_67,
-        1.003_341_3,
-        0.996_711_97,
-        1.003_236_5,
-        0.996_813_4,
-        1.003_138_2,
-        0.996_908_7,
-        1.003_045_8,
-        0.996_998_37,
-        1.002_958_8,
-        0.997_082_8,
-        1.002_876_8,
-        0.997_162_64,
-        1.002_799_2,
-        0.997_238_04,
-        1.002_725_8,
-        0.997_309_45,
-        1.002_656_2,
-        0.997_377_16,
-        1.002_590_3,
-        0.997_441_4,
-        1.002_527_6,
-        0.997_502_57,
-        1.002_468,
-        0.997_560_74,
-        1.002_411_2,
-        0.997_616_2,
-        1.002_357,
-        0.997_669_1,
-        1.002_305_4,
-        0.997_719_6,
-        1.002_256,
-        0.997_767_9,
-        1.002_208_7,
-        0.997_814_1,
-        1.002_163_5,
-        0.997_858_35,
-        1.002_120_3,
-        0.997_900_8,
-        1.002_078_7,
-        0.997_941_5,
-        1.002_038_8,
-        0.997_980_54,
-        1.002_000_5,
-        0.998_018_1,
-        1.001_963_7,
-        0.998_054_2,
-        1.001_928_2,
-        0.998_088_96,
-        1.001_894_2,
-        0.998_122_4,
-        1.001_861_3,
-        0.998_154_64,
-        1.001_829_7,
-        0.998_185_7,
-        1.001_799_2,
-        0.998_215_7,
-        1.001_769_7,
-        0.998_244_64,
-        1.001_741_3,
-        0.998_272_6,
-        1.001_713_8,
-        0.998_299_6,
-        1.001_687_3,
-        0.998_325_7,
-        1.001_661_5,
-        0.998_351,
-        1.001_636_7,
-        0.998_375_4,
-        1.001_612_7,
-        0.998_399_14,
-        1.001_589_3,
-        0.998_422_1,
-        1.001_566_8,
-        0.998_444_3,
-        1.001_544_8,
-        0.998_465_9,
-        1.001_523_6,
-        0.998_486_8,
-        1.001_503,
-        0.998_507_1,
-        1.001_483,
-        0.998_526_8,
-        1.001_463_5,
-        0.998_545_94,
-        1.001_444_7,
-        0.998_564_54,
-        1.001_426_3,
-        0.998_582_6,
-        1.001_408_6,
-        0.998_600_2,
-        1.001_391_2,
-        0.998_617_3,
-        1.001_374_4,
-        0.998_633_86,
-        1.001_357_9,
-        0.998_650_1,
-        1.001_342,
-        0.998_665_8,
-        1.001_326_4,
-        0.998_681_2,
-        1.001_311_3,
-        0.998_696_1,
-        1.001_296_5,
-        0.998_710_7,
-        1.001_282_2,
-        0.998_724_9,
-        1.001_268_1,
-        0.998_738_7,
-        1.001_254_6,
-        0.998_752_2,
-        1.001_241_2,
-        0.998_765_35,
-        1.001_228_2,
-        0.998_778_16,
-        1.001_215_5,
-        0.998_790_74,
-        1.001_203_2,
-        0.998_802_96,
-        1.001_191,
-        0.998_814_9,
-        1.001_179_2,
-        0.998_826_56,
-        1.001_167_8,
-        0.998_837_95,
-        1.001_156_4,
-        0.998_849_1,
-        1.001_145_5,
-        0.998_859_94,
-        1.001_134_8,
-        0.998_870_6,
-        1.001_124_1,
-        0.998_881,
-        1.001_113_9,
-        0.998_891_1,
-        1.001_103_9,
-        0.998_901_07,
-        1.001_094,
-        0.998_910_8,
-        1.001_084_4,
-        0.998_920_3,
-        1.001_075,
-        0.998_929_6,
-        1.001_065_9,
-        0.998_938_74,
-        1.001_056_8,
-        0.998_947_6,
-        1.001_048,
-        0.998_956_4,
-        1.001_039_4,
-        0.998_964_9,
-        1.001_030_9,
-        0.998_973_25,
-        1.001_022_6,
-        0.998_981_5,
-        1.001_014_5,
-        0.998_989_46,
-        1.001_006_6,
-        0.998_997_33,
-        1.000_998_7,
-        0.999_005_1,
-        1.000_991_1,
-        0.999_012_6,
-        1.000_983_7,
-        0.999_020_04,
-        1.000_976_3,
-        0.999_027_25,
-        1.000_969_2,
-        0.999_034_4,
-        1.000_962_1,
-        0.999_041_4,
-        1.000_955_2,
-        0.999_048_2,
-        1.000_948_4,
-        0.999_054_9,
-        1.000_941_8,
-        0.999_061_47,
-        1.000_935_3,
-        0.999_067_9,
-        1.000_928_9,
-        0.999_074_2,
-        1.000_922_7,
-        0.999_080_5,
-        1.000_916_5,
-        0.999_086_56,
-        1.000_910_4,
-        0.999_092_5,
-        1.000_904_6,
-        0.999_098_36,
-        1.000_898_7,
-        0.999_104_14,
-        1.000_893,
-        0.999_109_8,
-        1.000_887_4,
-        0.999_115_35,
-        1.000_881_9,
-        0.999_120_8,
-        1.000_876_5,
-        0.999_126_14,
-        1.000_871_2,
-        0.999_131_4,
-        1.000_866,
-        0.999_136_5,
-        1.000_860_9,
-        0.999_141_6,
-        1.000_855_9,
-        0.999_146_6,
-        1.000_851,
-        0.999_151_47,
-        1.000_846_1,
-        0.999_156_24,
-        1.000_841_4,
-        0.999_160_95,
-        1.000_836_7,
-        0.999_165_6,
-        1.000_832_2,
-        0.999_170_1,
-        1.000_827_7,
-        0.999_174_6,
-        1.000_823_3,
-        0.999_178_95,
-        1.000_818_8,
-        0.999_183_3,
-        1.000_814_6,
-        0.999_187_5,
-        1.000_810_4,
-        0.999_191_64,
-        1.000_806_3,
-        0.999_195_75,
-        1.000_802_3,
-        0.999_199_75,
-        1.000_798_2,
-        0.999_203_7,
-        1.000_794_3,
-        0.999_207_56,
-        1.000_790_5,
-        0.999_211_4,
-        1.000_786_8,
-        0.999_215_1,
-        1.000_783,
-        0.999_218_8,
-        1.000_779_4,
-        0.999_222_46,
-        1.000_775_8,
-        0.999_226,
-        1.000_772_2,
-        0.999_229_5,
-        1.000_768_8,
-        0.999_232_9,
-        1.000_765_4,
-        0.999_236_3,
-        1.000_762_1,
-        0.999_239_56,
-        1.000_758_8,
-        0.999_242_84,
-        1.000_755_5,
-        0.999_246_06,
-        1.000_752_3,
-        0.999_249_2,
-        1.000_749_2,
-        0.999_252_3,
-        1.000_746_1,
-        0.999_255_3,
-        1.000_743_2,
-        0.999_258_34,
-        1.000_740_2,
-        0.999_261_26,
-        1.000_737_3,
-        0.999_264_1,
-        1.000_734_4,
-        0.999_267,
-        1.000_731_6,
-        0.999_269_8,
-        1.000_728_8,
-        0.999_272_47,
-        1.000_726_1,
-        0.999_275_2,
-        1.000_723_5,
-        0.999_277_83,
-        1.000_720_9,
-        0.999_280_4,
-        1.000_718_4,
-        0.999_282_96,
-        1.000_715_7,
-        0.999_285_46,
-        1.000_713_2,
-        0.999_287_96,
-        1.000_710_8,
-        0.999_290_35,
-        1.000_708_5,
-        0.999_292_73,
-        1.000_706_1,
-        0.999_295_06,
-        1.000_703_8,
-        0.999_297_4,
-        1.000_701_5,
-        0.999_299_6,
-        1.000_699_3,
-        0.999_301_8,
-        1.000_697_1,
-        0.999_304,
-        1.000_695,
-        0.999_306_14,
-        1.000_692_8,
-        0.999_308_2,
-        1.000_690_8,
-        0.999_310_26,
-        1.000_688_7,
-        0.999_312_3,
-        1.000_686_8,
-        0.999_314_25,
-        1.000_684_7,
-        0.999_316_2,
-        1.000_682_8,
-        0.999_318_1,
-        1.000_680_9,
-        0.999_32,
-        1.000_679_1,
-        0.999_321_8,
-        1.000_677_3,
-        0.999_323_6,
-        1.000_675_6,
-        0.999_325_34,
-        1.000_673_8,
-        0.999_327_06,
-        1.000_672_1,
-        0.999_328_8,
-        1.000_670_4,
-        0.999_330_46,
-        1.000_668_8,
-        0.999_332_1,
-        1.000_667_1,
-        0.999_333_7,
-        1.000_665_5,
-        0.999_335_2,
-        1.000_664,
-        0.999_336_7,
-        1.000_662_6,
-        0.999_338_2,
-        1.000_661,
-        0.999_339_7,
-        1.000_659_6,
-        0.999_341_13,
-        1.000_658_2,
-        0.999_342_56,
-        1.000_656_7,
-        0.999_343_93,
-        1.000_655_4,
-        0.999_345_24,
-        1.000_654_1,
-        0.999_346_55,
-        1.000_652_8,
-        0.999_347_87,
-        1.000_651_5,
-        0.999_349_1,
-        1.000_650_3,
-        0.999_350_3,
-        1.000_649_1,
-        0.999_351_5,
-        1.000_647_9,
-        0.999_352_7,
-        1.000_646_7,
-        0.999_353_8,
-        1.000_645_6,
-        0.999_354_96,
-        1.000_644_6,
-        0.999_356_03,
-        1.000_643_5,
-        0.999_357_04,
-        1.000_642_4,
-        0.999_358_1,
-        1.000_641_3,
-        0.999_359_1,
-        1.000_640_4,
-        0.999_360_1,
-        1.000_639_4,
-        0.999_361_04,
-        1.000_638_5,
-        0.999_361_93,
-        1.000_637_7,
-        0.999_362_8,
-        1.000_636_7,
-        0.999_363_66,
-        1.000_635_9,
-        0.999_364_5,
-        1.000_635,
-        0.999_365_3,
-        1.000_634_3,
-        0.999_366_1,
-        1.000_633_5,
-        0.999_366_9,
-        1.000_632_8,
-        0.999_367_6,
-        1.000_632,
-        0.999_368_3,
-        1.000_631_3,
-        0.999_369,
-        1.000_630_6,
-        0.999_369_7,
-        1.000_63,
-        0.999_370_3,
-        1.000_629_4,
-        0.999_370_93,
-        1.000_628_8,
-        0.999_371_5,
-        1.000_628_2,
-        0.999_372_07,
-        1.000_627_6,
-        0.999_372_6,
-        1.000_627_2,
-        0.999_373_1,
-        1.000_626_7,
-        0.999_373_6,
-        1.000_626_2,
-        0.999_374_03,
-        1.000_625_7,
-        0.999_374_5,
-        1.000_625_3,
-        0.999_374_9,
-        1.000_624_9,
-        0.999_375_3,
-        1.000_624_5,
-        0.999_375_64,
-        1.000_624_2,
-        0.999_376,
-        1.000_623_8,
-        0.999_376_3,
-        1.000_623_6,
-        0.999_376_6,
-        1.000_623_2,
-        0.999_376_9,
-        1.000_623,
-        0.999_377_13,
-        1.000_622_7,
-        0.999_377_37,
-        1.000_622_5,
-        0.999_377_55,
-        1.000_622_4,
-        0.999_377_7,
-        1.000_622_2,
-        0.999_377_9,
-        1.000_622,
-        0.999_378,
-        1.000_621_9,
-        0.999_378_1,
-        1.000_621_8,
-        0.999_378_2,
-        1.000_621_8,
-        0.999_378_26,
-        1.000_621_7,
-        0.999_378_26,
-        1.000_621_7,
-        0.999_378_3,
-        1.000_621_7,
-        0.999_378_26,
-        1.000_621_7,
-        0.999_378_26,
-        1.000_621_8,
-        0.999_378_2,
-        1.000_621_8,
-        0.999_378_1,
-        1.000_621_9,
-        0.999_378,
-        1.000_622,
-        0.999_377_9,
-        1.000_622_2,
-        0.999_377_7,
-        1.000_622_4,
-        0.999_377_55,
-        1.000_622_5,
-        0.999_377_37,
-        1.000_622_7,
-        0.999_377_13,
-        1.000_623,
-        0.999_376_9,
-        1.000_623_2,
-        0.999_376_6,
-        1.000_623_6,
-        0.999_376_3,
-        1.000_623_8,
-        0.999_376,
-        1.000_624_2,
-        0.999_375_64,
-        1.000_624_5,
-        0.999_375_3,
-        1.000_624_9,
-        0.999_374_9,
-        1.000_625_3,
-        0.999_374_5,
-        1.000_625_7,
-        0.999_374_03,
-        1.000_626_2,
-        0.999_373_6,
-        1.000_626_7,
-        0.999_373_1,
-        1.000_627_2,
-        0.999_372_6,
-        1.000_627_6,
-        0.999_372_07,
-        1.000_628_2,
-        0.999_371_5,
-        1.000_628_8,
-        0.999_370_93,
-        1.000_629_4,
-        0.999_370_3,
-        1.000_63,
-        0.999_369_7,
-        1.000_630_6,
-        0.999_369,
-        1.000_631_3,
-        0.999_368_3,
-        1.000_632,
-        0.999_367_6,
-        1.000_632_8,
-        0.999_366_9,
-        1.000_633_5,
-        0.999_366_1,
-        1.000_634_3,
-        0.999_365_3,
-        1.000_635,
-        0.999_364_5,
-        1.000_635_9,
-        0.999_363_66,
-        1.000_636_7,
-        0.999_362_8,
-        1.000_637_7,
-        0.999_361_93,
-        1.000_638_5,
-        0.999_361_04,
-        1.000_639_4,
-        0.999_360_1,
-        1.000_640_4,
-        0.999_359_1,
-        1.000_641_3,
-        0.999_358_1,
-        1.000_642_4,
-        0.999_357_04,
-        1.000_643_5,
-        0.999_356_03,
-        1.000_644_6,
-        0.999_354_96,
-        1.000_645_6,
-        0.999_353_8,
-        1.000_646_7,
-        0.999_352_7,
-        1.000_647_9,
-        0.999_351_5,
-        1.000_649_1,
-        0.999_350_3,
-        1.000_650_3,
-        0.999_349_1,
-        1.000_651_5,
-        0.999_347_87,
-        1.000_652_8,
-        0.999_346_55,
-        1.000_654_1,
-        0.999_345_24,
-        1.000_655_4,
-        0.999_343_93,
-        1.000_656_7,
-        0.999_342_56,
-        1.000_658_2,
-        0.999_341_13,
-        1.000_659_6,
-        0.999_339_7,
-        1.000_661,
-        0.999_338_2,
-        1.000_662_6,
-        0.999_336_7,
-        1.000_664,
-        0.999_335_2,
-        1.000_665_5,
-        0.999_333_7,
-        1.000_667_1,
-        0.999_332_1,
-        1.000_668_8,
-        0.999_330_46,
-        1.000_670_4,
-        0.999_328_8,
-        1.000_672_1,
-        0.999_327_06,
-        1.000_673_8,
-        0.999_325_34,
-        1.000_675_6,
-        0.999_323_6,
-        1.000_677_3,
-        0.999_321_8,
-        1.000_679_1,
-        0.999_32,
-        1.000_680_9,
-        0.999_318_1,
-        1.000_682_8,
-        0.999_316_2,
-        1.000_684_7,
-        0.999_314_25,
-        1.000_686_8,
-        0.999_312_3,
-        1.000_688_7,
-        0.999_310_26,
-        1.000_690_8,
-        0.999_308_2,
-        1.000_692_8,
-        0.999_306_14,
-        1.000_695,
-        0.999_304,
-        1.000_697_1,
-        0.999_301_8,
-        1.000_699_3,
-        0.999_299_6,
-        1.000_701_5,
-        0.999_297_4,
-        1.000_703_8,
-        0.999_295_06,
-        1.000_706_1,
-        0.999_292_73,
-        1.000_708_5,
-        0.999_290_35,
-        1.000_710_8,
-        0.999_287_96,
-        1.000_713_2,
-        0.999_285_46,
-        1.000_715_7,
-        0.999_282_96,
-        1.000_718_4,
-        0.999_280_4,
-        1.000_720_9,
-        0.999_277_83,
-        1.000_723_5,
-        0.999_275_2,
-        1.000_726_1,
-        0.999_272_47,
-        1.000_728_8,
-        0.999_269_8,
-        1.000_731_6,
-        0.999_267,
-        1.000_734_4,
-        0.999_264_1,
-        1.000_737_3,
-        0.999_261_26,
-        1.000_740_2,
-        0.999_258_34,
-        1.000_743_2,
-        0.999_255_3,
-        1.000_746_1,
-        0.999_252_3,
-        1.000_749_2,
-        0.999_249_2,
-        1.000_752_3,
-        0.999_246_06,
-        1.000_755_5,
-        0.999_242_84,
-        1.000_758_8,
-        0.999_239_56,
-        1.000_762_1,
-        0.999_236_3,
-        1.000_765_4,
-        0.999_232_9,
-        1.000_768_8,
-        0.999_229_5,
-        1.000_772_2,
-        0.999_226,
-        1.000_775_8,
-        0.999_222_46,
-        1.000_779_4,
-        0.999_218_8,
-        1.000_783,
-        0.999_215_1,
-        1.000_786_8,
-        0.999_211_4,
-        1.000_790_5,
-        0.999_207_56,
-        1.000_794_3,
-        0.999_203_7,
-        1.000_798_2,
-        0.999_199_75,
-        1.000_802_3,
-        0.999_195_75,
-        1.000_806_3,
-        0.999_191_64,
-        1.000_810_4,
-        0.999_187_5,
-        1.000_814_6,
-        0.999_183_3,
-        1.000_818_8,
-        0.999_178_95,
-        1.000_823_3,
-        0.999_174_6,
-        1.000_827_7,
-        0.999_170_1,
-        1.000_832_2,
-        0.999_165_6,
-        1.000_836_7,
-        0.999_160_95,
-        1.000_841_4,
-        0.999_156_24,
-        1.000_846_1,
-        0.999_151_47,
-        1.000_851,
-        0.999_146_6,
-        1.000_855_9,
-        0.999_141_6,
-        1.000_860_9,
-        0.999_136_5,
-        1.000_866,
-        0.999_131_4,
-        1.000_871_2,
-        0.999_126_14,
-        1.000_876_5,
-        0.999_120_8,
-        1.000_881_9,
-        0.999_115_35,
-        1.000_887_4,
-        0.999_109_8,
-        1.000_893,
-        0.999_104_14,
-        1.000_898_7,
-        0.999_098_36,
-        1.000_904_6,
-        0.999_092_5,
-        1.000_910_4,
-        0.999_086_56,
-        1.000_916_5,
-        0.999_080_5,
-        1.000_922_7,
-        0.999_074_2,
-        1.000_928_9,
-        0.999_067_9,
-        1.000_935_3,
-        0.999_061_47,
-        1.000_941_8,
-        0.999_054_9,
-        1.000_948_4,
-        0.999_048_2,
-        1.000_955_2,
-        0.999_041_4,
-        1.000_962_1,
-        0.999_034_4,
-        1.000_969_2,
-        0.999_027_25,
-        1.000_976_3,
-        0.999_020_04,
-        1.000_983_7,
-        0.999_012_6,
-        1.000_991_1,
-        0.999_005_1,
-        1.000_998_7,
-        0.998_997_33,
-        1.001_006_6,
-        0.998_989_46,
-        1.001_014_5,
-        0.998_981_5,
-        1.001_022_6,
-        0.998_973_25,
-        1.001_030_9,
-        0.998_964_9,
-        1.001_039_4,
-        0.998_956_4,
-        1.001_048,
-        0.998_947_6,
-        1.001_056_8,
-        0.998_938_74,
-        1.001_065_9,
-        0.998_929_6,
-        1.001_075,
-        0.998_920_3,
-        1.001_084_4,
-        0.998_910_8,
-        1.001_094,
-        0.998_901_07,
-        1.001_103_9,
-        0.998_891_1,
-        1.001_113_9,
-        0.998_881,
-        1.001_124_1,
-        0.998_870_6,
-        1.001_134_8,
-        0.998_859_94,
-        1.001_145_5,
-        0.998_849_1,
-        1.001_156_4,
-        0.998_837_95,
-        1.001_167_8,
-        0.998_826_56,
-        1.001_179_2,
-        0.998_814_9,
-        1.001_191,
-        0.998_802_96,
-        1.001_203_2,
-        0.998_790_74,
-        1.001_215_5,
-        0.998_778_16,
-        1.001_228_2,
-        0.998_765_35,
-        1.001_241_2,
-        0.998_752_2,
-        1.001_254_6,
-        0.998_738_7,
-        1.001_268_1,
-        0.998_724_9,
-        1.001_282_2,
-        0.998_710_7,
-        1.001_296_5,
-        0.998_696_1,
-        1.001_311_3,
-        0.998_681_2,
-        1.001_326_4,
-        0.998_665_8,
-        1.001_342,
-        0.998_650_1,
-        1.001_357_9,
-        0.998_633_86,
-        1.001_374_4,
-        0.998_617_3,
-        1.001_391_2,
-        0.998_600_2,
-        1.001_408_6,
-        0.998_582_6,
-        1.001_426_3,
-        0.998_564_54,
-        1.001_444_7,
-        0.998_545_94,
-        1.001_463_5,
-        0.998_526_8,
-        1.001_483,
-        0.998_507_1,
-        1.001_503,
-        0.998_486_8,
-        1.001_523_6,
-        0.998_465_9,
-        1.001_544_8,
-        0.998_444_3,
-        1.001_566_8,
-        0.998_422_1,
-        1.001_589_3,
-        0.998_399_14,
-        1.001_612_7,
-        0.998_375_4,
-        1.001_636_7,
-        0.998_351,
-        1.001_661_5,
-        0.998_325_7,
-        1.001_687_3,
-        0.998_299_6,
-        1.001_713_8,
-        0.998_272_6,
-        1.001_741_3,
-        0.998_244_64,
-        1.001_769_7,
-        0.998_215_7,
-        1.001_799_2,
-        0.998_185_7,
-        1.001_829_7,
-        0.998_154_64,
-        1.001_861_3,
-        0.998_122_4,
-        1.001_894_2,
-        0.998_088_96,
-        1.001_928_2,
-        0.998_054_2,
-        1.001_963_7,
-        0.998_018_1,
-        1.002_000_5,
-        0.997_980_54,
-        1.002_038_8,
-        0.997_941_5,
-        1.002_078_7,
-        0.997_900_8,
-        1.002_120_3,
-        0.997_858_35,
-        1.002_163_5,
-        0.997_814_1,
-        1.002_208_7,
-        0.997_767_9,
-        1.002_256,
-        0.997_719_6,
-        1.002_305_4,
-        0.997_669_1,
-        1.002_357,
-        0.997_616_2,
-        1.002_411_2,
-        0.997_560_74,
-        1.002_468,
-        0.997_502_57,
-        1.002_527_6,
-        0.997_441_4,
-        1.002_590_3,
-        0.997_377_16,
-        1.002_656_2,
-        0.997_309_45,
-        1.002_725_8,
-        0.997_238_04,
-        1.002_799_2,
-        0.997_162_64,
-        1.002_876_8,
-        0.997_082_8,
-        1.002_958_8,
-        0.996_998_37,
-        1.003_045_8,
-        0.996_908_7,
-        1.003_138_2,
-        0.996_813_4,
-        1.003_236_5,
-        0.996_711_97,
-        1.003_341_3,
-        0.996_603_67,
-        1.003_453_3,
-        0.996_487_86,
-        1.003_573_1,
-        0.996_363_76,
-        1.003_701_7,
-        0.996_230_4,
-        1.003_840_1,
-        0.996_086_7,
-        1.003_989_3,
-        0.995_931_57,
-        1.004_150_9,
-        0.995_763_36,
-        1.004_326_1,
-        0.995_580_5,
-        1.004_517_1,
-        0.995_380_94,
-        1.004_725_8,
-        0.995_162_37,
-        1.004_954_9,
-        0.994_921_86,
-        1.005_207_7,
-        0.994_655_97,
-        1.005_487_8,
-        0.994_360_45,
-        1.005_8,
-        0.994_030_2,
-        1.006_15,
-        0.993_658_5,
-        1.006_545_3,
-        0.993_237_2,
-        1.006_995_2,
-        0.992_755_7,
-        1.007_511_7,
-        0.992_200_14,
-        1.008_111,
-        0.991_551_9,
-        1.008_814_5,
-        0.990_785_84,
-        1.009_651_9,
-        0.989_866_6,
-        1.010_665_4,
-        0.988_743_36,
-        1.011_917_2,
-        0.987_339_74,
-        1.013_502_1,
-        0.985_536,
-        1.015_573_5,
-        0.983_132_9,
-        1.018_395,
-        0.979_773_16,
-        1.022_463_2,
-        0.974_745_9,
-        1.028_834_2,
-        0.966_408_55,
-        1.040_216,
-        0.949_938_06,
-        1.066_187_4,
-        0.902_822_7,
-        1.178_980_1,
-        0.0,
-        -1.178_980_1,
-        -0.902_822_7,
-        -1.066_187_4,
-        -0.949_938_06,
-        -1.040_216,
-        -0.966_408_55,
-        -1.028_834_2,
-        -0.974_745_9,
-        -1.022_463_2,
-        -0.979_773_16,
-        -1.018_395,
-        -0.983_132_9,
-        -1.015_573_5,
-        -0.985_536,
-        -1.013_502_1,
-        -0.987_339_74,
-        -1.011_917_2,
-        -0.988_743_36,
-        -1.010_665_4,
-        -0.989_866_6,
-        -1.009_651_9,
-        -0.990_785_84,
-        -1.008_814_5,
-        -0.991_551_9,
-        -1.008_111,
-        -0.992_200_14,
-        -1.007_511_7,
-        -0.992_755_7,
-        -1.006_995_2,
-        -0.993_237_2,
-        -1.006_545_3,
-        -0.993_658_5,
-        -1.006_15,
-        -0.994_030_2,
-        -1.005_8,
-        -0.994_360_45,
-        -1.005_487_8,
-        -0.994_655_97,
-        -1.005_207_7,
-        -0.994_921_86,
-        -1.004_954_9,
-        -0.995_162_37,
-        -1.004_725_8,
-        -0.995_380_94,
-        -1.004_517_1,
-        -0.995_580_5,
-        -1.004_326_1,
-        -0.995_763_36,
-        -1.004_150_9,
-        -0.995_931_57,
-        -1.003_989_3,
-        -0.996_086_7,
-        -1.003_840_1,
-        -0.996_230_4,
-        -1.003_701_7,
-        -0.996_363_76,
-        -1.003_573_1,
-        -0.996_487_86,
-        -1.003_453_3,
-        -0.996_603_67,
-        -1.003_341_3,
-        -0.996_711_97,
-        -1.003_236_5,
-        -0.996_813_4,
-        -1.003_138_2,
-        -0.996_908_7,
-        -1.003_045_8,
-        -0.996_998_37,
-        -1.002_958_8,
-        -0.997_082_8,
-        -1.002_876_8,
-        -0.997_162_64,
-        -1.002_799_2,
-        -0.997_238_04,
-        -1.002_725_8,
-        -0.997_309_45,
-        -1.002_656_2,
-        -0.997_377_16,
-        -1.002_590_3,
-        -0.997_441_4,
-        -1.002_527_6,
-        -0.997_502_57,
-        -1.002_468,
-        -0.997_560_74,
-        -1.002_411_2,
-        -0.997_616_2,
-        -1.002_357,
-        -0.997_669_1,
-        -1.002_305_4,
-        -0.997_719_6,
-        -1.002_256,
-        -0.997_767_9,
-        -1.002_208_7,
-        -0.997_814_1,
-        -1.002_163_5,
-        -0.997_858_35,
-        -1.002_120_3,
-        -0.997_900_8,
-        -1.002_078_7,
-        -0.997_941_5,
-        -1.002_038_8,
-        -0.997_980_54,
-        -1.002_000_5,
-        -0.998_018_1,
-        -1.001_963_7,
-        -0.998_054_2,
-        -1.001_928_2,
-        -0.998_088_96,
-        -1.001_894_2,
-        -0.998_122_4,
-        -1.001_861_3,
-        -0.998_154_64,
-        -1.001_829_7,
-        -0.998_185_7,
-        -1.001_799_2,
-        -0.998_215_7,
-        -1.001_769_7,
-        -0.998_244_64,
-        -1.001_741_3,
-        -0.998_272_6,
-        -1.001_713_8,
-        -0.998_299_6,
-        -1.001_687_3,
-        -0.998_325_7,
-        -1.001_661_5,
-        -0.998_351,
-        -1.001_636_7,
-        -0.998_375_4,
-        -1.001_612_7,
-        -0.998_399_14,
-        -1.001_589_3,
-        -0.998_422_1,
-        -1.001_566_8,
-        -0.998_444_3,
-        -1.001_544_8,
-        -0.998_465_9,
-        -1.001_523_6,
-        -0.998_486_8,
-        -1.001_503,
-        -0.998_507_1,
-        -1.001_483,
-        -0.998_526_8,
-        -1.001_463_5,
-        -0.998_545_94,
-        -1.001_444_7,
-        -0.998_564_54,
-        -1.001_426_3,
-        -0.998_582_6,
-        -1.001_408_6,
-        -0.998_600_2,
-        -1.001_391_2,
-        -0.998_617_3,
-        -1.001_374_4,
-        -0.998_633_86,
-        -1.001_357_9,
-        -0.998_650_1,
-        -1.001_342,
-        -0.998_665_8,
-        -1.001_326_4,
-        -0.998_681_2,
-        -1.001_311_3,
-        -0.998_696_1,
-        -1.001_296_5,
-        -0.998_710_7,
-        -1.001_282_2,
-        -0.998_724_9,
-        -1.001_268_1,
-        -0.998_738_7,
-        -1.001_254_6,
-        -0.998_752_2,
-        -1.001_241_2,
-        -0.998_765_35,
-        -1.001_228_2,
-        -0.998_778_16,
-        -1.001_215_5,
-        -0.998_790_74,
-        -1.001_203_2,
-        -0.998_802_96,
-        -1.001_191,
-        -0.998_814_9,
-        -1.001_179_2,
-        -0.998_826_56,
-        -1.001_167_8,
-        -0.998_837_95,
-        -1.001_156_4,
-        -0.998_849_1,
-        -1.001_145_5,
-        -0.998_859_94,
-        -1.001_134_8,
-        -0.998_870_6,
-        -1.001_124_1,
-        -0.998_881,
-        -1.001_113_9,
-        -0.998_891_1,
-        -1.001_103_9,
-        -0.998_901_07,
-        -1.001_094,
-        -0.998_910_8,
-        -1.001_084_4,
-        -0.998_920_3,
-        -1.001_075,
-        -0.998_929_6,
-        -1.001_065_9,
-        -0.998_938_74,
-        -1.001_056_8,
-        -0.998_947_6,
-        -1.001_048,
-        -0.998_956_4,
-        -1.001_039_4,
-        -0.998_964_9,
-        -1.001_030_9,
-        -0.998_973_25,
-        -1.001_022_6,
-        -0.998_981_5,
-        -1.001_014_5,
-        -0.998_989_46,
-        -1.001_006_6,
-        -0.998_997_33,
-        -1.000_998_7,
-        -0.999_005_1,
-        -1.000_991_1,
-        -0.999_012_6,
-        -1.000_983_7,
-        -0.999_020_04,
-        -1.000_976_3,
-        -0.999_027_25,
-        -1.000_969_2,
-        -0.999_034_4,
-        -1.000_962_1,
-        -0.999_041_4,
-        -1.000_955_2,
-        -0.999_048_2,
-        -1.000_948_4,
-        -0.999_054_9,
-        -1.000_941_8,
-        -0.999_061_47,
-        -1.000_935_3,
-        -0.999_067_9,
-        -1.000_928_9,
-        -0.999_074_2,
-        -1.000_922_7,
-        -0.999_080_5,
-        -1.000_916_5,
-        -0.999_086_56,
-        -1.000_910_4,
-        -0.999_092_5,
-        -1.000_904_6,
-        -0.999_098_36,
-        -1.000_898_7,
-        -0.999_104_14,
-        -1.000_893,
-        -0.999_109_8,
-        -1.000_887_4,
-        -0.999_115_35,
-        -1.000_881_9,
-        -0.999_120_8,
-        -1.000_876_5,
-        -0.999_126_14,
-        -1.000_871_2,
-        -0.999_131_4,
-        -1.000_866,
-        -0.999_136_5,
-        -1.000_860_9,
-        -0.999_141_6,
-        -1.000_855_9,
-        -0.999_146_6,
-        -1.000_851,
-        -0.999_151_47,
-        -1.000_846_1,
-        -0.999_156_24,
-        -1.000_841_4,
-        -0.999_160_95,
-        -1.000_836_7,
-        -0.999_165_6,
-        -1.000_832_2,
-        -0.999_170_1,
-        -1.000_827_7,
-        -0.999_174_6,
-        -1.000_823_3,
-        -0.999_178_95,
-        -1.000_818_8,
-        -0.999_183_3,
-        -1.000_814_6,
-        -0.999_187_5,
-        -1.000_810_4,
-        -0.999_191_64,
-        -1.000_806_3,
-        -0.999_195_75,
-        -1.000_802_3,
-        -0.999_199_75,
-        -1.000_798_2,
-        -0.999_203_7,
-        -1.000_794_3,
-        -0.999_207_56,
-        -1.000_790_5,
-        -0.999_211_4,
-        -1.000_786_8,
-        -0.999_215_1,
-        -1.000_783,
-        -0.999_218_8,
-        -1.000_779_4,
-        -0.999_222_46,
-        -1.000_775_8,
-        -0.999_226,
-        -1.000_772_2,
-        -0.999_229_5,
-        -1.000_768_8,
-        -0.999_232_9,
-        -1.000_765_4,
-        -0.999_236_3,
-        -1.000_762_1,
-        -0.999_239_56,
-        -1.000_758_8,
-        -0.999_242_84,
-        -1.000_755_5,
-        -0.999_246_06,
-        -1.000_752_3,
-        -0.999_249_2,
-        -1.000_749_2,
-        -0.999_252_3,
-        -1.000_746_1,
-        -0.999_255_3,
-        -1.000_743_2,
-        -0.999_258_34,
-        -1.000_740_2,
-        -0.999_261_26,
-        -1.000_737_3,
-        -0.999_264_1,
-        -1.000_734_4,
-        -0.999_267,
-        -1.000_731_6,
-        -0.999_269_8,
-        -1.000_728_8,
-        -0.999_272_47,
-        -1.000_726_1,
-        -0.999_275_2,
-        -1.000_723_5,
-        -0.999_277_83,
-        -1.000_720_9,
-        -0.999_280_4,
-        -1.000_718_4,
-        -0.999_282_96,
-        -1.000_715_7,
-        -0.999_285_46,
-        -1.000_713_2,
-        -0.999_287_96,
-        -1.000_710_8,
-        -0.999_290_35,
-        -1.000_708_5,
-        -0.999_292_73,
-        -1.000_706_1,
-        -0.999_295_06,
-        -1.000_703_8,
-        -0.999_297_4,
-        -1.000_701_5,
-        -0.999_299_6,
-        -1.000_699_3,
-        -0.999_301_8,
-        -1.000_697_1,
-        -0.999_304,
-        -1.000_695,
-        -0.999_306_14,
-        -1.000_692_8,
-        -0.999_308_2,
-        -1.000_690_8,
-        -0.999_310_26,
-        -1.000_688_7,
-        -0.999_312_3,
-        -1.000_686_8,
-        -0.999_314_25,
-        -1.000_684_7,
-        -0.999_316_2,
-        -1.000_682_8,
-        -0.999_318_1,
-        -1.000_680_9,
-        -0.999_32,
-        -1.000_679_1,
-        -0.999_321_8,
-        -1.000_677_3,
-        -0.999_323_6,
-        -1.000_675_6,
-        -0.999_325_34,
-        -1.000_673_8,
-        -0.999_327_06,
-        -1.000_672_1,
-        -0.999_328_8,
-        -1.000_670_4,
-        -0.999_330_46,
-        -1.000_668_8,
-        -0.999_332_1,
-        -1.000_667_1,
-        -0.999_333_7,
-        -1.000_665_5,
-        -0.999_335_2,
-        -1.000_664,
-        -0.999_336_7,
-        -1.000_662_6,
-        -0.999_338_2,
-        -1.000_661,
-        -0.999_339_7,
-        -1.000_659_6,
-        -0.999_341_13,
-        -1.000_658_2,
-        -0.999_342_56,
-        -1.000_656_7,
-        -0.999_343_93,
-        -1.000_655_4,
-        -0.999_345_24,
-        -1.000_654_1,
-        -0.999_346_55,
-        -1.000_652_8,
-        -0.999_347_87,
-        -1.000_651_5,
-        -0.999_349_1,
-        -1.000_650_3,
-        -0.999_350_3,
-        -1.000_649_1,
-        -0.999_351_5,
-        -1.000_647_9,
-        -0.999_352_7,
-        -1.000_646_7,
-        -0.999_353_8,
-        -1.000_645_6,
-        -0.999_354_96,
-        -1.000_644_6,
-        -0.999_356_03,
-        -1.000_643_5,
-        -0.999_357_04,
-        -1.000_642_4,
-        -0.999_358_1,
-        -1.000_641_3,
-        -0.999_359_1,
-        -1.000_640_4,
-        -0.999_360_1,
-        -1.000_639_4,
-        -0.999_361_04,
-        -1.000_638_5,
-        -0.999_361_93,
-        -1.000_637_7,
-        -0.999_362_8,
-        -1.000_636_7,
-        -0.999_363_66,
-        -1.000_635_9,
-        -0.999_364_5,
-        -1.000_635,
-        -0.999_365_3,
-        -1.000_634_3,
-        -0.999_366_1,
-        -1.000_633_5,
-        -0.999_366_9,
-        -1.000_632_8,
-        -0.999_367_6,
-        -1.000_632,
-        -0.999_368_3,
-        -1.000_631_3,
-        -0.999_369,
-        -1.000_630_6,
-        -0.999_369_7,
-        -1.000_63,
-        -0.999_370_3,
-        -1.000_629_4,
-        -0.999_370_93,
-        -1.000_628_8,
-        -0.999_371_5,
-        -1.000_628_2,
-        -0.999_372_07,
-        -1.000_627_6,
-        -0.999_372_6,
-        -1.000_627_2,
-        -0.999_373_1,
-        -1.000_626_7,
-        -0.999_373_6,
-        -1.000_626_2,
-        -0.999_374_03,
-        -1.000_625_7,
-        -0.999_374_5,
-        -1.000_625_3,
-        -0.999_374_9,
-        -1.000_624_9,
-        -0.999_375_3,
-        -1.000_624_5,
-        -0.999_375_64,
-        -1.000_624_2,
-        -0.999_376,
-        -1.000_623_8,
-        -0.999_376_3,
-        -1.000_623_6,
-        -0.999_376_6,
-        -1.000_623_2,
-        -0.999_376_9,
-        -1.000_623,
-        -0.999_377_13,
-        -1.000_622_7,
-        -0.999_377_37,
-        -1.000_622_5,
-        -0.999_377_55,
-        -1.000_622_4,
-        -0.999_377_7,
-        -1.000_622_2,
-        -0.999_377_9,
-        -1.000_622,
-        -0.999_378,
-        -1.000_621_9,
-        -0.999_378_1,
-        -1.000_621_8,
-        -0.999_378_2,
-        -1.000_621_8,
-        -0.999_378_26,
-        -1.000_621_7,
-        -0.999_378_26,
-        -1.000_621_7,
-        -0.999_378_3,
-        -1.000_621_7,
-        -0.999_378_26,
-        -1.000_621_7,
-        -0.999_378_26,
-        -1.000_621_8,
-        -0.999_378_2,
-        -1.000_621_8,
-        -0.999_378_1,
-        -1.000_621_9,
-        -0.999_378,
-        -1.000_622,
-        -0.999_377_9,
-        -1.000_622_2,
-        -0.999_377_7,
-        -1.000_622_4,
-        -0.999_377_55,
-        -1.000_622_5,
-        -0.999_377_37,
-        -1.000_622_7,
-        -0.999_377_13,
-        -1.000_623,
-        -0.999_376_9,
-        -1.000_623_2,
-        -0.999_376_6,
-        -1.000_623_6,
-        -0.999_376_3,
-        -1.000_623_8,
-        -0.999_376,
-        -1.000_624_2,
-        -0.999_375_64,
-        -1.000_624_5,
-        -0.999_375_3,
-        -1.000_624_9,
-        -0.999_374_9,
-        -1.000_625_3,
-        -0.999_374_5,
-        -1.000_625_7,
-        -0.999_374_03,
-        -1.000_626_2,
-        -0.999_373_6,
-        -1.000_626_7,
-        -0.999_373_1,
-        -1.000_627_2,
-        -0.999_372_6,
-        -1.000_627_6,
-        -0.999_372_07,
-        -1.000_628_2,
-        -0.999_371_5,
-        -1.000_628_8,
-        -0.999_370_93,
-        -1.000_629_4,
-        -0.999_370_3,
-        -1.000_63,
-        -0.999_369_7,
-        -1.000_630_6,
-        -0.999_369,
-        -1.000_631_3,
-        -0.999_368_3,
-        -1.000_632,
-        -0.999_367_6,
-        -1.000_632_8,
-        -0.999_366_9,
-        -1.000_633_5,
-        -0.999_366_1,
-        -1.000_634_3,
-        -0.999_365_3,
-        -1.000_635,
-        -0.999_364_5,
-        -1.000_635_9,
-        -0.999_363_66,
-        -1.000_636_7,
-        -0.999_362_8,
-        -1.000_637_7,
-        -0.999_361_93,
-        -1.000_638_5,
-        -0.999_361_04,
-        -1.000_639_4,
-        -0.999_360_1,
-        -1.000_640_4,
-        -0.999_359_1,
-        -1.000_641_3,
-        -0.999_358_1,
-        -1.000_642_4,
-        -0.999_357_04,
-        -1.000_643_5,
-        -0.999_356_03,
-        -1.000_644_6,
-        -0.999_354_96,
-        -1.000_645_6,
-        -0.999_353_8,
-        -1.000_646_7,
-        -0.999_352_7,
-        -1.000_647_9,
-        -0.999_351_5,
-        -1.000_649_1,
-        -0.999_350_3,
-        -1.000_650_3,
-        -0.999_349_1,
-        -1.000_651_5,
-        -0.999_347_87,
-        -1.000_652_8,
-        -0.999_346_55,
-        -1.000_654_1,
-        -0.999_345_24,
-        -1.000_655_4,
-        -0.999_343_93,
-        -1.000_656_7,
-        -0.999_342_56,
-        -1.000_658_2,
-        -0.999_341_13,
-        -1.000_659_6,
-        -0.999_339_7,
-        -1.000_661,
-        -0.999_338_2,
-        -1.000_662_6,
-        -0.999_336_7,
-        -1.000_664,
-        -0.999_335_2,
-        -1.000_665_5,
-        -0.999_333_7,
-        -1.000_667_1,
-        -0.999_332_1,
-        -1.000_668_8,
-        -0.999_330_46,
-        -1.000_670_4,
-        -0.999_328_8,
-        -1.000_672_1,
-        -0.999_327_06,
-        -1.000_673_8,
-        -0.999_325_34,
-        -1.000_675_6,
-        -0.999_323_6,
-        -1.000_677_3,
-        -0.999_321_8,
-        -1.000_679_1,
-        -0.999_32,
-        -1.000_680_9,
-        -0.999_318_1,
-        -1.000_682_8,
-        -0.999_316_2,
-        -1.000_684_7,
-        -0.999_314_25,
-        -1.000_686_8,
-        -0.999_312_3,
-        -1.000_688_7,
-        -0.999_310_26,
-        -1.000_690_8,
-        -0.999_308_2,
-        -1.000_692_8,
-        -0.999_306_14,
-        -1.000_695,
-        -0.999_304,
-        -1.000_697_1,
-        -0.999_301_8,
-        -1.000_699_3,
-        -0.999_299_6,
-        -1.000_701_5,
-        -0.999_297_4,
-        -1.000_703_8,
-        -0.999_295_06,
-        -1.000_706_1,
-        -0.999_292_73,
-        -1.000_708_5,
-        -0.999_290_35,
-        -1.000_710_8,
-        -0.999_287_96,
-        -1.000_713_2,
-        -0.999_285_46,
-        -1.000_715_7,
-        -0.999_282_96,
-        -1.000_718_4,
-        -0.999_280_4,
-        -1.000_720_9,
-        -0.999_277_83,
-        -1.000_723_5,
-        -0.999_275_2,
-        -1.000_726_1,
-        -0.999_272_47,
-        -1.000_728_8,
-        -0.999_269_8,
-        -1.000_731_6,
-        -0.999_267,
-        -1.000_734_4,
-        -0.999_264_1,
-        -1.000_737_3,
-        -0.999_261_26,
-        -1.000_740_2,
-        -0.999_258_34,
-        -1.000_743_2,
-        -0.999_255_3,
-        -1.000_746_1,
-        -0.999_252_3,
-        -1.000_749_2,
-        -0.999_249_2,
-        -1.000_752_3,
-        -0.999_246_06,
-        -1.000_755_5,
-        -0.999_242_84,
-        -1.000_758_8,
-        -0.999_239_56,
-        -1.000_762_1,
-        -0.999_236_3,
-        -1.000_765_4,
-        -0.999_232_9,
-        -1.000_768_8,
-        -0.999_229_5,
-        -1.000_772_2,
-        -0.999_226,
-        -1.000_775_8,
-        -0.999_222_46,
-        -1.000_779_4,
-        -0.999_218_8,
-        -1.000_783,
-        -0.999_215_1,
-        -1.000_786_8,
-        -0.999_211_4,
-        -1.000_790_5,
-        -0.999_207_56,
-        -1.000_794_3,
-        -0.999_203_7,
-        -1.000_798_2,
-        -0.999_199_75,
-        -1.000_802_3,
-        -0.999_195_75,
-        -1.000_806_3,
-        -0.999_191_64,
-        -1.000_810_4,
-        -0.999_187_5,
-        -1.000_814_6,
-        -0.999_183_3,
-        -1.000_818_8,
-        -0.999_178_95,
-        -1.000_823_3,
-        -0.999_174_6,
-        -1.000_827_7,
-        -0.999_170_1,
-        -1.000_832_2,
-        -0.999_165_6,
-        -1.000_836_7,
-        -0.999_160_95,
-        -1.000_841_4,
-        -0.999_156_24,
-        -1.000_846_1,
-        -0.999_151_47,
-        -1.000_851,
-        -0.999_146_6,
-        -1.000_855_9,
-        -0.999_141_6,
-        -1.000_860_9,
-        -0.999_136_5,
-        -1.000_866,
-        -0.999_131_4,
-        -1.000_871_2,
-        -0.999_126_14,
-        -1.000_876_5,
-        -0.999_120_8,
-        -1.000_881_9,
-        -0.999_115_35,
-        -1.000_887_4,
-        -0.999_109_8,
-        -1.000_893,
-        -0.999_104_14,
-        -1.000_898_7,
-        -0.999_098_36,
-        -1.000_904_6,
-        -0.999_092_5,
-        -1.000_910_4,
-        -0.999_086_56,
-        -1.000_916_5,
-        -0.999_080_5,
-        -1.000_922_7,
-        -0.999_074_2,
-        -1.000_928_9,
-        -0.999_067_9,
-        -1.000_935_3,
-        -0.999_061_47,
-        -1.000_941_8,
-        -0.999_054_9,
-        -1.000_948_4,
-        -0.999_048_2,
-        -1.000_955_2,
-        -0.999_041_4,
-        -1.000_962_1,
-        -0.999_034_4,
-        -1.000_969_2,
-        -0.999_027_25,
-        -1.000_976_3,
-        -0.999_020_04,
-        -1.000_983_7,
-        -0.999_012_6,
-        -1.000_991_1,
-        -0.999_005_1,
-        -1.000_998_7,
-        -0.998_997_33,
-        -1.001_006_6,
-        -0.998_989_46,
-        -1.001_014_5,
-        -0.998_981_5,
-        -1.001_022_6,
-        -0.998_973_25,
-        -1.001_030_9,
-        -0.998_964_9,
-        -1.001_039_4,
-        -0.998_956_4,
-        -1.001_048,
-        -0.998_947_6,
-        -1.001_056_8,
-        -0.998_938_74,
-        -1.001_065_9,
-        -0.998_929_6,
-        -1.001_075,
-        -0.998_920_3,
-        -1.001_084_4,
-        -0.998_910_8,
-        -1.001_094,
-        -0.998_901_07,
-        -1.001_103_9,
-        -0.998_891_1,
-        -1.001_113_9,
-        -0.998_881,
-        -1.001_124_1,
-        -0.998_870_6,
-        -1.001_134_8,
-        -0.998_859_94,
-        -1.001_145_5,
-        -0.998_849_1,
-        -1.001_156_4,
-        -0.998_837_95,
-        -1.001_167_8,
-        -0.998_826_56,
-        -1.001_179_2,
-        -0.998_814_9,
-        -1.001_191,
-        -0.998_802_96,
-        -1.001_203_2,
-        -0.998_790_74,
-        -1.001_215_5,
-        -0.998_778_16,
-        -1.001_228_2,
-        -0.998_765_35,
-        -1.001_241_2,
-        -0.998_752_2,
-        -1.001_254_6,
-        -0.998_738_7,
-        -1.001_268_1,
-        -0.998_724_9,
-        -1.001_282_2,
-        -0.998_710_7,
-        -1.001_296_5,
-        -0.998_696_1,
-        -1.001_311_3,
-        -0.998_681_2,
-        -1.001_326_4,
-        -0.998_665_8,
-        -1.001_342,
-        -0.998_650_1,
-        -1.001_357_9,
-        -0.998_633_86,
-        -1.001_374_4,
-        -0.998_617_3,
-        -1.001_391_2,
-        -0.998_600_2,
-        -1.001_408_6,
-        -0.998_582_6,
-        -1.001_426_3,
-        -0.998_564_54,
-        -1.001_444_7,
-        -0.998_545_94,
-        -1.001_463_5,
-        -0.998_526_8,
-        -1.001_483,
-        -0.998_507_1,
-        -1.001_503,
-        -0.998_486_8,
-        -1.001_523_6,
-        -0.998_465_9,
-        -1.001_544_8,
-        -0.998_444_3,
-        -1.001_566_8,
-        -0.998_422_1,
-        -1.001_589_3,
-        -0.998_399_14,
-        -1.001_612_7,
-        -0.998_375_4,
-        -1.001_636_7,
-        -0.998_351,
-        -1.001_661_5,
-        -0.998_325_7,
-        -1.001_687_3,
-        -0.998_299_6,
-        -1.001_713_8,
-        -0.998_272_6,
-        -1.001_741_3,
-        -0.998_244_64,
-        -1.001_769_7,
-        -0.998_215_7,
-        -1.001_799_2,
-        -0.998_185_7,
-        -1.001_829_7,
-        -0.998_154_64,
-        -1.001_861_3,
-        -0.998_122_4,
-        -1.001_894_2,
-        -0.998_088_96,
-        -1.001_928_2,
-        -0.998_054_2,
-        -1.001_963_7,
-        -0.998_018_1,
-        -1.002_000_5,
-        -0.997_980_54,
-        -1.002_038_8,
-        -0.997_941_5,
-        -1.002_078_7,
-        -0.997_900_8,
-        -1.002_120_3,
-        -0.997_858_35,
-        -1.002_163_5,
-        -0.997_814_1,
-        -1.002_208_7,
-        -0.997_767_9,
-        -1.002_256,
-        -0.997_719_6,
-        -1.002_305_4,
-        -0.997_669_1,
-        -1.002_357,
-        -0.997_616_2,
-        -1.002_411_2,
-        -0.997_560_74,
-        -1.002_468,
-        -0.997_502_57,
-        -1.002_527_6,
-        -0.997_441_4,
-        -1.002_590_3,
-        -0.997_377_16,
-        -1.002_656_2,
-        -0.997_309_45,
-        -1.002_725_8,
-        -0.997_238_04,
-        -1.002_799_2,
-        -0.997_162_64,
-        -1.002_876_8,
-        -0.997_082_8,
-        -1.002_958_8,
-        -0.996_998_37,
-        -1.003_045_8,
-        -0.996_908_7,
-        -1.003_138_2,
-        -0.996_813_4,
-        -1.003_236_5,
-        -0.996_711_97,
-        -1.003_341_3,
-        -0.996_603_67,
-        -1.003_453_3,
-        -0.996_487_86,
-        -1.003_573_1,
-        -0.996_363_76,
-        -1.003_701_7,
-        -0.996_230_4,
-        -1.003_840_1,
-        -0.996_086_7,
-        -1.003_989_3,
-        -0.995_931_57,
-        -1.004_150_9,
-        -0.995_763_36,
-        -1.004_326_1,
-        -0.995_580_5,
-        -1.004_517_1,
-        -0.995_380_94,
-        -1.004_725_8,
-        -0.995_162_37,
-        -1.004_954_9,
-        -0.994_921_86,
-        -1.005_207_7,
-        -0.994_655_97,
-        -1.005_487_8,
-        -0.994_360_45,
-        -1.005_8,
-        -0.994_030_2,
-        -1.006_15,
-        -0.993_658_5,
-        -1.006_545_3,
-        -0.993_237_2,
-        -1.006_995_2,
-        -0.992_755_7,
-        -1.007_511_7,
-        -0.992_200_14,
-        -1.008_111,
-        -0.991_551_9,
-        -1.008_814_5,
-        -0.990_785_84,
-        -1.009_651_9,
-        -0.989_866_6,
-        -1.010_665_4,
-        -0.988_743_36,
-        -1.011_917_2,
-        -0.987_339_74,
-        -1.013_502_1,
-        -0.985_536,
-        -1.015_573_5,
-        -0.983_132_9,
-        -1.018_395,
-        -0.979_773_16,
-        -1.022_463_2,
-        -0.974_745_9,
-        -1.028_834_2,
-        -0.966_408_55,
-        -1.040_216,
-        -0.949_938_06,
-        -1.066_187_4,
-        -0.902_822_7,
-        -1.178_980_1,
+        Formant::new(350., 40., 0.),
+        Formant::new(600., 60., -20.),
+        Formant::new(2700., 100., -17.),
     ],
 ];
+
+/// The pitch the vowel table's formants are placed relative to, they
+/// move along with the pitch of the notes played, like in any wavetable
+pub const VOWEL_FUNDAMENTAL_HZ: f32 = 110.;
+/// Frames between two consecutive vowels of the vowel table
+pub const VOWEL_MORPH_FRAMES: usize = 16;
+
+/// `amplitude(k)` is that of the `k`th harmonic, starting from `1`, in sine phase
+fn harmonics(amplitude: impl Fn(usize) -> f32) -> [Complex32; NUM_HARMONICS] {
+    array::from_fn(|i| Complex32::new(amplitude(i + 1), 0.))
+}
+
+/// Of a pulse, high for the first `duty_cycle` of a cycle, low for the rest, `k` starts from `1`
+fn pulse_harmonic(k: usize, duty_cycle: f32) -> Complex32 {
+    let (sin, cos) = (2. * PI * k as f32 * duty_cycle).sin_cos();
+    Complex32::new(1. - cos, sin) * (2. / (PI * k as f32))
+}
+
+/// `1` for even `n`s, `-1` for odd ones
+fn alternating(n: usize) -> f32 {
+    if n % 2 == 0 {
+        1.
+    } else {
+        -1.
+    }
+}
+
+fn saw() -> [Complex32; NUM_HARMONICS] {
+    harmonics(|k| alternating(k - 1) * 2. / (PI * k as f32))
+}
+
+fn square() -> [Complex32; NUM_HARMONICS] {
+    harmonics(|k| match k % 2 {
+        1 => 4. / (PI * k as f32),
+        _ => 0.,
+    })
+}
+
+/// Sine, triangle, saw and square, in that order, all starting at `0`, and rising
+pub fn generate() -> Box<BandLimitedWaveTables> {
+    let sine = harmonics(|k| if k == 1 { 1. } else { 0. });
+
+    // odd harmonics only
+    let triangle = harmonics(|k| match k % 2 {
+        1 => alternating(k / 2) * 8. / (PI * PI * (k * k) as f32),
+        _ => 0.,
+    });
+
+    BandLimitedWaveTables::from_harmonics(&[&sine, &triangle, &saw(), &square()]).unwrap()
+}
+
+/// Scales every frame's peak to 0 dBFS, then regenerates the mipmaps
+fn normalized(mut table: Box<BandLimitedWaveTables>) -> Box<BandLimitedWaveTables> {
+    table.normalize_frames(Normalize::PerFramePeak);
+    table.create_mipmaps();
+    table
+}
+
+/// Pulses, with duty cycles going linearly from 50% (a square) to 5%
+pub fn pwm_sweep() -> Box<BandLimitedWaveTables> {
+    let frames: Vec<_> = (0..PWM_SWEEP_FRAMES)
+        .map(|i| {
+            let duty_cycle = 0.5 - 0.45 * i as f32 / (PWM_SWEEP_FRAMES - 1) as f32;
+            array::from_fn::<_, NUM_HARMONICS, _>(|k| pulse_harmonic(k + 1, duty_cycle))
+        })
+        .collect();
+
+    let frames: Vec<_> = frames.iter().map(|frame| frame.as_slice()).collect();
+
+    normalized(BandLimitedWaveTables::from_harmonics(&frames).unwrap())
+}
+
+/// Sines at the harmonics of `DRAWBAR_HARMONICS`, one frame per entry of
+/// `DRAWBAR_REGISTRATIONS`, every drawbar position is 3 dB louder than the previous one
+pub fn harmonic_drawbars() -> Box<BandLimitedWaveTables> {
+    let frames = DRAWBAR_REGISTRATIONS.map(|registration| {
+        // up to that of the 1' drawbar
+        let mut harmonics = [Complex32::new(0., 0.); 8];
+
+        for (&harmonic, &position) in DRAWBAR_HARMONICS.iter().zip(&registration) {
+            if position != 0 {
+                let amplitude = ((f32::from(position) - 8.) / 2.).exp2();
+                harmonics[harmonic - 1] = Complex32::new(amplitude, 0.);
+            }
+        }
+
+        harmonics
+    });
+
+    let frames = frames.each_ref().map(|frame| frame.as_slice());
+
+    normalized(BandLimitedWaveTables::from_harmonics(&frames).unwrap())
+}
+
+/// Morphs through the vowels of `VOWEL_FORMANTS`, the `n`th one is at frame
+/// `n * VOWEL_MORPH_FRAMES`, formants glide linearly between vowels
+pub fn vowels() -> Box<BandLimitedWaveTables> {
+    let last_vowel = VOWEL_FORMANTS.len() - 1;
+
+    let frames: Vec<_> = (0..=last_vowel * VOWEL_MORPH_FRAMES)
+        .map(|i| {
+            let vowel = i / VOWEL_MORPH_FRAMES;
+            let next = (vowel + 1).min(last_vowel);
+            let t = (i % VOWEL_MORPH_FRAMES) as f32 / VOWEL_MORPH_FRAMES as f32;
+
+            let formants: [_; 3] =
+                array::from_fn(|j| VOWEL_FORMANTS[vowel][j].lerp(&VOWEL_FORMANTS[next][j], t));
+
+            // a saw-like source, filtered by the vocal tract
+            harmonics(|k| {
+                let frequency = k as f32 * VOWEL_FUNDAMENTAL_HZ;
+                let response: f32 = formants.iter().map(|f| f.response(frequency)).sum();
+                response / k as f32
+            })
+        })
+        .collect();
+
+    let frames: Vec<_> = frames.iter().map(|frame| frame.as_slice()).collect();
+
+    normalized(BandLimitedWaveTables::from_harmonics(&frames).unwrap())
+}
+
+/// Crossfades, spectrally, from a saw to a square, the even harmonics fade out, the
+/// odd ones go from the saw's amplitudes to the square's
+pub fn saw_to_square_morph() -> Box<BandLimitedWaveTables> {
+    let (saw, square) = (saw(), square());
+
+    let frames: Vec<_> = (0..SAW_TO_SQUARE_FRAMES)
+        .map(|i| {
+            let t = i as f32 / (SAW_TO_SQUARE_FRAMES - 1) as f32;
+            array::from_fn::<_, NUM_HARMONICS, _>(|k| saw[k] * (1. - t) + square[k] * t)
+        })
+        .collect();
+
+    let frames: Vec<_> = frames.iter().map(|frame| frame.as_slice()).collect();
+
+    BandLimitedWaveTables::from_harmonics(&frames).unwrap()
+}
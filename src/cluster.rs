@@ -1,6 +1,7 @@
 use super::*;
 use cell_project::cell_project as cp;
-use voice::Oscillator;
+use rng::{Rng, XorShift32};
+use voice::{DetuneCurve, Oscillator};
 
 /// # Safety
 /// Both `from` and `to` must be `< STEREO_VOICES_PER_VECTOR`
@@ -17,6 +18,53 @@ unsafe fn swap_index_cell_unchecked<T>(
     );
 }
 
+/// Exponential smoothing never quite reaches its target, and the shrinking difference
+/// ends up in the denormal range, which is very slow on most CPUs. Values this close
+/// to their targets are snapped to them
+const SMOOTHER_SNAP_DISTANCE: f32 = 1e-6;
+
+/// How the frame scan moves the frame over a cycle, see `WTOscVoiceCluster::advance_frame_scan`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum FrameScanShape {
+    /// From the frame parameter's position up by the depth, then back in one step
+    Forward,
+    /// Up by the depth, then back down, over a cycle
+    PingPong,
+    /// Towards a new random position, within the depth, every cycle
+    RandomWalk,
+}
+
+impl FrameScanShape {
+    pub const ALL: [Self; 3] = [Self::Forward, Self::PingPong, Self::RandomWalk];
+}
+
+/// The largest move, of a random walk frame scan, in one cycle, relative to its depth
+const SCAN_WALK_STEP: f32 = 0.25;
+
+/// Unison voice `k`, `FLOATS_PER_VECTOR` of them per oscillator, at
+/// `frac(k / φ)`, see `RetriggerMode::GoldenRatio`
+fn golden_ratio_phases() -> [Float; OSCS_PER_VOICE] {
+    // 1 / φ
+    const INV_GOLDEN_RATIO: f64 = 0.618_033_988_749_894_8;
+
+    array::from_fn(|i| {
+        Float::from_array(array::from_fn(|lane| {
+            let k = i * FLOATS_PER_VECTOR + lane;
+            (k as f64 * INV_GOLDEN_RATIO).fract() as f32
+        }))
+    })
+}
+
+#[inline]
+fn snap_if_close(smoother: &mut GenericSmoother) {
+    let close = (smoother.target - smoother.current)
+        .abs()
+        .simd_lt(Simd::splat(SMOOTHER_SNAP_DISTANCE));
+
+    smoother.current = close.select(smoother.target, smoother.current);
+}
+
 unsafe fn permute_smoother_values(
     this: &Cell<GenericSmoother>,
     from: usize,
@@ -34,6 +82,8 @@ unsafe fn permute_smoother_values(
     swap_index_cell_unchecked(this_target_vals, from, other_target_vals, to);
 }
 
+/// Rendering reads the smoothers' current values, note on decisions
+/// (like how random starting phases are) read their targets
 pub struct WTOscClusterNormParams {
     level: GenericSmoother,
     pub frame: GenericSmoother,
@@ -44,7 +94,79 @@ pub struct WTOscClusterNormParams {
     stereo: GenericSmoother,
     pub detune_range: GenericSmoother,
     pub random: GenericSmoother,
+    pub frame_spread: GenericSmoother,
+    pub unison_stack: GenericSmoother,
+    /// In cycles per unit of input
+    pub pm_amount: GenericSmoother,
+    /// 0 disables hard sync, see `Self::sync_ratio`
+    pub sync: GenericSmoother,
+    /// See `DetuneCurve`
+    pub detune_curve: GenericSmoother,
+    /// Level of the side unison voices, relative to the center ones
+    pub blend: GenericSmoother,
+    /// Analog-style pitch drift depth, see `MAX_DRIFT_CENTS`
+    pub drift: GenericSmoother,
+    /// Portamento time, see `Self::glide_secs`
+    pub glide: GenericSmoother,
+    /// How much softer notes are quieter, see `Self::velocity_gain`
+    pub velocity_level: GenericSmoother,
+    /// How much harder notes move the frame forward
+    pub velocity_frame: GenericSmoother,
+    /// How much per voice pressure moves the frame forward
+    pub pressure_frame: GenericSmoother,
+    /// How much per voice slide raises the level, as a linear gain
+    pub slide_level: GenericSmoother,
+    /// Which loaded table the cluster renders, see `Self::table_slot`
+    pub table_select: GenericSmoother,
+    /// Of the output soft clipper, see `Self::drive_gain`
+    pub drive: GenericSmoother,
+    /// Random pitch offset depth, see `Self::humanize_semitones`
+    pub humanize: GenericSmoother,
+    /// See `Self::detune_skew`
+    pub detune_skew: GenericSmoother,
+    /// See `Self::scan_rate`
+    pub scan_rate: GenericSmoother,
+    /// See `FrameScanShape`
+    pub scan_shape: GenericSmoother,
+    /// Normalized, see `Self::frame_scan`
+    pub scan_depth: GenericSmoother,
+    /// See `Self::feedback`
+    pub feedback: GenericSmoother,
+    /// See `Self::ring_ratio`
+    pub ring_ratio: GenericSmoother,
+    /// See `WTOscVoiceCluster::apply_ring_mod`
+    pub ring_mix: GenericSmoother,
+    /// Of the wavefolder, see `Self::fold_gain`
+    pub fold: GenericSmoother,
+    /// Linear, see `WTOscVoiceCluster::add_noise`
+    pub noise_level: GenericSmoother,
+    /// Per voice expression, normalized, see `Self::set_expression`
+    pressure: GenericSmoother,
+    slide: GenericSmoother,
+    /// The last normalized value each parameter was set to, after sanitizing,
+    /// indexed by `WTOscParam`, see `WTOsc::save_state`
+    norm_targets: [Float; NUM_PARAMS as usize],
     pub phase_delta: Float,
+    /// Normalized, of the last note played by each voice
+    velocity: Float,
+    /// MIDI note number, of the last note played by each voice, NaN
+    /// for voices set to a frequency, see `WTOsc::set_voice_frequency`
+    pub note: Float,
+    /// In `[-1 ; 1)`, drawn for each note played by each voice, scaled by `Self::humanize`
+    pub humanize_offsets: Float,
+    /// Normalized, added to the frame, see `WTOscVoiceCluster::advance_frame_scan`
+    pub frame_scan: Float,
+    /// Shared by all clusters, see `WTOsc::custom_event`
+    pub pan_law: PanLaw,
+    /// Shared by all clusters, see `WTOsc::custom_event`
+    pub unison_normalization: UnisonNormalization,
+    /// Shared by all clusters, see `FrameSnap`
+    pub frame_snap: bool,
+    glide_target: Float,
+    /// Per sample, in octaves
+    glide_log2_step: Float,
+    /// In samples
+    glide_remaining: Float,
 }
 
 impl Default for WTOscClusterNormParams {
@@ -59,13 +181,50 @@ impl Default for WTOscClusterNormParams {
             stereo: Default::default(),
             detune_range: Default::default(),
             random: Default::default(),
+            frame_spread: Default::default(),
+            unison_stack: Default::default(),
+            pm_amount: Default::default(),
+            sync: Default::default(),
+            detune_curve: Default::default(),
+            blend: Default::default(),
+            drift: Default::default(),
+            glide: Default::default(),
+            velocity_level: Default::default(),
+            velocity_frame: Default::default(),
+            pressure_frame: Default::default(),
+            slide_level: Default::default(),
+            table_select: Default::default(),
+            drive: Default::default(),
+            humanize: Default::default(),
+            detune_skew: Default::default(),
+            scan_rate: Default::default(),
+            scan_shape: Default::default(),
+            scan_depth: Default::default(),
+            feedback: Default::default(),
+            ring_ratio: Default::default(),
+            ring_mix: Default::default(),
+            fold: Default::default(),
+            noise_level: Default::default(),
+            pressure: Default::default(),
+            slide: Default::default(),
+            norm_targets: Default::default(),
             phase_delta: Default::default(),
+            velocity: Float::splat(1.),
+            note: Default::default(),
+            humanize_offsets: Default::default(),
+            frame_scan: Default::default(),
+            pan_law: Default::default(),
+            unison_normalization: Default::default(),
+            frame_snap: false,
+            glide_target: Default::default(),
+            glide_log2_step: Default::default(),
+            glide_remaining: Default::default(),
         };
 
         let all_voices = TMask::splat(true);
 
-        for (i, value) in DEFAULT_PARAMS.iter().copied().map(splat_stereo).enumerate() {
-            out.set_param_instantly(i as u64, value, all_voices);
+        for (&param, &value) in WTOscParam::ALL.iter().zip(DEFAULT_PARAMS.iter()) {
+            out.set_param_instantly(param, splat_stereo(value), all_voices);
         }
 
         out
@@ -73,18 +232,47 @@ impl Default for WTOscClusterNormParams {
 }
 
 impl WTOscClusterNormParams {
+    pub const TRANSPOSE_RANGE_SEMITONES: f32 = PITCH_RANGE_SEMITONES;
+
+    /// `log2_alphas` holds each parameter's smoothing rate, and `expression_log2_alpha`
+    /// that of the voices' expression, see `WTOsc::set_smoothing_time_ms`
     #[inline]
-    pub fn tick_n(&mut self, log2_alpha: f32, n: usize) {
-        let alpha = Simd::splat(exp2(Simd::from_array([log2_alpha * n as f32]))[0]);
-        self.level.smooth_exp(alpha);
-        self.frame.smooth_exp(alpha);
-        self.num_voices.smooth_exp(alpha);
-        self.detune.smooth_exp(alpha);
-        self.pan.smooth_exp(alpha);
-        self.transpose.smooth_exp(alpha);
-        self.stereo.smooth_exp(alpha);
-        self.detune_range.smooth_exp(alpha);
-        self.random.smooth_exp(alpha);
+    pub fn tick_n(
+        &mut self,
+        log2_alphas: &[f32; NUM_PARAMS as usize],
+        expression_log2_alpha: f32,
+        n: usize,
+    ) {
+        let alpha =
+            |log2_alpha: f32| Simd::splat(exp2(Simd::from_array([log2_alpha * n as f32]))[0]);
+
+        for (param, &log2_alpha) in WTOscParam::ALL.into_iter().zip(log2_alphas) {
+            let smoother = self.get_param_smoother_mut(param);
+            smoother.smooth_exp(alpha(log2_alpha));
+            snap_if_close(smoother);
+        }
+
+        let expression_alpha = alpha(expression_log2_alpha);
+        self.pressure.smooth_exp(expression_alpha);
+        self.slide.smooth_exp(expression_alpha);
+        snap_if_close(&mut self.pressure);
+        snap_if_close(&mut self.slide);
+
+        self.tick_glide(n);
+    }
+
+    /// Glides, exponentially, `n` samples closer to the target phase delta
+    #[inline]
+    fn tick_glide(&mut self, n: usize) {
+        let gliding = self.glide_remaining.simd_gt(Simd::splat(0.));
+
+        let steps = self.glide_remaining.simd_min(Simd::splat(n as f32));
+        self.glide_remaining -= steps;
+
+        let done = self.glide_remaining.simd_le(Simd::splat(0.));
+        let glided = self.phase_delta * exp2(self.glide_log2_step * steps);
+
+        self.phase_delta = gliding.select(done.select(self.glide_target, glided), self.phase_delta);
     }
 
     #[inline]
@@ -113,37 +301,247 @@ impl WTOscClusterNormParams {
             (cp!(Self, this.stereo), cp!(Self, other.stereo)),
             (cp!(Self, this.detune_range), cp!(Self, other.detune_range)),
             (cp!(Self, this.random), cp!(Self, other.random)),
+            (cp!(Self, this.frame_spread), cp!(Self, other.frame_spread)),
+            (cp!(Self, this.unison_stack), cp!(Self, other.unison_stack)),
+            (cp!(Self, this.pm_amount), cp!(Self, other.pm_amount)),
+            (cp!(Self, this.sync), cp!(Self, other.sync)),
+            (cp!(Self, this.detune_curve), cp!(Self, other.detune_curve)),
+            (cp!(Self, this.blend), cp!(Self, other.blend)),
+            (cp!(Self, this.drift), cp!(Self, other.drift)),
+            (cp!(Self, this.glide), cp!(Self, other.glide)),
+            (
+                cp!(Self, this.velocity_level),
+                cp!(Self, other.velocity_level),
+            ),
+            (
+                cp!(Self, this.velocity_frame),
+                cp!(Self, other.velocity_frame),
+            ),
+            (
+                cp!(Self, this.pressure_frame),
+                cp!(Self, other.pressure_frame),
+            ),
+            (cp!(Self, this.slide_level), cp!(Self, other.slide_level)),
+            (cp!(Self, this.table_select), cp!(Self, other.table_select)),
+            (cp!(Self, this.drive), cp!(Self, other.drive)),
+            (cp!(Self, this.humanize), cp!(Self, other.humanize)),
+            (cp!(Self, this.detune_skew), cp!(Self, other.detune_skew)),
+            (cp!(Self, this.scan_rate), cp!(Self, other.scan_rate)),
+            (cp!(Self, this.scan_shape), cp!(Self, other.scan_shape)),
+            (cp!(Self, this.scan_depth), cp!(Self, other.scan_depth)),
+            (cp!(Self, this.feedback), cp!(Self, other.feedback)),
+            (cp!(Self, this.ring_ratio), cp!(Self, other.ring_ratio)),
+            (cp!(Self, this.ring_mix), cp!(Self, other.ring_mix)),
+            (cp!(Self, this.fold), cp!(Self, other.fold)),
+            (cp!(Self, this.noise_level), cp!(Self, other.noise_level)),
+            (cp!(Self, this.pressure), cp!(Self, other.pressure)),
+            (cp!(Self, this.slide), cp!(Self, other.slide)),
         ] {
             permute_smoother_values(input, from, output, to);
         }
 
-        swap_index_cell_unchecked(
-            split_stereo_cell(cp!(Self, this.phase_delta)),
-            from,
-            split_stereo_cell(cp!(Self, other.phase_delta)),
-            to,
-        );
+        for (input, output) in [
+            (cp!(Self, this.phase_delta), cp!(Self, other.phase_delta)),
+            (cp!(Self, this.velocity), cp!(Self, other.velocity)),
+            (cp!(Self, this.note), cp!(Self, other.note)),
+            (
+                cp!(Self, this.humanize_offsets),
+                cp!(Self, other.humanize_offsets),
+            ),
+            (cp!(Self, this.frame_scan), cp!(Self, other.frame_scan)),
+            (cp!(Self, this.glide_target), cp!(Self, other.glide_target)),
+            (
+                cp!(Self, this.glide_log2_step),
+                cp!(Self, other.glide_log2_step),
+            ),
+            (
+                cp!(Self, this.glide_remaining),
+                cp!(Self, other.glide_remaining),
+            ),
+        ] {
+            swap_index_cell_unchecked(
+                split_stereo_cell(input),
+                from,
+                split_stereo_cell(output),
+                to,
+            );
+        }
+
+        let inputs = cp!(Self, this.norm_targets).as_array_of_cells();
+        let outputs = cp!(Self, other.norm_targets).as_array_of_cells();
+
+        for (input, output) in inputs.iter().zip(outputs) {
+            swap_index_cell_unchecked(
+                split_stereo_cell(input),
+                from,
+                split_stereo_cell(output),
+                to,
+            );
+        }
     }
 
     #[inline]
-    pub fn get_param_smoother_mut(&mut self, param_id: u64) -> &mut GenericSmoother {
-        match param_id {
-            0 => &mut self.level,
-            1 => &mut self.frame,
-            2 => &mut self.num_voices,
-            3 => &mut self.detune,
-            4 => &mut self.pan,
-            5 => &mut self.transpose,
-            6 => &mut self.stereo,
-            7 => &mut self.detune_range,
-            8 => &mut self.random,
-            _ => unreachable!(),
+    pub fn get_param_smoother_mut(&mut self, param: WTOscParam) -> &mut GenericSmoother {
+        match param {
+            WTOscParam::Level => &mut self.level,
+            WTOscParam::Frame => &mut self.frame,
+            WTOscParam::NumVoices => &mut self.num_voices,
+            WTOscParam::Detune => &mut self.detune,
+            WTOscParam::Pan => &mut self.pan,
+            WTOscParam::Transpose => &mut self.transpose,
+            WTOscParam::Stereo => &mut self.stereo,
+            WTOscParam::DetuneRange => &mut self.detune_range,
+            WTOscParam::Random => &mut self.random,
+            WTOscParam::FrameSpread => &mut self.frame_spread,
+            WTOscParam::UnisonStack => &mut self.unison_stack,
+            WTOscParam::PmAmount => &mut self.pm_amount,
+            WTOscParam::Sync => &mut self.sync,
+            WTOscParam::DetuneCurve => &mut self.detune_curve,
+            WTOscParam::Blend => &mut self.blend,
+            WTOscParam::Drift => &mut self.drift,
+            WTOscParam::Glide => &mut self.glide,
+            WTOscParam::VelocityLevel => &mut self.velocity_level,
+            WTOscParam::VelocityFrame => &mut self.velocity_frame,
+            WTOscParam::PressureFrame => &mut self.pressure_frame,
+            WTOscParam::SlideLevel => &mut self.slide_level,
+            WTOscParam::TableSelect => &mut self.table_select,
+            WTOscParam::Drive => &mut self.drive,
+            WTOscParam::Humanize => &mut self.humanize,
+            WTOscParam::DetuneSkew => &mut self.detune_skew,
+            WTOscParam::ScanRate => &mut self.scan_rate,
+            WTOscParam::ScanShape => &mut self.scan_shape,
+            WTOscParam::ScanDepth => &mut self.scan_depth,
+            WTOscParam::Feedback => &mut self.feedback,
+            WTOscParam::RingRatio => &mut self.ring_ratio,
+            WTOscParam::RingMix => &mut self.ring_mix,
+            WTOscParam::Fold => &mut self.fold,
+            WTOscParam::NoiseLevel => &mut self.noise_level,
         }
     }
 
+    /// Transpose spans `±TRANSPOSE_RANGE_SEMITONES`, centered at `0.5`
+    #[inline]
+    pub fn transpose_semitones_from_norm(norm_val: f32x2) -> f32x2 {
+        norm_val.mul_add(Simd::splat(2.0), Simd::splat(-1.0))
+            * Simd::splat(Self::TRANSPOSE_RANGE_SEMITONES)
+    }
+
+    /// Whole voice counts, see `normalized_to_unison_count`
     #[inline]
     pub fn num_voices_from_norm(norm_val: Float) -> Float {
-        norm_val.mul_add(Simd::splat(15.998), Simd::splat(1.001))
+        const LAST_COUNT: Float = const_splat((MAX_UNISON - 1) as f32);
+
+        // unlike simd_clamp, simd_max and simd_min turn NaNs into 0
+        let norm_val = norm_val.simd_max(Simd::splat(0.)).simd_min(Simd::splat(1.));
+
+        (norm_val * LAST_COUNT).round() + Simd::splat(1.)
+    }
+
+    /// The frequency ratio of stacked unison voices, see `UNISON_STACK_RATIOS`
+    #[inline]
+    pub fn unison_stack_ratio_from_norm(norm_val: f32x2) -> f32x2 {
+        let last_mode = (UNISON_STACK_RATIOS.len() - 1) as f32;
+
+        f32x2::from_array(norm_val.to_array().map(|norm| {
+            UNISON_STACK_RATIOS
+                [((norm * last_mode).round() as usize).min(UNISON_STACK_RATIOS.len() - 1)]
+        }))
+    }
+
+    /// The index of the selected unison detune curve, see `DetuneCurve`
+    #[inline]
+    pub fn detune_curve_from_norm(norm_val: f32x2) -> Simd<u32, 2> {
+        let last_curve = (DetuneCurve::ALL.len() - 1) as f32;
+
+        Simd::from_array(norm_val.to_array().map(|norm| {
+            DetuneCurve::ALL[((norm * last_curve).round() as usize).min(DetuneCurve::ALL.len() - 1)]
+                as u32
+        }))
+    }
+
+    #[inline]
+    pub fn drift_semitones(&self) -> Float {
+        self.drift.current * Simd::splat(MAX_DRIFT_CENTS / 100.)
+    }
+
+    /// Half the difference between the left and right channels' detune factors, see
+    /// `MAX_DETUNE_SKEW`
+    #[inline]
+    pub fn detune_skew(&self) -> Float {
+        self.detune_skew.current * Simd::splat(MAX_DETUNE_SKEW / 2.)
+    }
+
+    /// Per voice, the depth times the offset drawn for the voice's last note
+    #[inline]
+    pub fn humanize_semitones(&self) -> Float {
+        self.humanize.current * self.humanize_offsets * Simd::splat(MAX_HUMANIZE_CENTS / 100.)
+    }
+
+    /// Input gain of the output soft clipper, up to `MAX_DRIVE_DB`,
+    /// `0` where the drive is all the way down, and the clipper bypassed
+    #[inline]
+    pub fn drive_gain(&self) -> Float {
+        Float::from_array(self.drive.current.to_array().map(|norm| {
+            if norm <= 0. {
+                0.
+            } else {
+                10f32.powf(norm * MAX_DRIVE_DB / 20.)
+            }
+        }))
+    }
+
+    /// Input gain of the wavefolder, in `(1 ; MAX_FOLD_GAIN]`, `0`
+    /// where the fold is all the way down, and the wavefolder bypassed
+    #[inline]
+    pub fn fold_gain(&self) -> Float {
+        let norm = self.fold.current;
+
+        norm.simd_gt(Float::splat(0.)).select(
+            norm.mul_add(Simd::splat(MAX_FOLD_GAIN - 1.), Simd::splat(1.)),
+            Float::splat(0.),
+        )
+    }
+
+    /// Out of `num_slots` (non-zero) evenly spread over the normalized range. Tables
+    /// are chosen per cluster, this reads the first voice's value
+    #[inline]
+    pub fn table_slot(&self, num_slots: usize) -> usize {
+        let norm = self.table_select.current[0].clamp(0., 1.);
+        ((norm * num_slots as f32) as usize).min(num_slots - 1)
+    }
+
+    /// Quadratic, for finer control over short glides
+    #[inline]
+    pub fn glide_secs(&self) -> Float {
+        let norm = self.glide.current;
+        norm * norm * Simd::splat(MAX_GLIDE_SECS)
+    }
+
+    /// Slave/master frequency ratio, in `[1 ; MAX_SYNC_RATIO]`
+    #[inline]
+    pub fn sync_ratio(&self) -> Float {
+        self.sync
+            .current
+            .mul_add(Simd::splat(MAX_SYNC_RATIO - 1.), Simd::splat(1.))
+    }
+
+    /// Self phase modulation depth, in cycles per unit of output, see `MAX_FEEDBACK`
+    #[inline]
+    pub fn feedback(&self) -> Float {
+        self.feedback.current * Simd::splat(MAX_FEEDBACK)
+    }
+
+    /// Of the ring modulator's frequency to the note's, in
+    /// `[2^-RING_RATIO_OCTAVES ; 2^RING_RATIO_OCTAVES]`, `1` at `0.5`
+    #[inline]
+    pub fn ring_ratio(&self) -> Float {
+        const SEMITONES: f32 = 12. * RING_RATIO_OCTAVES;
+
+        semitones_to_ratio(
+            self.ring_ratio
+                .current
+                .mul_add(Simd::splat(2. * SEMITONES), Simd::splat(-SEMITONES)),
+        )
     }
 
     #[inline]
@@ -151,43 +549,280 @@ impl WTOscClusterNormParams {
         Self::num_voices_from_norm(self.num_voices.current)
     }
 
+    #[inline]
+    pub fn set_velocity(&mut self, velocity: Float, voice_mask: TMask) {
+        self.velocity = voice_mask.select(velocity, self.velocity);
+    }
+
+    /// MPE-style continuous per voice controllers, both normalized
+    #[inline]
+    pub fn set_expression(&mut self, pressure: Float, slide: Float, voice_mask: TMask) {
+        self.pressure.set_target(pressure, voice_mask);
+        self.slide.set_target(slide, voice_mask);
+    }
+
+    /// `1` at full velocity, down to `1 - velocity_level` at zero velocity
+    #[inline]
+    pub fn velocity_gain(&self) -> Float {
+        let depth = self.velocity_level.current;
+        depth.mul_add(self.velocity - Float::splat(1.), Float::splat(1.))
+    }
+
+    /// The frame parameter, plus the voices' velocity and pressure contributions,
+    /// and the frame scan, not clamped
+    #[inline]
+    pub fn modulated_frame(&self) -> Float {
+        let frame = self
+            .velocity_frame
+            .current
+            .mul_add(self.velocity, self.frame.current);
+
+        let frame = self
+            .pressure_frame
+            .current
+            .mul_add(self.pressure.current, frame);
+
+        // exactly zero at zero depth, which leaves the frame bit-identical
+        frame + self.frame_scan
+    }
+
+    /// In Hz, or cycles per beat at `tempo` (in beats per minute), quadratic,
+    /// like the glide time, for finer control over slow scans
+    #[inline]
+    pub fn scan_rate(&self, tempo: Option<f32>) -> Float {
+        let norm = self.scan_rate.current;
+        let rate = norm * norm * Simd::splat(MAX_SCAN_RATE);
+
+        match tempo {
+            Some(bpm) => rate * Simd::splat(bpm / 60.),
+            None => rate,
+        }
+    }
+
+    /// The index of the selected frame scan shape, see `FrameScanShape`
+    #[inline]
+    pub fn scan_shape_from_norm(norm_val: Float) -> UInt {
+        let last_shape = (FrameScanShape::ALL.len() - 1) as f32;
+
+        UInt::from_array(norm_val.to_array().map(|norm| {
+            FrameScanShape::ALL
+                [((norm * last_shape).round() as usize).min(FrameScanShape::ALL.len() - 1)]
+                as u32
+        }))
+    }
+
+    /// Linear gain, the level parameter scaled by the voices' velocity, plus their slide
+    #[inline]
+    pub fn modulated_level(&self) -> Float {
+        let level = self.level.current * self.velocity_gain();
+        self.slide_level.current.mul_add(self.slide.current, level)
+    }
+
     #[inline]
     pub fn set_base_phase_delta(&mut self, w: Float, voice_mask: TMask) {
         self.phase_delta = voice_mask.select(w, self.phase_delta);
+        self.glide_target = voice_mask.select(w, self.glide_target);
+        self.glide_remaining = voice_mask.select(Simd::splat(0.), self.glide_remaining);
     }
 
+    /// Retunes every voice, including gliding ones, by the same ratio
     #[inline]
-    pub fn set_param_target(&mut self, param_id: u64, norm_val: Float, voice_mask: TMask) {
-        match param_id {
-            0..=MAX_PARAM_INDEX => {
-                let smoother = self.get_param_smoother_mut(param_id);
-                smoother.set_target(norm_val, voice_mask);
-            }
-            _ => unreachable!(),
+    pub fn scale_base_phase_delta(&mut self, ratio: Float) {
+        self.phase_delta *= ratio;
+        self.glide_target *= ratio;
+    }
+
+    /// After a sample rate change, `ratio` is the old rate over the new one,
+    /// voices, and glides, keep their pitch, and the time glides have left
+    #[inline]
+    pub fn rescale_sample_rate(&mut self, ratio: f32) {
+        let ratio = Simd::splat(ratio);
+
+        self.scale_base_phase_delta(ratio);
+        self.glide_log2_step *= ratio;
+        self.glide_remaining /= ratio;
+    }
+
+    /// The ratio by which to scale every voice for them to play `phase_delta`,
+    /// or `1` for voices that haven't played a note yet, or are set to a frequency
+    #[inline]
+    pub fn retune_ratio(&self, phase_delta: Float) -> Float {
+        let played = self.glide_target.simd_gt(Simd::splat(0.)) & !self.note.is_nan();
+        played.select(phase_delta / self.glide_target, Simd::splat(1.))
+    }
+
+    /// Glide from the current base phase delta to `w`, linearly in
+    /// semitones, over `num_samples` samples, see `Self::tick_n`
+    #[inline]
+    pub fn glide_base_phase_delta(&mut self, w: Float, num_samples: Float, voice_mask: TMask) {
+        let octaves = Simd::from_array((w / self.phase_delta).to_array().map(f32::log2));
+
+        self.glide_target = voice_mask.select(w, self.glide_target);
+        self.glide_log2_step = voice_mask.select(octaves / num_samples, self.glide_log2_step);
+        self.glide_remaining = voice_mask.select(num_samples, self.glide_remaining);
+    }
+
+    #[inline]
+    pub fn set_param_target(&mut self, param: WTOscParam, norm_val: Float, voice_mask: TMask) {
+        self.set_norm_target(param, norm_val, voice_mask);
+        let val = Self::smoothed_val_from_norm(param, norm_val);
+        self.get_param_smoother_mut(param)
+            .set_target(val, voice_mask);
+    }
+
+    #[inline]
+    pub fn set_param_instantly(&mut self, param: WTOscParam, norm_val: Float, voice_mask: TMask) {
+        self.set_norm_target(param, norm_val, voice_mask);
+        let val = Self::smoothed_val_from_norm(param, norm_val);
+        self.get_param_smoother_mut(param)
+            .set_val_instantly(val, voice_mask);
+    }
+
+    #[inline]
+    fn set_norm_target(&mut self, param: WTOscParam, norm_val: Float, voice_mask: TMask) {
+        let target = &mut self.norm_targets[param as usize];
+        *target = voice_mask.select(Self::sanitize_norm(param, norm_val), *target);
+    }
+
+    /// The normalized value `param` was last set to, per lane
+    #[inline]
+    pub fn norm_target(&self, param: WTOscParam) -> Float {
+        self.norm_targets[param as usize]
+    }
+
+    /// Most parameters are smoothed in the normalized domain, except for
+    /// the level, which is smoothed as a linear gain, to avoid zipper noise
+    #[inline]
+    fn smoothed_val_from_norm(param: WTOscParam, norm_val: Float) -> Float {
+        let norm_val = Self::sanitize_norm(param, norm_val);
+
+        match param {
+            WTOscParam::Level => Self::level_gain_from_norm(norm_val),
+            _ => norm_val,
+        }
+    }
+
+    /// Hosts may overshoot `[0 ; 1]` slightly when automating, or send
+    /// NaNs, these are replaced by the parameter's default value
+    #[inline]
+    fn sanitize_norm(param: WTOscParam, norm_val: Float) -> Float {
+        let default = splat_stereo(DEFAULT_PARAMS[param as usize]);
+        let clamped = norm_val.simd_clamp(Simd::splat(0.), Simd::splat(1.));
+
+        norm_val.is_nan().select(default, clamped)
+    }
+
+    /// In dB: -inf at `0`, -60 at `0.05`, 0 at `0.8` and +6 at `1`, linear in dB
+    /// between these points, except below `0.05`, where it's linear in gain
+    #[inline]
+    pub fn level_db_from_norm(norm_val: f32) -> f32 {
+        if norm_val >= LEVEL_UNITY_NORM {
+            (norm_val - LEVEL_UNITY_NORM) * (LEVEL_MAX_DB / (1. - LEVEL_UNITY_NORM))
+        } else if norm_val >= LEVEL_FLOOR_NORM {
+            (norm_val - LEVEL_UNITY_NORM)
+                * (-LEVEL_FLOOR_DB / (LEVEL_UNITY_NORM - LEVEL_FLOOR_NORM))
+        } else {
+            20. * (norm_val / LEVEL_FLOOR_NORM).log10() + LEVEL_FLOOR_DB
         }
     }
 
+    /// The inverse of `Self::level_db_from_norm`
+    #[inline]
+    pub fn level_norm_from_db(db: f32) -> f32 {
+        let norm_val = if db >= 0. {
+            db * ((1. - LEVEL_UNITY_NORM) / LEVEL_MAX_DB) + LEVEL_UNITY_NORM
+        } else if db >= LEVEL_FLOOR_DB {
+            db * ((LEVEL_UNITY_NORM - LEVEL_FLOOR_NORM) / -LEVEL_FLOOR_DB) + LEVEL_UNITY_NORM
+        } else {
+            10f32.powf((db - LEVEL_FLOOR_DB) / 20.) * LEVEL_FLOOR_NORM
+        };
+
+        norm_val.clamp(0., 1.)
+    }
+
     #[inline]
-    pub fn set_param_instantly(&mut self, param_id: u64, norm_val: Float, voice_mask: TMask) {
-        match param_id {
-            0..=MAX_PARAM_INDEX => {
-                let smoother = self.get_param_smoother_mut(param_id);
-                smoother.set_val_instantly(norm_val, voice_mask);
+    pub fn level_gain_from_norm(norm_val: Float) -> Float {
+        Float::from_array(norm_val.to_array().map(|norm| {
+            if norm <= 0. {
+                0.
+            } else {
+                10f32.powf(Self::level_db_from_norm(norm) / 20.)
             }
-            _ => unreachable!(),
+        }))
+    }
+
+    /// Keeps the unison's total power, assuming uncorrelated voices, the same
+    /// regardless of the voice count and of the side voices' level (`blend`).
+    /// In adaptive mode, also compensates for the voices' estimated correlation
+    #[inline]
+    pub fn unison_normalisation(&self) -> Float {
+        let num_voices = self.num_voices_f().floor();
+        let num_pairs = (num_voices * Float::splat(0.5)).ceil();
+        let blend = self.blend.current;
+
+        let side_pairs = num_pairs - Float::splat(1.);
+        let side_pairs_power = side_pairs * blend * blend;
+
+        let uncorrelated = num_pairs / (num_voices * (Float::splat(1.) + side_pairs_power));
+
+        if self.unison_normalization == UnisonNormalization::Uncorrelated {
+            return uncorrelated;
+        }
+
+        // every channel gets one voice of each pair, fully correlated voices add up
+        // in amplitude, so their power is that many times higher than uncorrelated ones'
+        let gain_sum = side_pairs.mul_add(blend, Float::splat(1.));
+        let coherent_gain = gain_sum * gain_sum / (Float::splat(1.) + side_pairs_power);
+        let correlation = self.unison_correlation();
+
+        uncorrelated / correlation.mul_add(coherent_gain - Float::splat(1.), Float::splat(1.))
+    }
+
+    /// A rough estimate of how correlated unison voices are, `1` without detune nor random
+    /// phases, falling linearly to `0` at `DECORRELATED_DETUNE_CENTS` of detune
+    #[inline]
+    pub fn unison_correlation(&self) -> Float {
+        let detune_cents = self.detune_range.current
+            * Simd::splat(PITCH_RANGE_SEMITONES * 100.)
+            * self.detune.current;
+
+        let detuned = (detune_cents * Simd::splat(DECORRELATED_DETUNE_CENTS.recip()))
+            .simd_clamp(Simd::splat(0.), Simd::splat(1.));
+
+        (Float::splat(1.) - self.random.current) * (Float::splat(1.) - detuned)
+    }
+
+    /// Per channel power weights, for every stereo voice
+    #[inline]
+    pub fn pan_weights(pan_law: PanLaw, pan: Float) -> Float {
+        if pan_law == PanLaw::Triangular {
+            return triangular_pan_weights(pan);
         }
+
+        // how far towards each lane's channel every voice is panned, in [0 ; 1]
+        let right = UInt::from_array(array::from_fn(|i| i as u32 & 1)).simd_eq(UInt::splat(1));
+        let towards = right.select(pan, Float::splat(1.) - pan);
+
+        Float::from_array(towards.to_array().map(|x| {
+            let constant_power = (x * FRAC_PI_2).sin();
+
+            match pan_law {
+                PanLaw::ConstantPower => constant_power * constant_power,
+                // halfway (in dB) between the linear and constant power laws
+                _ => x * constant_power,
+            }
+        }))
     }
 
     #[inline]
     pub fn get_sample_weights(&self) -> (Float, Float) {
-        let norm_level = self.level.current;
-        let level = norm_level * norm_level;
+        let level = self.modulated_level();
 
         let stereo = self.stereo.current;
         let pan = self.pan.current;
 
-        let unison_normalisation = self.num_voices_f().recip();
-        let pan_weights = triangular_pan_weights(pan) * unison_normalisation;
+        let unison_normalisation = self.unison_normalisation();
+        let pan_weights = Self::pan_weights(self.pan_law, pan) * unison_normalisation;
 
         (
             pan_weights.mul_add(stereo, pan_weights).sqrt() * level,
@@ -201,26 +836,313 @@ pub struct WTOscVoiceCluster {
     voices: [[Oscillator; OSCS_PER_VOICE]; STEREO_VOICES_PER_VECTOR],
     normal_weights: LinearSmoother,
     flipped_weights: LinearSmoother,
+    /// Per voice amplitude ramps, to avoid clicks when voices start and stop
+    voice_fades: Float,
+    voice_fade_steps: Float,
+    /// Per lane, see `Self::block_dc`
+    dc_last_input: Float,
+    dc_last_output: Float,
+    rng: Rng,
+    /// Separate from `rng`, humanizing notes doesn't change the random starting phases
+    humanize_rng: Rng,
+    /// Per voice, in cycles, in `[0 ; 1)`
+    scan_phases: Float,
+    /// Random walk frame scan positions, relative to the depth, in `[0 ; 1]`, at
+    /// the start of the current cycle, and at its end
+    scan_walk_start: Float,
+    scan_walk_end: Float,
+    /// Separate from `rng`, for the same reason as `humanize_rng`
+    scan_rng: Rng,
+    /// Of the ring modulators' sines, per voice, in cycles, in `[0 ; 1)`
+    ring_phases: Float,
+    /// Separate from `rng`, for the same reason as `humanize_rng`
+    noise_rng: XorShift32,
 }
 
 impl WTOscVoiceCluster {
+    #[inline]
+    pub fn voices(&self) -> &[[Oscillator; OSCS_PER_VOICE]; STEREO_VOICES_PER_VECTOR] {
+        &self.voices
+    }
+
     #[inline]
     pub fn voices_mut(&mut self) -> &mut [[Oscillator; OSCS_PER_VOICE]; STEREO_VOICES_PER_VECTOR] {
         &mut self.voices
     }
 
     #[inline]
-    pub fn get_sample_weights(&self) -> (Float, Float) {
-        (
-            self.normal_weights.get_current(),
-            self.flipped_weights.get_current(),
-        )
+    pub fn seed(&mut self, seed: u32) {
+        self.rng.seed(seed);
+        self.humanize_rng.seed(!seed);
+        self.scan_rng.seed(seed.rotate_left(16));
+        self.noise_rng.seed(seed.rotate_left(8));
+
+        for osc in self.voices.iter_mut().flatten() {
+            osc.seed_drift(self.rng.next_u32()[0]);
+        }
+    }
+
+    /// Sets `params.frame_scan` to the offsets the frame scan adds to the frame for
+    /// the block about to be rendered, then advances it by `cycles`, per voice
+    #[inline]
+    pub fn advance_frame_scan(&mut self, params: &mut WTOscClusterNormParams, cycles: Float) {
+        const ONE: Float = const_splat(1.);
+        const TWO: Float = const_splat(2.);
+
+        let phases = self.scan_phases;
+        let shapes = WTOscClusterNormParams::scan_shape_from_norm(params.scan_shape.current);
+        let is_shape = |shape: FrameScanShape| shapes.simd_eq(UInt::splat(shape as u32));
+
+        let ping_pong = ONE - phases.mul_add(TWO, -ONE).abs();
+        let walk = lerp(self.scan_walk_start, self.scan_walk_end, phases);
+
+        let shaped = is_shape(FrameScanShape::PingPong).select(
+            ping_pong,
+            is_shape(FrameScanShape::RandomWalk).select(walk, phases),
+        );
+
+        params.frame_scan = params.scan_depth.current * shaped;
+
+        let phases = phases + cycles;
+        let wrapped = phases.simd_ge(ONE);
+        self.scan_phases = phases - phases.floor();
+
+        if wrapped.any() {
+            // one step per voice, the same in both of its channels
+            let random = self.scan_rng.next_f32();
+            let steps = Float::from_array(array::from_fn(|i| random[i & !1]));
+            let steps = steps.mul_add(TWO, -ONE) * Simd::splat(SCAN_WALK_STEP);
+
+            let end = (self.scan_walk_end + steps).simd_clamp(Simd::splat(0.), ONE);
+
+            self.scan_walk_start = wrapped.select(self.scan_walk_end, self.scan_walk_start);
+            self.scan_walk_end = wrapped.select(end, self.scan_walk_end);
+        }
+    }
+
+    /// Restarts the frame scan of the voices in `voice_mask`, from the frame parameter's position
+    #[inline]
+    pub fn reset_frame_scan(&mut self, params: &mut WTOscClusterNormParams, voice_mask: TMask) {
+        let zero = Float::splat(0.);
+
+        self.scan_phases = voice_mask.select(zero, self.scan_phases);
+        self.scan_walk_start = voice_mask.select(zero, self.scan_walk_start);
+        self.scan_walk_end = voice_mask.select(zero, self.scan_walk_end);
+        params.frame_scan = voice_mask.select(zero, params.frame_scan);
+    }
+
+    /// Adds white noise, in `[-level ; level)`, independent in every lane, to each voice's
+    /// unison sum, lanes with a zero level are left bit-identical, and when every lane's
+    /// level is zero, the noise generator isn't advanced
+    #[inline]
+    pub fn add_noise(&mut self, output_buf: &mut [Float], level: Float) {
+        let noisy = level.simd_gt(Float::splat(0.));
+
+        if !noisy.any() {
+            return;
+        }
+
+        for sample in output_buf {
+            let noise = self.noise_rng.next_bipolar();
+            *sample = noisy.select(noise.mul_add(level, *sample), *sample);
+        }
     }
 
+    /// Multiplies each voice's unison sum by a sine at `params.ring_ratio()` times the
+    /// voice's pitch, crossfaded in by the ring mix, a zero mix leaves the voice's
+    /// samples bit-identical, and when every voice's mix is zero, its sine isn't advanced
     #[inline]
-    pub fn tick_weight_smoothers(&mut self) {
-        self.normal_weights.tick1();
-        self.flipped_weights.tick1();
+    pub fn apply_ring_mod(&mut self, output_buf: &mut [Float], params: &WTOscClusterNormParams) {
+        const ONE: Float = const_splat(1.);
+
+        let mix = params.ring_mix.current;
+
+        if mix.simd_eq(Float::splat(0.)).all() {
+            return;
+        }
+
+        let phase_delta = params.phase_delta * params.ring_ratio();
+        let phase_delta = phase_delta - phase_delta.floor();
+        let mut phases = self.ring_phases;
+
+        for sample in output_buf {
+            *sample *= (Self::sin_cycles(phases) - ONE).mul_add(mix, ONE);
+
+            phases += phase_delta;
+            phases -= phases.floor();
+        }
+
+        self.ring_phases = phases;
+    }
+
+    #[inline]
+    pub fn reset_ring_mod(&mut self, voice_mask: TMask) {
+        self.ring_phases = voice_mask.select(Float::splat(0.), self.ring_phases);
+    }
+
+    /// `sin(2π * phase)`, for phases in `[0 ; 1)`, within `1e-6`, the phase is
+    /// reflected into a quarter of a cycle, around zero, where an odd polynomial is used
+    #[inline]
+    fn sin_cycles(phase: Float) -> Float {
+        const HALF: Float = const_splat(0.5);
+        const QUARTER: Float = const_splat(0.25);
+        // the Taylor series of `sin(2π * x)`, up to `x^11`
+        const COEFFS: [f32; 6] = [
+            TAU,
+            -41.341_702,
+            81.605_25,
+            -76.705_86,
+            42.058_694,
+            -15.094_643,
+        ];
+
+        // in `[-0.5 ; 0.5)`, half a cycle away, so the sign is flipped
+        let x = phase - HALF;
+        let x = x.abs().simd_gt(QUARTER).select(HALF.copysign(x) - x, x);
+        let x2 = x * x;
+
+        let poly = COEFFS.iter().rev().fold(Float::splat(0.), |acc, &coeff| {
+            acc.mul_add(x2, Simd::splat(coeff))
+        });
+
+        -(x * poly)
+    }
+
+    /// In `[-1 ; 1)`, one per voice, the same in both of its channels,
+    /// see `WTOscClusterNormParams::humanize_offsets`
+    #[inline]
+    pub fn next_humanize_offsets(&mut self) -> Float {
+        let random = self.humanize_rng.next_f32();
+        let offsets = Float::from_array(array::from_fn(|i| random[i & !1]));
+
+        offsets.mul_add(Simd::splat(2.), Simd::splat(-1.))
+    }
+
+    /// One-pole, one-zero high-pass, removing DC offsets from `output_buf`, in place:
+    /// `y[n] = x[n] - x[n - 1] + coeff * y[n - 1]`
+    #[inline]
+    pub fn block_dc(&mut self, output_buf: &mut [Float], coeff: Float) {
+        let mut last_input = self.dc_last_input;
+        let mut last_output = self.dc_last_output;
+
+        for sample in output_buf {
+            let input = *sample;
+            last_output = coeff.mul_add(last_output, input - last_input);
+            last_input = input;
+            *sample = last_output;
+        }
+
+        // on silence, the output decays towards zero forever, flushed before it gets denormal
+        const FLUSH_BELOW: Float = const_splat(1e-15);
+        let flushed = last_output.abs().simd_lt(FLUSH_BELOW);
+
+        self.dc_last_input = last_input;
+        self.dc_last_output = flushed.select(Float::splat(0.), last_output);
+    }
+
+    #[inline]
+    pub fn reset_dc_blocker(&mut self, voice_mask: TMask) {
+        self.dc_last_input = voice_mask.select(Float::splat(0.), self.dc_last_input);
+        self.dc_last_output = voice_mask.select(Float::splat(0.), self.dc_last_output);
+    }
+
+    /// Folds each voice's unison sum back into `[-1 ; 1]`, after scaling it by `fold_gain`
+    /// (see `WTOscClusterNormParams::fold_gain`), lanes where it's zero are left untouched
+    #[inline]
+    pub fn apply_wavefold(&mut self, output_buf: &mut [Float], fold_gain: Float) {
+        let folded = fold_gain.simd_gt(Float::splat(0.));
+
+        if !folded.any() {
+            return;
+        }
+
+        for sample in output_buf {
+            *sample = folded.select(Self::fold(*sample * fold_gain), *sample);
+        }
+    }
+
+    /// Triangular, unity gain in `[-1 ; 1]`, reflected back and forth at `±1` past it.
+    /// Inputs are clamped to `±MAX_FOLD_GAIN`, bounding the number of folds
+    #[inline]
+    fn fold(x: Float) -> Float {
+        const LIMIT: Float = const_splat(MAX_FOLD_GAIN);
+        const ONE: Float = const_splat(1.);
+        const TWO: Float = const_splat(2.);
+        const PERIOD: Float = const_splat(4.);
+
+        // shifted by a quarter of a period, so that zero lands in the middle of a rising slope
+        let x = x.simd_clamp(-LIMIT, LIMIT) + ONE;
+        let wrapped = x - (x / PERIOD).floor() * PERIOD;
+
+        ONE - (wrapped - TWO).abs()
+    }
+
+    /// Cubic, unity gain around zero, reaching `±1` (with a zero slope) at `±1.5`
+    #[inline]
+    fn soft_clip(x: Float) -> Float {
+        const LIMIT: Float = const_splat(1.5);
+        const CUBIC: Float = const_splat(-4. / 27.);
+        const ONE: Float = const_splat(1.);
+
+        let x = x.simd_clamp(-LIMIT, LIMIT);
+
+        (x * x * CUBIC).mul_add(x, x).simd_clamp(-ONE, ONE)
+    }
+
+    /// Applies the stereo weights, and the voice fades, to `output_buf`, while advancing
+    /// their ramps, then the soft clipper, where `drive_gain` isn't zero (see
+    /// `WTOscClusterNormParams::drive_gain`), lanes where it is are left untouched.
+    /// The smoothers are copied out, so that they stay in registers during the loop,
+    /// and are still ticked one sample at a time, to stay sample-exact
+    /// (`start + i * increment` rounds differently than repeatedly adding `increment`)
+    #[inline]
+    pub fn apply_weights(&mut self, output_buf: &mut [Float], drive_gain: Float) {
+        let mut normal_weights = self.normal_weights;
+        let mut flipped_weights = self.flipped_weights;
+        let mut fades = self.voice_fades;
+        let fade_steps = self.voice_fade_steps;
+
+        let clipped = drive_gain.simd_gt(Float::splat(0.));
+
+        for poly_sample in output_buf {
+            let sample = *poly_sample;
+            let out = sample * normal_weights.get_current()
+                + swap_stereo(sample) * flipped_weights.get_current();
+            let out = out * fades;
+            *poly_sample = clipped.select(Self::soft_clip(out * drive_gain), out);
+
+            normal_weights.tick1();
+            flipped_weights.tick1();
+            fades = (fades + fade_steps).simd_clamp(Simd::splat(0.), Simd::splat(1.));
+        }
+
+        self.normal_weights = normal_weights;
+        self.flipped_weights = flipped_weights;
+        self.voice_fades = fades;
+    }
+
+    /// Ramps the voices in `voice_mask` up from their current level, `step` per sample
+    #[inline]
+    pub fn fade_in(&mut self, voice_mask: TMask, step: Float) {
+        self.voice_fade_steps = voice_mask.select(step, self.voice_fade_steps);
+    }
+
+    /// Ramps the voices in `voice_mask` down to silence, `step` per sample
+    #[inline]
+    pub fn fade_out(&mut self, voice_mask: TMask, step: Float) {
+        self.voice_fade_steps = voice_mask.select(-step, self.voice_fade_steps);
+    }
+
+    /// Voices that have been activated, and haven't yet been faded out completely
+    #[inline]
+    pub fn active_voices(&self) -> TMask {
+        self.voice_fade_steps.simd_gt(Simd::splat(0.)) | self.voice_fades.simd_gt(Simd::splat(0.))
+    }
+
+    /// Voices that have been faded out completely
+    #[inline]
+    pub fn faded_out_voices(&self) -> TMask {
+        self.voice_fade_steps.simd_lt(Simd::splat(0.)) & self.voice_fades.simd_le(Simd::splat(0.))
     }
 
     #[inline]
@@ -246,15 +1168,57 @@ impl WTOscVoiceCluster {
         }
     }
 
+    /// After a sample rate change, `ratio` is the old rate over the new one,
+    /// oscillators keep their pitch, and voice fades their length
     #[inline]
-    pub fn scale_phase_deltas(&mut self, ratio: Float) {
+    pub fn rescale_sample_rate(&mut self, ratio: f32) {
+        let ratio = Simd::splat(ratio);
+
+        self.scale_phase_deltas(ratio);
+        self.voice_fade_steps *= ratio;
+    }
+
+    /// See `Oscillator::clamp_frame`
+    #[inline]
+    pub fn clamp_frames(&mut self, max_frame: Float) {
         for oscs in self.voices.iter_mut() {
+            for osc in oscs {
+                osc.clamp_frame(max_frame);
+            }
+        }
+    }
+
+    #[inline]
+    pub fn scale_phase_deltas(&mut self, ratio: Float) {
+        // one ratio per voice, the oscillators' lanes hold unison voices
+        for (oscs, &ratio) in self.voices.iter_mut().zip(split_stereo(&ratio)) {
+            let ratio = splat_stereo(ratio);
+
             for osc in oscs {
                 osc.scale_phase_delta(ratio);
             }
         }
     }
 
+    /// Snaps the phase deltas of the voices in `voice_mask` to those `params` gives them
+    #[inline]
+    pub fn set_phase_deltas(&mut self, params: &WTOscClusterNormParams, voice_mask: TMask) {
+        for (i, oscs) in self
+            .voices
+            .iter_mut()
+            .enumerate()
+            .zip(voice_mask.to_array().into_iter().step_by(2))
+            .filter_map(|(data, active)| active.then_some(data))
+        {
+            let (voice_params, _) = unsafe { VoiceParams::new_unchecked(i, params) };
+
+            for (j, osc) in oscs.iter_mut().enumerate() {
+                let (total_detune, _, _) = voice_params.get_params(j);
+                osc.set_phase_delta(voice_params.base_phase_delta * total_detune);
+            }
+        }
+    }
+
     #[inline]
     pub fn set_params(
         &mut self,
@@ -330,6 +1294,54 @@ impl WTOscVoiceCluster {
             to,
         );
 
+        swap_index_cell_unchecked(
+            split_stereo_cell(cp!(Self, this.voice_fades)),
+            from,
+            split_stereo_cell(cp!(Self, other.voice_fades)),
+            to,
+        );
+
+        swap_index_cell_unchecked(
+            split_stereo_cell(cp!(Self, this.voice_fade_steps)),
+            from,
+            split_stereo_cell(cp!(Self, other.voice_fade_steps)),
+            to,
+        );
+
+        swap_index_cell_unchecked(
+            split_stereo_cell(cp!(Self, this.dc_last_input)),
+            from,
+            split_stereo_cell(cp!(Self, other.dc_last_input)),
+            to,
+        );
+
+        swap_index_cell_unchecked(
+            split_stereo_cell(cp!(Self, this.dc_last_output)),
+            from,
+            split_stereo_cell(cp!(Self, other.dc_last_output)),
+            to,
+        );
+
+        for (input, output) in [
+            (cp!(Self, this.scan_phases), cp!(Self, other.scan_phases)),
+            (
+                cp!(Self, this.scan_walk_start),
+                cp!(Self, other.scan_walk_start),
+            ),
+            (
+                cp!(Self, this.scan_walk_end),
+                cp!(Self, other.scan_walk_end),
+            ),
+            (cp!(Self, this.ring_phases), cp!(Self, other.ring_phases)),
+        ] {
+            swap_index_cell_unchecked(
+                split_stereo_cell(input),
+                from,
+                split_stereo_cell(output),
+                to,
+            );
+        }
+
         let this_voice = cp!(Self, this.voices);
         let other_voice = cp!(Self, other.voices);
 
@@ -340,9 +1352,16 @@ impl WTOscVoiceCluster {
     pub fn reset_phases(
         &mut self,
         voice_mask: TMask,
+        mode: RetriggerMode,
         randomisation: Float,
         starting_phases: &[Float; OSCS_PER_VOICE],
     ) {
+        if mode == RetriggerMode::Free {
+            return;
+        }
+
+        let golden_ratio_phases = golden_ratio_phases();
+
         for (voice, &random) in self
             .voices
             .iter_mut()
@@ -351,8 +1370,18 @@ impl WTOscVoiceCluster {
             .filter_map(|(data, active)| active.then_some(data))
         {
             let random = splat_stereo(random);
-            for (osc, starting_phase) in voice.iter_mut().zip(starting_phases) {
-                osc.set_phase(flp_to_fxp(starting_phase * random));
+            for ((osc, starting_phase), golden_ratio_phase) in voice
+                .iter_mut()
+                .zip(starting_phases)
+                .zip(golden_ratio_phases)
+            {
+                let phase = match mode {
+                    RetriggerMode::Random => self.rng.next_f32() * random,
+                    RetriggerMode::GoldenRatio => golden_ratio_phase,
+                    _ => *starting_phase,
+                };
+
+                osc.set_phase(flp_to_fxp(wrap_phase(phase)));
             }
         }
     }
@@ -0,0 +1,140 @@
+use super::*;
+use core::f64::consts::PI;
+
+/// Linear phase, with a group delay of `(TAPS - 1) / 2` samples at the input rate
+const TAPS: usize = 65;
+/// Of the Kaiser window, roughly 80 dB of stopband attenuation
+const KAISER_BETA: f64 = 8.;
+
+/// Zeroth order modified Bessel function of the first kind
+fn bessel_i0(x: f64) -> f64 {
+    let mut term = 1.;
+    let mut sum = 1.;
+
+    for k in 1..32 {
+        term *= (x / (2. * k as f64)).powi(2);
+        sum += term;
+    }
+
+    sum
+}
+
+/// Kaiser windowed sinc, cut off at a quarter of the input sample rate
+fn half_band_coeffs() -> [f32; TAPS] {
+    let center = (TAPS - 1) as i32 / 2;
+
+    let coeffs: [f64; TAPS] = array::from_fn(|k| {
+        let n = k as i32 - center;
+
+        let sinc = match n {
+            0 => 0.5,
+            // exactly zero, at every other tap
+            n if n % 2 == 0 => return 0.,
+            n => (PI / 2. * n as f64).sin() / (PI * n as f64),
+        };
+
+        let x = n as f64 / center as f64;
+        let window = bessel_i0(KAISER_BETA * (1. - x * x).sqrt()) / bessel_i0(KAISER_BETA);
+
+        sinc * window
+    });
+
+    // unity gain at DC
+    let sum: f64 = coeffs.iter().sum();
+    coeffs.map(|coeff| (coeff / sum) as f32)
+}
+
+#[derive(Clone)]
+struct HalfBand {
+    delay: [Float; TAPS],
+    pos: usize,
+}
+
+impl Default for HalfBand {
+    fn default() -> Self {
+        Self {
+            delay: [Float::splat(0.); TAPS],
+            pos: 0,
+        }
+    }
+}
+
+impl HalfBand {
+    #[inline]
+    fn push(&mut self, sample: Float) {
+        self.pos = (self.pos + 1) % TAPS;
+        self.delay[self.pos] = sample;
+    }
+
+    #[inline]
+    fn output(&self, coeffs: &[f32; TAPS]) -> Float {
+        let (newest, oldest) = self.delay.split_at(self.pos + 1);
+
+        // every other coefficient (but the center one) is zero
+        newest
+            .iter()
+            .rev()
+            .chain(oldest.iter().rev())
+            .zip(coeffs)
+            .filter(|(_, &coeff)| coeff != 0.)
+            .fold(Float::splat(0.), |acc, (&sample, &coeff)| {
+                sample.mul_add(Simd::splat(coeff), acc)
+            })
+    }
+
+    /// Halves the sample rate of `buffer`, writing the result to its first half
+    #[inline]
+    fn decimate_in_place(&mut self, buffer: &mut [Float], coeffs: &[f32; TAPS]) {
+        for i in 0..buffer.len() / 2 {
+            self.push(buffer[2 * i]);
+            self.push(buffer[2 * i + 1]);
+            buffer[i] = self.output(coeffs);
+        }
+    }
+}
+
+/// A cascade of half-band FIR filters, each halving the sample rate
+#[derive(Clone)]
+pub struct Decimator {
+    coeffs: [f32; TAPS],
+    stages: Box<[HalfBand]>,
+}
+
+impl Decimator {
+    pub fn new(factor: usize) -> Self {
+        Self {
+            coeffs: half_band_coeffs(),
+            stages: iter::repeat_with(Default::default)
+                .take(factor.trailing_zeros() as usize)
+                .collect(),
+        }
+    }
+
+    /// The ratio of the input rate to the output rate
+    pub fn factor(&self) -> usize {
+        1 << self.stages.len()
+    }
+
+    /// In samples, at the output rate
+    pub fn latency(factor: usize) -> usize {
+        let stage_delay = (TAPS - 1) / 2;
+
+        // the first stage runs at the highest rate
+        (0..factor.trailing_zeros())
+            .map(|stage| stage_delay >> (factor.trailing_zeros() - stage))
+            .sum()
+    }
+
+    /// `input` is used as scratch space, its length must be that of `output` times the factor
+    #[inline]
+    pub fn process(&mut self, input: &mut [Float], output: &mut [Float]) {
+        let mut len = input.len();
+
+        for stage in self.stages.iter_mut() {
+            stage.decimate_in_place(&mut input[..len], &self.coeffs);
+            len /= 2;
+        }
+
+        output.copy_from_slice(&input[..len]);
+    }
+}
@@ -2,14 +2,28 @@
 
 extern crate alloc;
 
-mod basic_shapes;
+pub mod basic_shapes;
 mod cluster;
+mod decimator;
 mod oscillator;
+pub mod params;
+pub mod render;
+mod rng;
+pub mod tuning;
 mod voice;
 pub mod wavetable;
 
+use alloc::{format, string::String, sync::Arc};
 use cluster::{WTOscClusterNormParams, WTOscVoiceCluster};
-use core::{any::Any, array, cell::Cell, f32::consts::FRAC_1_SQRT_2, iter, mem, num::NonZeroUsize};
+use core::{
+    any::Any,
+    array,
+    cell::Cell,
+    f32::consts::{FRAC_PI_2, TAU},
+    iter, mem,
+    num::NonZeroUsize,
+};
+use decimator::Decimator;
 use polygraph::{
     buffer::Buffers,
     processor::{Parameters, Processor},
@@ -20,263 +34,6305 @@ use polygraph::{
         *,
     },
 };
-use voice::VoiceParams;
-use wavetable::BandLimitedWaveTables;
+use std::{error, fmt};
+use tuning::TuningTable;
+use voice::{Oscillator, VoiceParams};
+use wavetable::{BandLimitedWaveTables, Quality};
 
 pub const MAX_UNISON: usize = 16;
 pub const PITCH_RANGE_SEMITONES: f32 = 48.0;
+/// Frequency ratios of every other unison voice pair for each unison stack
+/// mode: off, +1 octave, -1 octave, fifth, octave + fifth
+pub const UNISON_STACK_RATIOS: [f32; 5] = [1.0, 2.0, 0.5, 1.5, 3.0];
+/// Highest hard sync slave/master frequency ratio, a ratio of 1 disables hard sync
+pub const MAX_SYNC_RATIO: f32 = 16.0;
+/// Depth of the analog-style pitch drift, when the drift parameter is at its maximum
+pub const MAX_DRIFT_CENTS: f32 = 50.0;
+/// Largest random pitch offset of a note, when the humanize parameter is at its maximum
+pub const MAX_HUMANIZE_CENTS: f32 = 25.0;
+/// Difference between the left and right channels' detune factors, when the detune skew
+/// parameter is at its maximum, the left unison voices then detune 20% wider than the right
+pub const MAX_DETUNE_SKEW: f32 = 2.0 / 11.0;
+/// Frame scan rate, in Hz, or cycles per beat (see `Tempo`), at its maximum
+pub const MAX_SCAN_RATE: f32 = 20.0;
+/// Self phase modulation depth, in cycles per unit of output, when the feedback parameter
+/// is at its maximum, a quarter cycle (`π / 2` radians) already turns a sine into a near saw
+pub const MAX_FEEDBACK: f32 = 0.25;
+/// The ring modulator's frequency ratio to the note's spans this many octaves below, and above
+pub const RING_RATIO_OCTAVES: f32 = 4.0;
+/// Input gain of the wavefolder, when the fold parameter is at its maximum, a sine
+/// then folds back 4 times, in each direction
+pub const MAX_FOLD_GAIN: f32 = 8.0;
+pub const MAX_GLIDE_SECS: f32 = 10.0;
+/// Detune amount past which unison voices are considered uncorrelated,
+/// see `UnisonNormalization::Adaptive`
+pub const DECORRELATED_DETUNE_CENTS: f32 = 25.0;
+/// Input gain of the output soft clipper, when the drive parameter is at its maximum
+pub const MAX_DRIVE_DB: f32 = 24.0;
+/// Level parameter taper, see `WTOscClusterNormParams::level_db_from_norm`
+const LEVEL_FLOOR_NORM: f32 = 0.05;
+const LEVEL_FLOOR_DB: f32 = -60.0;
+const LEVEL_UNITY_NORM: f32 = 0.8;
+const LEVEL_MAX_DB: f32 = 6.0;
+/// What happens to the oscillators' phases when a note starts, send one
+/// through `custom_event` to change it
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum RetriggerMode {
+    /// Reset to the starting phases, exactly
+    #[default]
+    Retrigger,
+    /// Reset to fresh random phases, scaled by the random amount parameter
+    Random,
+    /// Keep running from where the previous note left off
+    Free,
+    /// Reset unison voice `k` to `frac(k / φ)`, which spreads the phases as evenly as
+    /// possible, for any voice count, without the thump aligned detuned voices make on
+    /// note on. Deterministic, unlike `Self::Random`, recommended for detuned unison
+    GoldenRatio,
+}
+
+/// A parameter change, queued to happen partway through the next processed block
+struct ParamEvent {
+    cluster_idx: usize,
+    voice_mask: TMask,
+    param: WTOscParam,
+    norm_val: Float,
+    sample_offset: usize,
+}
+
+/// The current, smoothed, state of a voice, every field holds a value per stereo channel
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct VoiceStateSnapshot {
+    /// Normalized
+    pub frame: f32x2,
+    /// In semitones, the largest offset of the unison voices
+    pub detune: f32x2,
+    /// Linear gain
+    pub level: f32x2,
+    /// Normalized
+    pub pan: f32x2,
+    pub num_voices: u32x2,
+    /// In Hz, before transposition and detuning
+    pub frequency: f32x2,
+}
+
+/// The normalized values every parameter was last set to, in every
+/// cluster, e. g. for presets, see `WTOsc::save_state`
+#[derive(Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WTOscState {
+    /// One entry per cluster, of `(param id, value of every lane)` pairs
+    pub clusters: Vec<Vec<(u64, Vec<f32>)>>,
+}
+
+/// How the pan parameter distributes voices' levels between channels,
+/// send one through `custom_event` to change it
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum PanLaw {
+    #[default]
+    Triangular,
+    /// -3 dB at the center
+    ConstantPower,
+    /// -4.5 dB at the center
+    Minus4_5dB,
+}
+
+/// How the unison's level is compensated for its voice count, send one
+/// through `custom_event` to change it
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum UnisonNormalization {
+    /// Keeps the power of uncorrelated (detuned) voices constant, fully
+    /// correlated voices get louder as more are added
+    #[default]
+    Uncorrelated,
+    /// Moves towards the compensation correlated voices need as the detune (and
+    /// random phase) amount goes to zero, see `DECORRELATED_DETUNE_CENTS`
+    Adaptive,
+}
+
+/// How many times faster than the host's sample rate the oscillators run, before their
+/// output is filtered and decimated, reducing aliasing, see `WTOsc::with_oversampling`
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Oversampling {
+    #[default]
+    None,
+    X2,
+    X4,
+}
+
+impl Oversampling {
+    #[inline]
+    pub fn factor(self) -> usize {
+        match self {
+            Self::None => 1,
+            Self::X2 => 2,
+            Self::X4 => 4,
+        }
+    }
+}
+
+/// Where `WTOsc::process` writes the voices, see `WTOsc::with_output_layout`
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum OutputLayout {
+    /// Every voice in its own pair of lanes, of a single output
+    #[default]
+    Polyphonic,
+    /// One output per voice, `STEREO_VOICES_PER_VECTOR` of them, each holding its voice's
+    /// stereo samples in its first two lanes, and silence in the others, e. g. for per note
+    /// effects. Pan and stereo width are applied as usual, unconnected outputs are skipped
+    PerVoice,
+}
+
+/// Seeds the oscillators' random number generators, for reproducible
+/// renders, send it through `custom_event`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RandomSeed(pub u32);
+
+/// Turns the high-pass filter removing DC offsets from the output on, or off,
+/// send it through `custom_event`, it's off by default
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct DcBlocker(pub bool);
+
+/// Rounds the oscillators' frame positions to whole frames, so that frame changes step
+/// between frames instead of morphing through them, send it through `custom_event`, it's
+/// off by default. The other parameters are still smoothed
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct FrameSnap(pub bool);
+
+/// The host's tempo, in beats per minute, send it through `custom_event`, while it's
+/// set, the frame scan rate is in cycles per beat, instead of Hz
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Tempo(pub Option<f32>);
+
+/// Displays a normalized transpose parameter value in semitones, e. g. `"+0 st"` for `0.5`
+pub fn format_transpose(norm_val: f32) -> String {
+    let semitones =
+        WTOscClusterNormParams::transpose_semitones_from_norm(f32x2::splat(norm_val))[0];
+
+    // adding 0 turns -0 into +0
+    format!("{:+} st", (semitones * 100.).round() / 100. + 0.)
+}
+
+/// The number of unison voices heard for a normalized num voices parameter value:
+/// `round(norm_val * (MAX_UNISON - 1)) + 1`, with `norm_val` clamped to `[0 ; 1]`,
+/// so every count gets an equal share of the range, and a bit of slack either side
+#[inline]
+pub fn normalized_to_unison_count(norm_val: f32) -> u32 {
+    WTOscClusterNormParams::num_voices_from_norm(Float::splat(norm_val))[0] as u32
+}
+
+/// `(count - 1) / (MAX_UNISON - 1)`, the center of the range mapped to `count`, which
+/// is clamped to `[1 ; MAX_UNISON]`, see `normalized_to_unison_count`
+#[inline]
+pub fn unison_count_to_normalized(count: u32) -> f32 {
+    (count.clamp(1, MAX_UNISON as u32) - 1) as f32 / (MAX_UNISON - 1) as f32
+}
+
+/// Keeps the first `len` elements of `boxed`, if there are as many, and appends
+/// new ones, made by `f`, otherwise. Only reallocates if the length changes
+fn resize_boxed<T>(boxed: &mut Box<[T]>, len: usize, f: impl FnMut() -> T) {
+    if boxed.len() != len {
+        let mut vec = mem::take(boxed).into_vec();
+        vec.resize_with(len, f);
+        *boxed = vec.into_boxed_slice();
+    }
+}
+
+/// The fractional part of `phase`, in cycles, so the result is in `[0 ; 1)`,
+/// which `flp_to_fxp` expects. NaNs and infinities become `0`
+#[inline]
+fn wrap_phase(phase: Float) -> Float {
+    let wrapped = phase - phase.floor();
+
+    // tiny negative phases round up to 1
+    wrapped
+        .simd_lt(Simd::splat(1.))
+        .select(wrapped, Simd::splat(0.))
+}
+
+// voices are stereo, even lanes are left channels, odd lanes right ones, so vectors hold
+// at least one voice, nothing else assumes a minimum width
+const _: () = assert!(FLOATS_PER_VECTOR >= 2 && FLOATS_PER_VECTOR % 2 == 0);
+
 const OSCS_PER_VOICE: usize = enclosing_div(MAX_UNISON, FLOATS_PER_VECTOR);
-const NUM_PARAMS: u64 = 9;
-const MAX_PARAM_INDEX: u64 = NUM_PARAMS - 1;
+const NUM_PARAMS: u64 = 33;
+/// Parameter ids, as passed to `Processor::set_param`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u64)]
+pub enum WTOscParam {
+    Level = 0,
+    Frame,
+    NumVoices,
+    Detune,
+    Pan,
+    Transpose,
+    Stereo,
+    DetuneRange,
+    Random,
+    FrameSpread,
+    UnisonStack,
+    PmAmount,
+    Sync,
+    DetuneCurve,
+    Blend,
+    Drift,
+    Glide,
+    VelocityLevel,
+    VelocityFrame,
+    PressureFrame,
+    SlideLevel,
+    /// Picks between the main table and those of the bank, see `WTOsc::table_slot`
+    TableSelect,
+    /// Of the output soft clipper, all the way down bypasses it
+    Drive,
+    /// Depth of a random pitch offset, drawn once per note, see `MAX_HUMANIZE_CENTS`
+    Humanize,
+    /// Widens the left channel's unison detune, and narrows the right's, see `MAX_DETUNE_SKEW`
+    DetuneSkew,
+    /// How fast the frame scan cycles, see `MAX_SCAN_RATE`
+    ScanRate,
+    /// See `FrameScanShape`
+    ScanShape,
+    /// How far, in normalized frames, the frame scan moves the frame forward
+    ScanDepth,
+    /// Each oscillator phase modulating itself with its last output, see `MAX_FEEDBACK`
+    Feedback,
+    /// Frequency ratio of the ring modulator's sine to the note, see `RING_RATIO_OCTAVES`
+    RingRatio,
+    /// Crossfades each voice from its dry signal to its ring modulated one
+    RingMix,
+    /// Of the wavefolder, all the way down bypasses it, see `MAX_FOLD_GAIN`
+    Fold,
+    /// Of the white noise added to each voice, linear, all the way up peaks at full scale
+    NoiseLevel,
+}
+
+impl WTOscParam {
+    /// Indexed by id
+    pub const ALL: [Self; NUM_PARAMS as usize] = [
+        Self::Level,
+        Self::Frame,
+        Self::NumVoices,
+        Self::Detune,
+        Self::Pan,
+        Self::Transpose,
+        Self::Stereo,
+        Self::DetuneRange,
+        Self::Random,
+        Self::FrameSpread,
+        Self::UnisonStack,
+        Self::PmAmount,
+        Self::Sync,
+        Self::DetuneCurve,
+        Self::Blend,
+        Self::Drift,
+        Self::Glide,
+        Self::VelocityLevel,
+        Self::VelocityFrame,
+        Self::PressureFrame,
+        Self::SlideLevel,
+        Self::TableSelect,
+        Self::Drive,
+        Self::Humanize,
+        Self::DetuneSkew,
+        Self::ScanRate,
+        Self::ScanShape,
+        Self::ScanDepth,
+        Self::Feedback,
+        Self::RingRatio,
+        Self::RingMix,
+        Self::Fold,
+        Self::NoiseLevel,
+    ];
+}
+
+impl TryFrom<u64> for WTOscParam {
+    type Error = u64;
+
+    /// Unknown ids are handed back
+    fn try_from(id: u64) -> Result<Self, Self::Error> {
+        Self::ALL.get(id as usize).copied().ok_or(id)
+    }
+}
+
 pub static DEFAULT_PARAMS: [f32x2; NUM_PARAMS as usize] = [
-    f32x2::from_array([FRAC_1_SQRT_2; 2]), // level
-    f32x2::from_array([0.0; 2]),           // frame
-    f32x2::from_array([0.0; 2]),           // num_voices
-    f32x2::from_array([0.5; 2]),           // detune
-    f32x2::from_array([0.5; 2]),           // pan
-    f32x2::from_array([0.5; 2]),           // transpose
-    f32x2::from_array([1.0; 2]),           // stereo
-    f32x2::from_array([1.0 / 48.0; 2]),    // detune range
-    f32x2::from_array([1.0; 2]),           // random amount
+    f32x2::from_array([LEVEL_UNITY_NORM; 2]), // level (0 dB)
+    f32x2::from_array([0.0; 2]),              // frame
+    f32x2::from_array([0.0; 2]),              // num_voices
+    f32x2::from_array([0.5; 2]),              // detune
+    f32x2::from_array([0.5; 2]),              // pan
+    f32x2::from_array([0.5; 2]),              // transpose
+    f32x2::from_array([1.0; 2]),              // stereo
+    f32x2::from_array([1.0 / 48.0; 2]),       // detune range
+    f32x2::from_array([1.0; 2]),              // random amount
+    f32x2::from_array([0.0; 2]),              // frame spread
+    f32x2::from_array([0.0; 2]),              // unison stack
+    f32x2::from_array([0.0; 2]),              // phase modulation amount
+    f32x2::from_array([0.0; 2]),              // hard sync
+    f32x2::from_array([0.0; 2]),              // detune curve
+    f32x2::from_array([1.0; 2]),              // blend
+    f32x2::from_array([0.0; 2]),              // drift
+    f32x2::from_array([0.0; 2]),              // glide
+    f32x2::from_array([0.0; 2]),              // velocity to level
+    f32x2::from_array([0.0; 2]),              // velocity to frame
+    f32x2::from_array([0.0; 2]),              // pressure to frame
+    f32x2::from_array([0.0; 2]),              // slide to level
+    f32x2::from_array([0.0; 2]),              // table select
+    f32x2::from_array([0.0; 2]),              // drive (off)
+    f32x2::from_array([0.0; 2]),              // humanize
+    f32x2::from_array([0.0; 2]),              // detune skew
+    f32x2::from_array([0.0; 2]),              // scan rate
+    f32x2::from_array([0.0; 2]),              // scan shape (forward)
+    f32x2::from_array([0.0; 2]),              // scan depth (off)
+    f32x2::from_array([0.0; 2]),              // feedback (off)
+    f32x2::from_array([0.5; 2]),              // ring modulation ratio (unison)
+    f32x2::from_array([0.0; 2]),              // ring modulation mix (off)
+    f32x2::from_array([0.0; 2]),              // fold (off)
+    f32x2::from_array([0.0; 2]),              // noise level (off)
 ];
 
-#[derive(Default)]
+/// Defaults to `BandLimitedWaveTables::basic_shapes()`, so it makes sound out of the box,
+/// voices are silent while an empty table (no frames) is installed
 pub struct WTOsc {
-    table: Box<BandLimitedWaveTables>,
+    table: Arc<BandLimitedWaveTables>,
+    /// The table we're fading out of, after a new one was sent
+    old_table: Arc<BandLimitedWaveTables>,
+    old_frames_ratio: f32,
+    /// Extra tables, selectable per cluster, after the main one, see `Self::table_slot`
+    table_bank: Vec<Arc<BandLimitedWaveTables>>,
+    /// Per cluster, the number of frames of the table its oscillators' frames are scaled to
+    cluster_num_frames: Box<[usize]>,
+    /// Per cluster, the number of samples since the last table swap
+    fade_positions: Box<[usize]>,
+    fade_len: usize,
+    fade_buffer: Box<[f32x2]>,
+    pm_input: Box<[Float]>,
+    phase_offsets: Box<[UInt]>,
+    quality: Quality,
+    pan_law: PanLaw,
+    unison_normalization: UnisonNormalization,
+    frame_snap: bool,
+    /// See `Tempo`
+    tempo: Option<f32>,
+    oversampling: Oversampling,
+    output_layout: OutputLayout,
+    /// Where the voices are rendered to, before being split into their
+    /// outputs, empty with the polyphonic layout
+    per_voice_buffer: Box<[Float]>,
+    /// Per cluster, empty without oversampling
+    decimators: Box<[Decimator]>,
+    /// Where the oscillators render to, before decimation
+    oversampled_buffer: Box<[Float]>,
+    /// Per cluster, the last phase modulation input sample, to interpolate from
+    pm_last: Box<[Float]>,
+    /// How long voices take to fade in and out
+    voice_fade_secs: f32,
+    /// Per sample
+    voice_fade_step: f32,
+    retrigger_mode: RetriggerMode,
+    dc_blocker: bool,
+    /// Pole of the DC blocker, which runs at the host's rate
+    dc_blocker_coeff: f32,
+    seed: u32,
+    /// Of MIDI note 69, in Hz
+    a4_frequency: f32,
+    /// Replaces 12-TET, and the A4 reference, when set
+    tuning: Option<TuningTable>,
     starting_phases: [Float; OSCS_PER_VOICE],
+    /// The oscillators' sample rate, including oversampling
     sr: f32,
-    log2_alpha: f32,
-    scratch_buffer: Box<[Float]>,
+    /// Per parameter, see `Self::set_smoothing_time_ms`
+    log2_alphas: [f32; NUM_PARAMS as usize],
+    smoothing_ms: f32,
+    smoothing_overrides_ms: [Option<f32>; NUM_PARAMS as usize],
+    /// Of the voices' expression, which follows the global smoothing time
+    expression_log2_alpha: f32,
     clusters: Box<[WTOscVoiceCluster]>,
     params: Box<[WTOscClusterNormParams]>,
+    /// Preallocated, see `Self::set_param_at`
+    param_events: Vec<ParamEvent>,
 }
 
-impl Processor for WTOsc {
-    type Sample = Float;
+impl Default for WTOsc {
+    fn default() -> Self {
+        Self::new(BandLimitedWaveTables::basic_shapes().into())
+    }
+}
 
-    fn audio_io_layout(&self) -> (usize, usize) {
-        (0, 1)
+impl WTOsc {
+    /// With the given wavetable, and default settings, see `WTOscBuilder`
+    /// for more, the oscillator is ready to go after `Processor::initialize`
+    pub fn new(table: Arc<BandLimitedWaveTables>) -> Self {
+        Self {
+            table,
+            old_table: BandLimitedWaveTables::empty().into(),
+            old_frames_ratio: 1.,
+            table_bank: Default::default(),
+            cluster_num_frames: Default::default(),
+            fade_positions: Default::default(),
+            fade_len: Default::default(),
+            fade_buffer: Default::default(),
+            pm_input: Default::default(),
+            phase_offsets: Default::default(),
+            quality: Default::default(),
+            pan_law: Default::default(),
+            unison_normalization: Default::default(),
+            frame_snap: false,
+            tempo: None,
+            oversampling: Default::default(),
+            output_layout: Default::default(),
+            per_voice_buffer: Default::default(),
+            decimators: Default::default(),
+            oversampled_buffer: Default::default(),
+            pm_last: Default::default(),
+            voice_fade_secs: Self::DEFAULT_VOICE_FADE_SECS,
+            voice_fade_step: Default::default(),
+            retrigger_mode: Default::default(),
+            dc_blocker: false,
+            dc_blocker_coeff: Default::default(),
+            seed: Default::default(),
+            a4_frequency: Self::DEFAULT_A4_FREQUENCY,
+            tuning: None,
+            starting_phases: Default::default(),
+            sr: Default::default(),
+            log2_alphas: Default::default(),
+            smoothing_ms: Self::DEFAULT_SMOOTHING_MS,
+            smoothing_overrides_ms: Default::default(),
+            expression_log2_alpha: Default::default(),
+            clusters: Default::default(),
+            params: Default::default(),
+            param_events: Default::default(),
+        }
     }
+}
 
-    fn process(&mut self, mut buffers: Buffers<Self::Sample>, cluster_idx: usize, voice_mask: TMask) {
-        let table = self.table.as_ref();
+/// Smoothing and fade times must be finite, and non-negative
+fn is_valid_duration(duration: f32) -> bool {
+    duration.is_finite() && duration >= 0.
+}
 
-        if let Some((output_buf, num_frames)) = buffers
-            .get_output(0)
-            .zip(NonZeroUsize::new(table.num_frames()))
-        {
-            let buffer_size = output_buf.len();
-            let smooth_dt = Float::splat(1.0 / buffer_size as f32);
+/// Why `WTOscBuilder::build` failed
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum BuildError {
+    /// The wavetable has no frames, and would render silence
+    EmptyTable,
+    /// Starting phases must be in `[0 ; 1)`
+    StartingPhaseOutOfRange { index: usize, phase: f32 },
+    /// The smoothing time must be finite, and non-negative
+    InvalidSmoothingTime(f32),
+    /// The voice fade time must be finite, and non-negative
+    InvalidVoiceFadeTime(f32),
+}
 
-            let cluster = &mut self.clusters[cluster_idx];
-            let cluster_params = &mut self.params[cluster_idx];
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyTable => write!(f, "wavetable contains no frames"),
+            Self::StartingPhaseOutOfRange { index, phase } => {
+                write!(f, "starting phase {index} out of range: {phase}")
+            }
+            Self::InvalidSmoothingTime(ms) => write!(f, "invalid smoothing time: {ms} ms"),
+            Self::InvalidVoiceFadeTime(secs) => write!(f, "invalid voice fade time: {secs} s"),
+        }
+    }
+}
 
-            cluster_params.tick_n(self.log2_alpha, buffer_size);
+impl error::Error for BuildError {}
 
-            let num_frames_f = Float::splat(num_frames.get() as f32);
+/// Validated `WTOsc` construction, e. g.
+/// `WTOscBuilder::default().table(table).smoothing_ms(5.).build()`
+pub struct WTOscBuilder {
+    /// `BandLimitedWaveTables::basic_shapes()` if not set, only generated when building
+    table: Option<Arc<BandLimitedWaveTables>>,
+    starting_phases: [f32; MAX_UNISON],
+    smoothing_ms: f32,
+    voice_fade_secs: f32,
+    oversampling: Oversampling,
+    output_layout: OutputLayout,
+}
 
-            for (voice_index, voice) in cluster
-                .voices_mut()
-                .iter_mut()
-                .enumerate()
-                .zip(voice_mask.to_array().into_iter().step_by(2))
-                .filter_map(|(data, active)| active.then_some(data))
-            {
-                let (voice_params, num_oscs) =
-                    VoiceParams::new(voice_index, cluster_params).unwrap();
+impl Default for WTOscBuilder {
+    fn default() -> Self {
+        Self {
+            table: None,
+            starting_phases: [0.; MAX_UNISON],
+            smoothing_ms: WTOsc::DEFAULT_SMOOTHING_MS,
+            voice_fade_secs: WTOsc::DEFAULT_VOICE_FADE_SECS,
+            oversampling: Default::default(),
+            output_layout: Default::default(),
+        }
+    }
+}
 
-                let (first_osc, other_oscs) = unsafe { voice.get_unchecked_mut(..num_oscs.get()) }
-                    .split_first_mut()
-                    .unwrap();
+impl WTOscBuilder {
+    pub fn table(mut self, table: Arc<BandLimitedWaveTables>) -> Self {
+        self.table = Some(table);
+        self
+    }
 
-                let mask = first_osc.set_params_smoothed(&voice_params, 0, num_frames_f, smooth_dt);
-                let voice_samples = split_stereo_slice_mut(output_buf)
-                    .flatten_mut()
-                    .iter_mut()
-                    .skip(voice_index)
-                    .step_by(STEREO_VOICES_PER_VECTOR);
+    /// One per unison voice, in cycles, in `[0 ; 1)`
+    pub fn starting_phases(mut self, starting_phases: [f32; MAX_UNISON]) -> Self {
+        self.starting_phases = starting_phases;
+        self
+    }
 
-                if OSCS_PER_VOICE > 1 {
-                    let scratch_buffer = &mut self.scratch_buffer[..buffer_size];
+    /// See `WTOsc::set_smoothing_time_ms`
+    pub fn smoothing_ms(mut self, ms: f32) -> Self {
+        self.smoothing_ms = ms;
+        self
+    }
 
-                    for sample in scratch_buffer.iter_mut() {
-                        *sample = unsafe { first_osc.tick_all(table, mask) };
-                    }
+    /// See `WTOsc::with_voice_fade_secs`
+    pub fn voice_fade_secs(mut self, secs: f32) -> Self {
+        self.voice_fade_secs = secs;
+        self
+    }
 
-                    for (osc, osc_index) in other_oscs.iter_mut().zip(1..) {
-                        let mask = osc.set_params_smoothed(
-                            &voice_params,
-                            osc_index,
-                            num_frames_f,
-                            smooth_dt,
-                        );
+    /// See `WTOsc::with_oversampling`
+    pub fn oversampling(mut self, oversampling: Oversampling) -> Self {
+        self.oversampling = oversampling;
+        self
+    }
 
-                        for sample in scratch_buffer.iter_mut() {
-                            *sample += unsafe { osc.tick_all(table, mask) };
-                        }
-                    }
+    /// See `WTOsc::with_output_layout`
+    pub fn output_layout(mut self, layout: OutputLayout) -> Self {
+        self.output_layout = layout;
+        self
+    }
 
-                    for (out_sample, &scratch) in voice_samples.zip(scratch_buffer.iter()) {
-                        *out_sample = sum_to_stereo_sample(scratch);
-                    }
-                } else {
-                    // On devices with vectors that can hold as many or more floats
-                    // as there are unison voices (e. g. AVX-512 for 16 voices)
-                    // a scratch buffer wouldn't be necessary
-                    for out_sample in voice_samples {
-                        let output = unsafe { first_osc.tick_all(table, mask) };
-                        *out_sample = sum_to_stereo_sample(output);
-                    }
-                }
-            }
+    /// The oscillator is ready to go after `Processor::initialize`
+    pub fn build(self) -> Result<WTOsc, BuildError> {
+        if self
+            .table
+            .as_ref()
+            .is_some_and(|table| table.num_frames() == 0)
+        {
+            return Err(BuildError::EmptyTable);
+        }
 
-            cluster.set_weights_smoothed(cluster_params, smooth_dt);
+        if let Some((index, &phase)) = self
+            .starting_phases
+            .iter()
+            .enumerate()
+            .find(|(_, phase)| !(0. ..1.).contains(*phase))
+        {
+            return Err(BuildError::StartingPhaseOutOfRange { index, phase });
+        }
 
-            for poly_sample in output_buf {
-                let (normal, flipped) = cluster.get_sample_weights();
-                cluster.tick_weight_smoothers();
-                let sample = *poly_sample;
-                let out = sample * normal + swap_stereo(sample) * flipped;
-                *poly_sample = out;
-            }
+        if !is_valid_duration(self.smoothing_ms) {
+            return Err(BuildError::InvalidSmoothingTime(self.smoothing_ms));
         }
-    }
 
-    fn initialize(&mut self, sr: f32, max_buffer_size: usize, max_num_clusters: usize) {
-        self.sr = sr;
+        if !is_valid_duration(self.voice_fade_secs) {
+            return Err(BuildError::InvalidVoiceFadeTime(self.voice_fade_secs));
+        }
 
-        // reach the target value (0.999%) in approximately 20ms
-        const BASE_LOG2_ALPHA: f32 = -500.0;
+        let table = self
+            .table
+            .unwrap_or_else(|| BandLimitedWaveTables::basic_shapes().into());
 
-        self.log2_alpha = BASE_LOG2_ALPHA / sr;
+        let mut osc = WTOsc::new(table)
+            .with_voice_fade_secs(self.voice_fade_secs)
+            .with_oversampling(self.oversampling)
+            .with_output_layout(self.output_layout);
+        osc.set_starting_phases(&self.starting_phases);
+        osc.smoothing_ms = self.smoothing_ms;
 
-        self.clusters = iter::repeat_with(Default::default)
-            .take(max_num_clusters)
-            .collect();
+        Ok(osc)
+    }
+}
 
-        self.params = iter::repeat_with(Default::default)
-            .take(max_num_clusters)
-            .collect();
+impl WTOsc {
+    /// How long it takes to crossfade to a new wavetable
+    const TABLE_FADE_SECS: f32 = 0.01;
+    /// Of the DC blocker, see `DcBlocker`
+    const DC_BLOCKER_CUTOFF_HZ: f32 = 7.;
+    const DEFAULT_VOICE_FADE_SECS: f32 = 0.003;
+    const MAX_PARAM_EVENTS: usize = 1024;
+    const DEFAULT_SMOOTHING_MS: f32 = 20.;
+    const DEFAULT_A4_FREQUENCY: f32 = 440.;
+
+    /// Sets the tuning reference, the frequency of MIDI note 69, in Hz, ignored
+    /// while a tuning table is installed. Sounding voices are retuned. Non-positive,
+    /// and non-finite frequencies are ignored
+    pub fn set_a4_frequency(&mut self, hz: f32) {
+        let valid = hz.is_finite() && hz > 0.;
+        debug_assert!(valid, "invalid A4 frequency: {hz}");
+
+        if !valid {
+            return;
+        }
+
+        self.a4_frequency = hz;
+        self.retune();
+    }
 
-        // On devices with vectors that can hold as many or more floats as there are unison voices
-        // (e. g. AVX-512 for 16 voices) a scratch buffer wouldn't be necessary
-        self.scratch_buffer = unsafe {
-            Box::new_uninit_slice((OSCS_PER_VOICE > 1) as usize * max_buffer_size).assume_init()
+    /// Per sample, of the given (possibly fractional) MIDI notes
+    fn note_phase_deltas(&self, notes: Float) -> Float {
+        let frequencies = match &self.tuning {
+            Some(table) => Float::from_array(notes.to_array().map(|note| table.frequency(note))),
+            None => Simd::splat(self.a4_frequency) * semitones_to_ratio(notes - Simd::splat(69.)),
         };
+
+        frequencies / Simd::splat(self.sr)
     }
 
-    fn set_param(&mut self, cluster_idx: usize, voice_mask: TMask, param_id: u64, norm_val: Float) {
-        self.params[cluster_idx].set_param_target(param_id, norm_val, voice_mask);
+    /// Brings sounding voices to the current tuning, by scaling their
+    /// phase deltas, which keeps their phases continuous
+    fn retune(&mut self) {
+        for cluster_idx in 0..self.params.len() {
+            let phase_deltas = self.note_phase_deltas(self.params[cluster_idx].note);
+            let ratio = self.params[cluster_idx].retune_ratio(phase_deltas);
+
+            self.params[cluster_idx].scale_base_phase_delta(ratio);
+            self.clusters[cluster_idx].scale_phase_deltas(ratio);
+        }
     }
 
-    fn custom_event(&mut self, event: &mut dyn Any) {
-        if let Some(wt) = event.downcast_mut::<Box<BandLimitedWaveTables>>() {
-            if self.table.num_frames() != 0 {
-                let ratio = Simd::splat(wt.num_frames() as f32 / self.table.num_frames() as f32);
+    /// In cycles, wrapped into `[0 ; 1)`
+    fn set_starting_phases(&mut self, starting_phases: &[f32; MAX_UNISON]) {
+        self.starting_phases
+            .iter_mut()
+            .flat_map(Simd::as_mut_array)
+            .zip(starting_phases.iter())
+            .for_each(|(i, &o)| *i = o);
 
-                for cluster in self.clusters.iter_mut() {
-                    cluster.scale_frames(ratio);
-                }
-            }
+        for phases in self.starting_phases.iter_mut() {
+            *phases = wrap_phase(*phases);
+        }
+    }
 
-            mem::swap(wt, &mut self.table);
+    /// Sets how long parameters take to reach 99.9% of their target values, except
+    /// for those with an override, see `Self::set_param_smoothing_time_ms`.
+    /// This doesn't change the parameters' current values. Negative, and non-finite
+    /// times are ignored
+    pub fn set_smoothing_time_ms(&mut self, ms: f32) {
+        debug_assert!(is_valid_duration(ms), "invalid smoothing time: {ms}");
+
+        if !is_valid_duration(ms) {
+            return;
         }
 
-        if let Some(starting_phases) = event.downcast_mut::<[f32; MAX_UNISON]>() {
-            self.starting_phases
-                .iter_mut()
-                .flat_map(Simd::as_mut_array)
-                .zip(starting_phases.iter())
-                .for_each(|(i, &o)| *i = o);
+        self.smoothing_ms = ms;
+        self.update_smoothing_rates();
+    }
+
+    /// Overrides the smoothing time of a single parameter, or removes its override,
+    /// invalid times are ignored, like in `Self::set_smoothing_time_ms`
+    pub fn set_param_smoothing_time_ms(&mut self, param: WTOscParam, ms: Option<f32>) {
+        debug_assert!(
+            ms.map_or(true, is_valid_duration),
+            "invalid smoothing time: {ms:?}"
+        );
+
+        if !ms.map_or(true, is_valid_duration) {
+            return;
         }
+
+        self.smoothing_overrides_ms[param as usize] = ms;
+        self.update_smoothing_rates();
     }
 
-    fn reset(&mut self, cluster_idx: usize, voice_mask: TMask) {
-        let random = self.params[cluster_idx].random.current;
-        self.clusters[cluster_idx].reset_phases(voice_mask, random, &self.starting_phases);
+    fn update_smoothing_rates(&mut self) {
+        // log2(1 - 0.999)
+        const LOG2_PRECISION: f32 = -9.965_784;
+
+        let log2_alpha = |ms: f32| LOG2_PRECISION / (ms / 1000. * self.sr);
+
+        for (alpha, ms) in self.log2_alphas.iter_mut().zip(self.smoothing_overrides_ms) {
+            *alpha = log2_alpha(ms.unwrap_or(self.smoothing_ms));
+        }
+
+        self.expression_log2_alpha = log2_alpha(self.smoothing_ms);
     }
 
-    fn move_state(
-        &mut self,
-        (from_cluster, from_voice): (usize, usize),
-        (to_cluster, to_voice): (usize, usize),
-    ) {
-        (from_voice < STEREO_VOICES_PER_VECTOR && to_voice < STEREO_VOICES_PER_VECTOR)
-            .then(|| {
-                let clusters = Cell::from_mut(self.clusters.as_mut()).as_slice_of_cells();
-                let params = Cell::from_mut(self.params.as_mut()).as_slice_of_cells();
+    /// Sets how long voices take to fade in when activated, and
+    /// out when released, takes effect on the next `initialize` call. Negative,
+    /// and non-finite times are ignored
+    pub fn with_voice_fade_secs(mut self, secs: f32) -> Self {
+        debug_assert!(is_valid_duration(secs), "invalid voice fade time: {secs}");
 
-                unsafe {
-                    WTOscVoiceCluster::move_state_unchecked(
-                        &clusters[from_cluster],
-                        from_voice,
-                        &clusters[to_cluster],
-                        to_voice,
-                    );
+        if is_valid_duration(secs) {
+            self.voice_fade_secs = secs;
+        }
 
-                    WTOscClusterNormParams::move_state_unchecked(
-                        &params[from_cluster],
-                        from_voice,
-                        &params[to_cluster],
-                        to_voice,
-                    );
+        self
+    }
+
+    /// Sets the oversampling factor, takes effect on the next `initialize` call
+    pub fn with_oversampling(mut self, oversampling: Oversampling) -> Self {
+        self.oversampling = oversampling;
+        self
+    }
+
+    /// Sets the output layout, and so the number of outputs reported by
+    /// `Processor::audio_io_layout`, takes effect on the next `initialize` call
+    pub fn with_output_layout(mut self, layout: OutputLayout) -> Self {
+        self.output_layout = layout;
+        self
+    }
+
+    /// In samples, the delay introduced by oversampling, for hosts to compensate
+    pub fn latency(&self) -> usize {
+        Decimator::latency(self.oversampling.factor())
+    }
+
+    /// Reads back the current state of a voice, e. g. for display, between `process` calls
+    pub fn voice_state(&self, cluster_idx: usize, voice_idx: usize) -> Option<VoiceStateSnapshot> {
+        let params = self.params.get(cluster_idx)?;
+
+        (voice_idx < STEREO_VOICES_PER_VECTOR).then(|| {
+            let get = |values: &Float| split_stereo(values)[voice_idx];
+
+            VoiceStateSnapshot {
+                frame: get(&params.frame.current),
+                detune: get(&params.detune.current)
+                    * get(&params.detune_range.current)
+                    * Simd::splat(PITCH_RANGE_SEMITONES),
+                level: get(&params.level.current),
+                pan: get(&params.pan.current),
+                num_voices: get(&params.num_voices_f()).cast(),
+                frequency: get(&params.phase_delta) * Simd::splat(self.sr),
+            }
+        })
+    }
+
+    /// The (normalized) targets of every parameter, of every cluster, allocates
+    pub fn save_state(&self) -> WTOscState {
+        WTOscState {
+            clusters: self
+                .params
+                .iter()
+                .map(|params| {
+                    WTOscParam::ALL
+                        .iter()
+                        .map(|&param| (param as u64, params.norm_target(param).to_array().into()))
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// Sets every parameter in `state` instantly, in all voices, like `set_all_params`.
+    /// Unknown parameters, and clusters past those allocated in `initialize` are
+    /// ignored, parameters missing from `state` are left untouched
+    pub fn load_state(&mut self, state: &WTOscState) {
+        let voice_mask = TMask::splat(true);
+
+        for (cluster_idx, values) in state.clusters.iter().enumerate().take(self.params.len()) {
+            for (id, lanes) in values {
+                let Ok(param) = WTOscParam::try_from(*id) else {
+                    continue;
+                };
+
+                if lanes.is_empty() {
+                    continue;
                 }
-            })
-            .expect("out of bounds voice indices")
+
+                // states saved with narrower vectors are repeated across lanes
+                let value = Float::from_array(array::from_fn(|i| lanes[i % lanes.len()]));
+                self.params[cluster_idx].set_param_instantly(param, value, voice_mask);
+            }
+
+            let num_frames_f = Simd::splat(self.update_cluster_frames(cluster_idx) as f32);
+            self.clusters[cluster_idx].set_params(
+                &self.params[cluster_idx],
+                num_frames_f,
+                voice_mask,
+            );
+        }
     }
 
-    fn activate_voices(
+    /// Like `Processor::set_param`, but takes effect `sample_offset` samples into the
+    /// next processed block of the given cluster, instead of at its start. Events
+    /// that don't fit in the (preallocated) queue take effect at the start instead
+    pub fn set_param_at(
         &mut self,
         cluster_idx: usize,
         voice_mask: TMask,
-        _velocity: Float,
-        note: UInt,
+        param_id: u64,
+        norm_val: Float,
+        sample_offset: usize,
+    ) {
+        // unknown ids are ignored
+        let Ok(param) = WTOscParam::try_from(param_id) else {
+            return;
+        };
+
+        if self.param_events.len() < self.param_events.capacity() {
+            self.param_events.push(ParamEvent {
+                cluster_idx,
+                voice_mask,
+                param,
+                norm_val,
+                // in oscillator samples
+                sample_offset: sample_offset * self.oversampling.factor(),
+            });
+        } else {
+            self.params[cluster_idx].set_param_target(param, norm_val, voice_mask);
+        }
+    }
+
+    /// Like `Processor::activate_voices`, but with (possibly fractional) MIDI notes
+    pub fn activate_voices_fractional(
+        &mut self,
+        cluster_idx: usize,
+        voice_mask: TMask,
+        velocity: Float,
+        note: Float,
     ) {
-        let a4_phase_delta = Simd::splat(440. / self.sr);
-        let nice = Simd::splat(69);
-        let a4_detune_semitones = note.cast::<i32>() - nice;
-        let new_phase_delta = a4_phase_delta * semitones_to_ratio(a4_detune_semitones.cast());
+        let humanize_offsets = self.clusters[cluster_idx].next_humanize_offsets();
+
+        let params = &mut self.params[cluster_idx];
+        params.set_velocity(velocity, voice_mask);
+        params.humanize_offsets = voice_mask.select(humanize_offsets, params.humanize_offsets);
+
+        self.play_note(cluster_idx, voice_mask, note);
+    }
+
+    /// Moves the voices in `voice_mask` to `note`, without restarting them, for overlapping
+    /// notes on mono/legato patches. Their phases are left untouched, they glide to the new
+    /// pitch, or jump to it if glide is off, and parameter smoothers keep ramping towards
+    /// their targets. Velocity (and humanization) stay those of the note that started the
+    /// voices. `Processor::reset`, followed by `Processor::activate_voices`, is the hard
+    /// retrigger path
+    pub fn note_on_legato(&mut self, cluster_idx: usize, voice_mask: TMask, note: Float) {
+        self.play_note(cluster_idx, voice_mask, note);
+    }
+
+    fn play_note(&mut self, cluster_idx: usize, voice_mask: TMask, note: Float) {
+        let new_phase_delta = self.note_phase_deltas(note);
 
         let params = &mut self.params[cluster_idx];
+        params.note = voice_mask.select(note, params.note);
+
+        // voices that are already playing glide to their new pitch, if
+        // it's enabled, the oscillators follow every buffer
+        let glide_samples = params.glide_secs() * Simd::splat(self.sr);
+        let gliding = voice_mask
+            & glide_samples.simd_ge(Simd::splat(1.))
+            & params.phase_delta.simd_gt(Simd::splat(0.));
+
+        params.glide_base_phase_delta(new_phase_delta, glide_samples, gliding);
 
-        let ratio = voice_mask.select(new_phase_delta / params.phase_delta, Simd::splat(1.0));
+        let snapping = voice_mask & !gliding;
 
-        params.set_base_phase_delta(new_phase_delta, voice_mask);
+        // voices that haven't played a note yet have no phase delta to scale, it's set instead
+        let fresh = snapping & params.phase_delta.simd_eq(Simd::splat(0.));
+        let scaled = snapping & !fresh;
 
-        self.clusters[cluster_idx].scale_phase_deltas(ratio);
+        let ratio = scaled.select(new_phase_delta / params.phase_delta, Simd::splat(1.0));
+
+        params.set_base_phase_delta(new_phase_delta, snapping);
+
+        let cluster = &mut self.clusters[cluster_idx];
+        cluster.scale_phase_deltas(ratio);
+        cluster.set_phase_deltas(params, fresh);
+        cluster.fade_in(voice_mask, Simd::splat(self.voice_fade_step));
     }
 
-    fn set_all_params(
+    /// Sets the frequency, in Hz, of the voices in `voice_mask` directly, instead
+    /// of from a note, e. g. to use them as LFOs (see `Quality::Unfiltered`), glides
+    /// are skipped. Voices at 0 Hz (or negative, or NaN, frequencies) hold their phase.
+    /// Tuning changes leave these voices alone, until they play a note again
+    pub fn set_voice_frequency(&mut self, cluster_idx: usize, voice_mask: TMask, hz: Float) {
+        // simd_max returns the other operand for NaNs
+        let phase_delta = hz.simd_max(Simd::splat(0.)) / Simd::splat(self.sr);
+
+        let params = &mut self.params[cluster_idx];
+        params.note = voice_mask.select(Simd::splat(f32::NAN), params.note);
+        params.set_base_phase_delta(phase_delta, voice_mask);
+        self.clusters[cluster_idx].set_phase_deltas(params, voice_mask);
+    }
+
+    /// The normalized frame parameter value that plays `frame` of the main table,
+    /// exactly, with `FrameSnap`, past the last frame, the last one is played
+    pub fn frame_to_normalized(&self, frame: u32) -> f32 {
+        let num_frames = self.table.num_frames() as u32;
+
+        if num_frames == 0 {
+            return 0.;
+        }
+
+        frame.min(num_frames - 1) as f32 / num_frames as f32
+    }
+
+    /// Name, default value, and formatting of the parameter with the given id
+    pub fn param_info(id: u64) -> Option<&'static params::ParamInfo> {
+        params::PARAMS.get(id as usize)
+    }
+
+    /// Overrides the (normalized) velocity the voices in `voice_mask` were activated
+    /// with, see the velocity to level and velocity to frame parameters
+    pub fn set_voice_velocity(&mut self, cluster_idx: usize, voice_mask: TMask, velocity: Float) {
+        self.params[cluster_idx].set_velocity(velocity, voice_mask);
+    }
+
+    /// Sets the (normalized) MPE-style pressure and slide of the voices in `voice_mask`,
+    /// smoothed, see the pressure to frame and slide to level parameters
+    pub fn set_voice_expression(
         &mut self,
         cluster_idx: usize,
         voice_mask: TMask,
-        params: &dyn Parameters<Float>,
+        pressure: Float,
+        slide: Float,
     ) {
-        let cluster_params = &mut self.params[cluster_idx];
+        self.params[cluster_idx].set_expression(pressure, slide, voice_mask);
+    }
+
+    /// Starts fading out the voices in `voice_mask`, they must still be
+    /// processed until `Self::finished_voices` reports them as silent
+    pub fn release_voices(&mut self, cluster_idx: usize, voice_mask: TMask) {
+        let step = Simd::splat(self.voice_fade_step);
+        self.clusters[cluster_idx].fade_out(voice_mask, step);
+    }
+
+    /// Number of voices in the given cluster that are sounding, including released
+    /// voices that are still fading out
+    pub fn active_voice_count(&self, cluster_idx: usize) -> usize {
+        self.clusters[cluster_idx]
+            .active_voices()
+            .to_array()
+            .into_iter()
+            .step_by(2)
+            .filter(|&active| active)
+            .count()
+    }
+
+    pub fn is_voice_active(&self, cluster_idx: usize, voice_idx: usize) -> bool {
+        voice_idx < STEREO_VOICES_PER_VECTOR
+            && self.clusters[cluster_idx]
+                .active_voices()
+                .test(voice_idx * 2)
+    }
+
+    /// The phase, in `[0 ; 1)`, and the frame, of the first unison voice of `voice_idx`'s
+    /// first oscillator, e. g. to draw a phase-locked oscilloscope, `None` if it isn't active
+    pub fn voice_phase(&self, cluster_idx: usize, voice_idx: usize) -> Option<(f32, f32)> {
+        self.is_voice_active(cluster_idx, voice_idx).then(|| {
+            let osc = &self.clusters[cluster_idx].voices()[voice_idx][0];
+            (fxp_to_flp(osc.phase())[0], osc.frame()[0])
+        })
+    }
+
+    /// `(cluster_idx, voice_idx)` pairs of every active voice, see `Self::active_voice_count`
+    pub fn active_voices(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.clusters
+            .iter()
+            .enumerate()
+            .flat_map(|(cluster_idx, cluster)| {
+                cluster
+                    .active_voices()
+                    .to_array()
+                    .into_iter()
+                    .step_by(2)
+                    .enumerate()
+                    .filter_map(move |(voice_idx, active)| {
+                        active.then_some((cluster_idx, voice_idx))
+                    })
+            })
+    }
 
-        for param_id in 0..NUM_PARAMS {
-            let param_value = params.get_param(param_id, cluster_idx, voice_mask).unwrap();
+    /// Released voices that have been faded out completely, and can be freed
+    pub fn finished_voices(&self, cluster_idx: usize) -> TMask {
+        self.clusters[cluster_idx].faded_out_voices()
+    }
 
-            cluster_params.set_param_instantly(param_id, param_value, voice_mask);
+    /// Seeds every cluster from `first` onwards, each with its own random sequence
+    fn seed_clusters(&mut self, first: usize) {
+        for (cluster, i) in self.clusters.iter_mut().zip(0..).skip(first) {
+            cluster.seed(self.seed.wrapping_add(i));
         }
+    }
+
+    /// Applies the queued parameter events for the given cluster that are due at or before
+    /// `sample_offset`, and returns the offset of the next one, or `usize::MAX` if there's none
+    fn apply_param_events(&mut self, cluster_idx: usize, sample_offset: usize) -> usize {
+        let params = &mut self.params[cluster_idx];
+        let mut next_offset = usize::MAX;
+
+        self.param_events.retain(|event| {
+            if event.cluster_idx != cluster_idx {
+                return true;
+            }
+
+            if event.sample_offset <= sample_offset {
+                params.set_param_target(event.param, event.norm_val, event.voice_mask);
+                return false;
+            }
 
-        let num_frames_f = Simd::splat(self.table.num_frames() as f32);
+            next_offset = next_offset.min(event.sample_offset);
+            true
+        });
 
-        self.clusters[cluster_idx].set_params(cluster_params, num_frames_f, voice_mask);
+        next_offset
     }
-}
 
-#[cfg(test)]
-mod tests {
+    /// Linearly interpolates `input` to the oscillators' sample rate, into `output`
+    fn upsample(input: &[Float], last: &mut Float, factor: usize, output: &mut [Float]) {
+        let step = Float::splat((factor as f32).recip());
 
-    use std::io::{self, Write};
+        for (&sample, chunk) in input.iter().zip(output.chunks_exact_mut(factor)) {
+            let delta = (sample - *last) * step;
 
-    use polygraph::{
-        buffer::{BufferHandleLocal, OutputBufferIndex},
-        processor::{new_vfloat_buffer, ParamsList},
-    };
+            for (out, i) in chunk.iter_mut().zip(1..) {
+                *out = delta.mul_add(Simd::splat(i as f32), *last);
+            }
 
-    use super::*;
+            *last = sample;
+        }
+    }
+
+    /// `Self::render_oversampled`, followed by the DC blocker, if it's enabled
+    fn render_output(
+        &mut self,
+        cluster_idx: usize,
+        voice_mask: TMask,
+        output_buf: &mut [Float],
+        has_pm_input: bool,
+    ) {
+        self.render_oversampled(cluster_idx, voice_mask, output_buf, has_pm_input);
+
+        if self.dc_blocker {
+            let coeff = Float::splat(self.dc_blocker_coeff);
+            self.clusters[cluster_idx].block_dc(output_buf, coeff);
+        }
+    }
+
+    /// Render `output_buf.len()` samples of a cluster's voices, with phase modulation from
+    /// `self.pm_input`, if `has_pm_input`, at the oscillators' rate, then decimated if needed
+    fn render_oversampled(
+        &mut self,
+        cluster_idx: usize,
+        voice_mask: TMask,
+        output_buf: &mut [Float],
+        has_pm_input: bool,
+    ) {
+        let factor = self.oversampling.factor();
+
+        if factor == 1 {
+            self.render_split(cluster_idx, voice_mask, output_buf, has_pm_input);
+            return;
+        }
+
+        // taken, so that it isn't borrowed at the same time as `self`
+        let mut oversampled_buffer = mem::take(&mut self.oversampled_buffer);
+        let oversampled = &mut oversampled_buffer[..output_buf.len() * factor];
+
+        self.render_split(cluster_idx, voice_mask, oversampled, has_pm_input);
+        self.decimators[cluster_idx].process(oversampled, output_buf);
+
+        self.oversampled_buffer = oversampled_buffer;
+    }
+
+    /// Renders `output_buf`, split at every queued parameter event
+    fn render_split(
+        &mut self,
+        cluster_idx: usize,
+        voice_mask: TMask,
+        output_buf: &mut [Float],
+        has_pm_input: bool,
+    ) {
+        let buffer_size = output_buf.len();
+        let mut start = 0;
+
+        while start < buffer_size {
+            let end = self.apply_param_events(cluster_idx, start).min(buffer_size);
+
+            self.render_block(
+                cluster_idx,
+                voice_mask,
+                &mut output_buf[start..end],
+                has_pm_input.then_some(start),
+            );
+
+            start = end;
+        }
+    }
+
+    /// Which table `cluster_idx` renders, `0` for the main table, `i` for
+    /// the `i`th table of the bank, from its `TableSelect` parameter
+    fn table_slot(&self, cluster_idx: usize) -> usize {
+        self.params[cluster_idx].table_slot(self.table_bank.len() + 1)
+    }
+
+    fn slot_table(&self, slot: usize) -> &BandLimitedWaveTables {
+        match slot.checked_sub(1) {
+            Some(i) => &self.table_bank[i],
+            None => &self.table,
+        }
+    }
+
+    /// Rescales the frames of `cluster_idx`'s oscillators if the table it renders changed
+    /// size since they were last set (after switching slots, or swapping tables), so that
+    /// they keep pointing at the same relative position. Returns the table's frame count
+    fn update_cluster_frames(&mut self, cluster_idx: usize) -> usize {
+        let num_frames = self.slot_table(self.table_slot(cluster_idx)).num_frames();
+        let current = &mut self.cluster_num_frames[cluster_idx];
+
+        if *current != num_frames {
+            let cluster = &mut self.clusters[cluster_idx];
+
+            // frames can't be rescaled from, or to, an empty table, but
+            // they're brought back in range regardless
+            if *current != 0 && num_frames != 0 {
+                let ratio = num_frames as f32 / *current as f32;
+                cluster.scale_frames(Simd::splat(ratio));
+            }
+
+            if num_frames != 0 {
+                cluster.clamp_frames(Self::max_frame(num_frames));
+            }
+
+            *current = num_frames;
+        }
+
+        num_frames
+    }
+
+    /// The highest frame position `VoiceParams::get_params` gives oscillators
+    fn max_frame(num_frames: usize) -> Float {
+        Simd::splat(num_frames as f32 * 0.9999)
+    }
+
+    /// Render `output_buf.len()` samples of a cluster's voices, phase modulated
+    /// by the copied input starting at `pm_start`, if any
+    fn render_block(
+        &mut self,
+        cluster_idx: usize,
+        voice_mask: TMask,
+        output_buf: &mut [Float],
+        pm_start: Option<usize>,
+    ) {
+        self.update_cluster_frames(cluster_idx);
+
+        let slot = self.table_slot(cluster_idx);
+        let table = match slot.checked_sub(1) {
+            Some(i) => self.table_bank[i].as_ref(),
+            None => self.table.as_ref(),
+        };
+        let old_table = self.old_table.as_ref();
+        let quality = self.quality;
+
+        let has_pm_input = pm_start.is_some();
+        let pm_input = &self.pm_input[pm_start.unwrap_or(0)..];
+
+        if table.num_frames() != 0 {
+            let buffer_size = output_buf.len();
+            let smooth_dt = Float::splat(1.0 / buffer_size as f32);
+
+            let cluster = &mut self.clusters[cluster_idx];
+            let cluster_params = &mut self.params[cluster_idx];
+            let fade_pos = &mut self.fade_positions[cluster_idx];
+            let phase_offsets = &mut self.phase_offsets[..buffer_size];
+
+            cluster_params.tick_n(&self.log2_alphas, self.expression_log2_alpha, buffer_size);
+
+            let scan_cycles =
+                cluster_params.scan_rate(self.tempo) * Simd::splat(buffer_size as f32 / self.sr);
+            cluster.advance_frame_scan(cluster_params, scan_cycles);
+
+            if !has_pm_input {
+                phase_offsets.fill(UInt::splat(0));
+            }
+
+            let old_frames_ratio = Float::splat(self.old_frames_ratio);
+            let old_max_frame = Self::max_frame(old_table.num_frames());
+
+            // only the main table crossfades when swapped
+            let fading = slot == 0 && *fade_pos < self.fade_len && old_table.num_frames() != 0;
+
+            for (voice_index, voice) in cluster
+                .voices_mut()
+                .iter_mut()
+                .enumerate()
+                .zip(voice_mask.to_array().into_iter().step_by(2))
+                .filter_map(|(data, active)| active.then_some(data))
+            {
+                // SAFETY: there are STEREO_VOICES_PER_VECTOR voices per cluster
+                let (voice_params, num_oscs) =
+                    unsafe { VoiceParams::new_unchecked(voice_index, cluster_params) };
+
+                // oscillators with lanes still fading out, after the
+                // voice count was reduced, are rendered too
+                let num_oscs = voice
+                    .iter()
+                    .rposition(Oscillator::is_active)
+                    .map_or(num_oscs.get(), |i| num_oscs.get().max(i + 1));
+
+                if has_pm_input {
+                    let pm_amount =
+                        splat_stereo(split_stereo(&cluster_params.pm_amount.current)[voice_index]);
+
+                    for (offset, input) in phase_offsets.iter_mut().zip(pm_input) {
+                        let pm = splat_stereo(split_stereo(input)[voice_index]) * pm_amount;
+                        *offset = flp_to_fxp(pm - pm.floor());
+                    }
+                }
+
+                // the outgoing table is rendered by copies of the oscillators
+                // that haven't yet been advanced, with frames in its own scale
+                let old_voice = fading.then(|| *voice);
+
+                unsafe {
+                    Self::render_voice(
+                        voice.get_unchecked_mut(..num_oscs),
+                        &voice_params,
+                        table,
+                        quality,
+                        phase_offsets,
+                        split_stereo_slice_mut(output_buf)
+                            .flatten_mut()
+                            .iter_mut()
+                            .skip(voice_index)
+                            .step_by(STEREO_VOICES_PER_VECTOR),
+                    );
+                }
+
+                if let Some(mut old_voice) = old_voice {
+                    let old_oscs = unsafe { old_voice.get_unchecked_mut(..num_oscs) };
+
+                    for osc in old_oscs.iter_mut() {
+                        osc.scale_frame(old_frames_ratio);
+                        osc.clamp_frame(old_max_frame);
+                    }
+
+                    let fade_buffer = &mut self.fade_buffer[..buffer_size];
+
+                    unsafe {
+                        Self::render_voice(
+                            old_oscs,
+                            &voice_params,
+                            old_table,
+                            quality,
+                            phase_offsets,
+                            fade_buffer.iter_mut(),
+                        );
+                    }
+
+                    let fade_step = (self.fade_len as f32).recip();
+
+                    let voice_samples = split_stereo_slice_mut(output_buf)
+                        .flatten_mut()
+                        .iter_mut()
+                        .skip(voice_index)
+                        .step_by(STEREO_VOICES_PER_VECTOR);
+
+                    for ((out_sample, &old_sample), i) in
+                        voice_samples.zip(fade_buffer.iter()).zip(*fade_pos..)
+                    {
+                        // equal power
+                        let t = (i as f32 * fade_step).min(1.) * FRAC_PI_2;
+                        let (new_gain, old_gain) = t.sin_cos();
+                        *out_sample = *out_sample * Simd::splat(new_gain)
+                            + old_sample * Simd::splat(old_gain);
+                    }
+                }
+            }
+
+            *fade_pos = fade_pos.saturating_add(buffer_size);
+
+            cluster.add_noise(output_buf, cluster_params.noise_level.current);
+            cluster.apply_ring_mod(output_buf, cluster_params);
+            cluster.apply_wavefold(output_buf, cluster_params.fold_gain());
+
+            cluster.set_weights_smoothed(cluster_params, smooth_dt);
+
+            cluster.apply_weights(output_buf, cluster_params.drive_gain());
+        } else {
+            output_buf.fill(Float::splat(0.));
+        }
+    }
+
+    /// Render the sum of `oscs`' outputs into `output`, one sample
+    /// per phase offset (e. g. from phase modulation) in `phase_offsets`
+    ///
+    /// # Safety
+    ///
+    /// `oscs` must be those of the voice `voice_params` was computed for
+    #[inline]
+    unsafe fn render_voice<'a>(
+        oscs: &mut [Oscillator],
+        voice_params: &VoiceParams,
+        table: &BandLimitedWaveTables,
+        quality: Quality,
+        phase_offsets: &[UInt],
+        output: impl Iterator<Item = &'a mut f32x2>,
+    ) {
+        let buffer_size = phase_offsets.len();
+        let smooth_dt = Float::splat(1.0 / buffer_size as f32);
+        let num_frames_f = Float::splat(table.num_frames() as f32);
+
+        let mut masks = [TMask::splat(false); OSCS_PER_VOICE];
+
+        for ((osc, mask), osc_index) in oscs.iter_mut().zip(&mut masks).zip(0..) {
+            *mask = osc.set_params_smoothed(voice_params, osc_index, num_frames_f, smooth_dt);
+        }
+
+        let (first_osc, other_oscs) = oscs.split_first_mut().unwrap();
+        let (&first_mask, other_masks) = masks.split_first().unwrap();
+
+        // when every oscillator would render the exact same samples (e. g. no detune, frame
+        // spread, or random phases) only the first one is, the sum stays bit-identical
+        let collapsed =
+            other_oscs
+                .iter()
+                .zip(other_masks)
+                .zip(1..)
+                .all(|((osc, &mask), osc_index)| {
+                    mask == first_mask
+                        && voice_params.same_params(osc_index, 0)
+                        && osc.renders_like(first_osc)
+                });
+
+        // every oscillator is ticked once per sample, and summed straight into
+        // the output, so that no intermediate buffer is needed
+        for (out_sample, &offset) in output.zip(phase_offsets) {
+            let gain = first_osc.get_gain();
+            let first = unsafe { first_osc.tick_all_pm(table, first_mask, quality, offset) } * gain;
+            let mut sample = first;
+
+            if collapsed {
+                for _ in other_oscs.iter() {
+                    sample += first;
+                }
+            } else {
+                for (osc, &mask) in other_oscs.iter_mut().zip(other_masks) {
+                    let gain = osc.get_gain();
+                    sample += unsafe { osc.tick_all_pm(table, mask, quality, offset) } * gain;
+                }
+            }
+
+            *out_sample = sum_to_stereo_sample(sample);
+        }
+
+        if collapsed {
+            for osc in other_oscs.iter_mut() {
+                osc.follow(first_osc, buffer_size);
+            }
+        }
+    }
+}
+
+impl Processor for WTOsc {
+    type Sample = Float;
+
+    fn audio_io_layout(&self) -> (usize, usize) {
+        let num_outputs = match self.output_layout {
+            OutputLayout::Polyphonic => 1,
+            OutputLayout::PerVoice => STEREO_VOICES_PER_VECTOR,
+        };
+
+        // the input modulates the oscillators' phases
+        (1, num_outputs)
+    }
+
+    fn process(&mut self, mut buffers: Buffers<Self::Sample>, cluster_idx: usize, voice_mask: TMask) {
+        let factor = self.oversampling.factor();
+
+        // copied, so that it isn't borrowed at the same time as the output
+        let pm_input = &mut self.pm_input;
+        let pm_last = &mut self.pm_last;
+        let has_pm_input = buffers
+            .get_input(0)
+            .map(|input| {
+                if factor == 1 {
+                    pm_input[..input.len()].copy_from_slice(input);
+                } else {
+                    Self::upsample(input, &mut pm_last[cluster_idx], factor, pm_input);
+                }
+            })
+            .is_some();
+
+        match self.output_layout {
+            OutputLayout::Polyphonic => {
+                if let Some(output_buf) = buffers.get_output(0) {
+                    self.render_output(cluster_idx, voice_mask, output_buf, has_pm_input);
+                }
+            }
+            OutputLayout::PerVoice => {
+                let len = (0..STEREO_VOICES_PER_VECTOR)
+                    .find_map(|i| buffers.get_output(i).map(|output_buf| output_buf.len()));
+
+                if let Some(len) = len {
+                    // moved out, so that it isn't borrowed at the same time as `self`
+                    let mut per_voice_buffer = mem::take(&mut self.per_voice_buffer);
+                    let rendered = &mut per_voice_buffer[..len];
+
+                    self.render_output(cluster_idx, voice_mask, rendered, has_pm_input);
+
+                    for voice_idx in 0..STEREO_VOICES_PER_VECTOR {
+                        let Some(output_buf) = buffers.get_output(voice_idx) else {
+                            continue;
+                        };
+
+                        for (out_sample, sample) in output_buf.iter_mut().zip(rendered.iter()) {
+                            let mut voice_sample = Float::splat(0.);
+                            voice_sample.as_mut_array()[..2]
+                                .copy_from_slice(split_stereo(sample)[voice_idx].as_array());
+                            *out_sample = voice_sample;
+                        }
+                    }
+
+                    self.per_voice_buffer = per_voice_buffer;
+                }
+            }
+        }
+
+        // events past the end of the block take effect in the next one
+        self.apply_param_events(cluster_idx, usize::MAX);
+    }
+
+    // may be called again, e. g. when the host's sample rate changes, sounding voices,
+    // and parameters, are kept, scratch buffers are only reallocated if they grow
+    fn initialize(&mut self, sr: f32, max_buffer_size: usize, max_num_clusters: usize) {
+        // everything below runs at the oscillators' rate
+        let factor = self.oversampling.factor();
+        let sr = sr * factor as f32;
+        let max_buffer_size = max_buffer_size * factor;
+
+        // existing clusters, and their voices, carry over, at the same pitch
+        if self.sr > 0. && self.sr != sr {
+            let ratio = self.sr / sr;
+
+            for (params, cluster) in self.params.iter_mut().zip(self.clusters.iter_mut()) {
+                params.rescale_sample_rate(ratio);
+                cluster.rescale_sample_rate(ratio);
+            }
+        }
+
+        self.sr = sr;
+        self.dc_blocker_coeff = (-TAU * Self::DC_BLOCKER_CUTOFF_HZ * factor as f32 / sr).exp();
+
+        self.update_smoothing_rates();
+
+        let num_old_clusters = self.clusters.len();
+        resize_boxed(&mut self.clusters, max_num_clusters, Default::default);
+        self.seed_clusters(num_old_clusters);
+
+        let pan_law = self.pan_law;
+        let unison_normalization = self.unison_normalization;
+        let frame_snap = self.frame_snap;
+        resize_boxed(&mut self.params, max_num_clusters, || {
+            WTOscClusterNormParams {
+                pan_law,
+                unison_normalization,
+                frame_snap,
+                ..Default::default()
+            }
+        });
+
+        self.fade_len = (Self::TABLE_FADE_SECS * sr) as usize;
+        self.voice_fade_step = (self.voice_fade_secs * sr).recip();
+
+        let num_frames = self.table.num_frames();
+        resize_boxed(&mut self.fade_positions, max_num_clusters, || usize::MAX);
+        resize_boxed(&mut self.cluster_num_frames, max_num_clusters, || {
+            num_frames
+        });
+        resize_boxed(&mut self.pm_last, max_num_clusters, Default::default);
+
+        // scratch buffers only ever grow
+        let len = self.fade_buffer.len().max(max_buffer_size);
+        resize_boxed(&mut self.fade_buffer, len, Default::default);
+        let len = self.pm_input.len().max(max_buffer_size);
+        resize_boxed(&mut self.pm_input, len, Default::default);
+        let len = self.phase_offsets.len().max(max_buffer_size);
+        resize_boxed(&mut self.phase_offsets, len, Default::default);
+
+        let oversampling = factor > 1;
+
+        // filter states are kept if the oversampling factor didn't change
+        if self
+            .decimators
+            .iter()
+            .any(|decimator| decimator.factor() != factor)
+        {
+            self.decimators = Default::default();
+        }
+
+        resize_boxed(
+            &mut self.decimators,
+            oversampling as usize * max_num_clusters,
+            || Decimator::new(factor),
+        );
+
+        if oversampling {
+            let len = self.oversampled_buffer.len().max(max_buffer_size);
+            resize_boxed(&mut self.oversampled_buffer, len, Default::default);
+        }
+
+        // at the host's rate
+        if self.output_layout == OutputLayout::PerVoice {
+            let len = self.per_voice_buffer.len().max(max_buffer_size / factor);
+            resize_boxed(&mut self.per_voice_buffer, len, Default::default);
+        }
+
+        self.param_events.clear();
+        self.param_events.reserve_exact(Self::MAX_PARAM_EVENTS);
+    }
+
+    fn set_param(&mut self, cluster_idx: usize, voice_mask: TMask, param_id: u64, norm_val: Float) {
+        // unknown ids are ignored
+        if let Ok(param) = WTOscParam::try_from(param_id) {
+            self.params[cluster_idx].set_param_target(param, norm_val, voice_mask);
+        }
+    }
+
+    fn custom_event(&mut self, event: &mut dyn Any) {
+        // playing voices crossfade from the current table to the new one, the table
+        // replaced in the previous swap is handed back in the event, so that it isn't
+        // dropped on the audio thread
+        // frames are rescaled per cluster, see `Self::update_cluster_frames`
+        if let Some(wt) = event.downcast_mut::<Arc<BandLimitedWaveTables>>() {
+            self.old_frames_ratio = 1.;
+
+            if self.table.num_frames() != 0 && wt.num_frames() != 0 {
+                self.old_frames_ratio = self.table.num_frames() as f32 / wt.num_frames() as f32;
+            }
+
+            mem::swap(wt, &mut self.old_table);
+            mem::swap(&mut self.old_table, &mut self.table);
+            self.fade_positions.fill(0);
+        }
+
+        // the previous bank is handed back, like the replaced table above
+        if let Some(bank) = event.downcast_mut::<Vec<Arc<BandLimitedWaveTables>>>() {
+            mem::swap(bank, &mut self.table_bank);
+        }
+
+        if let Some(&mut quality) = event.downcast_mut::<Quality>() {
+            self.quality = quality;
+        }
+
+        // goes through the weight smoothers, like any other change in the pan parameter
+        if let Some(&mut pan_law) = event.downcast_mut::<PanLaw>() {
+            self.pan_law = pan_law;
+
+            for params in self.params.iter_mut() {
+                params.pan_law = pan_law;
+            }
+        }
+
+        if let Some(&mut normalization) = event.downcast_mut::<UnisonNormalization>() {
+            self.unison_normalization = normalization;
+
+            for params in self.params.iter_mut() {
+                params.unison_normalization = normalization;
+            }
+        }
+
+        if let Some(&mut Tempo(bpm)) = event.downcast_mut::<Tempo>() {
+            self.tempo = bpm;
+        }
+
+        if let Some(&mut FrameSnap(enabled)) = event.downcast_mut::<FrameSnap>() {
+            self.frame_snap = enabled;
+
+            for params in self.params.iter_mut() {
+                params.frame_snap = enabled;
+            }
+        }
+
+        if let Some(&mut tuning) = event.downcast_mut::<Option<TuningTable>>() {
+            self.tuning = tuning;
+            self.retune();
+        }
+
+        if let Some(&mut mode) = event.downcast_mut::<RetriggerMode>() {
+            self.retrigger_mode = mode;
+        }
+
+        if let Some(&mut DcBlocker(enabled)) = event.downcast_mut::<DcBlocker>() {
+            self.dc_blocker = enabled;
+        }
+
+        if let Some(&mut RandomSeed(seed)) = event.downcast_mut::<RandomSeed>() {
+            self.seed = seed;
+            self.seed_clusters(0);
+        }
+
+        // phases outside of [0 ; 1) are wrapped
+        if let Some(starting_phases) = event.downcast_mut::<[f32; MAX_UNISON]>() {
+            self.set_starting_phases(starting_phases);
+        }
+    }
+
+    fn reset(&mut self, cluster_idx: usize, voice_mask: TMask) {
+        // a note starts with the parameters it was set to play with, which
+        // the smoothers may not have reached yet, e. g. after a preset change
+        let random = self.params[cluster_idx].random.target;
+        self.clusters[cluster_idx].reset_phases(
+            voice_mask,
+            self.retrigger_mode,
+            random,
+            &self.starting_phases,
+        );
+        self.clusters[cluster_idx].reset_dc_blocker(voice_mask);
+
+        // like the oscillators' phases, the frame scan, and ring
+        // modulator, run freely across notes
+        if self.retrigger_mode != RetriggerMode::Free {
+            self.clusters[cluster_idx].reset_frame_scan(&mut self.params[cluster_idx], voice_mask);
+            self.clusters[cluster_idx].reset_ring_mod(voice_mask);
+        }
+
+        // the voices' stereo weights, and oscillators, start from the current parameter
+        // values, instead of ramping from where the previous note left them
+        let num_frames_f = Simd::splat(self.update_cluster_frames(cluster_idx) as f32);
+        self.clusters[cluster_idx].set_params(&self.params[cluster_idx], num_frames_f, voice_mask);
+    }
+
+    fn move_state(
+        &mut self,
+        (from_cluster, from_voice): (usize, usize),
+        (to_cluster, to_voice): (usize, usize),
+    ) {
+        (from_voice < STEREO_VOICES_PER_VECTOR && to_voice < STEREO_VOICES_PER_VECTOR)
+            .then(|| {
+                let clusters = Cell::from_mut(self.clusters.as_mut()).as_slice_of_cells();
+                let params = Cell::from_mut(self.params.as_mut()).as_slice_of_cells();
+
+                unsafe {
+                    WTOscVoiceCluster::move_state_unchecked(
+                        &clusters[from_cluster],
+                        from_voice,
+                        &clusters[to_cluster],
+                        to_voice,
+                    );
+
+                    WTOscClusterNormParams::move_state_unchecked(
+                        &params[from_cluster],
+                        from_voice,
+                        &params[to_cluster],
+                        to_voice,
+                    );
+                }
+            })
+            .expect("out of bounds voice indices")
+    }
+
+    fn activate_voices(
+        &mut self,
+        cluster_idx: usize,
+        voice_mask: TMask,
+        velocity: Float,
+        note: UInt,
+    ) {
+        self.activate_voices_fractional(cluster_idx, voice_mask, velocity, note.cast());
+    }
+
+    fn set_all_params(
+        &mut self,
+        cluster_idx: usize,
+        voice_mask: TMask,
+        params: &dyn Parameters<Float>,
+    ) {
+        let cluster_params = &mut self.params[cluster_idx];
+
+        for param in WTOscParam::ALL {
+            let param_value = params
+                .get_param(param as u64, cluster_idx, voice_mask)
+                .unwrap();
+
+            cluster_params.set_param_instantly(param, param_value, voice_mask);
+        }
+
+        let num_frames_f = Simd::splat(self.update_cluster_frames(cluster_idx) as f32);
+
+        self.clusters[cluster_idx].set_params(&self.params[cluster_idx], num_frames_f, voice_mask);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        io::{self, Cursor, Write},
+    };
+
+    use core::f32::consts::{FRAC_1_SQRT_2, TAU};
+    use hound::{SampleFormat, WavSpec, WavWriter};
+    use polygraph::{
+        buffer::{BufferHandleLocal, OutputBufferIndex},
+        processor::{new_vfloat_buffer, ParamsList},
+    };
+    use realfft::{num_complex::Complex32, RealFftPlanner};
+    use wavetable::{
+        loader, ChannelMode, FrameInterpolation, LoadOptions, MipmapGenerator, MipmapOptions,
+        Normalize, PhaseAlignment, Quality, Transition, WavetableError,
+    };
+
+    use super::*;
+
+    /// Counts the allocations made by each thread, so tests can check that
+    /// some code doesn't allocate, even when ran in parallel
+    struct CountingAllocator;
+
+    thread_local! {
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn allocations() -> usize {
+        ALLOCATIONS.with(Cell::get)
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            // the thread local might already be destroyed
+            let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    fn wav_bytes<S: hound::Sample + Copy>(
+        sample_format: SampleFormat,
+        bits_per_sample: u16,
+        samples: &[S],
+    ) -> Vec<u8> {
+        wav_bytes_with_channels(1, sample_format, bits_per_sample, samples)
+    }
+
+    fn wav_bytes_with_channels<S: hound::Sample + Copy>(
+        channels: u16,
+        sample_format: SampleFormat,
+        bits_per_sample: u16,
+        samples: &[S],
+    ) -> Vec<u8> {
+        let spec = WavSpec {
+            channels,
+            sample_rate: 44100,
+            bits_per_sample,
+            sample_format,
+        };
+
+        let mut cursor = Cursor::new(Vec::new());
+        let mut writer = WavWriter::new(&mut cursor, spec).unwrap();
+
+        for &sample in samples {
+            writer.write_sample(sample).unwrap();
+        }
+
+        writer.finalize().unwrap();
+        cursor.into_inner()
+    }
+
+    /// Load options that leave the top mipmaps exactly as they are in the file
+    fn verbatim() -> LoadOptions {
+        LoadOptions {
+            mipmaps: MipmapOptions {
+                remove_dc: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    pub fn truncated_wav() {
+        let frame = [0.5f32; BandLimitedWaveTables::FRAME_LEN];
+        let mut bytes = wav_bytes(SampleFormat::Float, 32, &frame);
+        bytes.truncate(bytes.len() - 100);
+
+        let result = BandLimitedWaveTables::from_reader(bytes.as_slice());
+        assert!(matches!(result, Err(WavetableError::Io(_))));
+
+        let bytes = wav_bytes(SampleFormat::Float, 32, &frame[..1000]);
+
+        let result = BandLimitedWaveTables::from_reader(bytes.as_slice());
+        assert!(matches!(
+            result,
+            Err(WavetableError::WrongLength {
+                cycle_len: BandLimitedWaveTables::FRAME_LEN,
+                expected: BandLimitedWaveTables::FRAME_LEN,
+                actual: 1000,
+            })
+        ));
+    }
+
+    #[test]
+    pub fn unsupported_format_wav() {
+        let bytes = wav_bytes(
+            SampleFormat::Int,
+            8,
+            &[0i8; BandLimitedWaveTables::FRAME_LEN],
+        );
+
+        let result = BandLimitedWaveTables::from_reader(bytes.as_slice());
+        assert!(matches!(
+            result,
+            Err(WavetableError::UnsupportedFormat {
+                format: SampleFormat::Int,
+                bits_per_sample: 8,
+            })
+        ));
+    }
+
+    #[test]
+    pub fn nonexistent_wav_path() {
+        let result = BandLimitedWaveTables::from_file("this/file/does/not/exist.wav");
+        assert!(matches!(result, Err(WavetableError::Io(_))));
+    }
+
+    #[test]
+    pub fn int_format_wavs() {
+        let frame: [f32; BandLimitedWaveTables::FRAME_LEN] = array::from_fn(|i| {
+            (i as f32 / BandLimitedWaveTables::FRAME_LEN as f32 * TAU).sin() * 0.9
+        });
+
+        let reference = BandLimitedWaveTables::from_reader(
+            wav_bytes(SampleFormat::Float, 32, &frame).as_slice(),
+        )
+        .unwrap();
+
+        let int16 = frame.map(|s| (s * 32768.) as i16);
+        let int24 = frame.map(|s| (s * 8388608.) as i32);
+
+        for (table, epsilon) in [
+            (wav_bytes(SampleFormat::Int, 16, &int16), 1e-4),
+            (wav_bytes(SampleFormat::Int, 24, &int24), 1e-6),
+        ] {
+            let table = BandLimitedWaveTables::from_reader(table.as_slice()).unwrap();
+            assert_eq!(table.num_frames(), 1);
+
+            let expected = reference.as_slice()[0].last().unwrap();
+            let actual = table.as_slice()[0].last().unwrap();
+
+            for (a, b) in actual.iter().zip(expected) {
+                assert!((a - b).abs() < epsilon);
+            }
+        }
+    }
+
+    #[test]
+    pub fn stereo_wav() {
+        let samples: Vec<_> = iter::repeat([0.5f32, -0.25])
+            .take(BandLimitedWaveTables::FRAME_LEN * 2)
+            .flatten()
+            .collect();
+
+        let bytes = wav_bytes_with_channels(2, SampleFormat::Float, 32, &samples);
+
+        for (channel_mode, expected) in [
+            (ChannelMode::Left, 0.5),
+            (ChannelMode::Right, -0.25),
+            (ChannelMode::Mix, 0.125),
+        ] {
+            let table = BandLimitedWaveTables::from_reader_with_options(
+                bytes.as_slice(),
+                LoadOptions {
+                    channel_mode,
+                    mipmaps: MipmapOptions {
+                        remove_dc: false,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+            assert_eq!(table.num_frames(), 2);
+
+            for mipmaps in table.as_slice() {
+                assert!(mipmaps.last().unwrap().iter().all(|&s| s == expected));
+            }
+        }
+    }
+
+    #[test]
+    pub fn in_memory_round_trip() {
+        let table = BandLimitedWaveTables::basic_shapes();
+
+        let samples: Vec<_> = table
+            .as_slice()
+            .iter()
+            .flat_map(|mipmaps| mipmaps.last().unwrap())
+            .copied()
+            .collect();
+
+        let cursor = Cursor::new(wav_bytes(SampleFormat::Float, 32, &samples));
+        let loaded = BandLimitedWaveTables::from_reader_with_options(cursor, verbatim()).unwrap();
+
+        assert_eq!(loaded.num_frames(), table.num_frames());
+
+        for (a, b) in loaded.as_slice().iter().zip(table.as_slice()) {
+            assert_eq!(a.last(), b.last());
+        }
+    }
+
+    #[test]
+    pub fn table_from_frames() {
+        assert!(matches!(
+            BandLimitedWaveTables::from_frames(&[]),
+            Err(WavetableError::Empty)
+        ));
+
+        assert!(matches!(
+            BandLimitedWaveTables::from_samples(&[0.; 3000]),
+            Err(WavetableError::WrongLength {
+                cycle_len: 2048,
+                expected: 4096,
+                actual: 3000,
+            })
+        ));
+
+        let baked = &basic_shapes::baked::WAVETABLES;
+
+        let frames = BandLimitedWaveTables::from_frames(baked).unwrap();
+        let samples = BandLimitedWaveTables::from_samples(baked.flatten()).unwrap();
+        let reference = Box::<BandLimitedWaveTables>::from(baked.as_slice());
+
+        assert_eq!(frames.as_slice(), reference.as_slice());
+        assert_eq!(samples.as_slice(), reference.as_slice());
+    }
+
+    #[test]
+    pub fn generated_basic_shapes() {
+        let baked = &basic_shapes::baked::WAVETABLES;
+        let table = BandLimitedWaveTables::basic_shapes();
+
+        assert_eq!(table.num_frames(), baked.len());
+
+        // a full scale sine's bin is half as large as a frame
+        let scale = 2. / BandLimitedWaveTables::FRAME_LEN as f32;
+
+        for (i, (mipmaps, baked)) in table.as_slice().iter().zip(baked).enumerate() {
+            let generated = spectrum(mipmaps.last().unwrap());
+            let baked = spectrum(baked);
+
+            for (bin, (a, b)) in generated.iter().zip(&baked).enumerate() {
+                let db = 20. * ((a - b).norm() * scale).log10();
+                assert!(db < -100., "frame {i}, bin {bin}: {db} dB");
+            }
+        }
+    }
+
+    /// Magnitudes of the harmonics of `frame`'s top mipmap, relative to its fundamental's
+    fn relative_harmonics(frame: &[[f32; BandLimitedWaveTables::FRAME_LEN]]) -> Vec<f32> {
+        let spectrum = spectrum(frame.last().unwrap());
+        let fundamental = spectrum[1].norm();
+        spectrum
+            .iter()
+            .map(|bin| bin.norm() / fundamental)
+            .collect()
+    }
+
+    #[test]
+    pub fn pwm_sweep() {
+        let table = BandLimitedWaveTables::pwm_sweep();
+        assert_eq!(table.num_frames(), basic_shapes::PWM_SWEEP_FRAMES);
+
+        // a square, odd harmonics only
+        let square = relative_harmonics(&table.as_slice()[0]);
+        assert!(square[2] < 1e-4);
+        assert!((square[3] - 1. / 3.).abs() < 1e-4);
+
+        // 25% duty cycle, every fourth harmonic is missing
+        let quarter = relative_harmonics(&table.as_slice()[35]);
+        assert!(quarter[2] > 0.1);
+        assert!(quarter[4] < 1e-4);
+        assert!(quarter[8] < 1e-4);
+
+        for mipmaps in table.as_slice() {
+            let peak = mipmaps
+                .last()
+                .unwrap()
+                .iter()
+                .fold(0f32, |p, s| p.max(s.abs()));
+            assert!((peak - 1.).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    pub fn harmonic_drawbars() {
+        let table = BandLimitedWaveTables::harmonic_drawbars();
+        assert_eq!(
+            table.num_frames(),
+            basic_shapes::DRAWBAR_REGISTRATIONS.len()
+        );
+
+        // only the 8' drawbar, a sine
+        let flute = relative_harmonics(&table.as_slice()[0]);
+        assert!(flute[2..].iter().all(|&harmonic| harmonic < 1e-4));
+
+        // every drawbar, all at the same level, the 7th harmonic has none
+        let full = relative_harmonics(table.as_slice().last().unwrap());
+
+        for k in basic_shapes::DRAWBAR_HARMONICS {
+            assert!((full[k] - 1.).abs() < 1e-4);
+        }
+
+        assert!(full[7] < 1e-4);
+        assert!(full[9..].iter().all(|&harmonic| harmonic < 1e-4));
+    }
+
+    #[test]
+    pub fn saw_to_square_morph() {
+        let table = BandLimitedWaveTables::saw_to_square_morph();
+        assert_eq!(table.num_frames(), basic_shapes::SAW_TO_SQUARE_FRAMES);
+
+        let saw = relative_harmonics(&table.as_slice()[0]);
+        let square = relative_harmonics(table.as_slice().last().unwrap());
+
+        for k in 2..10 {
+            assert!((saw[k] - 1. / k as f32).abs() < 1e-4);
+        }
+
+        assert!(square[2] < 1e-4);
+        assert!((square[5] - 1. / 5.).abs() < 1e-4);
+
+        // on the way, the even harmonics fade out
+        let middle = relative_harmonics(&table.as_slice()[basic_shapes::SAW_TO_SQUARE_FRAMES / 2]);
+        assert!(middle[2] > 0.1 && middle[2] < saw[2]);
+    }
+
+    #[test]
+    pub fn vowels() {
+        use basic_shapes::{VOWEL_FORMANTS, VOWEL_FUNDAMENTAL_HZ, VOWEL_MORPH_FRAMES};
+
+        let table = BandLimitedWaveTables::vowels();
+        assert_eq!(
+            table.num_frames(),
+            (VOWEL_FORMANTS.len() - 1) * VOWEL_MORPH_FRAMES + 1
+        );
+
+        // A and O
+        for vowel in [0, 3] {
+            let harmonics = relative_harmonics(&table.as_slice()[vowel * VOWEL_MORPH_FRAMES]);
+
+            // the harmonics closest to the first two formants are louder than their neighbours
+            for formant in &VOWEL_FORMANTS[vowel][..2] {
+                let closest = (formant.frequency / VOWEL_FUNDAMENTAL_HZ).round() as usize;
+                let loudest = (closest - 2..=closest + 2)
+                    .max_by(|&a, &b| harmonics[a].total_cmp(&harmonics[b]))
+                    .unwrap();
+
+                assert_eq!(loudest, closest, "vowel {vowel}, {formant:?}");
+            }
+        }
+    }
+
+    #[test]
+    pub fn chebyshev_table() {
+        use core::f64::consts::PI;
+
+        // T_2 to T_5
+        let weights = [0.5, 0.25, 0., 0.125];
+        let table = BandLimitedWaveTables::from_chebyshev(&weights, 8).unwrap();
+
+        assert_eq!(table.num_frames(), 8);
+
+        let sine = relative_harmonics(&table.as_slice()[0]);
+        assert!(sine[2..].iter().all(|&harmonic| harmonic < 1e-4));
+
+        let last = relative_harmonics(table.as_slice().last().unwrap());
+
+        for (k, &harmonic) in last.iter().enumerate().skip(2) {
+            let expected = weights.get(k - 2).copied().unwrap_or(0.);
+            assert!(
+                (harmonic - expected).abs() < 1e-4,
+                "harmonic {k}: {harmonic}"
+            );
+        }
+
+        // the same, in the time domain, T_n(x) = 2x * T_(n-1)(x) - T_(n-2)(x)
+        let shaped: Vec<_> = (0..BandLimitedWaveTables::FRAME_LEN)
+            .map(|i| {
+                let x = (i as f64 / BandLimitedWaveTables::FRAME_LEN as f64 * 2. * PI).sin();
+                let (mut previous, mut current) = (1., x);
+                let mut sum = x;
+
+                for &weight in &weights {
+                    (previous, current) = (current, 2. * x * current - previous);
+                    sum += weight as f64 * current;
+                }
+
+                sum
+            })
+            .collect();
+
+        let peak = shaped.iter().fold(0f64, |peak, s| peak.max(s.abs()));
+        assert!(peak > 1.);
+
+        let frame = table.as_slice().last().unwrap().last().unwrap();
+
+        for (&sample, &expected) in frame.iter().zip(&shaped) {
+            assert!((sample as f64 - expected / peak).abs() < 1e-4);
+        }
+    }
+
+    fn sine_cycle(len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| (i as f32 / len as f32 * TAU).sin())
+            .collect()
+    }
+
+    fn assert_all_frames_eq(table: &BandLimitedWaveTables, frame: &[f32], epsilon: f32) {
+        let reference = BandLimitedWaveTables::from_samples(frame).unwrap();
+
+        for mipmaps in table.as_slice() {
+            for (a, b) in mipmaps
+                .flatten()
+                .iter()
+                .zip(reference.as_slice()[0].flatten())
+            {
+                assert!((a - b).abs() < epsilon);
+            }
+        }
+    }
+
+    fn assert_tables_eq(a: &BandLimitedWaveTables, b: &BandLimitedWaveTables, epsilon: f32) {
+        assert_eq!(a.num_frames(), b.num_frames());
+
+        for (a, b) in a.as_slice().flatten().iter().zip(b.as_slice().flatten()) {
+            for (a, b) in a.iter().zip(b) {
+                assert!((a - b).abs() < epsilon);
+            }
+        }
+    }
+
+    #[test]
+    pub fn resampled_cycles() {
+        for cycle_len in [256, 1000, 4096] {
+            let samples: Vec<_> = iter::repeat(sine_cycle(cycle_len))
+                .take(3)
+                .flatten()
+                .collect();
+
+            let table =
+                BandLimitedWaveTables::from_cycles(&samples, NonZeroUsize::new(cycle_len).unwrap())
+                    .unwrap();
+
+            assert_eq!(table.num_frames(), 3);
+            assert_all_frames_eq(&table, &sine_cycle(2048), 1e-4);
+        }
+    }
+
+    #[test]
+    pub fn serum_clm_chunk() {
+        let samples: Vec<_> = iter::repeat(sine_cycle(1024)).take(3).flatten().collect();
+        let mut bytes = wav_bytes(SampleFormat::Float, 32, &samples);
+
+        let result = BandLimitedWaveTables::from_reader(bytes.as_slice());
+        assert!(matches!(result, Err(WavetableError::WrongLength { .. })));
+
+        let clm_text = b"<!>1024 10000000 wavetable (www.xferrecords.com)";
+        let mut clm_chunk = b"clm ".to_vec();
+        clm_chunk.extend_from_slice(&(clm_text.len() as u32).to_le_bytes());
+        clm_chunk.extend_from_slice(clm_text);
+
+        let data_pos = bytes.windows(4).position(|id| id == b"data").unwrap();
+        let data = bytes.split_off(data_pos);
+        bytes.extend_from_slice(&clm_chunk);
+        bytes.extend_from_slice(&data);
+
+        let riff_len = bytes.len() as u32 - 8;
+        bytes[4..8].copy_from_slice(&riff_len.to_le_bytes());
+
+        let table = BandLimitedWaveTables::from_reader(bytes.as_slice()).unwrap();
+
+        assert_eq!(table.num_frames(), 3);
+        assert_all_frames_eq(&table, &sine_cycle(2048), 1e-4);
+    }
+
+    #[test]
+    pub fn wavex_float() {
+        let samples: Vec<_> = iter::repeat(sine_cycle(2048)).take(2).flatten().collect();
+        let plain = wav_bytes(SampleFormat::Float, 32, &samples);
+
+        let mut fmt = Vec::new();
+        fmt.extend_from_slice(&0xfffe_u16.to_le_bytes());
+        fmt.extend_from_slice(&1u16.to_le_bytes()); // channels
+        fmt.extend_from_slice(&44100u32.to_le_bytes());
+        fmt.extend_from_slice(&(44100u32 * 4).to_le_bytes()); // byte rate
+        fmt.extend_from_slice(&4u16.to_le_bytes()); // block align
+        fmt.extend_from_slice(&32u16.to_le_bytes()); // bits per sample
+        fmt.extend_from_slice(&22u16.to_le_bytes()); // extension size
+        fmt.extend_from_slice(&32u16.to_le_bytes()); // valid bits per sample
+        fmt.extend_from_slice(&0x4u32.to_le_bytes()); // channel mask, front center
+                                                      // KSDATAFORMAT_SUBTYPE_IEEE_FLOAT
+        fmt.extend_from_slice(&[
+            0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xaa, 0x00, 0x38,
+            0x9b, 0x71,
+        ]);
+
+        let info = b"INFOISFT\x05\0\0\0wtosc\0";
+        let data: Vec<_> = samples.iter().flat_map(|s| s.to_le_bytes()).collect();
+
+        let mut wavex = b"RIFF\0\0\0\0WAVE".to_vec();
+
+        for (id, contents) in [
+            (b"fmt ", fmt.as_slice()),
+            (b"LIST", info.as_slice()),
+            (b"cue ", [0; 4].as_slice()),
+            (b"data", data.as_slice()),
+        ] {
+            wavex.extend_from_slice(id);
+            wavex.extend_from_slice(&(contents.len() as u32).to_le_bytes());
+            wavex.extend_from_slice(contents);
+        }
+
+        let riff_len = wavex.len() as u32 - 8;
+        wavex[4..8].copy_from_slice(&riff_len.to_le_bytes());
+
+        let expected = BandLimitedWaveTables::from_reader(plain.as_slice()).unwrap();
+        let table = BandLimitedWaveTables::from_reader(wavex.as_slice()).unwrap();
+
+        assert_eq!(table.num_frames(), 2);
+        assert_eq!(table.as_slice(), expected.as_slice());
+    }
+
+    fn surge_wt_bytes(wave_size: u32, flags: u16, samples: &[u8]) -> Vec<u8> {
+        let mut bytes = b"vawt".to_vec();
+        let wave_count =
+            (samples.len() / wave_size as usize / if flags & 0x4 != 0 { 2 } else { 4 }) as u16;
+        bytes.extend_from_slice(&wave_size.to_le_bytes());
+        bytes.extend_from_slice(&wave_count.to_le_bytes());
+        bytes.extend_from_slice(&flags.to_le_bytes());
+        bytes.extend_from_slice(samples);
+        bytes
+    }
+
+    #[test]
+    pub fn surge_wt() {
+        let samples: Vec<_> = iter::repeat(sine_cycle(1024)).take(2).flatten().collect();
+
+        let float_bytes: Vec<_> = samples.iter().flat_map(|s| s.to_le_bytes()).collect();
+        let int16_bytes: Vec<_> = samples
+            .iter()
+            .flat_map(|s| ((s * 32767.) as i16).to_le_bytes())
+            .collect();
+
+        for (bytes, epsilon) in [
+            (surge_wt_bytes(1024, 0, &float_bytes), 1e-4),
+            (surge_wt_bytes(1024, 0x4 | 0x8, &int16_bytes), 1e-3),
+        ] {
+            let table = BandLimitedWaveTables::from_surge_wt_reader(bytes.as_slice()).unwrap();
+
+            assert_eq!(table.num_frames(), 2);
+            assert_all_frames_eq(&table, &sine_cycle(2048), epsilon);
+        }
+
+        let mut bytes = surge_wt_bytes(1024, 0, &float_bytes);
+        bytes[..4].copy_from_slice(b"RIFF");
+
+        assert!(matches!(
+            BandLimitedWaveTables::from_surge_wt_reader(bytes.as_slice()),
+            Err(WavetableError::InvalidHeader(_))
+        ));
+    }
+
+    #[test]
+    pub fn oversized_surge_wt_header() {
+        let header = |wave_size: u32, wave_count: u16| {
+            let mut bytes = b"vawt".to_vec();
+            bytes.extend_from_slice(&wave_size.to_le_bytes());
+            bytes.extend_from_slice(&wave_count.to_le_bytes());
+            bytes.extend_from_slice(&0u16.to_le_bytes());
+            bytes
+        };
+
+        let load = |bytes: Vec<u8>| BandLimitedWaveTables::from_surge_wt_reader(bytes.as_slice());
+
+        // petabytes of samples, claimed by a 12 byte file
+        assert!(matches!(
+            load(header(u32::MAX, u16::MAX)),
+            Err(WavetableError::InvalidHeader(_))
+        ));
+        assert!(matches!(load(header(1024, 0)), Err(WavetableError::Empty)));
+
+        // plausible, but the data isn't there
+        let mut truncated = header(4096, u16::MAX);
+        truncated.extend_from_slice(&[0; 1024]);
+
+        assert!(matches!(
+            load(truncated),
+            Err(WavetableError::Io(err)) if err.kind() == io::ErrorKind::UnexpectedEof
+        ));
+    }
+
+    #[test]
+    pub fn wav_directory() {
+        let dir = std::env::temp_dir().join(format!("wt_osc_directory_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let sine = sine_cycle(600);
+        let negated_sine: Vec<_> = sine.iter().map(|s| -s).collect();
+        let sine_then_silence: Vec<_> = sine.iter().copied().chain([0.; 600]).collect();
+
+        for (name, samples) in [
+            ("b.wav", sine_then_silence.as_slice()),
+            ("a.WAV", negated_sine.as_slice()),
+            ("c.txt", sine.as_slice()),
+        ] {
+            let bytes = wav_bytes(SampleFormat::Float, 32, samples);
+            std::fs::write(dir.join(name), bytes).unwrap();
+        }
+
+        let table = BandLimitedWaveTables::from_directory_with_options(
+            &dir,
+            LoadOptions {
+                cycle_len: NonZeroUsize::new(600),
+                ..Default::default()
+            },
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let frames: Vec<_> = sine_cycle(2048)
+            .into_iter()
+            .map(|s| -s)
+            .chain(sine_cycle(2048))
+            .collect();
+
+        let expected = BandLimitedWaveTables::from_samples(&frames).unwrap();
+
+        assert_tables_eq(&table.unwrap(), &expected, 1e-4);
+    }
+
+    #[test]
+    pub fn wav_export_round_trip() {
+        let table = BandLimitedWaveTables::basic_shapes();
+
+        for serum_clm_chunk in [false, true] {
+            let mut bytes = Vec::new();
+            table.write_wav_to(&mut bytes, serum_clm_chunk).unwrap();
+
+            let loaded =
+                BandLimitedWaveTables::from_reader_with_options(bytes.as_slice(), verbatim())
+                    .unwrap();
+
+            assert_eq!(loaded.num_frames(), table.num_frames());
+
+            for (a, b) in loaded.as_slice().iter().zip(table.as_slice()) {
+                assert_eq!(a.last(), b.last());
+            }
+        }
+    }
+
+    fn rms(samples: &[f32]) -> f32 {
+        (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+    }
+
+    #[test]
+    pub fn additive_table() {
+        let fundamental = [Complex32::new(1., 0.)];
+        let table = BandLimitedWaveTables::from_harmonics(&[&fundamental, &[]]).unwrap();
+
+        let (sine, silence) = (&table.as_slice()[0], &table.as_slice()[1]);
+
+        assert!((rms(sine.last().unwrap()) - FRAC_1_SQRT_2).abs() < 1e-4);
+        assert!(silence.flatten().iter().all(|&s| s == 0.));
+
+        for (a, b) in sine.last().unwrap().iter().zip(sine_cycle(2048)) {
+            assert!((a - b).abs() < 1e-4);
+        }
+
+        let too_many = vec![Complex32::new(1., 0.); BandLimitedWaveTables::FRAME_LEN / 2];
+        assert!(matches!(
+            BandLimitedWaveTables::from_harmonics(&[&too_many]),
+            Err(WavetableError::TooManyHarmonics { .. })
+        ));
+    }
+
+    #[test]
+    pub fn table_from_fn() {
+        let harmonic = |i: usize, phase: f32| (phase * TAU * (i + 1) as f32).sin();
+
+        let table = BandLimitedWaveTables::from_fn(3, |i, norm_frame, phase| {
+            harmonic(i, phase) * norm_frame
+        })
+        .unwrap();
+
+        for (i, (mipmaps, amp)) in table.as_slice().iter().zip([0., 0.5, 1.]).enumerate() {
+            for (j, &sample) in mipmaps.last().unwrap().iter().enumerate() {
+                let phase = j as f32 / BandLimitedWaveTables::FRAME_LEN as f32;
+                assert!((sample - harmonic(i, phase) * amp).abs() < 1e-5);
+            }
+        }
+
+        assert!(matches!(
+            BandLimitedWaveTables::from_fn(0, |_, _, _| 0.),
+            Err(WavetableError::Empty)
+        ));
+    }
+
+    #[test]
+    pub fn interpolated_frames() {
+        let quarter = BandLimitedWaveTables::FRAME_LEN / 4;
+        let sine = sine_cycle(2048);
+        let cosine: Vec<_> = sine[quarter..]
+            .iter()
+            .chain(&sine[..quarter])
+            .copied()
+            .collect();
+        let samples: Vec<_> = sine.iter().chain(&cosine).copied().collect();
+
+        let table = BandLimitedWaveTables::from_samples(&samples).unwrap();
+
+        for (mode, middle_rms) in [
+            (FrameInterpolation::Linear, 0.5),
+            (FrameInterpolation::Spectral, FRAC_1_SQRT_2),
+        ] {
+            let interpolated = table.interpolated_with_mode(5, mode).unwrap();
+            let frames = interpolated.as_slice();
+
+            assert_eq!(frames.len(), 5);
+
+            for (frame, expected) in [(&frames[0], &sine), (&frames[4], &cosine)] {
+                for (&a, &b) in frame.last().unwrap().iter().zip(expected) {
+                    assert!((a - b).abs() < 1e-4);
+                }
+            }
+
+            assert!((rms(frames[2].last().unwrap()) - middle_rms).abs() < 1e-3);
+        }
+
+        let single_frame = BandLimitedWaveTables::from_samples(&sine).unwrap();
+        let tiled = single_frame.interpolated(4).unwrap();
+
+        assert_eq!(tiled.num_frames(), 4);
+        assert_all_frames_eq(&tiled, &sine, 1e-4);
+    }
+
+    #[test]
+    pub fn frame_slicing_and_concatenation() {
+        let a = BandLimitedWaveTables::basic_shapes();
+        let b = BandLimitedWaveTables::from_fn(3, |i, _, phase| phase * i as f32).unwrap();
+
+        let composite = BandLimitedWaveTables::concat(&[&a, &b]).unwrap();
+        let slice = composite.slice_frames(2..6).unwrap();
+
+        assert_eq!(composite.num_frames(), 7);
+        assert_eq!(slice.as_slice(), &composite.as_slice()[2..6]);
+
+        assert!(matches!(
+            composite.slice_frames(5..8),
+            Err(WavetableError::FramesOutOfRange { .. })
+        ));
+
+        let sources = (0..4).map(|i| (&a, i)).chain((0..3).map(|i| (&b, i)));
+
+        for (frame, (source, source_frame)) in sources.enumerate() {
+            for phase_delta in [1 << 12, 1 << 19, 1 << 27] {
+                for phase in [0, 1 << 20, 3 << 29, u32::MAX] {
+                    let (phase_delta, phase) = (UInt::splat(phase_delta), UInt::splat(phase));
+
+                    let (expected, actual) = unsafe {
+                        (
+                            source.resample(phase_delta, UInt::splat(source_frame), phase),
+                            composite.resample(phase_delta, UInt::splat(frame as u32), phase),
+                        )
+                    };
+
+                    assert_eq!(expected, actual);
+                }
+            }
+        }
+    }
+
+    #[test]
+    pub fn frame_normalization() {
+        let amplitudes = [0., 0.1, 0.2, 0.4, 0.8];
+
+        let samples: Vec<_> = amplitudes
+            .iter()
+            .flat_map(|&amp| sine_cycle(2048).into_iter().map(move |s| s * amp))
+            .collect();
+
+        let bytes = wav_bytes(SampleFormat::Float, 32, &samples);
+
+        for (normalize, expected_peaks) in [
+            (Normalize::None, amplitudes),
+            (Normalize::GlobalPeak, [0., 0.125, 0.25, 0.5, 1.]),
+            (Normalize::PerFramePeak, [0., 1., 1., 1., 1.]),
+            (Normalize::PerFrameRms, [0., 1., 1., 1., 1.]),
+        ] {
+            let table = BandLimitedWaveTables::from_reader_with_options(
+                bytes.as_slice(),
+                LoadOptions {
+                    normalize,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+            for (mipmaps, expected_peak) in table.as_slice().iter().zip(expected_peaks) {
+                let frame = mipmaps.last().unwrap();
+                let peak = frame.iter().fold(0f32, |max, s| max.max(s.abs()));
+
+                assert!((peak - expected_peak).abs() < 1e-4);
+                assert!((rms(frame) - expected_peak * FRAC_1_SQRT_2).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    pub fn dc_removal() {
+        let samples: Vec<_> = sine_cycle(2048).into_iter().map(|s| s + 0.3).collect();
+
+        let table = BandLimitedWaveTables::from_samples(&samples).unwrap();
+
+        for mipmap in table.as_slice()[0].iter() {
+            assert!((mipmap.iter().sum::<f32>() / mipmap.len() as f32).abs() < 1e-6);
+        }
+
+        assert_all_frames_eq(&table, &sine_cycle(2048), 1e-5);
+
+        let with_dc = BandLimitedWaveTables::from_reader_with_options(
+            wav_bytes(SampleFormat::Float, 32, &samples).as_slice(),
+            verbatim(),
+        )
+        .unwrap();
+
+        assert_eq!(with_dc.as_slice()[0].last().unwrap().as_slice(), samples);
+    }
+
+    #[test]
+    pub fn phase_alignment() {
+        let len = BandLimitedWaveTables::FRAME_LEN;
+        let shifted_sine =
+            |shift: f32| (0..len).map(move |i| ((i as f32 / len as f32 + shift) * TAU).sin());
+
+        let samples: Vec<_> = shifted_sine(0.125).chain(shifted_sine(0.625)).collect();
+        let bytes = wav_bytes(SampleFormat::Float, 32, &samples);
+
+        for phase_alignment in [PhaseAlignment::Fundamental, PhaseAlignment::Peak] {
+            let table = BandLimitedWaveTables::from_reader_with_options(
+                bytes.as_slice(),
+                LoadOptions {
+                    phase_alignment,
+                    ..verbatim()
+                },
+            )
+            .unwrap();
+
+            let (a, b) = (&table.as_slice()[0], &table.as_slice()[1]);
+
+            for (a, b) in a.flatten().iter().zip(b.flatten()) {
+                assert!((a - b).abs() < 1e-4);
+            }
+        }
+
+        let mut table = BandLimitedWaveTables::from_samples(&samples).unwrap();
+        table.align_phases(PhaseAlignment::Fundamental);
+        table.create_mipmaps();
+
+        assert_all_frames_eq(&table, &sine_cycle(len), 1e-4);
+    }
+
+    fn spectrum(samples: &[f32]) -> Vec<Complex32> {
+        let r2c = RealFftPlanner::new().plan_fft_forward(samples.len());
+        let mut input = samples.to_vec();
+        let mut output = r2c.make_output_vec();
+        r2c.process(&mut input, &mut output).unwrap();
+        output
+    }
+
+    /// A2, exactly 400 samples per cycle at `SINE_SR`
+    const SINE_NOTE: f32 = 45.;
+    const SINE_SR: f32 = 44000.;
+    const SINE_PERIOD: usize = 400;
+    const SINE_CYCLES: usize = 40;
+    /// Skips the parameters' smoothing
+    const SINE_SKIP: usize = 4400;
+
+    /// `SINE_CYCLES` cycles of the first basic shape, a sine, with `params`
+    /// overriding the defaults, once the parameters have been smoothed
+    fn render_sine(params: &[(u64, f32)]) -> Vec<f32> {
+        let params: Vec<_> = iter::once((WTOscParam::Frame as u64, 0.))
+            .chain(params.iter().copied())
+            .collect();
+
+        let len = SINE_SKIP + SINE_PERIOD * SINE_CYCLES;
+        let mut output =
+            render::render_mono(&mut WTOsc::default(), SINE_NOTE, &params, SINE_SR, len);
+
+        output.drain(..SINE_SKIP);
+        output
+    }
+
+    /// Magnitudes of the harmonics of `render_sine(params)`, by number, `0` being DC
+    fn sine_harmonics(params: &[(u64, f32)]) -> impl Fn(usize) -> f32 {
+        let bins = spectrum(&render_sine(params));
+        move |k| bins[k * SINE_CYCLES].norm()
+    }
+
+    #[test]
+    pub fn raised_cosine_transition() {
+        let harmonics = [Complex32::new(1., 0.); BandLimitedWaveTables::FRAME_LEN / 2 - 1];
+        let mut table = BandLimitedWaveTables::from_harmonics(&[&harmonics]).unwrap();
+
+        table.create_mipmaps_with_options(&MipmapOptions {
+            transition: Transition::RaisedCosine { width_bins: 3 },
+            ..Default::default()
+        });
+
+        // this mipmap has 32 partials
+        let spectrum = spectrum(&table.as_slice()[0][6]);
+        let full_scale = BandLimitedWaveTables::FRAME_LEN as f32 / 2.;
+
+        for (bin, expected_gain) in [
+            (1, 1.),
+            (29, 1.),
+            (30, 0.853_553_4),
+            (31, 0.5),
+            (32, 0.146_446_6),
+            (33, 0.),
+            (100, 0.),
+        ] {
+            assert!((spectrum[bin].norm() / full_scale - expected_gain).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    pub fn mipmap_crossfade() {
+        let harmonics: Vec<_> = (1..BandLimitedWaveTables::FRAME_LEN / 2)
+            .map(|k| Complex32::new(1. / k as f32, 0.))
+            .collect();
+
+        let table = BandLimitedWaveTables::from_harmonics(&[&harmonics]).unwrap();
+        let frame = UInt::splat(0);
+
+        let phases = (0..64).map(|i| UInt::splat(i << 26));
+
+        let mut max_step = 0f32;
+
+        // switching mipmaps right at octave boundaries
+        for boundary in [1 << 12, 1 << 20, 1 << 27] {
+            let (below, above) = (UInt::splat(boundary - 1), UInt::splat(boundary));
+
+            for phase in phases.clone() {
+                let (xfade_below, xfade_above, below, above) = unsafe {
+                    (
+                        table.resample_xfade(below, frame, phase),
+                        table.resample_xfade(above, frame, phase),
+                        table.resample(below, frame, phase),
+                        table.resample(above, frame, phase),
+                    )
+                };
+
+                assert!((xfade_below - xfade_above).abs().reduce_max() < 1e-3);
+                max_step = max_step.max((below - above).abs().reduce_max());
+            }
+        }
+
+        assert!(max_step > 1e-2);
+
+        // out of range frequencies stick to the top and bottom mipmaps
+        for phase_delta in [0, 1, 1 << 31, u32::MAX] {
+            let phase_delta = UInt::splat(phase_delta);
+
+            for phase in phases.clone() {
+                let (expected, actual) = unsafe {
+                    (
+                        table.resample(phase_delta, frame, phase),
+                        table.resample_xfade(phase_delta, frame, phase),
+                    )
+                };
+
+                assert!((expected - actual).abs().reduce_max() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    pub fn cubic_interpolation() {
+        const HARMONIC: usize = 16;
+
+        let mut harmonics = [Complex32::new(0., 0.); HARMONIC];
+        harmonics[HARMONIC - 1] = Complex32::new(1., 0.);
+
+        let table = BandLimitedWaveTables::from_harmonics(&[&harmonics]).unwrap();
+        let (frame, phase_delta) = (UInt::splat(0), UInt::splat(40_000));
+
+        let (mut linear_error, mut cubic_error) = (0f32, 0f32);
+        let mut phase = UInt::splat(0);
+
+        for _ in 0..100_000 {
+            let (linear, cubic) = unsafe {
+                (
+                    table.resample(phase_delta, frame, phase),
+                    table.resample_cubic(phase_delta, frame, phase),
+                )
+            };
+
+            // computed in double precision, to not drown the cubic interpolation error
+            let exact = Float::from_array(phase.to_array().map(|phase| {
+                let phase = phase as f64 / (1u64 << 32) as f64;
+                (core::f64::consts::TAU * HARMONIC as f64 * phase).sin() as f32
+            }));
+
+            linear_error += ((linear - exact) * (linear - exact)).reduce_sum();
+            cubic_error += ((cubic - exact) * (cubic - exact)).reduce_sum();
+
+            phase += phase_delta;
+        }
+
+        // at least 20 dB quieter
+        assert!(cubic_error * 100. < linear_error);
+    }
+
+    #[test]
+    pub fn frame_interpolation() {
+        let loud = [Complex32::new(1., 0.), Complex32::new(0.5, 0.)];
+        let quiet = [Complex32::new(0.5, 0.), Complex32::new(0., 0.)];
+
+        let single = BandLimitedWaveTables::from_harmonics(&[&loud]).unwrap();
+        let table = BandLimitedWaveTables::from_harmonics(&[&loud, &quiet]).unwrap();
+
+        let mask = TMask::splat(true);
+
+        for quality in [Quality::Standard, Quality::High, Quality::Cubic] {
+            for phase_delta in [1 << 12, 1 << 22, 1 << 27] {
+                for phase in [0, 1 << 20, 3 << 29, u32::MAX] {
+                    let (phase_delta, phase) = (UInt::splat(phase_delta), UInt::splat(phase));
+
+                    let at = |table: &BandLimitedWaveTables, frame: f32| unsafe {
+                        table.resample_frame_lerp(
+                            phase_delta,
+                            Float::splat(frame),
+                            phase,
+                            mask,
+                            quality,
+                        )
+                    };
+
+                    let (first, second) = (at(&table, 0.), at(&table, 1.));
+
+                    assert!(
+                        (at(&table, 0.25) - lerp(first, second, Float::splat(0.25)))
+                            .abs()
+                            .reduce_max()
+                            < 1e-6
+                    );
+
+                    // past the last frame
+                    assert_eq!(at(&table, 1.75), second);
+                    assert_eq!(at(&single, 0.75), at(&single, 0.));
+                }
+            }
+        }
+    }
+
+    #[test]
+    pub fn mipmap_indexing() {
+        let mut table = BandLimitedWaveTables::with_frame_count(2);
+
+        let slot_value = |frame: usize, mipmap: usize| {
+            (frame * BandLimitedWaveTables::NUM_MIPMAPS + mipmap) as f32
+        };
+
+        for (i, mipmaps) in table.as_mut_slice().iter_mut().enumerate() {
+            for (j, mipmap) in mipmaps.iter_mut().enumerate() {
+                mipmap.fill(slot_value(i, j));
+            }
+        }
+
+        for frame in 0..2 {
+            for shift in 0..u32::BITS {
+                let phase_delta = 1u32 << shift;
+                let mipmap = (phase_delta.leading_zeros() as usize)
+                    .min(BandLimitedWaveTables::NUM_MIPMAPS - 1);
+
+                let expected = Float::splat(slot_value(frame, mipmap));
+                let (phase_delta, frame) = (UInt::splat(phase_delta), UInt::splat(frame as u32));
+
+                for phase in [0, 1 << 20, 3 << 29, u32::MAX] {
+                    let phase = UInt::splat(phase);
+
+                    let (linear, cubic) = unsafe {
+                        (
+                            table.resample(phase_delta, frame, phase),
+                            table.resample_cubic(phase_delta, frame, phase),
+                        )
+                    };
+
+                    // slots are whole numbers apart
+                    assert!((linear - expected).abs().reduce_max() < 1e-3);
+                    assert!((cubic - expected).abs().reduce_max() < 1e-3);
+                }
+            }
+        }
+    }
+
+    #[test]
+    pub fn above_nyquist() {
+        let sr = 44100.;
+        let harmonics: Vec<_> = (1..BandLimitedWaveTables::FRAME_LEN / 2)
+            .map(|k| Complex32::new(1. / k as f32, 0.))
+            .collect();
+
+        let table = BandLimitedWaveTables::from_harmonics(&[&harmonics]).unwrap();
+        let mask = TMask::splat(true);
+
+        // note 135, with a +48 semitone detune
+        let phase_delta = Float::splat(440.) * semitones_to_ratio(Float::splat(135. - 69. + 48.))
+            / Float::splat(sr);
+
+        for phase_delta in [phase_delta, Float::splat(0.5), Float::splat(f32::NAN)] {
+            for quality in [Quality::Standard, Quality::High, Quality::Cubic] {
+                let mut osc = voice::Oscillator::default();
+                osc.set_phase_delta(phase_delta);
+
+                for _ in 0..1000 {
+                    let sample = unsafe { osc.tick_all(&table, mask, quality) };
+                    assert_eq!(sample, Float::splat(0.));
+                }
+            }
+        }
+
+        // note 135 itself is still (barely) below nyquist
+        let phase_delta =
+            Float::splat(440.) * semitones_to_ratio(Float::splat(135. - 69.)) / Float::splat(sr);
+        let mut osc = voice::Oscillator::default();
+        osc.set_phase_delta(phase_delta);
+
+        let samples: Vec<_> = (0..1000)
+            .map(|_| unsafe { osc.tick_all(&table, mask, Quality::Standard) })
+            .collect();
+
+        assert!(samples.iter().all(|sample| sample.is_finite().all()));
+        assert!(samples.iter().any(|sample| sample.abs().reduce_max() > 0.1));
+    }
+
+    #[test]
+    pub fn reused_mipmap_generator() {
+        let harmonics: Vec<_> = (1..100)
+            .map(|k| Complex32::new(1. / k as f32, 0.))
+            .collect();
+
+        let new_table =
+            || BandLimitedWaveTables::from_harmonics(&[&harmonics, &harmonics[..10]]).unwrap();
+
+        let expected = new_table();
+        let mut tables: Vec<_> = iter::repeat_with(new_table).take(100).collect();
+
+        let mut generator = MipmapGenerator::new();
+        generator.generate(&mut tables[0]);
+
+        let allocations_before = allocations();
+
+        for table in &mut tables[1..] {
+            generator.generate(table);
+        }
+
+        assert_eq!(allocations(), allocations_before);
+
+        for table in &tables {
+            assert_tables_eq(table, &expected, 1e-6);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    pub fn parallel_mipmaps() {
+        let frames: Vec<Vec<_>> = (1..64)
+            .map(|n| {
+                (1..n * 8)
+                    .map(|k| Complex32::new(1. / k as f32, n as f32))
+                    .collect()
+            })
+            .collect();
+
+        let frames: Vec<_> = frames.iter().map(Vec::as_slice).collect();
+
+        let mut serial = BandLimitedWaveTables::from_harmonics(&frames).unwrap();
+        let mut parallel = BandLimitedWaveTables::from_harmonics(&frames).unwrap();
+
+        let options = MipmapOptions {
+            transition: Transition::RaisedCosine { width_bins: 2 },
+            ..Default::default()
+        };
+
+        serial.create_mipmaps_with_options(&options);
+        parallel.create_mipmaps_par_with_options(&options);
+
+        assert_eq!(serial.as_slice(), parallel.as_slice());
+    }
+
+    #[test]
+    pub fn background_loading() {
+        let path =
+            std::env::temp_dir().join(format!("wt_osc_background_{}.wav", std::process::id()));
+
+        let samples: Vec<_> = sine_cycle(2048).repeat(3);
+        std::fs::write(&path, wav_bytes(SampleFormat::Float, 32, &samples)).unwrap();
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        // superseded right away
+        let mut loader = loader::Loader::default();
+        loader.load("this/file/does/not/exist.wav", LoadOptions::default(), {
+            let sender = sender.clone();
+            move |result| drop(sender.send(result))
+        });
+        loader.load(&path, LoadOptions::default(), move |result| {
+            drop(sender.send(result))
+        });
+
+        // the first load might have completed before being cancelled
+        let mut table = receiver.iter().find_map(Result::ok).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let mut osc = WTOsc::default();
+        osc.custom_event(&mut table);
+
+        assert_eq!(osc.table.num_frames(), 3);
+        // no table was replaced before
+        assert_eq!(table.num_frames(), 0);
+    }
+
+    #[test]
+    pub fn cancelled_load() {
+        let bytes = wav_bytes(SampleFormat::Float, 32, &sine_cycle(2048).repeat(256));
+
+        let load = |cancelled| {
+            let mut generator = MipmapGenerator::new()
+                .with_cancel_flag(Arc::new(std::sync::atomic::AtomicBool::new(cancelled)));
+
+            let start = std::time::Instant::now();
+            let result = BandLimitedWaveTables::from_reader_with_generator(
+                bytes.as_slice(),
+                LoadOptions::default(),
+                Some(&mut generator),
+            );
+
+            (result, start.elapsed())
+        };
+
+        let (full, full_time) = load(false);
+        assert_eq!(full.unwrap().num_frames(), 256);
+
+        let (cancelled, cancelled_time) = load(true);
+        assert!(matches!(cancelled, Err(WavetableError::Cancelled)));
+        // no mipmaps were generated
+        assert!(cancelled_time < full_time / 2);
+    }
+
+    #[test]
+    pub fn shared_table() {
+        let table: Arc<BandLimitedWaveTables> = BandLimitedWaveTables::basic_shapes().into();
+
+        let mut a = WTOsc::default();
+        let mut b = WTOsc::default();
+
+        for osc in [&mut a, &mut b] {
+            let mut event = Arc::clone(&table);
+            osc.custom_event(&mut event);
+
+            // the previous table is handed back
+            assert!(!Arc::ptr_eq(&event, &table));
+        }
+
+        // only one copy of the table's data exists
+        assert_eq!(Arc::strong_count(&table), 3);
+        assert!(Arc::ptr_eq(&a.table, &b.table));
+    }
+
+    #[test]
+    pub fn table_crossfade() {
+        const BUFFER_SIZE: usize = 64;
+        const CLUSTER_IDX: usize = 0;
+
+        let mut osc = WTOsc::default();
+        osc.initialize(44100., BUFFER_SIZE, 1);
+        let voice_mask = TMask::splat(true);
+
+        let sine = [Complex32::new(1., 0.)];
+        let negated_sine = [Complex32::new(-1., 0.)];
+
+        let mut table: Arc<_> = BandLimitedWaveTables::from_harmonics(&[&sine])
+            .unwrap()
+            .into();
+        osc.custom_event(&mut table);
+
+        let mut notes = Simd::splat(0);
+        for (i, note) in split_stereo_mut(&mut notes).iter_mut().enumerate() {
+            *note = u32x2::splat(9 + 12 * i as u32);
+        }
+
+        osc.reset(CLUSTER_IDX, voice_mask);
+        osc.activate_voices(CLUSTER_IDX, voice_mask, Float::splat(1.0), notes);
+
+        let params = ParamsList(Box::new([DEFAULT_PARAMS
+            .iter()
+            .copied()
+            .map(splat_stereo)
+            .collect()]));
+        osc.set_all_params(CLUSTER_IDX, voice_mask, &params);
+
+        let mut intermediate_buffers = Box::new([new_vfloat_buffer::<Float>(BUFFER_SIZE)]);
+
+        let mut render = |osc: &mut WTOsc, num_blocks| {
+            let mut samples = Vec::new();
+
+            for _ in 0..num_blocks {
+                let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+                    .with_indices(&[], &[Some(OutputBufferIndex::Local(0))])
+                    .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+                osc.process(buffers, CLUSTER_IDX, voice_mask);
+                samples.extend_from_slice(Cell::get_mut(intermediate_buffers[0].as_mut()));
+            }
+
+            samples
+        };
+
+        let max_steps = |samples: &[Float]| {
+            samples
+                .windows(2)
+                .map(|pair| (pair[1] - pair[0]).abs())
+                .fold(Float::splat(0.), Float::simd_max)
+        };
+
+        // let the initial fade finish
+        render(&mut osc, 10);
+        let before = render(&mut osc, 10);
+
+        let mut table: Arc<_> = BandLimitedWaveTables::from_harmonics(&[&negated_sine])
+            .unwrap()
+            .into();
+        osc.custom_event(&mut table);
+
+        let mut across = vec![*before.last().unwrap()];
+        across.extend(render(&mut osc, 10));
+
+        let (before, after) = (max_steps(&before), max_steps(&across));
+
+        // switching right away to the negated sine would be a jump of twice the amplitude
+        assert!(after.simd_le(before * Float::splat(4.)).all());
+    }
+
+    #[test]
+    pub fn unison_frame_spread() {
+        let sine = [Complex32::new(1., 0.)];
+        let saw: Vec<_> = (1..BandLimitedWaveTables::FRAME_LEN / 2)
+            .map(|k| Complex32::new(1. / k as f32, 0.))
+            .collect();
+
+        let table = BandLimitedWaveTables::from_harmonics(&[&sine, &saw]).unwrap();
+
+        let all_voices = TMask::splat(true);
+        let mut params = WTOscClusterNormParams::default();
+
+        params.set_param_instantly(WTOscParam::Frame, Float::splat(0.5), all_voices);
+        // 8 unison voices
+        params.set_param_instantly(
+            WTOscParam::NumVoices,
+            Float::splat(unison_count_to_normalized(8)),
+            all_voices,
+        );
+        params.set_param_instantly(WTOscParam::FrameSpread, Float::splat(1.), all_voices);
+
+        let (voice_params, num_oscs) = voice::VoiceParams::new(0, &params).unwrap();
+
+        let mut frames = Vec::new();
+        let mut second_harmonics = Vec::new();
+
+        for i in 0..num_oscs.get() {
+            let (_, norm_frame, mask) = voice_params.get_params(i);
+            let frame = norm_frame * Float::splat(table.num_frames() as f32);
+
+            let cycle: Vec<_> = (0..BandLimitedWaveTables::FRAME_LEN as u32)
+                .map(|j| unsafe {
+                    table.resample_frame_lerp(
+                        UInt::splat(1 << 12),
+                        frame,
+                        UInt::splat(j << 21),
+                        mask,
+                        Quality::Standard,
+                    )
+                })
+                .collect();
+
+            for (lane, active) in mask.to_array().into_iter().enumerate() {
+                if active {
+                    let samples: Vec<_> = cycle.iter().map(|s| s[lane]).collect();
+                    frames.push(norm_frame[lane]);
+                    second_harmonics.push(spectrum(&samples)[2].norm());
+                }
+            }
+        }
+
+        assert_eq!(frames.len(), 8);
+
+        let (min, max) = frames
+            .iter()
+            .enumerate()
+            .fold((0, 0), |(min, max), (i, &frame)| {
+                (
+                    if frame < frames[min] { i } else { min },
+                    if frame > frames[max] { i } else { max },
+                )
+            });
+
+        // spread symmetrically around the base frame, and still in range
+        assert!((frames[min] + frames[max] - 1.).abs() < 1e-4);
+        assert!(frames.iter().all(|frame| (0. ..1.).contains(frame)));
+
+        let mut distinct = frames.clone();
+        distinct.sort_by(f32::total_cmp);
+        distinct.dedup();
+        assert_eq!(distinct.len(), 8);
+
+        // the sine has no second harmonic, the saw does
+        assert!(second_harmonics[min] < 1.);
+        assert!(second_harmonics[max] > 100.);
+    }
+
+    #[test]
+    pub fn unison_octave_stack() {
+        const NUM_SAMPLES: usize = 1024;
+
+        let table = BandLimitedWaveTables::from_harmonics(&[&[Complex32::new(1., 0.)]]).unwrap();
+
+        let all_voices = TMask::splat(true);
+        let mut params = WTOscClusterNormParams::default();
+
+        // 8 unison voices, no detune, +1 octave stack
+        params.set_param_instantly(
+            WTOscParam::NumVoices,
+            Float::splat(unison_count_to_normalized(8)),
+            all_voices,
+        );
+        params.set_param_instantly(WTOscParam::Detune, Float::splat(0.), all_voices);
+        params.set_param_instantly(WTOscParam::UnisonStack, Float::splat(0.25), all_voices);
+        params.set_base_phase_delta(Float::splat(1. / 64.), all_voices);
+
+        let (voice_params, num_oscs) = voice::VoiceParams::new(0, &params).unwrap();
+
+        let mut output = [0.; NUM_SAMPLES];
+        let mut stacked_voices = 0;
+
+        for i in 0..num_oscs.get() {
+            let (ratio, _, mask) = voice_params.get_params(i);
+
+            stacked_voices += mask
+                .select(ratio, Float::splat(1.))
+                .simd_eq(Float::splat(2.))
+                .to_bitmask()
+                .count_ones();
+
+            let mut osc = voice::Oscillator::default();
+            osc.set_params(&voice_params, i, Float::splat(1.));
+
+            for sample in output.iter_mut() {
+                *sample += unsafe { osc.tick_all(&table, mask, Quality::Standard) }.reduce_sum();
+            }
+        }
+
+        assert_eq!(stacked_voices, 4);
+
+        // 16 cycles of the fundamental, 32 of the octave
+        let spectrum = spectrum(&output);
+        let voice_magnitude = NUM_SAMPLES as f32 / 2.;
+
+        for (bin, magnitude) in spectrum.iter().enumerate() {
+            let expected = match bin {
+                16 | 32 => 4. * voice_magnitude,
+                _ => 0.,
+            };
+
+            assert!((magnitude.norm() - expected).abs() < 1.);
+        }
+    }
+
+    #[test]
+    pub fn phase_modulation() {
+        let table = BandLimitedWaveTables::from_harmonics(&[&[Complex32::new(1., 0.)]]).unwrap();
+        let mask = TMask::splat(true);
+
+        let new_osc = || {
+            let mut osc = voice::Oscillator::default();
+            osc.set_phase_delta(Float::splat(1. / 64.));
+            osc
+        };
+
+        let (mut plain, mut silent, mut quarter) = (new_osc(), new_osc(), new_osc());
+
+        for _ in 0..256 {
+            let (plain, silent, quarter) = unsafe {
+                (
+                    plain.tick_all(&table, mask, Quality::Standard),
+                    silent.tick_all_pm(&table, mask, Quality::Standard, UInt::splat(0)),
+                    quarter.tick_all_pm(&table, mask, Quality::Standard, UInt::splat(1 << 30)),
+                )
+            };
+
+            // silent modulation changes nothing
+            assert_eq!(plain, silent);
+
+            // the phase isn't advanced by the offset, a quarter
+            // of a cycle ahead of a sine is a cosine
+            assert!(
+                (quarter * quarter + plain * plain - Float::splat(1.))
+                    .abs()
+                    .reduce_max()
+                    < 1e-3
+            );
+        }
+    }
+
+    #[test]
+    pub fn hard_sync() {
+        const NUM_SAMPLES: usize = 1024;
+        const PERIOD: usize = 64;
+
+        let table = BandLimitedWaveTables::from_harmonics(&[&[Complex32::new(1., 0.)]]).unwrap();
+        let mask = TMask::splat(true);
+
+        let mut osc = voice::Oscillator::default();
+        osc.set_phase_delta(Float::splat(1. / PERIOD as f32));
+        osc.set_sync_ratio(Float::splat(2.5));
+
+        let output: Vec<_> = (0..NUM_SAMPLES)
+            .map(|_| unsafe { osc.tick_all(&table, mask, Quality::Standard) }[0])
+            .collect();
+
+        // the output repeats at the master's frequency
+        for (a, b) in output.iter().zip(&output[PERIOD..]) {
+            assert!((a - b).abs() < 1e-4);
+        }
+
+        let magnitudes: Vec<_> = spectrum(&output).iter().map(|bin| bin.norm()).collect();
+        let fundamental = NUM_SAMPLES / PERIOD;
+
+        for (bin, &magnitude) in magnitudes.iter().enumerate() {
+            if bin % fundamental != 0 {
+                assert!(magnitude < 1e-1);
+            }
+        }
+
+        // but its strongest partials are near the slave's frequency (2.5 x the master's)
+        let strongest = (0..magnitudes.len())
+            .max_by(|&a, &b| magnitudes[a].total_cmp(&magnitudes[b]))
+            .unwrap();
+
+        assert!([2, 3].contains(&(strongest / fundamental)));
+    }
+
+    #[test]
+    pub fn unison_detune_curves() {
+        let all_voices = TMask::splat(true);
+
+        let curves: [(f32, fn(f32) -> f32); 3] =
+            [(0., |x| x), (0.5, |x| x * x), (1., |x| x * (2. - x))];
+
+        for (norm_curve, curve) in curves {
+            let mut params = WTOscClusterNormParams::default();
+
+            // 16 unison voices, detuned by up to a semitone
+            params.set_param_instantly(
+                WTOscParam::NumVoices,
+                Float::splat(unison_count_to_normalized(16)),
+                all_voices,
+            );
+            params.set_param_instantly(WTOscParam::Detune, Float::splat(1.), all_voices);
+            params.set_param_instantly(
+                WTOscParam::DetuneCurve,
+                Float::splat(norm_curve),
+                all_voices,
+            );
+
+            let (voice_params, num_oscs) = voice::VoiceParams::new(0, &params).unwrap();
+
+            let mut detunes = Vec::new();
+
+            for i in 0..num_oscs.get() {
+                let (ratio, _, mask) = voice_params.get_params(i);
+
+                for (lane, active) in mask.to_array().into_iter().enumerate() {
+                    if active {
+                        detunes.push(12. * ratio[lane].log2());
+                    }
+                }
+            }
+
+            detunes.sort_by(f32::total_cmp);
+
+            // linearly, the outermost pair is 1 semitone away from the center,
+            // the innermost one 1/15th of a semitone away
+            let mut expected: Vec<_> = (0..8)
+                .map(|pair| curve((2 * pair + 1) as f32 / 15.))
+                .flat_map(|detune| [-detune, detune])
+                .collect();
+
+            expected.sort_by(f32::total_cmp);
+
+            assert_eq!(detunes.len(), 16);
+
+            for (detune, expected) in detunes.into_iter().zip(expected) {
+                assert!((detune - expected).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    pub fn unison_detune_symmetry() {
+        let all_voices = TMask::splat(true);
+
+        for count in 1..=MAX_UNISON as u32 {
+            let mut params = WTOscClusterNormParams::default();
+
+            // detuned by up to a semitone
+            params.set_param_instantly(
+                WTOscParam::NumVoices,
+                Float::splat(unison_count_to_normalized(count)),
+                all_voices,
+            );
+            params.set_param_instantly(WTOscParam::Detune, Float::splat(1.), all_voices);
+
+            let (voice_params, num_oscs) = voice::VoiceParams::new(0, &params).unwrap();
+
+            // one entry per lane, the center voice, if any, takes two
+            let mut detunes = Vec::new();
+
+            for i in 0..num_oscs.get() {
+                let (ratio, _, mask) = voice_params.get_params(i);
+
+                for (lane, active) in mask.to_array().into_iter().enumerate() {
+                    if active {
+                        detunes.push(12. * ratio[lane].log2());
+                    }
+                }
+            }
+
+            detunes.sort_by(f32::total_cmp);
+
+            let odd = count % 2 == 1;
+            assert_eq!(detunes.len(), count as usize + odd as usize);
+
+            for (low, high) in detunes.iter().zip(detunes.iter().rev()) {
+                assert!((low + high).abs() < 1e-4, "{count} voices: {detunes:?}");
+            }
+
+            let center_lanes = detunes.iter().filter(|d| d.abs() < 1e-5).count();
+            assert_eq!(center_lanes, if odd { 2 } else { 0 }, "{count} voices");
+
+            if count > 1 {
+                assert!((detunes[0] + 1.).abs() < 1e-4, "{count} voices");
+                assert!(
+                    (detunes[detunes.len() - 1] - 1.).abs() < 1e-4,
+                    "{count} voices"
+                );
+            }
+
+            // evenly spaced, counting the center voice once
+            detunes.dedup_by(|a, b| (*a - *b).abs() < 1e-5);
+            let step = 2. / (count.max(2) - 1) as f32;
+
+            for pair in detunes.windows(2) {
+                assert!((pair[1] - pair[0] - step).abs() < 1e-3, "{count} voices");
+            }
+        }
+    }
+
+    #[test]
+    pub fn unison_blend() {
+        const NUM_SAMPLES: usize = 4096;
+
+        let table = BandLimitedWaveTables::from_harmonics(&[&[Complex32::new(1., 0.)]]).unwrap();
+        let all_voices = TMask::splat(true);
+
+        for blend in [0., 0.5, 1.] {
+            let mut params = WTOscClusterNormParams::default();
+
+            // 8 unison voices
+            params.set_param_instantly(
+                WTOscParam::NumVoices,
+                Float::splat(unison_count_to_normalized(8)),
+                all_voices,
+            );
+            params.set_param_instantly(WTOscParam::Blend, Float::splat(blend), all_voices);
+            params.set_base_phase_delta(Float::splat(1. / 64.), all_voices);
+
+            let (voice_params, num_oscs) = voice::VoiceParams::new(0, &params).unwrap();
+
+            // sum of squares, per voice pair
+            let mut energies = [0.; 4];
+
+            for i in 0..num_oscs.get() {
+                let (_, _, mask) = voice_params.get_params(i);
+
+                let mut osc = voice::Oscillator::default();
+                osc.set_params(&voice_params, i, Float::splat(1.));
+
+                for _ in 0..NUM_SAMPLES {
+                    let gain = osc.get_gain();
+                    let sample = unsafe { osc.tick_all(&table, mask, Quality::Standard) } * gain;
+
+                    for (lane, s) in sample.to_array().into_iter().enumerate() {
+                        if mask.test(lane) {
+                            energies[(i * FLOATS_PER_VECTOR + lane) / 2] += s * s;
+                        }
+                    }
+                }
+            }
+
+            let relative_rms = (energies[3] / energies[0]).sqrt();
+
+            assert!((relative_rms - blend).abs() < 1e-2);
+        }
+    }
+
+    #[test]
+    pub fn retrigger_modes() {
+        let table = BandLimitedWaveTables::from_harmonics(&[&[Complex32::new(1., 0.)]]).unwrap();
+        let mask = TMask::splat(true);
+        let starting_phases = [Float::splat(0.25); OSCS_PER_VOICE];
+
+        let new_cluster = |seed| {
+            let mut cluster = WTOscVoiceCluster::default();
+            cluster.seed(seed);
+
+            for voice in cluster.voices_mut() {
+                for osc in voice {
+                    osc.set_phase_delta(Float::splat(0.01));
+                }
+            }
+
+            cluster
+        };
+
+        // returns the phase at note-on, and the first few samples after it
+        let note_on = |cluster: &mut WTOscVoiceCluster, mode| {
+            cluster.reset_phases(mask, mode, Float::splat(1.), &starting_phases);
+
+            let osc = &mut cluster.voices_mut()[0][0];
+            let phase = osc.phase();
+            let output: Vec<_> = (0..64)
+                .map(|_| unsafe { osc.tick_all(&table, mask, Quality::Standard) })
+                .collect();
+
+            (phase, output)
+        };
+
+        let mut cluster = new_cluster(0);
+
+        let (phase, first) = note_on(&mut cluster, RetriggerMode::Retrigger);
+        let (_, second) = note_on(&mut cluster, RetriggerMode::Retrigger);
+        assert_eq!(phase, flp_to_fxp(starting_phases[0]));
+        assert_eq!(first, second);
+
+        let (_, first) = note_on(&mut cluster, RetriggerMode::Random);
+        let (_, second) = note_on(&mut cluster, RetriggerMode::Random);
+        assert_ne!(first, second);
+
+        // the same seed gives the same phases
+        let (_, reseeded) = note_on(&mut new_cluster(0), RetriggerMode::Random);
+        assert_eq!(first, reseeded);
+
+        let last_phase = cluster.voices_mut()[0][0].phase();
+        let (phase, _) = note_on(&mut cluster, RetriggerMode::Free);
+        assert_eq!(phase, last_phase);
+    }
+
+    #[test]
+    pub fn low_frequency_precision() {
+        const SR: f32 = 48000.;
+        const FREQUENCY: f32 = 0.25;
+        const SECS: usize = 60;
+
+        let table = BandLimitedWaveTables::from_harmonics(&[&[Complex32::new(1., 0.)]]).unwrap();
+        let mask = TMask::splat(true);
+
+        let mut osc = voice::Oscillator::default();
+        osc.set_phase_delta(Float::splat(FREQUENCY / SR));
+
+        let mut cycles = 0;
+
+        for _ in 0..SECS * SR as usize {
+            let phase = osc.phase()[0];
+            unsafe { osc.tick_all(&table, mask, Quality::Standard) };
+            cycles += u32::from(osc.phase()[0] < phase);
+        }
+
+        let total = f64::from(cycles) + f64::from(fxp_to_flp(osc.phase())[0]);
+        let error = (total / SECS as f64 / f64::from(FREQUENCY) - 1.).abs();
+
+        assert!(error < 1e-4, "relative frequency error: {error}");
+
+        if cfg!(feature = "precise-phase") {
+            assert!(error < 1e-6, "relative frequency error: {error}");
+        }
+    }
+
+    #[test]
+    pub fn analog_drift() {
+        const NUM_SAMPLES: usize = 1 << 18;
+
+        let table = BandLimitedWaveTables::from_harmonics(&[&[Complex32::new(1., 0.)]]).unwrap();
+        let mask = TMask::splat(true);
+        let base_phase_delta = 1. / 64.;
+
+        let new_osc = |cents: f32| {
+            let mut osc = voice::Oscillator::default();
+            osc.seed_drift(1);
+            osc.set_phase_delta(Float::splat(base_phase_delta));
+            osc.set_drift_depth(Float::splat(cents / 100.));
+            osc
+        };
+
+        // with no drift, the random sequence makes no difference
+        let (mut plain, mut drifting) = (new_osc(0.), new_osc(0.));
+        plain.seed_drift(2);
+
+        for _ in 0..4096 {
+            let (a, b) = unsafe {
+                (
+                    plain.tick_all(&table, mask, Quality::Standard),
+                    drifting.tick_all(&table, mask, Quality::Standard),
+                )
+            };
+
+            assert_eq!(a.to_bits(), b.to_bits());
+        }
+
+        let mut osc = new_osc(5.);
+        let mut last_cents = None;
+        let (mut min, mut max) = (f32::INFINITY, f32::NEG_INFINITY);
+
+        for _ in 0..NUM_SAMPLES {
+            let phase = osc.phase();
+            unsafe { osc.tick_all(&table, mask, Quality::Standard) };
+            let phase_delta = fxp_to_flp(osc.phase() - phase)[0];
+
+            let cents = 1200. * (phase_delta / base_phase_delta).log2();
+
+            assert!(cents.abs() < 5.01);
+
+            if let Some(last_cents) = last_cents {
+                assert!((cents - last_cents).abs() < 0.05);
+            }
+
+            last_cents = Some(cents);
+            min = min.min(cents);
+            max = max.max(cents);
+        }
+
+        // it does wander
+        assert!(max - min > 1.);
+    }
+
+    #[test]
+    pub fn glide() {
+        const SR: f32 = 48000.;
+        const BLOCK_SIZE: usize = 64;
+
+        let mask = TMask::splat(true);
+        let velocity = Float::splat(1.);
+        let a3_phase_delta = 220. / SR;
+        let a4_phase_delta = 440. / SR;
+
+        let mut osc = WTOsc::default();
+        osc.initialize(SR, BLOCK_SIZE, 1);
+
+        // no glide, instant pitch changes
+        osc.activate_voices(0, mask, velocity, UInt::splat(57));
+        osc.activate_voices(0, mask, velocity, UInt::splat(69));
+        assert_eq!(osc.params[0].phase_delta, Float::splat(a4_phase_delta));
+
+        // 100ms
+        osc.params[0].set_param_instantly(WTOscParam::Glide, Float::splat(0.1), mask);
+        let glide_samples = (osc.params[0].glide_secs()[0] * SR) as usize;
+
+        osc.activate_voices(0, mask, velocity, UInt::splat(57));
+        assert_eq!(osc.params[0].phase_delta, Float::splat(a4_phase_delta));
+
+        let mut elapsed = 0;
+        while elapsed < glide_samples / 2 {
+            osc.params[0].tick_n(&osc.log2_alphas, osc.expression_log2_alpha, BLOCK_SIZE);
+            elapsed += BLOCK_SIZE;
+        }
+
+        // half an octave down, roughly half-way through
+        let half_octave = a4_phase_delta * FRAC_1_SQRT_2;
+        let octaves = (osc.params[0].phase_delta[0] / half_octave).log2();
+        assert!(octaves.abs() < BLOCK_SIZE as f32 / glide_samples as f32);
+
+        while elapsed < glide_samples {
+            osc.params[0].tick_n(&osc.log2_alphas, osc.expression_log2_alpha, BLOCK_SIZE);
+            elapsed += BLOCK_SIZE;
+        }
+
+        let error = osc.params[0].phase_delta[0] / a3_phase_delta - 1.;
+        assert!(error.abs() < 1e-4);
+    }
+
+    #[test]
+    pub fn transpose_range() {
+        let table = BandLimitedWaveTables::from_harmonics(&[&[Complex32::new(1., 0.)]]).unwrap();
+        let mask = TMask::splat(true);
+
+        let mut params = WTOscClusterNormParams::default();
+        params.set_param_instantly(WTOscParam::Transpose, Float::splat(0.75), mask);
+        params.set_base_phase_delta(Float::splat(1. / 256.), mask);
+
+        let (voice_params, _) = voice::VoiceParams::new(0, &params).unwrap();
+        let (_, _, voice_mask) = voice_params.get_params(0);
+
+        let mut osc = voice::Oscillator::default();
+        osc.set_params(&voice_params, 0, Float::splat(1.));
+
+        let output: Vec<_> = (0..1024)
+            .map(|_| unsafe { osc.tick_all(&table, voice_mask, Quality::Standard) }[0])
+            .collect();
+
+        // a quarter of the range up, 2 octaves with the default range, 4 cycles become 16
+        let quarter_range = WTOscClusterNormParams::TRANSPOSE_RANGE_SEMITONES / 2.;
+        let bin = 4. * 2f32.powf(quarter_range / 12.);
+        assert_eq!(bin, 16.);
+
+        let magnitudes: Vec<_> = spectrum(&output).iter().map(|bin| bin.norm()).collect();
+        let strongest = (0..magnitudes.len())
+            .max_by(|&a, &b| magnitudes[a].total_cmp(&magnitudes[b]))
+            .unwrap();
+
+        assert_eq!(strongest, bin as usize);
+        assert!((magnitudes[strongest] - 512.).abs() < 2.);
+
+        assert_eq!(format_transpose(0.5), "+0 st");
+        assert_eq!(format_transpose(0.75), "+24 st");
+        assert_eq!(format_transpose(0.), "-48 st");
+    }
+
+    #[test]
+    pub fn detune_range() {
+        let all_voices = TMask::splat(true);
+
+        let outermost_detune = |norm_range: f32| {
+            let mut params = WTOscClusterNormParams::default();
+
+            // 8 unison voices, fully detuned
+            params.set_param_instantly(
+                WTOscParam::NumVoices,
+                Float::splat(unison_count_to_normalized(8)),
+                all_voices,
+            );
+            params.set_param_instantly(WTOscParam::Detune, Float::splat(1.), all_voices);
+            params.set_param_instantly(
+                WTOscParam::DetuneRange,
+                Float::splat(norm_range),
+                all_voices,
+            );
+
+            let (voice_params, num_oscs) = voice::VoiceParams::new(0, &params).unwrap();
+
+            (0..num_oscs.get())
+                .flat_map(|i| {
+                    let (ratio, _, mask) = voice_params.get_params(i);
+                    mask.select(ratio, Float::splat(1.)).to_array()
+                })
+                .map(|ratio| 12. * ratio.log2())
+                .fold(0f32, f32::max)
+        };
+
+        let single = outermost_detune(1. / 48.);
+        let double = outermost_detune(2. / 48.);
+
+        assert!((single - 1.).abs() < 1e-3);
+        assert!((double - 2. * single).abs() < 1e-3);
+    }
+
+    #[test]
+    pub fn voice_fades() {
+        const BUFFER_SIZE: usize = 64;
+        const CLUSTER_IDX: usize = 0;
+        const SR: f32 = 44100.;
+        const FADE_SECS: f32 = 0.005;
+
+        let mut osc = WTOsc::default().with_voice_fade_secs(FADE_SECS);
+        osc.initialize(SR, BUFFER_SIZE, 1);
+        let voice_mask = TMask::splat(true);
+
+        let mut notes = Simd::splat(0);
+        for (i, note) in split_stereo_mut(&mut notes).iter_mut().enumerate() {
+            *note = u32x2::splat(57 + 12 * i as u32);
+        }
+
+        osc.reset(CLUSTER_IDX, voice_mask);
+        osc.activate_voices(CLUSTER_IDX, voice_mask, Float::splat(1.0), notes);
+
+        let params = ParamsList(Box::new([DEFAULT_PARAMS
+            .iter()
+            .copied()
+            .map(splat_stereo)
+            .collect()]));
+        osc.set_all_params(CLUSTER_IDX, voice_mask, &params);
+
+        let mut intermediate_buffers = Box::new([new_vfloat_buffer::<Float>(BUFFER_SIZE)]);
+
+        let mut render = |osc: &mut WTOsc, num_blocks| {
+            let mut samples = Vec::new();
+
+            for _ in 0..num_blocks {
+                let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+                    .with_indices(&[], &[Some(OutputBufferIndex::Local(0))])
+                    .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+                osc.process(buffers, CLUSTER_IDX, voice_mask);
+                samples.extend_from_slice(Cell::get_mut(intermediate_buffers[0].as_mut()));
+            }
+
+            samples
+        };
+
+        let max_steps = |samples: &[Float]| {
+            samples
+                .windows(2)
+                .map(|pair| (pair[1] - pair[0]).abs())
+                .fold(Float::splat(0.), Float::simd_max)
+        };
+
+        let fade_blocks = (FADE_SECS * SR) as usize / BUFFER_SIZE + 2;
+
+        // the first sample is silent, and the fade in is complete afterwards
+        let fade_in = render(&mut osc, fade_blocks);
+        assert_eq!(fade_in[0], Float::splat(0.));
+
+        let before = render(&mut osc, 10);
+        let peak = before
+            .iter()
+            .fold(Float::splat(0.), |peak, sample| peak.simd_max(sample.abs()));
+
+        osc.release_voices(CLUSTER_IDX, voice_mask);
+        assert!(!osc.finished_voices(CLUSTER_IDX).any());
+
+        let mut across = vec![*before.last().unwrap()];
+        across.extend(render(&mut osc, fade_blocks));
+
+        // the gain changes by at most the ramp's slope every sample
+        let slope = Float::splat((FADE_SECS * SR).recip());
+        let bound = max_steps(&before) + peak * slope + Float::splat(1e-6);
+        assert!(max_steps(&across).simd_le(bound).all());
+
+        assert!(osc.finished_voices(CLUSTER_IDX).all());
+        assert_eq!(*across.last().unwrap(), Float::splat(0.));
+    }
+
+    #[test]
+    pub fn unison_voice_count_changes() {
+        const BUFFER_SIZE: usize = 64;
+        const CLUSTER_IDX: usize = 0;
+
+        let mut osc = WTOsc::default();
+        osc.initialize(44100., BUFFER_SIZE, 1);
+        let voice_mask = TMask::splat(true);
+
+        let mut table: Arc<_> = BandLimitedWaveTables::from_harmonics(&[&[Complex32::new(1., 0.)]])
+            .unwrap()
+            .into();
+        osc.custom_event(&mut table);
+
+        osc.reset(CLUSTER_IDX, voice_mask);
+        osc.activate_voices(CLUSTER_IDX, voice_mask, Float::splat(1.0), UInt::splat(57));
+
+        let params = ParamsList(Box::new([DEFAULT_PARAMS
+            .iter()
+            .copied()
+            .map(splat_stereo)
+            .collect()]));
+        osc.set_all_params(CLUSTER_IDX, voice_mask, &params);
+
+        let mut intermediate_buffers = Box::new([new_vfloat_buffer::<Float>(BUFFER_SIZE)]);
+
+        let mut render = |osc: &mut WTOsc, norm_num_voices: f32| {
+            osc.params[CLUSTER_IDX].set_param_instantly(
+                WTOscParam::NumVoices,
+                Float::splat(norm_num_voices),
+                voice_mask,
+            );
+
+            let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+                .with_indices(&[], &[Some(OutputBufferIndex::Local(0))])
+                .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+            osc.process(buffers, CLUSTER_IDX, voice_mask);
+            Cell::get_mut(intermediate_buffers[0].as_mut()).to_vec()
+        };
+
+        let max_steps = |samples: &[Float]| {
+            samples
+                .windows(2)
+                .map(|pair| (pair[1] - pair[0]).abs())
+                .fold(Float::splat(0.), Float::simd_max)
+        };
+
+        // held at 1 and 16 voices, to measure the steady steps
+        let mut steady: Vec<_> = (0..10).flat_map(|_| render(&mut osc, 0.)).collect();
+        let steady_1 = max_steps(&steady);
+        steady = (0..10).flat_map(|_| render(&mut osc, 1.)).collect();
+        let steady_16 = max_steps(&steady);
+
+        // 1 to 16 voices, and back, one voice per buffer
+        let mut automated = vec![*steady.last().unwrap()];
+        automated.extend(
+            (0..16)
+                .rev()
+                .flat_map(|n| render(&mut osc, unison_count_to_normalized(n + 1))),
+        );
+        automated.extend((0..16).flat_map(|n| render(&mut osc, unison_count_to_normalized(n + 1))));
+
+        let peak = automated
+            .iter()
+            .fold(Float::splat(0.), |peak, sample| peak.simd_max(sample.abs()));
+
+        // lanes, and the normalisation, ramp over a buffer
+        let bound = steady_1.simd_max(steady_16) + peak * Float::splat(2. / BUFFER_SIZE as f32);
+        assert!(max_steps(&automated).simd_le(bound).all());
+    }
+
+    #[test]
+    pub fn param_info() {
+        for (id, info) in params::PARAMS.iter().enumerate() {
+            assert_eq!(info.id, id as u64);
+            assert!(core::ptr::eq(WTOsc::param_info(id as u64).unwrap(), info));
+
+            for norm_val in [0., 0.25, 0.5, 0.75, 1., info.default()] {
+                let string = info.normalized_to_string(norm_val);
+                let parsed = info.string_to_normalized(&string).unwrap();
+
+                assert_eq!(info.normalized_to_string(parsed), string);
+            }
+        }
+
+        assert!(WTOsc::param_info(NUM_PARAMS).is_none());
+
+        // the displayed voice count is the one that's heard
+        let info = WTOsc::param_info(2).unwrap();
+        let all_voices = TMask::splat(true);
+
+        for i in 0..=1000 {
+            let norm_val = i as f32 / 1000.;
+
+            let mut params = WTOscClusterNormParams::default();
+            params.set_param_instantly(WTOscParam::NumVoices, Float::splat(norm_val), all_voices);
+            let (voice_params, _) = voice::VoiceParams::new(0, &params).unwrap();
+
+            let num_voices = voice_params.num_voices[0];
+            let expected = if num_voices == 1 {
+                "1 voice".into()
+            } else {
+                format!("{num_voices} voices")
+            };
+
+            assert_eq!(info.normalized_to_string(norm_val), expected);
+        }
+
+        for num_voices in 2..=MAX_UNISON {
+            let string = format!("{num_voices} voices");
+            let norm_val = info.string_to_normalized(&string).unwrap();
+            assert_eq!(info.normalized_to_string(norm_val), string);
+        }
+
+        assert_eq!(info.string_to_normalized("17 voices"), None);
+        assert_eq!(
+            WTOsc::param_info(5).unwrap().normalized_to_string(0.5),
+            "+0 st"
+        );
+    }
+
+    #[test]
+    pub fn typed_param_ids() {
+        let all_voices = TMask::splat(true);
+        let mut params = WTOscClusterNormParams::default();
+
+        for (id, &param) in WTOscParam::ALL.iter().enumerate() {
+            assert_eq!(param as u64, id as u64);
+            assert_eq!(WTOscParam::try_from(id as u64), Ok(param));
+
+            params.set_param_instantly(param, Float::splat(id as f32), all_voices);
+        }
+
+        assert_eq!(WTOscParam::try_from(NUM_PARAMS), Err(NUM_PARAMS));
+
+        // every parameter has its own smoother
+        for (id, &param) in WTOscParam::ALL.iter().enumerate() {
+            let smoother = params.get_param_smoother_mut(param);
+            assert_eq!(smoother.current, Float::splat(id as f32));
+        }
+
+        // unknown ids are ignored
+        let mut osc = WTOsc::default();
+        osc.initialize(44100., 64, 1);
+        osc.set_param(0, all_voices, NUM_PARAMS, Float::splat(1.));
+    }
+
+    #[test]
+    pub fn voice_state() {
+        const BUFFER_SIZE: usize = 64;
+        const CLUSTER_IDX: usize = 0;
+        const SR: f32 = 44100.;
+
+        let mut osc = WTOsc::default();
+        osc.initialize(SR, BUFFER_SIZE, 1);
+        let voice_mask = TMask::splat(true);
+
+        osc.reset(CLUSTER_IDX, voice_mask);
+        osc.activate_voices(CLUSTER_IDX, voice_mask, Float::splat(1.0), UInt::splat(69));
+
+        let mut values = DEFAULT_PARAMS.to_vec();
+        values[WTOscParam::Frame as usize] = f32x2::from_array([0.25, 0.75]);
+        values[WTOscParam::Detune as usize] = f32x2::splat(0.5);
+        values[WTOscParam::DetuneRange as usize] = f32x2::splat(0.25);
+        values[WTOscParam::Level as usize] = f32x2::splat(0.5);
+        values[WTOscParam::Pan as usize] = f32x2::splat(0.3);
+        values[WTOscParam::NumVoices as usize] = f32x2::splat(unison_count_to_normalized(8));
+
+        let params = ParamsList(Box::new([values
+            .iter()
+            .copied()
+            .map(splat_stereo)
+            .collect()]));
+        osc.set_all_params(CLUSTER_IDX, voice_mask, &params);
+
+        let mut intermediate_buffers = Box::new([new_vfloat_buffer::<Float>(BUFFER_SIZE)]);
+        let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+            .with_indices(&[], &[Some(OutputBufferIndex::Local(0))])
+            .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+        osc.process(buffers, CLUSTER_IDX, voice_mask);
+
+        let state = osc.voice_state(CLUSTER_IDX, 1).unwrap();
+
+        assert_eq!(state.frame, f32x2::from_array([0.25, 0.75]));
+        assert_eq!(state.detune, f32x2::splat(6.));
+        let level = WTOscClusterNormParams::level_gain_from_norm(Float::splat(0.5));
+        assert_eq!(state.level, split_stereo(&level)[1]);
+        assert_eq!(state.pan, f32x2::splat(0.3));
+        assert_eq!(state.num_voices, u32x2::splat(8));
+        assert!((state.frequency - f32x2::splat(440.)).abs().reduce_max() < 1e-2);
+
+        assert!(osc
+            .voice_state(CLUSTER_IDX, STEREO_VOICES_PER_VECTOR)
+            .is_none());
+        assert!(osc.voice_state(1, 0).is_none());
+    }
+
+    #[test]
+    pub fn active_voices() {
+        const BUFFER_SIZE: usize = 64;
+
+        let mut osc = WTOsc::default();
+        osc.initialize(44100., BUFFER_SIZE, 2);
+
+        let all_voices = TMask::splat(true);
+        let first_voice = TMask::from_array(array::from_fn(|i| i < 2));
+
+        assert_eq!(osc.active_voices().count(), 0);
+
+        osc.activate_voices(1, all_voices, Float::splat(1.), UInt::splat(69));
+        osc.activate_voices(0, first_voice, Float::splat(1.), UInt::splat(69));
+
+        assert_eq!(osc.active_voice_count(0), 1);
+        assert_eq!(osc.active_voice_count(1), STEREO_VOICES_PER_VECTOR);
+        assert!(osc.is_voice_active(0, 0));
+        assert!(!osc.is_voice_active(0, 1));
+        assert!(!osc.is_voice_active(0, STEREO_VOICES_PER_VECTOR));
+        assert_eq!(osc.active_voices().next(), Some((0, 0)));
+        assert_eq!(osc.active_voices().count(), STEREO_VOICES_PER_VECTOR + 1);
+
+        osc.move_state((0, 0), (0, 1));
+        assert!(!osc.is_voice_active(0, 0));
+        assert!(osc.is_voice_active(0, 1));
+
+        osc.reset(0, all_voices);
+        assert_eq!(osc.active_voice_count(0), 1);
+
+        // released voices stay active until they're faded out
+        osc.release_voices(0, all_voices);
+        assert_eq!(osc.active_voice_count(0), 1);
+
+        let mut intermediate_buffers = Box::new([new_vfloat_buffer::<Float>(BUFFER_SIZE)]);
+
+        for _ in 0..10 {
+            let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+                .with_indices(&[], &[Some(OutputBufferIndex::Local(0))])
+                .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+            osc.process(buffers, 0, all_voices);
+        }
+
+        assert_eq!(osc.active_voice_count(0), 0);
+        assert!(osc.active_voices().all(|(cluster_idx, _)| cluster_idx == 1));
+    }
+
+    #[test]
+    pub fn sample_accurate_param_events() {
+        const BUFFER_SIZE: usize = 256;
+        const OFFSET: usize = 64;
+        const CLUSTER_IDX: usize = 0;
+
+        let voice_mask = TMask::splat(true);
+
+        let params = ParamsList(Box::new([DEFAULT_PARAMS
+            .iter()
+            .copied()
+            .map(splat_stereo)
+            .collect()]));
+
+        let mut intermediate_buffers = Box::new([new_vfloat_buffer::<Float>(BUFFER_SIZE)]);
+
+        let mut render = |jump: bool| {
+            let mut osc = WTOsc::default().with_voice_fade_secs(0.);
+            osc.initialize(44100., BUFFER_SIZE, 1);
+
+            osc.reset(CLUSTER_IDX, voice_mask);
+            osc.activate_voices(CLUSTER_IDX, voice_mask, Float::splat(1.0), UInt::splat(69));
+            osc.set_all_params(CLUSTER_IDX, voice_mask, &params);
+
+            if jump {
+                // to the last frame
+                let frame = WTOscParam::Frame as u64;
+                osc.set_param_at(CLUSTER_IDX, voice_mask, frame, Float::splat(1.), OFFSET);
+            }
+
+            let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+                .with_indices(&[], &[Some(OutputBufferIndex::Local(0))])
+                .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+            osc.process(buffers, CLUSTER_IDX, voice_mask);
+            assert!(osc.param_events.is_empty());
+
+            Cell::get_mut(intermediate_buffers[0].as_mut()).to_vec()
+        };
+
+        let (steady, jumped) = (render(false), render(true));
+
+        let max_diff = |from: usize, to: usize| {
+            steady[from..to]
+                .iter()
+                .zip(&jumped[from..to])
+                .map(|(a, b)| (*a - *b).abs().reduce_max())
+                .fold(0f32, f32::max)
+        };
+
+        assert!(max_diff(0, OFFSET) < 1e-5);
+        assert!(max_diff(OFFSET, BUFFER_SIZE) > 1e-3);
+    }
+
+    #[test]
+    pub fn smoothing_time() {
+        const SR: f32 = 48000.;
+
+        let all_voices = TMask::splat(true);
+
+        let settling_samples = |osc: &WTOsc| {
+            let mut params = WTOscClusterNormParams::default();
+            params.set_param_instantly(WTOscParam::Level, Float::splat(0.), all_voices);
+            params.set_param_target(WTOscParam::Level, Float::splat(1.), all_voices);
+
+            let mut samples = 0;
+            while params.level.current[0] < 0.999 {
+                params.tick_n(&osc.log2_alphas, osc.expression_log2_alpha, 1);
+                samples += 1;
+            }
+
+            samples as f32
+        };
+
+        let mut osc = WTOsc::default();
+        osc.initialize(SR, 64, 1);
+
+        for ms in [2., 50.] {
+            osc.set_smoothing_time_ms(ms);
+            assert!((settling_samples(&osc) - ms / 1000. * SR).abs() <= 2.);
+        }
+
+        // overrides take precedence
+        osc.set_param_smoothing_time_ms(WTOscParam::Level, Some(10.));
+        assert!((settling_samples(&osc) - 0.01 * SR).abs() <= 2.);
+
+        osc.set_param_smoothing_time_ms(WTOscParam::Level, None);
+        assert!((settling_samples(&osc) - 0.05 * SR).abs() <= 2.);
+    }
+
+    #[test]
+    pub fn level_taper() {
+        const BUFFER_SIZE: usize = 64;
+        const CLUSTER_IDX: usize = 0;
+
+        type P = WTOscClusterNormParams;
+
+        let gain = |norm_val: f32| P::level_gain_from_norm(Float::splat(norm_val))[0];
+
+        assert_eq!(gain(0.), 0.);
+        assert!((gain(LEVEL_FLOOR_NORM) - 1e-3).abs() < 1e-6);
+        assert!((gain(LEVEL_UNITY_NORM) - 1.).abs() < 1e-6);
+        assert!((gain(1.) - 10f32.powf(0.3)).abs() < 1e-5);
+
+        for norm_val in [0.01, 0.05, 0.3, 0.8, 0.9, 1.] {
+            let db = P::level_db_from_norm(norm_val);
+            assert!((P::level_norm_from_db(db) - norm_val).abs() < 1e-5);
+        }
+
+        // a level of 0 is exactly silent
+        let mut osc = WTOsc::default();
+        osc.initialize(44100., BUFFER_SIZE, 1);
+        let voice_mask = TMask::splat(true);
+
+        osc.reset(CLUSTER_IDX, voice_mask);
+        osc.activate_voices(CLUSTER_IDX, voice_mask, Float::splat(1.0), UInt::splat(69));
+
+        let mut values = DEFAULT_PARAMS.to_vec();
+        values[WTOscParam::Level as usize] = f32x2::splat(0.);
+
+        let params = ParamsList(Box::new([values
+            .iter()
+            .copied()
+            .map(splat_stereo)
+            .collect()]));
+        osc.set_all_params(CLUSTER_IDX, voice_mask, &params);
+
+        let mut intermediate_buffers = Box::new([new_vfloat_buffer::<Float>(BUFFER_SIZE)]);
+
+        for _ in 0..4 {
+            let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+                .with_indices(&[], &[Some(OutputBufferIndex::Local(0))])
+                .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+            osc.process(buffers, CLUSTER_IDX, voice_mask);
+
+            let output = Cell::get_mut(intermediate_buffers[0].as_mut());
+            assert!(output.iter().all(|&sample| sample == Float::splat(0.)));
+        }
+    }
+
+    #[test]
+    pub fn pan_laws() {
+        let amplitudes = |pan_law, pan: f32| {
+            WTOscClusterNormParams::pan_weights(pan_law, Float::splat(pan)).sqrt()
+        };
+
+        for pan_law in [PanLaw::ConstantPower, PanLaw::Minus4_5dB] {
+            // hard left, and hard right
+            assert_eq!(
+                split_stereo(&amplitudes(pan_law, 0.))[0],
+                f32x2::from_array([1., 0.])
+            );
+            let right = split_stereo(&amplitudes(pan_law, 1.))[0];
+            assert!((right - f32x2::from_array([0., 1.])).abs().reduce_max() < 1e-6);
+        }
+
+        let center = amplitudes(PanLaw::ConstantPower, 0.5);
+        assert!((center - Float::splat(FRAC_1_SQRT_2)).abs().reduce_max() < 1e-6);
+
+        let center = amplitudes(PanLaw::Minus4_5dB, 0.5);
+        let minus_4_5_db = 10f32.powf(-4.5 / 20.);
+        assert!((center - Float::splat(minus_4_5_db)).abs().reduce_max() < 1e-2);
+
+        // the law is applied to every cluster
+        let mut osc = WTOsc::default();
+        osc.initialize(44100., 64, 2);
+        osc.custom_event(&mut PanLaw::ConstantPower);
+        assert!(osc
+            .params
+            .iter()
+            .all(|params| params.pan_law == PanLaw::ConstantPower));
+    }
+
+    #[test]
+    pub fn velocity_sensitivity() {
+        const BUFFER_SIZE: usize = 64;
+        const CLUSTER_IDX: usize = 0;
+        const DEPTH: f32 = 0.6;
+
+        let voice_mask = TMask::splat(true);
+
+        // the RMS of the first voice's left channel, after the fade in
+        let render = |velocity: f32| {
+            let mut osc = WTOsc::default();
+            osc.initialize(44100., BUFFER_SIZE, 1);
+
+            osc.reset(CLUSTER_IDX, voice_mask);
+            osc.activate_voices(
+                CLUSTER_IDX,
+                voice_mask,
+                Float::splat(velocity / 127.),
+                UInt::splat(60),
+            );
+
+            let mut values = DEFAULT_PARAMS.to_vec();
+            values[WTOscParam::VelocityLevel as usize] = f32x2::splat(DEPTH);
+
+            let params = ParamsList(Box::new([values
+                .iter()
+                .copied()
+                .map(splat_stereo)
+                .collect()]));
+            osc.set_all_params(CLUSTER_IDX, voice_mask, &params);
+
+            let mut intermediate_buffers = Box::new([new_vfloat_buffer::<Float>(BUFFER_SIZE)]);
+            let mut samples = Vec::new();
+
+            for i in 0..16 {
+                let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+                    .with_indices(&[], &[Some(OutputBufferIndex::Local(0))])
+                    .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+                osc.process(buffers, CLUSTER_IDX, voice_mask);
+
+                let output = Cell::get_mut(intermediate_buffers[0].as_mut());
+                if i >= 4 {
+                    samples.extend(output.iter().map(|sample| sample[0]));
+                }
+            }
+
+            rms(&samples)
+        };
+
+        let (soft, hard) = (render(32.), render(127.));
+        let expected = 1. - DEPTH * (1. - 32. / 127.);
+
+        assert!(hard > 0.);
+        assert!((soft / hard - expected).abs() < 1e-3);
+
+        // velocity moves the frame forward, before it's clamped
+        let mut params = WTOscClusterNormParams::default();
+        params.set_param_instantly(WTOscParam::Frame, Float::splat(0.5), voice_mask);
+        params.set_param_instantly(WTOscParam::VelocityFrame, Float::splat(0.8), voice_mask);
+        params.set_velocity(Float::splat(1.), voice_mask);
+
+        let (voice_params, _) = voice::VoiceParams::new(0, &params).unwrap();
+        assert!(
+            (voice_params.base_norm_frame - Float::splat(1.3))
+                .abs()
+                .reduce_max()
+                < 1e-6
+        );
+
+        let (_, norm_frame, _) = voice_params.get_params(0);
+        assert!(norm_frame.simd_le(Float::splat(0.9999)).all());
+    }
+
+    #[test]
+    pub fn voice_expression() {
+        const BUFFER_SIZE: usize = 64;
+
+        let all_voices = TMask::splat(true);
+        let first_voice = TMask::from_array(array::from_fn(|i| i < 2));
+
+        let mut osc = WTOsc::default();
+        osc.initialize(44100., BUFFER_SIZE, 1);
+
+        let params = &mut osc.params[0];
+        params.set_param_instantly(WTOscParam::Frame, Float::splat(0.25), all_voices);
+        params.set_param_instantly(WTOscParam::PressureFrame, Float::splat(0.5), all_voices);
+        params.set_param_instantly(WTOscParam::SlideLevel, Float::splat(0.5), all_voices);
+
+        let frames = |osc: &WTOsc| {
+            [0, 1].map(|i| {
+                let (voice_params, _) = voice::VoiceParams::new(i, &osc.params[0]).unwrap();
+                voice_params.base_norm_frame[0]
+            })
+        };
+
+        assert_eq!(frames(&osc), [0.25; 2]);
+
+        osc.set_voice_expression(0, first_voice, Float::splat(1.), Float::splat(1.));
+
+        // smoothed
+        osc.params[0].tick_n(&osc.log2_alphas, osc.expression_log2_alpha, 1);
+        let [first, second] = frames(&osc);
+        assert!(first > 0.25 && first < 0.75);
+        assert_eq!(second, 0.25);
+
+        for _ in 0..100 {
+            osc.params[0].tick_n(&osc.log2_alphas, osc.expression_log2_alpha, BUFFER_SIZE);
+        }
+
+        let [first, second] = frames(&osc);
+        assert!((first - 0.75).abs() < 1e-3);
+        assert_eq!(second, 0.25);
+
+        // additive, in linear gain
+        let level = osc.params[0].modulated_level();
+        assert!((level[0] - 1.5).abs() < 1e-3);
+        assert_eq!(level[2], 1.);
+
+        // still clamped
+        osc.params[0].set_param_instantly(WTOscParam::Frame, Float::splat(0.9), all_voices);
+        let (voice_params, _) = voice::VoiceParams::new(0, &osc.params[0]).unwrap();
+        let (_, norm_frame, _) = voice_params.get_params(0);
+        assert!(norm_frame.simd_le(Float::splat(0.9999)).all());
+    }
+
+    #[test]
+    pub fn a4_reference() {
+        const SR: f32 = 48000.;
+        const BUFFER_SIZE: usize = 64;
+        const CLUSTER_IDX: usize = 0;
+
+        let table = BandLimitedWaveTables::from_harmonics(&[&[Complex32::new(1., 0.)]]).unwrap();
+        let voice_mask = TMask::splat(true);
+
+        let mut osc = WTOsc::default();
+        osc.table = table.into();
+        osc.initialize(SR, BUFFER_SIZE, 1);
+        osc.set_a4_frequency(432.);
+
+        osc.reset(CLUSTER_IDX, voice_mask);
+        osc.activate_voices(CLUSTER_IDX, voice_mask, Float::splat(1.), UInt::splat(69));
+
+        let params = ParamsList(Box::new([DEFAULT_PARAMS
+            .iter()
+            .copied()
+            .map(splat_stereo)
+            .collect()]));
+        osc.set_all_params(CLUSTER_IDX, voice_mask, &params);
+
+        let mut intermediate_buffers = Box::new([new_vfloat_buffer::<Float>(BUFFER_SIZE)]);
+        let mut samples = Vec::new();
+
+        for _ in 0..SR as usize / BUFFER_SIZE {
+            let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+                .with_indices(&[], &[Some(OutputBufferIndex::Local(0))])
+                .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+            osc.process(buffers, CLUSTER_IDX, voice_mask);
+
+            let output = Cell::get_mut(intermediate_buffers[0].as_mut());
+            samples.extend(output.iter().map(|sample| sample[0]));
+        }
+
+        // interpolated rising zero crossings
+        let crossings: Vec<_> = samples
+            .windows(2)
+            .enumerate()
+            .filter(|(_, w)| w[0] < 0. && w[1] >= 0.)
+            .map(|(i, w)| i as f32 + w[0] / (w[0] - w[1]))
+            .collect();
+
+        let (first, last) = (crossings[0], *crossings.last().unwrap());
+        let period = (last - first) / (crossings.len() - 1) as f32;
+
+        assert!((period - SR / 432.).abs() < 1e-2);
+
+        // held notes are retuned
+        osc.set_a4_frequency(440.);
+        let error = osc.params[CLUSTER_IDX].phase_delta[0] / (440. / SR) - 1.;
+        assert!(error.abs() < 1e-6);
+
+        // invalid references are ignored (after asserting, in debug builds)
+        let phase_delta = osc.params[CLUSTER_IDX].phase_delta;
+
+        for hz in [0., -440., f32::NAN, f32::INFINITY] {
+            let _ =
+                std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| osc.set_a4_frequency(hz)));
+
+            assert_eq!(osc.a4_frequency, 440.);
+            assert_eq!(osc.params[CLUSTER_IDX].phase_delta, phase_delta);
+        }
+    }
+
+    #[test]
+    pub fn tuning_tables() {
+        const SR: f32 = 48000.;
+
+        // pitch classes' positions in the chain of fifths, from C
+        const FIFTHS: [i32; 12] = [0, 7, 2, -3, 4, -1, 6, 1, 8, 3, -2, 5];
+
+        let c4 = 440. * (-9f64 / 12.).exp2();
+        let meantone = TuningTable {
+            frequencies: array::from_fn(|note| {
+                let ratio = 5f64.powf(FIFTHS[note % 12] as f64 / 4.);
+                // reduced to the octave above C
+                let ratio = ratio / ratio.log2().floor().exp2();
+                c4 * ratio * ((note as i32 / 12 - 5) as f64).exp2()
+            }),
+        };
+
+        // fractional notes are half-way between the adjacent ones, in pitch
+        let half_step = meantone.frequency(60.5) / meantone.frequency(60.);
+        let step = meantone.frequency(61.) / meantone.frequency(60.);
+        assert!((half_step * half_step - step).abs() < 1e-5);
+
+        let mask = TMask::splat(true);
+        let c_and_e = UInt::from_array(array::from_fn(|i| if i < 2 { 60 } else { 64 }));
+
+        let mut osc = WTOsc::default();
+        osc.initialize(SR, 64, 1);
+        osc.custom_event(&mut Some(meantone));
+        osc.activate_voices(0, mask, Float::splat(1.), c_and_e);
+
+        let phase_deltas = osc.params[0].phase_delta;
+        assert!((phase_deltas[2] / phase_deltas[0] - 1.25).abs() < 1e-5);
+
+        // held notes are retuned, back to 12-TET
+        osc.custom_event(&mut None::<TuningTable>);
+
+        let phase_deltas = osc.params[0].phase_delta;
+        let equal_third = 2f32.powf(4. / 12.);
+        assert!((phase_deltas[2] / phase_deltas[0] - equal_third).abs() < 1e-5);
+        assert!((phase_deltas[0] * SR / c4 as f32 - 1.).abs() < 1e-5);
+    }
+
+    #[test]
+    pub fn scala_files() {
+        use tuning::{KeyboardMapping, Scale, TuningError};
+
+        let close = |a: f64, b: f64| (a / b - 1.).abs() < 1e-7;
+
+        // 19-EDO, in cents, with the default (linear, A4 = 440) mapping
+        let edo_19: String = [
+            "! 19-EDO.scl",
+            "!",
+            "19 equal divisions of the octave",
+            " 19",
+            "!",
+        ]
+        .into_iter()
+        .map(String::from)
+        .chain((1..19).map(|i| format!("{:.5}", i as f64 * 1200. / 19.)))
+        .chain(["2/1".into()])
+        .collect::<Vec<_>>()
+        .join("\n");
+
+        let scale: Scale = edo_19.parse().unwrap();
+        assert_eq!(scale.description, "19 equal divisions of the octave");
+        assert_eq!(scale.len(), 19);
+
+        let table = TuningTable::from_scala(&scale, &KeyboardMapping::default()).unwrap();
+        assert!(close(table.frequencies[69], 440.));
+        assert!(close(table.frequencies[70], 440. * (1f64 / 19.).exp2()));
+        assert!(close(table.frequencies[69 + 19], 880.));
+        assert!(close(table.frequencies[69 - 19], 220.));
+
+        // 5-limit just intonation, with ratios, mapped to the 12 keys of the octave
+        let just = "! just.scl\n\
+            5-limit just intonation\n\
+            12\n\
+            16/15\n9/8\n6/5\n5/4 major third\n4/3\n45/32\n3/2\n8/5\n5/3\n9/5\n15/8\n2\n";
+
+        let mapping = "! just.kbm\n\
+            12\n0\n127\n60\n69\n440.0\n12\n\
+            ! mapping\n\
+            0\n1\n2\n3\n4\n5\n6\n7\n8\n9\n10\nx\n";
+
+        let scale: Scale = just.parse().unwrap();
+        let mapping: KeyboardMapping = mapping.parse().unwrap();
+        assert_eq!(mapping.mapping.len(), 12);
+        assert_eq!(mapping.mapping[11], None);
+
+        let table = TuningTable::from_scala(&scale, &mapping).unwrap();
+        assert!(close(table.frequencies[69], 440.));
+        assert!(close(table.frequencies[60], 264.));
+        assert!(close(table.frequencies[64], 330.));
+        assert!(close(table.frequencies[72], 528.));
+        // unmapped keys repeat the one below
+        assert_eq!(table.frequencies[71], table.frequencies[70]);
+
+        // a single note scale, every key is an octave above the one below
+        let scale: Scale = "single\n1\n2/1".parse().unwrap();
+        let table = TuningTable::from_scala(&scale, &KeyboardMapping::default()).unwrap();
+        assert!(close(table.frequencies[70], 880.));
+        assert!(close(table.frequencies[68], 220.));
+
+        assert!(matches!(
+            "bad\n2\n3/2\n2/0".parse::<Scale>(),
+            Err(TuningError::Syntax { line: 4, .. })
+        ));
+        // reported at the count's line, after the comments
+        assert!(matches!(
+            "! empty.scl\n!\nempty\n0".parse::<Scale>(),
+            Err(TuningError::Syntax { line: 4, .. })
+        ));
+        assert!(matches!(
+            "short\n3\n3/2\n2/1".parse::<Scale>(),
+            Err(TuningError::UnexpectedEnd)
+        ));
+        assert!(matches!(
+            "0\n0\n127\n60\n69\nfour forty\n0".parse::<KeyboardMapping>(),
+            Err(TuningError::Syntax { line: 6, .. })
+        ));
+
+        let unmapped_a4 = KeyboardMapping {
+            last_note: 68,
+            ..Default::default()
+        };
+        assert!(matches!(
+            TuningTable::from_scala(&scale, &unmapped_a4),
+            Err(TuningError::UnmappedReference(69))
+        ));
+
+        let empty = Scale {
+            description: String::new(),
+            degrees: Vec::new(),
+        };
+        assert!(matches!(
+            TuningTable::from_scala(&empty, &KeyboardMapping::default()),
+            Err(TuningError::EmptyScale)
+        ));
+
+        for hz in [0., -440., f64::NAN, f64::INFINITY] {
+            let mapping = KeyboardMapping {
+                reference_frequency: hz,
+                ..Default::default()
+            };
+            assert!(matches!(
+                TuningTable::from_scala(&scale, &mapping),
+                Err(TuningError::InvalidReferenceFrequency(_))
+            ));
+        }
+    }
+
+    #[test]
+    pub fn builder() {
+        const BUFFER_SIZE: usize = 64;
+        const CLUSTER_IDX: usize = 0;
+
+        let voice_mask = TMask::splat(true);
+
+        let mut osc = WTOscBuilder::default()
+            .table(BandLimitedWaveTables::basic_shapes().into())
+            .starting_phases([0.25; MAX_UNISON])
+            .smoothing_ms(5.)
+            .build()
+            .unwrap();
+
+        assert_eq!(osc.starting_phases, [Float::splat(0.25); OSCS_PER_VOICE]);
+
+        osc.initialize(44100., BUFFER_SIZE, 1);
+        osc.reset(CLUSTER_IDX, voice_mask);
+        osc.activate_voices(CLUSTER_IDX, voice_mask, Float::splat(1.), UInt::splat(69));
+
+        let params = ParamsList(Box::new([DEFAULT_PARAMS
+            .iter()
+            .copied()
+            .map(splat_stereo)
+            .collect()]));
+        osc.set_all_params(CLUSTER_IDX, voice_mask, &params);
+
+        let mut intermediate_buffers = Box::new([new_vfloat_buffer::<Float>(BUFFER_SIZE)]);
+
+        let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+            .with_indices(&[], &[Some(OutputBufferIndex::Local(0))])
+            .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+        osc.process(buffers, CLUSTER_IDX, voice_mask);
+
+        let output = Cell::get_mut(intermediate_buffers[0].as_mut());
+        assert!(output.iter().any(|&sample| sample != Float::splat(0.)));
+
+        assert!(matches!(
+            WTOscBuilder::default()
+                .table(BandLimitedWaveTables::empty().into())
+                .build(),
+            Err(BuildError::EmptyTable)
+        ));
+
+        let mut phases = [0.; MAX_UNISON];
+        phases[3] = 1.;
+        assert!(matches!(
+            WTOscBuilder::default().starting_phases(phases).build(),
+            Err(BuildError::StartingPhaseOutOfRange { index: 3, .. })
+        ));
+
+        for bad_time in [-1., f32::NAN, f32::INFINITY] {
+            assert!(matches!(
+                WTOscBuilder::default().smoothing_ms(bad_time).build(),
+                Err(BuildError::InvalidSmoothingTime(_))
+            ));
+            assert!(matches!(
+                WTOscBuilder::default().voice_fade_secs(bad_time).build(),
+                Err(BuildError::InvalidVoiceFadeTime(_))
+            ));
+        }
+
+        // instant smoothing, and fades
+        assert!(WTOscBuilder::default()
+            .smoothing_ms(0.)
+            .voice_fade_secs(0.)
+            .build()
+            .is_ok());
+
+        assert_ne!(
+            WTOsc::new(BandLimitedWaveTables::basic_shapes().into())
+                .table
+                .num_frames(),
+            0
+        );
+    }
+
+    #[test]
+    pub fn golden_saw() {
+        // 440 Hz has a period of exactly 100 samples
+        const SR: f32 = 44000.;
+        const SKIP: usize = 400;
+        const NUM_PERIODS: usize = 40;
+        const LEN: usize = NUM_PERIODS * 100;
+
+        // the third basic shape
+        let saw_frame = (WTOscParam::Frame as u64, 0.5);
+
+        let mut osc = WTOsc::default();
+        let output = render::render_mono(&mut osc, 69., &[saw_frame], SR, SKIP + LEN);
+        assert_eq!(output.len(), SKIP + LEN);
+
+        let mut samples = output[SKIP..].to_vec();
+        let mut spectrum = vec![Complex32::default(); LEN / 2 + 1];
+
+        RealFftPlanner::<f32>::new()
+            .plan_fft_forward(LEN)
+            .process(&mut samples, &mut spectrum)
+            .unwrap();
+
+        let magnitude = |harmonic: usize| spectrum[harmonic * NUM_PERIODS].norm();
+        let fundamental = magnitude(1);
+
+        assert!(fundamental > 0.);
+
+        // harmonics fall off as 1/k
+        for k in 2..=10 {
+            let relative = magnitude(k) / fundamental;
+            assert!(
+                (relative * k as f32 - 1.).abs() < 1e-2,
+                "harmonic {k}: {relative}"
+            );
+        }
+
+        // within half a dB, up to 10 kHz
+        for k in 11..=10000 / 440 {
+            let db = 20. * (magnitude(k) / fundamental * k as f32).log10();
+            assert!(db.abs() < 0.5, "harmonic {k}: {db} dB");
+        }
+
+        // and there's nothing in between
+        for bin in [NUM_PERIODS / 2, NUM_PERIODS * 3 / 2, NUM_PERIODS * 5 / 2] {
+            assert!(spectrum[bin].norm() / fundamental < 1e-3);
+        }
+    }
+
+    #[test]
+    pub fn golden_saw_aliasing() {
+        const SR: f32 = 44100.;
+        const SKIP: usize = 4096;
+        const LEN: usize = 4096;
+        // close to note 96 (~2093 Hz), a whole number of cycles in `LEN` samples,
+        // odd, so that aliased partials can't land on harmonics
+        const CYCLES: usize = 195;
+
+        let mut tuning = TuningTable::default();
+        tuning.frequencies[96] = CYCLES as f64 * SR as f64 / LEN as f64;
+
+        let mut osc = WTOsc::default();
+        osc.custom_event(&mut Some(tuning));
+
+        let saw_frame = (WTOscParam::Frame as u64, 0.5);
+        let output = render::render_mono(&mut osc, 96., &[saw_frame], SR, SKIP + LEN);
+
+        let spectrum = spectrum(&output[SKIP..]);
+
+        let (mut harmonic, mut inharmonic) = (0., 0.);
+
+        for (bin, value) in spectrum.iter().enumerate().skip(1) {
+            let energy = value.norm_sqr() as f64;
+
+            if bin % CYCLES == 0 {
+                harmonic += energy;
+            } else {
+                inharmonic += energy;
+            }
+        }
+
+        let db = 10. * (inharmonic / harmonic).log10();
+        assert!(db < -60., "aliasing: {db} dB");
+    }
+
+    #[test]
+    pub fn repeated_renders() {
+        let params = [
+            (WTOscParam::Frame as u64, 0.3),
+            (WTOscParam::NumVoices as u64, unison_count_to_normalized(7)),
+            (WTOscParam::Detune as u64, 0.3),
+        ];
+
+        let render = || render::render_mono(&mut WTOsc::default(), 57.5, &params, 48000., 4096);
+
+        let (a, b) = (render(), render());
+
+        assert!(a.iter().any(|&sample| sample != 0.));
+        assert!(a.iter().zip(&b).all(|(a, b)| a.to_bits() == b.to_bits()));
+    }
+
+    #[test]
+    pub fn oversampling() {
+        use core::f64::consts::PI;
+
+        const SR: f32 = 48000.;
+        const BUFFER_SIZE: usize = 64;
+        const SKIP: usize = 2048;
+        const LEN: usize = 4096;
+        // the carrier, and the modulator's bins
+        const CARRIER: usize = 100;
+        const MODULATOR: usize = 300;
+
+        assert_eq!(WTOsc::default().latency(), 0);
+        assert_eq!(
+            WTOsc::default()
+                .with_oversampling(Oversampling::X2)
+                .latency(),
+            16
+        );
+        assert_eq!(
+            WTOsc::default()
+                .with_oversampling(Oversampling::X4)
+                .latency(),
+            24
+        );
+
+        let mask = TMask::splat(true);
+
+        // phase modulates a sine, the sidebands go well past nyquist. Returns
+        // the energy of the components that aren't sidebands, relative to the total
+        let aliasing = |oversampling: Oversampling| {
+            let factor = oversampling.factor();
+
+            let mut osc = WTOsc::default().with_oversampling(oversampling);
+            osc.initialize(SR, BUFFER_SIZE, 1);
+
+            // exactly on a bin, notes would go through approximations of exp2
+            let carrier_w = CARRIER as f32 / (LEN * factor) as f32;
+            osc.activate_voices(0, mask, Float::splat(1.), UInt::splat(69));
+            osc.params[0].set_base_phase_delta(Float::splat(carrier_w), mask);
+
+            // a sine, one cycle of phase modulation per unit of input
+            let mut values = DEFAULT_PARAMS.to_vec();
+            values[WTOscParam::PmAmount as usize] = f32x2::splat(1.);
+            let params = ParamsList(Box::new([values
+                .iter()
+                .copied()
+                .map(splat_stereo)
+                .collect()]));
+            osc.set_all_params(0, mask, &params);
+
+            let modulator_w = 2. * PI * MODULATOR as f64 / (LEN * factor) as f64;
+            let mut samples = Vec::new();
+            let mut block = vec![Float::splat(0.); BUFFER_SIZE];
+
+            for i in 0..(SKIP + LEN) / BUFFER_SIZE {
+                let start = i * BUFFER_SIZE * factor;
+
+                for (j, input) in osc.pm_input[..BUFFER_SIZE * factor].iter_mut().enumerate() {
+                    *input = Float::splat((modulator_w * (start + j) as f64).sin() as f32);
+                }
+
+                osc.render_oversampled(0, mask, &mut block, true);
+                samples.extend(block.iter().map(|sample| sample[0]));
+            }
+
+            let mut samples = samples[SKIP..].to_vec();
+            let mut spectrum = vec![Complex32::default(); LEN / 2 + 1];
+
+            RealFftPlanner::<f32>::new()
+                .plan_fft_forward(LEN)
+                .process(&mut samples, &mut spectrum)
+                .unwrap();
+
+            // the sidebands are at |CARRIER + n * MODULATOR|, the top of
+            // the spectrum is left out, as it's in the decimator's transition band
+            let is_sideband = |bin: usize| {
+                let offset = bin % MODULATOR;
+                offset == CARRIER || offset == MODULATOR - CARRIER
+            };
+
+            let energy = |bins: &mut dyn Iterator<Item = usize>| {
+                bins.map(|bin| spectrum[bin].norm_sqr() as f64).sum::<f64>()
+            };
+
+            let band = || 1..LEN * 2 / 5;
+            energy(&mut band().filter(|&bin| !is_sideband(bin))) / energy(&mut band())
+        };
+
+        let plain = aliasing(Oversampling::None);
+        let oversampled = aliasing(Oversampling::X4);
+
+        assert!(plain > 1e-6);
+        assert!(10. * (plain / oversampled).log10() >= 40.);
+    }
+
+    /// Renders the first voice of `params` with `WTOsc::render_voice`, and the old way,
+    /// every oscillator into a buffer, one after the other, returns the oscillators of both
+    fn unison_render(
+        params: &WTOscClusterNormParams,
+        table: &BandLimitedWaveTables,
+    ) -> ([Oscillator; OSCS_PER_VOICE], [Oscillator; OSCS_PER_VOICE]) {
+        const NUM_SAMPLES: usize = 256;
+
+        let num_frames_f = Float::splat(table.num_frames() as f32);
+
+        let (voice_params, num_oscs) = VoiceParams::new(0, params).unwrap();
+        assert_eq!(num_oscs.get(), OSCS_PER_VOICE);
+
+        let mut oscs = [Oscillator::default(); OSCS_PER_VOICE];
+        for (i, osc) in oscs.iter_mut().enumerate() {
+            osc.set_params(&voice_params, i, num_frames_f);
+        }
+
+        let mut slow_oscs = oscs;
+        let mut slow = vec![Float::splat(0.); NUM_SAMPLES];
+
+        for (osc, i) in slow_oscs.iter_mut().zip(0..) {
+            let smooth_dt = Float::splat(1. / NUM_SAMPLES as f32);
+            let mask = osc.set_params_smoothed(&voice_params, i, num_frames_f, smooth_dt);
+
+            for sample in slow.iter_mut() {
+                let gain = osc.get_gain();
+                *sample += unsafe { osc.tick_all(table, mask, Quality::Standard) } * gain;
+            }
+        }
+
+        let slow: Vec<_> = slow.into_iter().map(sum_to_stereo_sample).collect();
+
+        let mut fast = vec![f32x2::splat(0.); NUM_SAMPLES];
+
+        unsafe {
+            WTOsc::render_voice(
+                &mut oscs,
+                &voice_params,
+                table,
+                Quality::Standard,
+                &[UInt::splat(0); NUM_SAMPLES],
+                fast.iter_mut(),
+            );
+        }
+
+        assert_eq!(fast, slow);
+
+        (oscs, slow_oscs)
+    }
+
+    #[test]
+    pub fn unison_accumulation() {
+        let table = BandLimitedWaveTables::from_harmonics(&[
+            &[Complex32::new(1., 0.)],
+            &[Complex32::new(0.5, 0.), Complex32::new(0., 0.5)],
+        ])
+        .unwrap();
+
+        let all_voices = TMask::splat(true);
+
+        // 16 detuned voices, spread across the table
+        let mut params = WTOscClusterNormParams::default();
+        params.set_param_instantly(WTOscParam::NumVoices, Float::splat(1.), all_voices);
+        params.set_param_instantly(WTOscParam::Detune, Float::splat(0.7), all_voices);
+        params.set_param_instantly(WTOscParam::FrameSpread, Float::splat(0.5), all_voices);
+        params.set_base_phase_delta(Float::splat(1. / 64.), all_voices);
+
+        let (oscs, slow_oscs) = unison_render(&params, &table);
+
+        for (osc, slow_osc) in oscs.iter().zip(&slow_oscs) {
+            assert_eq!(osc.phase(), slow_osc.phase());
+        }
+    }
+
+    #[test]
+    pub fn collapsed_unison() {
+        let table = BandLimitedWaveTables::from_harmonics(&[&[Complex32::new(1., 0.)]]).unwrap();
+        let all_voices = TMask::splat(true);
+
+        // 16 voices, all at the same pitch
+        let mut params = WTOscClusterNormParams::default();
+        params.set_param_instantly(WTOscParam::NumVoices, Float::splat(1.), all_voices);
+        params.set_param_instantly(WTOscParam::Detune, Float::splat(0.), all_voices);
+        params.set_base_phase_delta(Float::splat(1. / 64.), all_voices);
+
+        let (voice_params, _) = VoiceParams::new(0, &params).unwrap();
+
+        let mut oscs = [Oscillator::default(); OSCS_PER_VOICE];
+        for (i, osc) in oscs.iter_mut().enumerate() {
+            osc.set_params(&voice_params, i, Float::splat(1.));
+        }
+
+        for i in 1..OSCS_PER_VOICE {
+            assert!(voice_params.same_params(i, 0));
+            assert!(oscs[i].renders_like(&oscs[0]));
+        }
+
+        let (oscs, slow_oscs) = unison_render(&params, &table);
+
+        // the skipped oscillators are left where they would've been
+        for (osc, slow_osc) in oscs.iter().zip(&slow_oscs) {
+            assert_eq!(osc.phase(), slow_osc.phase());
+            assert!(osc.renders_like(slow_osc));
+        }
+    }
+
+    #[test]
+    pub fn block_weight_ramps() {
+        const BLOCK_SIZE: usize = 64;
+
+        let all_voices = TMask::splat(true);
+
+        let mut params = WTOscClusterNormParams::default();
+        let mut cluster = WTOscVoiceCluster::default();
+        cluster.set_weights(&params, all_voices);
+
+        // a parameter jump, ramped over the block, with voices fading in
+        params.set_param_instantly(WTOscParam::Pan, Float::splat(0.1), all_voices);
+        params.set_param_instantly(WTOscParam::Stereo, Float::splat(0.8), all_voices);
+        cluster.set_weights_smoothed(&params, Float::splat(1. / BLOCK_SIZE as f32));
+        cluster.fade_in(all_voices, Float::splat(1. / 48.));
+
+        let input: Vec<_> = (0..BLOCK_SIZE)
+            .map(|i| {
+                Float::from_array(array::from_fn(|lane| ((i * 7 + lane * 3) % 11) as f32 - 5.))
+            })
+            .collect();
+
+        let no_drive = Float::splat(0.);
+        let mut per_sample_cluster = cluster;
+        let mut per_sample = input.clone();
+
+        for sample in per_sample.iter_mut() {
+            per_sample_cluster.apply_weights(core::slice::from_mut(sample), no_drive);
+        }
+
+        let mut block = input.clone();
+        cluster.apply_weights(&mut block, no_drive);
+
+        let to_arrays = |buf: &[Float]| buf.iter().map(|s| s.to_array()).collect::<Vec<_>>();
+        assert_eq!(to_arrays(&block), to_arrays(&per_sample));
+
+        // and the ramps end up at the same place
+        let (mut a, mut b) = (input.clone(), input);
+        cluster.apply_weights(&mut a, no_drive);
+        per_sample_cluster.apply_weights(&mut b, no_drive);
+        assert_eq!(to_arrays(&a), to_arrays(&b));
+    }
+
+    #[test]
+    pub fn scalar_gather() {
+        let table = BandLimitedWaveTables::basic_shapes();
+        let samples = table.as_slice().flatten().flatten();
+        let len = samples.len() as u32;
+
+        let mut state = 0x9e37_79b9u32;
+        let mut random = || {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            state
+        };
+
+        for _ in 0..1024 {
+            let idx = UInt::from_array(array::from_fn(|_| random() % len));
+            let mask = TMask::from_array(array::from_fn(|_| random() & 1 == 0));
+
+            let (scalar, hardware) = unsafe {
+                (
+                    wavetable::scalar_gather_select(samples.as_ptr(), idx, mask),
+                    gather_select_unchecked(samples.as_ptr(), idx, mask, Float::splat(0.)),
+                )
+            };
+
+            assert_eq!(scalar, hardware);
+        }
+    }
+
+    #[test]
+    pub fn table_bank() {
+        const SR: f32 = 44000.;
+        const BUFFER_SIZE: usize = 64;
+        const NUM_SAMPLES: usize = 4400;
+
+        let silence: [Complex32; 0] = [];
+        let fundamental = [Complex32::new(1., 0.)];
+        let second_harmonic = [Complex32::new(0., 0.), Complex32::new(1., 0.)];
+
+        // different frame counts, the last frames differ
+        let mut main: Arc<_> = BandLimitedWaveTables::from_harmonics(&[&silence, &fundamental])
+            .unwrap()
+            .into();
+        let mut bank: Vec<Arc<BandLimitedWaveTables>> = vec![
+            BandLimitedWaveTables::from_harmonics(&[&silence, &silence, &second_harmonic])
+                .unwrap()
+                .into(),
+        ];
+
+        let mut osc = WTOsc::default();
+        osc.initialize(SR, BUFFER_SIZE, 2);
+        osc.custom_event(&mut main);
+        osc.custom_event(&mut bank);
+        assert!(bank.is_empty());
+
+        let voice_mask = TMask::splat(true);
+
+        let cluster_params = |table_select: f32| -> Box<[Float]> {
+            let mut values: Vec<_> = DEFAULT_PARAMS.iter().copied().map(splat_stereo).collect();
+            values[WTOscParam::Frame as usize] = Float::splat(1.);
+            values[WTOscParam::NumVoices as usize] = Float::splat(0.);
+            values[WTOscParam::TableSelect as usize] = Float::splat(table_select);
+            values.into_boxed_slice()
+        };
+
+        let params = ParamsList(Box::new([cluster_params(0.), cluster_params(1.)]));
+
+        for cluster_idx in 0..2 {
+            osc.reset(cluster_idx, voice_mask);
+            osc.activate_voices_fractional(
+                cluster_idx,
+                voice_mask,
+                Float::splat(1.),
+                Float::splat(69.),
+            );
+            osc.set_all_params(cluster_idx, voice_mask, &params);
+        }
+
+        assert_eq!(osc.table_slot(0), 0);
+        assert_eq!(osc.table_slot(1), 1);
+
+        let mut intermediate_buffers = Box::new([new_vfloat_buffer::<Float>(BUFFER_SIZE)]);
+
+        let mut render = |osc: &mut WTOsc, cluster_idx| {
+            let mut samples = Vec::new();
+
+            while samples.len() < NUM_SAMPLES {
+                let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+                    .with_indices(&[], &[Some(OutputBufferIndex::Local(0))])
+                    .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+                osc.process(buffers, cluster_idx, voice_mask);
+
+                let block = Cell::get_mut(intermediate_buffers[0].as_mut());
+                samples.extend(block.iter().map(|sample| sample[0]));
+            }
+
+            samples.truncate(NUM_SAMPLES);
+            samples
+        };
+
+        // 440 Hz, an integer number of cycles
+        let magnitude = |samples: &[f32], bin: usize| {
+            let (re, im) = samples
+                .iter()
+                .enumerate()
+                .fold((0., 0.), |(re, im), (n, &x)| {
+                    let angle = TAU * (bin * n) as f32 / samples.len() as f32;
+                    (re + x * angle.cos(), im - x * angle.sin())
+                });
+
+            (re * re + im * im).sqrt() / samples.len() as f32
+        };
+
+        let main_samples = render(&mut osc, 0);
+        let bank_samples = render(&mut osc, 1);
+
+        assert!(magnitude(&main_samples, 44) > 100. * magnitude(&main_samples, 88));
+        assert!(magnitude(&bank_samples, 88) > 100. * magnitude(&bank_samples, 44));
+
+        // switching slots on a sounding voice keeps its relative frame position
+        osc.set_param(
+            1,
+            voice_mask,
+            WTOscParam::TableSelect as u64,
+            Float::splat(0.),
+        );
+        render(&mut osc, 1);
+        let switched = render(&mut osc, 1);
+
+        assert_eq!(osc.table_slot(1), 0);
+        assert!(magnitude(&switched, 44) > 100. * magnitude(&switched, 88));
+    }
+
+    #[test]
+    pub fn adaptive_unison_normalization() {
+        const SR: f32 = 44100.;
+        const NUM_SAMPLES: usize = 2 * SR as usize;
+
+        let table: Arc<_> = BandLimitedWaveTables::from_harmonics(&[&[Complex32::new(1., 0.)]])
+            .unwrap()
+            .into();
+
+        let level_db = |normalization: UnisonNormalization, num_voices: usize, detune: f32| {
+            let mut osc = WTOsc::new(table.clone());
+            osc.custom_event(&mut { normalization });
+
+            let params = [
+                (
+                    WTOscParam::NumVoices as u64,
+                    unison_count_to_normalized(num_voices as u32),
+                ),
+                (WTOscParam::Detune as u64, detune),
+                (WTOscParam::Random as u64, 0.),
+            ];
+
+            let samples = render::render_mono(&mut osc, 69., &params, SR, NUM_SAMPLES);
+
+            // past the voices' fade in
+            let samples = &samples[NUM_SAMPLES / 20..];
+            20. * rms(samples).log10()
+        };
+
+        let reference = level_db(UnisonNormalization::Adaptive, 1, 0.);
+
+        for num_voices in [1, 4, 16] {
+            for detune in [0., 1.] {
+                let level = level_db(UnisonNormalization::Adaptive, num_voices, detune);
+                assert!(
+                    (level - reference).abs() <= 1.5,
+                    "{num_voices} voices, detune {detune}: {level} dB vs {reference} dB"
+                );
+            }
+        }
+
+        // by default, correlated voices add up
+        let correlated = level_db(UnisonNormalization::Uncorrelated, 16, 0.);
+        assert!(correlated - reference > 6.);
+    }
+
+    #[test]
+    pub fn soft_clipper() {
+        const SR: f32 = 44100.;
+        const NUM_SAMPLES: usize = 8192;
+
+        // 16 in-phase voices, 6 dB hot
+        let hot = [
+            (WTOscParam::Level as u64, 1.),
+            (WTOscParam::NumVoices as u64, 1.),
+            (WTOscParam::Detune as u64, 0.),
+            (WTOscParam::Random as u64, 0.),
+        ];
+
+        let render = |drive: Option<f32>| {
+            let mut params = hot.to_vec();
+            params.extend(drive.map(|drive| (WTOscParam::Drive as u64, drive)));
+
+            let mut osc = WTOsc::default();
+            render::render_mono(&mut osc, 45., &params, SR, NUM_SAMPLES)
+        };
+
+        let peak = |samples: &[f32]| samples.iter().fold(0f32, |peak, s| peak.max(s.abs()));
+
+        let bypassed = render(Some(0.));
+        assert!(peak(&bypassed) > 1.);
+
+        // bit-identical to not touching the parameter at all
+        assert_eq!(bypassed, render(None));
+
+        for drive in [0.01, 0.5, 1.] {
+            assert!(peak(&render(Some(drive))) <= 1.);
+        }
+    }
+
+    #[test]
+    pub fn dc_blocker() {
+        const SR: f32 = 44000.;
+        const NUM_SAMPLES: usize = 2 * SR as usize;
+
+        let len = BandLimitedWaveTables::FRAME_LEN;
+        let offset_sine: [f32; BandLimitedWaveTables::FRAME_LEN] =
+            array::from_fn(|i| 0.5 + 0.4 * (i as f32 / len as f32 * TAU).sin());
+
+        let table: Arc<_> = BandLimitedWaveTables::from_frames(&[offset_sine])
+            .unwrap()
+            .into();
+
+        let steady_state_mean = |enabled| {
+            let mut osc = WTOsc::new(table.clone());
+            osc.custom_event(&mut DcBlocker(enabled));
+
+            let samples = render::render_mono(&mut osc, 69., &[], SR, NUM_SAMPLES);
+
+            // the last half second, a whole number of cycles
+            let tail = &samples[NUM_SAMPLES - SR as usize / 2..];
+            tail.iter().sum::<f32>() / tail.len() as f32
+        };
+
+        assert!(steady_state_mean(false).abs() > 0.1);
+        assert!(steady_state_mean(true).abs() < 1e-3);
+    }
+
+    #[test]
+    pub fn default_table_renders() {
+        let samples = render::render_mono(&mut WTOsc::default(), 69., &[], 44100., 1024);
+
+        let peak = samples
+            .iter()
+            .fold(0f32, |peak, sample| peak.max(sample.abs()));
+        assert!(peak > 0.1);
+    }
+
+    #[test]
+    pub fn voice_phase_readout() {
+        const NUM_SAMPLES: usize = 1234;
+
+        let mut osc = WTOsc::default();
+        assert_eq!(osc.voice_phase(0, 0), None);
+
+        // 440 Hz at 44 kHz, a hundredth of a cycle per sample
+        render::render_mono(&mut osc, 69., &[], 44000., NUM_SAMPLES);
+
+        let (phase, frame) = osc.voice_phase(0, 0).unwrap();
+        let expected = (NUM_SAMPLES as f32 / 100.).fract();
+
+        assert!((phase - expected).abs() < 1e-4);
+        assert_eq!(frame, osc.clusters[0].voices()[0][0].frame()[0]);
+        assert_eq!(osc.voice_phase(0, STEREO_VOICES_PER_VECTOR), None);
+    }
+
+    #[test]
+    pub fn unison_count_round_trip() {
+        for count in 1..=MAX_UNISON as u32 {
+            let norm_val = unison_count_to_normalized(count);
+            assert_eq!(normalized_to_unison_count(norm_val), count);
+
+            for offset in [-1e-4, -1e-5, 1e-5, 1e-4] {
+                assert_eq!(normalized_to_unison_count(norm_val + offset), count);
+            }
+        }
+
+        assert_eq!(normalized_to_unison_count(-0.5), 1);
+        assert_eq!(normalized_to_unison_count(1.5), MAX_UNISON as u32);
+        assert_eq!(normalized_to_unison_count(f32::NAN), 1);
+    }
+
+    #[test]
+    pub fn starting_phase_wrapping() {
+        const CLUSTER_IDX: usize = 0;
+        let voice_mask = TMask::splat(true);
+
+        let cases = [
+            (0., 0.),
+            (0.999, 0.999),
+            (1., 0.),
+            (1.5, 0.5),
+            (-0.25, 0.75),
+            (-1e-9, 0.),
+            (f32::NAN, 0.),
+            (f32::INFINITY, 0.),
+        ];
+
+        let mut osc = WTOsc::new(BandLimitedWaveTables::basic_shapes().into());
+        osc.initialize(44100., MAX_BUFFER_SIZE, 1);
+
+        let mut starting_phases = [0.; MAX_UNISON];
+        for (phase, &(input, _)) in starting_phases.iter_mut().zip(&cases) {
+            *phase = input;
+        }
+
+        osc.custom_event(&mut starting_phases);
+        osc.reset(CLUSTER_IDX, voice_mask);
+
+        let phases: Vec<_> = osc.clusters[CLUSTER_IDX].voices_mut()[0]
+            .iter()
+            .flat_map(|osc| osc.phase().to_array())
+            .collect();
+
+        for (&phase, &(input, expected)) in phases.iter().zip(&cases) {
+            assert_eq!(phase, flp_to_fxp(Float::splat(expected))[0], "{input}");
+        }
+    }
+
+    #[test]
+    pub fn first_note_phase_delta() {
+        const CLUSTER_IDX: usize = 0;
+        const BUFFER_SIZE: usize = 64;
+
+        let voice_mask = TMask::splat(true);
+
+        let mut osc = WTOsc::new(BandLimitedWaveTables::basic_shapes().into());
+        osc.initialize(44000., BUFFER_SIZE, 1);
+        osc.reset(CLUSTER_IDX, voice_mask);
+
+        // before any call to set_all_params, the cluster's phase deltas are all zero
+        osc.activate_voices(CLUSTER_IDX, voice_mask, Float::splat(1.), UInt::splat(69));
+
+        let start = osc.clusters[CLUSTER_IDX].voices_mut()[0][0].phase();
+
+        let mut intermediate_buffers = Box::new([new_vfloat_buffer::<Float>(BUFFER_SIZE)]);
+
+        let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+            .with_indices(&[], &[Some(OutputBufferIndex::Local(0))])
+            .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+        osc.process(buffers, CLUSTER_IDX, voice_mask);
+
+        let output = Cell::get_mut(intermediate_buffers[0].as_mut());
+        assert!(output.iter().all(|sample| sample.is_finite().all()));
+
+        // 440 Hz, a hundredth of a cycle per sample, from the very first sample
+        let end = osc.clusters[CLUSTER_IDX].voices_mut()[0][0].phase();
+        let advance = fxp_to_flp(end - start)[0];
+
+        assert!((advance - BUFFER_SIZE as f32 / 100.).abs() < 1e-4);
+    }
+
+    #[test]
+    pub fn empty_table_swaps() {
+        const CLUSTER_IDX: usize = 0;
+        const BUFFER_SIZE: usize = 64;
+
+        let voice_mask = TMask::splat(true);
+
+        let len = BandLimitedWaveTables::FRAME_LEN;
+        let sine: [f32; BandLimitedWaveTables::FRAME_LEN] =
+            array::from_fn(|i| (i as f32 / len as f32 * TAU).sin());
+
+        let table = |num_frames: usize| -> Arc<BandLimitedWaveTables> {
+            match num_frames {
+                0 => BandLimitedWaveTables::empty().into(),
+                n => BandLimitedWaveTables::from_frames(&vec![sine; n])
+                    .unwrap()
+                    .into(),
+            }
+        };
+
+        // starts out with an empty table
+        let mut osc = WTOsc::new(table(0));
+        osc.initialize(44100., BUFFER_SIZE, 1);
+
+        let mut values: Vec<_> = DEFAULT_PARAMS.iter().copied().map(splat_stereo).collect();
+        values[WTOscParam::Frame as usize] = Float::splat(1.);
+        values[WTOscParam::NumVoices as usize] = Float::splat(1.);
+        values[WTOscParam::Detune as usize] = Float::splat(1.);
+        values[WTOscParam::FrameSpread as usize] = Float::splat(1.);
+        let params = ParamsList(Box::new([values.into_boxed_slice()]));
+
+        let mut intermediate_buffers = Box::new([new_vfloat_buffer::<Float>(BUFFER_SIZE)]);
+
+        for (i, num_frames) in [64, 0, 4, 0, 256, 0, 1].into_iter().enumerate() {
+            osc.custom_event(&mut table(num_frames));
+
+            if i == 0 {
+                osc.reset(CLUSTER_IDX, voice_mask);
+                osc.activate_voices(CLUSTER_IDX, voice_mask, Float::splat(1.), UInt::splat(60));
+                osc.set_all_params(CLUSTER_IDX, voice_mask, &params);
+            }
+
+            let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+                .with_indices(&[], &[Some(OutputBufferIndex::Local(0))])
+                .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+            osc.process(buffers, CLUSTER_IDX, voice_mask);
+
+            let output = Cell::get_mut(intermediate_buffers[0].as_mut());
+            assert!(output.iter().all(|sample| sample.is_finite().all()));
+
+            if num_frames == 0 {
+                continue;
+            }
+
+            let max_frame = Float::splat(num_frames as f32);
+
+            for voice in osc.clusters[CLUSTER_IDX].voices_mut() {
+                for osc in voice {
+                    let frame = osc.frame();
+                    assert!(frame.is_finite().all());
+                    assert!(frame.simd_ge(Float::splat(0.)).all());
+                    assert!(frame.simd_lt(max_frame).all(), "{num_frames} frames");
+                }
+            }
+        }
+    }
+
+    #[test]
+    pub fn oversampled_empty_table() {
+        let mut osc = WTOsc::default().with_oversampling(Oversampling::X4);
+
+        let output = render::render_mono(&mut osc, 69., &[], 44100., 1000);
+        assert!(output.iter().any(|&sample| sample != 0.));
+
+        osc.custom_event(&mut Arc::<BandLimitedWaveTables>::from(
+            BandLimitedWaveTables::empty(),
+        ));
+
+        // the decimators' filters ring out over the first block, the stale
+        // oversampled block mustn't be filtered again after that
+        let output = render::render_more(&mut osc, 1000);
+        assert!(output[64..].iter().all(|&sample| sample == 0.));
+    }
+
+    #[test]
+    pub fn out_of_range_params() {
+        const CLUSTER_IDX: usize = 0;
+        const BUFFER_SIZE: usize = 64;
+
+        let voice_mask = TMask::splat(true);
+        let bad_values = [f32::NAN, f32::INFINITY, f32::NEG_INFINITY, -0.5, 1.5];
+
+        let mut osc = WTOsc::new(BandLimitedWaveTables::basic_shapes().into());
+        let mut intermediate_buffers = Box::new([new_vfloat_buffer::<Float>(BUFFER_SIZE)]);
+
+        for id in 0..NUM_PARAMS {
+            for value in bad_values {
+                // set instantly, through set_all_params
+                let samples = render::render_mono(&mut osc, 60., &[(id, value)], 44100., 256);
+                assert!(
+                    samples.iter().all(|sample| sample.is_finite()),
+                    "param {id}: {value}"
+                );
+
+                // and smoothed, through set_param
+                osc.set_param(CLUSTER_IDX, voice_mask, id, Float::splat(value));
+
+                for _ in 0..4 {
+                    let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+                        .with_indices(&[], &[Some(OutputBufferIndex::Local(0))])
+                        .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+                    osc.process(buffers, CLUSTER_IDX, voice_mask);
+
+                    let output = Cell::get_mut(intermediate_buffers[0].as_mut());
+                    assert!(
+                        output.iter().all(|sample| sample.is_finite().all()),
+                        "param {id}: {value}"
+                    );
+                }
+            }
+        }
+
+        // NaNs fall back to the default, out of range values are clamped
+        let mut params = WTOscClusterNormParams::default();
+        params.set_param_instantly(WTOscParam::NumVoices, Float::splat(f32::NAN), voice_mask);
+        assert_eq!(
+            params.num_voices.current,
+            splat_stereo(DEFAULT_PARAMS[WTOscParam::NumVoices as usize])
+        );
+
+        params.set_param_instantly(WTOscParam::Detune, Float::splat(1.5), voice_mask);
+        assert_eq!(params.detune.current, Float::splat(1.));
+
+        params.set_param_instantly(WTOscParam::Frame, Float::splat(-0.5), voice_mask);
+        assert_eq!(params.frame.current, Float::splat(0.));
+    }
+
+    #[test]
+    pub fn set_all_params_snaps_weights() {
+        const CLUSTER_IDX: usize = 0;
+        const BUFFER_SIZE: usize = 64;
+
+        let voice_mask = TMask::splat(true);
+
+        let params = |level: f32| {
+            let mut values: Vec<_> = DEFAULT_PARAMS.iter().copied().map(splat_stereo).collect();
+            values[WTOscParam::Level as usize] = Float::splat(level);
+            ParamsList(Box::new([values.into_boxed_slice()]))
+        };
+
+        // renders a block at full level, then one after setting every parameter
+        let render = |level: f32| {
+            let mut osc = WTOsc::new(BandLimitedWaveTables::basic_shapes().into());
+            osc.initialize(44100., BUFFER_SIZE, 1);
+            osc.reset(CLUSTER_IDX, voice_mask);
+            osc.activate_voices(CLUSTER_IDX, voice_mask, Float::splat(1.), UInt::splat(60));
+            osc.set_all_params(CLUSTER_IDX, voice_mask, &params(1.));
+
+            let mut intermediate_buffers = Box::new([new_vfloat_buffer::<Float>(BUFFER_SIZE)]);
+
+            let mut process = |osc: &mut WTOsc| {
+                let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+                    .with_indices(&[], &[Some(OutputBufferIndex::Local(0))])
+                    .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+                osc.process(buffers, CLUSTER_IDX, voice_mask);
+                Cell::get_mut(intermediate_buffers[0].as_mut()).to_vec()
+            };
+
+            // past the voice fade in
+            for _ in 0..100 {
+                process(&mut osc);
+            }
+
+            osc.set_all_params(CLUSTER_IDX, voice_mask, &params(level));
+            process(&mut osc)
+        };
+
+        let full = render(1.);
+        let quiet = render(0.2);
+
+        let ratio = WTOscClusterNormParams::level_gain_from_norm(Float::splat(0.2))
+            / WTOscClusterNormParams::level_gain_from_norm(Float::splat(1.));
+
+        // from the very first sample
+        for (&full, &quiet) in full.iter().zip(&quiet) {
+            assert!((full * ratio - quiet).abs().reduce_max() < 1e-5);
+        }
+
+        assert!(full.iter().any(|sample| sample.abs().reduce_max() > 0.1));
+    }
+
+    #[test]
+    pub fn reset_reads_random_target() {
+        const CLUSTER_IDX: usize = 0;
+        let voice_mask = TMask::splat(true);
+
+        let mut osc = WTOsc::new(BandLimitedWaveTables::basic_shapes().into());
+        osc.initialize(44100., MAX_BUFFER_SIZE, 1);
+        osc.custom_event(&mut RetriggerMode::Random);
+
+        let params = &mut osc.params[CLUSTER_IDX];
+        params.set_param_instantly(WTOscParam::Random, Float::splat(1.), voice_mask);
+
+        // still fully random, according to the smoother
+        osc.set_param(
+            CLUSTER_IDX,
+            voice_mask,
+            WTOscParam::Random as u64,
+            Float::splat(0.),
+        );
+        osc.reset(CLUSTER_IDX, voice_mask);
+
+        for voice in osc.clusters[CLUSTER_IDX].voices_mut() {
+            for osc in voice {
+                assert_eq!(osc.phase(), UInt::splat(0));
+            }
+        }
+    }
+
+    #[test]
+    pub fn golden_ratio_phases() {
+        let params = [
+            // the first basic shape, a sine
+            (WTOscParam::Frame as u64, 0.),
+            (WTOscParam::NumVoices as u64, unison_count_to_normalized(16)),
+            (WTOscParam::Detune as u64, 0.5),
+            (WTOscParam::Stereo as u64, 0.),
+        ];
+
+        let first_sample = |mut mode: RetriggerMode| {
+            let mut osc = WTOsc::default().with_voice_fade_secs(0.);
+            osc.custom_event(&mut mode);
+            // at the sine's peak, unless the mode overrides them
+            osc.custom_event(&mut [0.25f32; MAX_UNISON]);
+
+            render::render_mono(&mut osc, 60., &params, 44100., 1)[0]
+        };
+
+        let aligned = first_sample(RetriggerMode::Retrigger);
+        let golden_ratio = first_sample(RetriggerMode::GoldenRatio);
+
+        assert!(aligned.abs() > 0.1, "{aligned}");
+        assert!(
+            golden_ratio.abs() < aligned.abs() / 8.,
+            "{golden_ratio} vs {aligned}"
+        );
+
+        // deterministic
+        assert_eq!(first_sample(RetriggerMode::GoldenRatio), golden_ratio);
+    }
+
+    #[test]
+    pub fn seeded_humanize() {
+        let render = |seed: u32, humanize: f32| {
+            let mut osc = WTOsc::default();
+            osc.custom_event(&mut RandomSeed(seed));
+
+            let params = [(WTOscParam::Humanize as u64, humanize)];
+            let output = render::render_mono(&mut osc, 69., &params, 44100., 2000);
+
+            let semitones = osc.params[0].humanize_semitones();
+            assert!(semitones.abs().reduce_max() <= MAX_HUMANIZE_CENTS / 100. * humanize);
+
+            output
+        };
+
+        assert_eq!(render(1, 1.), render(1, 1.));
+        assert_ne!(render(1, 1.), render(2, 1.));
+
+        // no offset at all, whatever was drawn
+        let unhumanized = render::render_mono(&mut WTOsc::default(), 69., &[], 44100., 2000);
+        assert_eq!(render(1, 0.), unhumanized);
+        assert_eq!(render(2, 0.), unhumanized);
+    }
+
+    #[test]
+    pub fn single_voice_masks() {
+        const CLUSTER_IDX: usize = 0;
+        const BUFFER_SIZE: usize = 64;
+
+        let params = ParamsList(Box::new([DEFAULT_PARAMS
+            .iter()
+            .copied()
+            .map(splat_stereo)
+            .collect()]));
+
+        // every voice index, on its own, whatever the vector width
+        for voice_idx in 0..STEREO_VOICES_PER_VECTOR {
+            let voice_mask = TMask::from_array(array::from_fn(|lane| lane / 2 == voice_idx));
+
+            let mut osc = WTOsc::new(BandLimitedWaveTables::basic_shapes().into());
+            osc.initialize(44100., BUFFER_SIZE, 1);
+            osc.reset(CLUSTER_IDX, voice_mask);
+            osc.activate_voices(CLUSTER_IDX, voice_mask, Float::splat(1.), UInt::splat(60));
+            osc.set_all_params(CLUSTER_IDX, voice_mask, &params);
+
+            assert!(osc.is_voice_active(CLUSTER_IDX, voice_idx));
+            assert_eq!(osc.active_voice_count(CLUSTER_IDX), 1);
+
+            let mut intermediate_buffers = Box::new([new_vfloat_buffer::<Float>(BUFFER_SIZE)]);
+
+            for _ in 0..8 {
+                let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+                    .with_indices(&[], &[Some(OutputBufferIndex::Local(0))])
+                    .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+                osc.process(buffers, CLUSTER_IDX, voice_mask);
+            }
+
+            let output = Cell::get_mut(intermediate_buffers[0].as_mut());
+            let voice_peak = output
+                .iter()
+                .map(|sample| split_stereo(sample)[voice_idx].abs().reduce_max())
+                .fold(0., f32::max);
+
+            assert!(output.iter().all(|sample| sample.is_finite().all()));
+            assert!(voice_peak > 0.1, "voice {voice_idx}");
+        }
+    }
+
+    #[test]
+    pub fn no_denormals() {
+        const CLUSTER_IDX: usize = 0;
+        const SR: f32 = 44100.;
+        const BUFFER_SIZE: usize = 64;
+
+        let voice_mask = TMask::splat(true);
+
+        let mut osc = WTOsc::new(BandLimitedWaveTables::basic_shapes().into());
+        osc.custom_event(&mut DcBlocker(true));
+        osc.initialize(SR, BUFFER_SIZE, 1);
+        osc.reset(CLUSTER_IDX, voice_mask);
+        osc.activate_voices(CLUSTER_IDX, voice_mask, Float::splat(1.), UInt::splat(60));
+
+        let params = ParamsList(Box::new([DEFAULT_PARAMS
+            .iter()
+            .copied()
+            .map(splat_stereo)
+            .collect()]));
+        osc.set_all_params(CLUSTER_IDX, voice_mask, &params);
+
+        let mut intermediate_buffers = Box::new([new_vfloat_buffer::<Float>(BUFFER_SIZE)]);
+        let num_blocks = |secs: f32| (secs * SR) as usize / BUFFER_SIZE;
+
+        let mut process = |osc: &mut WTOsc| {
+            let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+                .with_indices(&[], &[Some(OutputBufferIndex::Local(0))])
+                .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+            osc.process(buffers, CLUSTER_IDX, voice_mask);
+            Cell::get_mut(intermediate_buffers[0].as_mut()).to_vec()
+        };
+
+        for _ in 0..num_blocks(0.5) {
+            process(&mut osc);
+        }
+
+        // a long, exponential, fade to silence, then the DC blocker's tail
+        for param in [WTOscParam::Level, WTOscParam::Frame, WTOscParam::Detune] {
+            osc.set_param(CLUSTER_IDX, voice_mask, param as u64, Float::splat(0.));
+        }
+
+        for _ in 0..num_blocks(4.) {
+            let output = process(&mut osc);
+            assert!(output.iter().all(|sample| !sample.is_subnormal().any()));
+
+            for param in WTOscParam::ALL {
+                let smoother = osc.params[CLUSTER_IDX].get_param_smoother_mut(param);
+                let distance = smoother.target - smoother.current;
+
+                assert!(!smoother.current.is_subnormal().any(), "{param:?}");
+                assert!(!distance.is_subnormal().any(), "{param:?}");
+            }
+        }
+
+        // the level snapped to zero, well before the end
+        let output = process(&mut osc);
+        assert!(output.iter().all(|&sample| sample == Float::splat(0.)));
+    }
+
+    #[test]
+    pub fn sample_rate_change() {
+        const CLUSTER_IDX: usize = 0;
+        const BUFFER_SIZE: usize = 64;
+
+        let voice_mask = TMask::splat(true);
+
+        let mut osc = WTOsc::new(BandLimitedWaveTables::basic_shapes().into());
+        osc.initialize(44100., BUFFER_SIZE, 1);
+        osc.reset(CLUSTER_IDX, voice_mask);
+        osc.activate_voices(CLUSTER_IDX, voice_mask, Float::splat(1.), UInt::splat(69));
+
+        let mut values: Vec<_> = DEFAULT_PARAMS.iter().copied().map(splat_stereo).collect();
+        values[WTOscParam::Level as usize] = Float::splat(0.7);
+        let params = ParamsList(Box::new([values.into_boxed_slice()]));
+        osc.set_all_params(CLUSTER_IDX, voice_mask, &params);
+
+        let mut intermediate_buffers = Box::new([new_vfloat_buffer::<Float>(BUFFER_SIZE)]);
+
+        // in Hz, from the first oscillator's phase increment over a block, and the peak
+        let mut render = |osc: &mut WTOsc, sr: f32| {
+            let start = osc.clusters[CLUSTER_IDX].voices_mut()[0][0].phase();
+
+            let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+                .with_indices(&[], &[Some(OutputBufferIndex::Local(0))])
+                .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+            osc.process(buffers, CLUSTER_IDX, voice_mask);
+
+            let output = Cell::get_mut(intermediate_buffers[0].as_mut());
+            let peak = output
+                .iter()
+                .fold(0f32, |peak, sample| peak.max(sample.abs().reduce_max()));
+
+            let end = osc.clusters[CLUSTER_IDX].voices_mut()[0][0].phase();
+            (fxp_to_flp(end - start)[0] / BUFFER_SIZE as f32 * sr, peak)
+        };
+
+        for _ in 0..16 {
+            let (frequency, _) = render(&mut osc, 44100.);
+            assert!((frequency - 440.).abs() < 1e-2);
+        }
+
+        osc.initialize(48000., BUFFER_SIZE, 1);
+
+        // nothing was dropped, and the pitch is the same
+        assert!(osc.is_voice_active(CLUSTER_IDX, 0));
+        assert_eq!(
+            osc.active_voice_count(CLUSTER_IDX),
+            STEREO_VOICES_PER_VECTOR
+        );
+        assert_eq!(
+            osc.params[CLUSTER_IDX]
+                .get_param_smoother_mut(WTOscParam::Level)
+                .target,
+            WTOscClusterNormParams::level_gain_from_norm(Float::splat(0.7))
+        );
+
+        for _ in 0..16 {
+            let (frequency, peak) = render(&mut osc, 48000.);
+            assert!((frequency - 440.).abs() < 1e-2);
+            assert!(peak > 0.1);
+        }
+
+        let state = osc.voice_state(CLUSTER_IDX, 0).unwrap();
+        assert!((state.frequency - f32x2::splat(440.)).abs().reduce_max() < 1e-2);
+    }
+
+    #[test]
+    pub fn state_round_trip() {
+        let all_voices = TMask::splat(true);
+        let first_voice = TMask::from_array(array::from_fn(|i| i < 2));
+
+        let mut osc = WTOsc::default();
+        osc.initialize(44100., 64, 2);
+
+        osc.set_param(1, all_voices, WTOscParam::Frame as u64, Float::splat(0.3));
+        osc.set_param(
+            0,
+            first_voice,
+            WTOscParam::Detune as u64,
+            Float::splat(0.123),
+        );
+        osc.set_param(0, all_voices, WTOscParam::Level as u64, Float::splat(0.7));
+
+        let state = osc.save_state();
+        assert_eq!(state.clusters.len(), 2);
+
+        let mut loaded = WTOsc::default();
+        loaded.initialize(44100., 64, 2);
+        loaded.load_state(&state);
+
+        assert_eq!(loaded.save_state(), state);
+        // set instantly
+        assert_eq!(loaded.params[1].frame.current, Float::splat(0.3));
+        assert_eq!(loaded.params[0].detune.current[0], 0.123);
+        assert_eq!(loaded.params[0].detune.current[2], 0.);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    pub fn serde_round_trip() {
+        let table = BandLimitedWaveTables::basic_shapes();
+
+        let json: Box<BandLimitedWaveTables> =
+            serde_json::from_str(&serde_json::to_string(&*table).unwrap()).unwrap();
+        let binary: Box<BandLimitedWaveTables> =
+            bincode::deserialize(&bincode::serialize(&*table).unwrap()).unwrap();
+
+        for loaded in [json, binary] {
+            assert_eq!(loaded.num_frames(), table.num_frames());
+
+            for (a, b) in loaded.as_slice().iter().zip(table.as_slice()) {
+                let bits = |mipmaps: &[[f32; BandLimitedWaveTables::FRAME_LEN]]| {
+                    mipmaps.last().unwrap().map(f32::to_bits)
+                };
+
+                assert_eq!(bits(a), bits(b));
+            }
+        }
+
+        let short_frame = serde_json::from_str::<Box<BandLimitedWaveTables>>("[[0.0, 1.0]]");
+        assert!(short_frame.is_err());
+
+        let mut osc = WTOsc::default();
+        osc.initialize(44100., 64, 1);
+        osc.set_param(
+            0,
+            TMask::splat(true),
+            WTOscParam::Frame as u64,
+            Float::splat(0.3),
+        );
+
+        let state = osc.save_state();
+
+        let json: WTOscState =
+            serde_json::from_str(&serde_json::to_string(&state).unwrap()).unwrap();
+        let binary: WTOscState =
+            bincode::deserialize(&bincode::serialize(&state).unwrap()).unwrap();
+
+        assert_eq!(json, state);
+        assert_eq!(binary, state);
+    }
+
+    #[test]
+    pub fn saw_lfo() {
+        const SR: f32 = 1000.;
+        const BUFFER_SIZE: usize = 250;
+        const CLUSTER_IDX: usize = 0;
+
+        let voice_mask = TMask::splat(true);
+
+        let mut values: Vec<_> = DEFAULT_PARAMS.iter().copied().map(splat_stereo).collect();
+        // the third basic shape
+        values[WTOscParam::Frame as usize] = Float::splat(0.5);
+        let params = ParamsList(Box::new([values.into_boxed_slice()]));
+
+        let mut osc = WTOsc::default().with_voice_fade_secs(0.);
+        osc.custom_event(&mut Quality::Unfiltered);
+        osc.initialize(SR, BUFFER_SIZE, 1);
+
+        osc.reset(CLUSTER_IDX, voice_mask);
+        osc.activate_voices(CLUSTER_IDX, voice_mask, Float::splat(1.), UInt::splat(69));
+        osc.set_all_params(CLUSTER_IDX, voice_mask, &params);
+        osc.set_voice_frequency(CLUSTER_IDX, voice_mask, Float::splat(2.));
+
+        let mut intermediate_buffers = Box::new([new_vfloat_buffer::<Float>(BUFFER_SIZE)]);
+
+        let mut render = |osc: &mut WTOsc, num_buffers: usize| {
+            let mut output = Vec::new();
+
+            for _ in 0..num_buffers {
+                let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+                    .with_indices(&[], &[Some(OutputBufferIndex::Local(0))])
+                    .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+                osc.process(buffers, CLUSTER_IDX, voice_mask);
+                let block = Cell::get_mut(intermediate_buffers[0].as_mut());
+                output.extend(block.iter().map(|sample| sample[0]));
+            }
+
+            output
+        };
+
+        // two seconds, four cycles
+        let output = render(&mut osc, 8);
+        let peak = output.iter().copied().map(f32::abs).fold(0., f32::max);
+        assert!(peak > 0.);
+
+        // the ramp drops, through zero, once every 500 samples
+        let drops: Vec<_> = output
+            .windows(2)
+            .enumerate()
+            .filter(|(_, pair)| pair[0] >= 0. && pair[1] < 0. && pair[0] - pair[1] > peak / 2.)
+            .map(|(i, _)| i)
+            .collect();
+
+        assert_eq!(drops.len(), 4, "{drops:?}");
+        assert!(drops
+            .windows(2)
+            .all(|pair| (pair[1] - pair[0]).abs_diff(500) <= 1));
+
+        // retuning doesn't move voices set to a frequency
+        let phase_delta = osc.params[CLUSTER_IDX].phase_delta;
+        osc.set_a4_frequency(432.);
+        assert_eq!(osc.params[CLUSTER_IDX].phase_delta, phase_delta);
+
+        // 0 Hz, and invalid frequencies, hold the current sample
+        for hz in [0., -1., f32::NAN] {
+            osc.set_voice_frequency(CLUSTER_IDX, voice_mask, Float::splat(hz));
+
+            let held = render(&mut osc, 1);
+            assert!(held[0].is_finite());
+            assert!(held.iter().all(|&sample| sample == held[0]));
+        }
+    }
+
+    #[test]
+    pub fn legato_phase_continuity() {
+        const LEN: usize = 4410;
+
+        // a sine
+        let params = [(WTOscParam::Frame as u64, 0.)];
+
+        let mut osc = WTOsc::default();
+        let first = render::render_mono(&mut osc, 60., &params, 44100., LEN);
+
+        let phase = osc.voice_phase(0, 0);
+        osc.note_on_legato(0, TMask::splat(true), Float::splat(64.));
+        assert_eq!(osc.voice_phase(0, 0), phase);
+
+        let second = render::render_more(&mut osc, LEN);
+
+        let max_step = |samples: &[f32]| {
+            samples
+                .windows(2)
+                .map(|pair| (pair[1] - pair[0]).abs())
+                .fold(0., f32::max)
+        };
+
+        // no step across the note change is larger than those of the higher note
+        let joined: Vec<_> = first[LEN - 100..]
+            .iter()
+            .chain(&second[..100])
+            .copied()
+            .collect();
+        assert!(max_step(&joined) <= max_step(&second) * 1.01);
+
+        // a tenth of a second of E4, without glide
+        let rising_zero_crossings = second
+            .windows(2)
+            .filter(|pair| pair[0] < 0. && pair[1] >= 0.)
+            .count();
+
+        assert!((32..=33).contains(&rising_zero_crossings));
+    }
+
+    #[test]
+    pub fn detune_skew() {
+        const SR: f32 = 44100.;
+        const BUFFER_SIZE: usize = 441;
+        // skips the parameters' smoothing
+        const SKIP: usize = 4410;
+
+        let params = [
+            // the first basic shape, a sine
+            (WTOscParam::Frame as u64, 0.),
+            // a single voice pair, one voice detuned upwards in the left
+            // channel, the other one downwards in the right channel
+            (WTOscParam::NumVoices as u64, unison_count_to_normalized(2)),
+            (WTOscParam::Detune as u64, 1.),
+            (WTOscParam::DetuneSkew as u64, 1.),
+        ];
+
+        let mut osc = WTOsc::default();
+        render::render_mono(&mut osc, 69., &params, SR, 0);
+
+        let mut intermediate_buffers = Box::new([new_vfloat_buffer::<Float>(BUFFER_SIZE)]);
+        let (mut left, mut right) = (Vec::new(), Vec::new());
+
+        for _ in 0..100 {
+            let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+                .with_indices(&[], &[Some(OutputBufferIndex::Local(0))])
+                .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+            osc.process(buffers, 0, TMask::splat(true));
+
+            for sample in Cell::get_mut(intermediate_buffers[0].as_mut()).iter() {
+                left.push(sample[0]);
+                right.push(sample[1]);
+            }
+        }
+
+        // in Hz, from the (interpolated) rising zero crossings
+        let frequency = |samples: &[f32]| {
+            let crossings: Vec<_> = samples
+                .windows(2)
+                .enumerate()
+                .filter(|(_, pair)| pair[0] < 0. && pair[1] >= 0.)
+                .map(|(i, pair)| i as f32 + pair[0] / (pair[0] - pair[1]))
+                .collect();
+
+            let (first, last) = (crossings[0], crossings[crossings.len() - 1]);
+            (crossings.len() - 1) as f32 * SR / (last - first)
+        };
+
+        let left_semitones = 12. * (frequency(&left[SKIP..]) / 440.).log2();
+        let right_semitones = 12. * (frequency(&right[SKIP..]) / 440.).log2();
+
+        assert!(left_semitones > 0. && right_semitones < 0.);
+        assert!((left_semitones / -right_semitones - 1.2).abs() < 1e-2);
+    }
+
+    #[test]
+    pub fn frame_snap() {
+        // skips the parameters' smoothing
+        const SKIP: usize = 4410;
+        const LEN: usize = 1000;
+
+        let render = |snap: bool, norm_frame: f32| {
+            let mut osc = WTOsc::default();
+            osc.custom_event(&mut FrameSnap(snap));
+
+            let params = [(WTOscParam::Frame as u64, norm_frame)];
+            render::render_mono(&mut osc, 60., &params, 44100., SKIP + LEN).split_off(SKIP)
+        };
+
+        let osc = WTOsc::default();
+        let frame_1 = osc.frame_to_normalized(1);
+        let frame_2 = osc.frame_to_normalized(2);
+        assert_eq!(frame_1, 0.25);
+        assert_eq!(osc.frame_to_normalized(100), 0.75);
+
+        // the second frame, read as is
+        let triangle = render(true, frame_1);
+
+        // between frames, the nearest one is played, without blending it with its neighbour
+        let between = frame_1 + 0.4 / 4.;
+        assert_eq!(render(true, between), triangle);
+        assert_ne!(render(false, between), triangle);
+        assert_eq!(render(true, frame_2 - 0.4 / 4.), render(true, frame_2));
+        assert_ne!(render(true, frame_2), triangle);
+    }
+
+    #[test]
+    pub fn ping_pong_frame_scan() {
+        const SR: f32 = 44100.;
+        // 10 ms
+        const STEP: usize = 441;
+
+        let scan = |depth: f32| {
+            [
+                (WTOscParam::ScanRate as u64, (1. / MAX_SCAN_RATE).sqrt()),
+                (WTOscParam::ScanShape as u64, 0.5),
+                (WTOscParam::ScanDepth as u64, depth),
+            ]
+        };
+
+        let mut osc = WTOsc::default();
+        render::render_mono(&mut osc, 60., &scan(1.), SR, 0);
+
+        // two seconds, in frames
+        let frames: Vec<_> = (0..200)
+            .map(|_| {
+                render::render_more(&mut osc, STEP);
+                osc.voice_phase(0, 0).unwrap().1
+            })
+            .collect();
+
+        let peak = frames.iter().copied().fold(0., f32::max);
+        assert!(peak > 3.9);
+
+        // rises for half a second, then falls back for the next half
+        for half_period in frames[1..].chunks(50) {
+            let (first, last) = (half_period[0], half_period[half_period.len() - 1]);
+            assert!((first - last).abs() > 3., "{first} {last}");
+        }
+
+        assert!(frames[5..45].windows(2).all(|pair| pair[1] > pair[0]));
+        assert!(frames[55..95].windows(2).all(|pair| pair[1] < pair[0]));
+        assert!(frames[105..145].windows(2).all(|pair| pair[1] > pair[0]));
+
+        // no scan at all, at zero depth
+        let unscanned = render::render_mono(&mut WTOsc::default(), 60., &[], SR, 4410);
+        let at_zero_depth = render::render_mono(&mut WTOsc::default(), 60., &scan(0.), SR, 4410);
+        assert_eq!(at_zero_depth, unscanned);
+    }
+
+    #[test]
+    pub fn per_voice_outputs() {
+        const BUFFER_SIZE: usize = 441;
+
+        let mut osc = WTOsc::default().with_output_layout(OutputLayout::PerVoice);
+        assert_eq!(osc.audio_io_layout(), (1, STEREO_VOICES_PER_VECTOR));
+        osc.initialize(44100., BUFFER_SIZE, 1);
+
+        // A3 in the first voice, A4 in the second one, both sines
+        let voice_mask = TMask::from_array(array::from_fn(|i| i < 4));
+        let notes = Float::from_array(array::from_fn(|i| if i < 2 { 57. } else { 69. }));
+
+        osc.reset(0, voice_mask);
+        osc.activate_voices_fractional(0, voice_mask, Float::splat(1.), notes);
+
+        let mut intermediate_buffers = Box::new([
+            new_vfloat_buffer::<Float>(BUFFER_SIZE),
+            new_vfloat_buffer::<Float>(BUFFER_SIZE),
+        ]);
+        let mut outputs = [Vec::new(), Vec::new()];
+
+        for i in 0..11 {
+            let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+                .with_indices(
+                    &[],
+                    &[
+                        Some(OutputBufferIndex::Local(0)),
+                        Some(OutputBufferIndex::Local(1)),
+                    ],
+                )
+                .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+            osc.process(buffers, 0, voice_mask);
+
+            // skips the voices' fade in
+            if i == 0 {
+                continue;
+            }
+
+            for (output, buffer) in outputs.iter_mut().zip(intermediate_buffers.iter_mut()) {
+                let block = Cell::get_mut(buffer.as_mut());
+
+                assert!(block
+                    .iter()
+                    .all(|sample| sample.as_array()[2..].iter().all(|&s| s == 0.)));
+
+                output.extend(block.iter().map(|sample| sample[0]));
+            }
+        }
+
+        // 10 Hz bins, each output only holds its own voice's pitch
+        for (output, (own, other)) in outputs.iter().zip([(22, 44), (44, 22)]) {
+            let bins = spectrum(output);
+            assert!(bins[own].norm() > 1000. * bins[other].norm());
+        }
+
+        // the previous block's samples aren't copied out again
+        osc.custom_event(&mut Arc::<BandLimitedWaveTables>::from(
+            BandLimitedWaveTables::empty(),
+        ));
+
+        let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+            .with_indices(
+                &[],
+                &[
+                    Some(OutputBufferIndex::Local(0)),
+                    Some(OutputBufferIndex::Local(1)),
+                ],
+            )
+            .with_buffer_pos(0, NonZeroUsize::new(BUFFER_SIZE).unwrap());
+
+        osc.process(buffers, 0, voice_mask);
+
+        for buffer in intermediate_buffers.iter_mut() {
+            let block = Cell::get_mut(buffer.as_mut());
+            assert!(block.iter().all(|sample| *sample == Float::splat(0.)));
+        }
+    }
+
+    #[test]
+    pub fn sine_feedback() {
+        // in radians
+        const BETA: f32 = 1.;
+
+        assert_eq!(
+            render_sine(&[(WTOscParam::Feedback as u64, 0.)]),
+            render_sine(&[])
+        );
+
+        let feedback = BETA / (TAU * MAX_FEEDBACK);
+        let harmonic = sine_harmonics(&[(WTOscParam::Feedback as u64, feedback)]);
+
+        // Bessel function of the first kind, of integer order
+        let bessel_j = |n: i32, x: f32| {
+            let mut term = (x / 2.).powi(n) / (1..=n).product::<i32>() as f32;
+            let mut sum = term;
+
+            for k in 1..32 {
+                term *= -(x / 2.).powi(2) / (k * (k + n)) as f32;
+                sum += term;
+            }
+
+            sum
+        };
+
+        // `y = sin(θ + β y)` has harmonics of amplitude `2 J_k(k β) / (k β)`, the
+        // feedback's one sample delay only slightly lowers them at this pitch
+        let expected = |k: i32| (2. * bessel_j(k, k as f32 * BETA) / (k as f32 * BETA)).abs();
+
+        for k in 2..=3 {
+            let ratio = harmonic(k) / harmonic(1);
+            let expected_ratio = expected(k as i32) / expected(1);
+
+            assert!(
+                (ratio / expected_ratio - 1.).abs() < 0.05,
+                "harmonic {k}: {ratio}, expected {expected_ratio}"
+            );
+        }
+    }
+
+    #[test]
+    pub fn sine_ring_mod() {
+        let ring = |ratio: f32, mix: f32| {
+            [
+                (WTOscParam::RingRatio as u64, ratio),
+                (WTOscParam::RingMix as u64, mix),
+            ]
+        };
+
+        // any ratio, fully dry
+        assert_eq!(render_sine(&ring(0.5, 0.)), render_sine(&ring(0.7, 0.)));
+        assert_eq!(render_sine(&ring(0.7, 0.)), render_sine(&[]));
+
+        // a sine times itself, `sin²(θ) = (1 - cos(2θ)) / 2`
+        let harmonic = sine_harmonics(&ring(0.5, 1.));
+
+        assert!((harmonic(2) / harmonic(0) - 0.5).abs() < 1e-3);
+        assert!(harmonic(1) / harmonic(0) < 1e-3);
+        assert!(harmonic(3) / harmonic(0) < 1e-3);
+    }
+
+    #[test]
+    pub fn sine_wavefold() {
+        let fold = |depth: f32| [(WTOscParam::Fold as u64, depth)];
+
+        assert_eq!(render_sine(&fold(0.)), render_sine(&[]));
+
+        // peaking at 2.75, folded back once on each side
+        let folded = fold(0.25);
+
+        assert!(render_sine(&folded).iter().all(|sample| sample.abs() <= 1.));
+
+        let harmonic = sine_harmonics(&folded);
+
+        // half-wave symmetric, like the sine
+        for k in [2, 4, 6] {
+            assert!(harmonic(k) / harmonic(1) < 1e-3);
+        }
+
+        for k in [3, 5, 7] {
+            assert!(harmonic(k) / harmonic(1) > 0.1);
+        }
+    }
+
+    #[test]
+    pub fn seeded_noise() {
+        const LEN: usize = 4410;
+
+        let render = |seed: u32, level: f32| {
+            let mut osc = WTOsc::default();
+            osc.custom_event(&mut RandomSeed(seed));
+
+            let params = [(WTOscParam::NoiseLevel as u64, level)];
+            render::render_mono(&mut osc, 69., &params, 44100., LEN)
+        };
+
+        assert_eq!(render(1, 0.5), render(1, 0.5));
+        assert_ne!(render(1, 0.5), render(2, 0.5));
+
+        // no noise at all, whatever the seed
+        let clean = render::render_mono(&mut WTOsc::default(), 69., &[], 44100., LEN);
+        assert_eq!(render(1, 0.), clean);
+        assert_eq!(render(2, 0.), clean);
+
+        // white, decorrelated from one sample to the next
+        let noise: Vec<_> = render(1, 0.5)
+            .iter()
+            .zip(&clean)
+            .map(|(noisy, clean)| noisy - clean)
+            .collect();
+
+        let energy: f32 = noise.iter().map(|x| x * x).sum();
+        let correlation: f32 = noise.windows(2).map(|pair| pair[0] * pair[1]).sum();
+
+        assert!(energy > 0.);
+        assert!((correlation / energy).abs() < 0.1);
+    }
 
     #[test]
     pub fn test() {
@@ -287,7 +6343,7 @@ mod tests {
         osc.initialize(44100., MAX_BUFFER_SIZE, 1);
         let voice_mask = TMask::splat(true);
 
-        let mut wt = Box::<BandLimitedWaveTables>::from(basic_shapes::WAVETABLES.as_slice());
+        let mut wt: Arc<BandLimitedWaveTables> = BandLimitedWaveTables::basic_shapes().into();
         osc.custom_event(&mut wt);
 
         let mut starting_phases = [0.0; MAX_UNISON];
@@ -0,0 +1,477 @@
+use super::*;
+use cluster::FrameScanShape;
+use voice::DetuneCurve;
+
+/// Host-facing description of a parameter, matching what
+/// `WTOscClusterNormParams` does with its normalized values
+pub struct ParamInfo {
+    pub id: u64,
+    pub name: &'static str,
+    pub unit: &'static str,
+    to_string: fn(f32) -> String,
+    from_string: fn(&str) -> Option<f32>,
+}
+
+impl ParamInfo {
+    /// Normalized, see `DEFAULT_PARAMS`
+    #[inline]
+    pub fn default(&self) -> f32 {
+        DEFAULT_PARAMS[self.id as usize][0]
+    }
+
+    #[inline]
+    pub fn normalized_to_string(&self, norm_val: f32) -> String {
+        (self.to_string)(norm_val)
+    }
+
+    /// Accepts the strings returned by `Self::normalized_to_string`, with or without the unit
+    #[inline]
+    pub fn string_to_normalized(&self, string: &str) -> Option<f32> {
+        (self.from_string)(string.trim())
+    }
+}
+
+/// Indexed by parameter id
+pub static PARAMS: [ParamInfo; NUM_PARAMS as usize] = [
+    info(
+        WTOscParam::Level,
+        "Level",
+        "dB",
+        level_to_string,
+        string_to_level,
+    ),
+    info(
+        WTOscParam::Frame,
+        "Frame",
+        "%",
+        percent_to_string,
+        string_to_percent,
+    ),
+    info(
+        WTOscParam::NumVoices,
+        "Unison",
+        "voices",
+        num_voices_to_string,
+        string_to_num_voices,
+    ),
+    info(
+        WTOscParam::Detune,
+        "Detune",
+        "%",
+        percent_to_string,
+        string_to_percent,
+    ),
+    info(
+        WTOscParam::Pan,
+        "Pan",
+        "%",
+        percent_to_string,
+        string_to_percent,
+    ),
+    info(
+        WTOscParam::Transpose,
+        "Transpose",
+        "st",
+        format_transpose,
+        string_to_transpose,
+    ),
+    info(
+        WTOscParam::Stereo,
+        "Stereo",
+        "%",
+        percent_to_string,
+        string_to_percent,
+    ),
+    info(
+        WTOscParam::DetuneRange,
+        "Detune Range",
+        "st",
+        detune_range_to_string,
+        string_to_detune_range,
+    ),
+    info(
+        WTOscParam::Random,
+        "Random",
+        "%",
+        percent_to_string,
+        string_to_percent,
+    ),
+    info(
+        WTOscParam::FrameSpread,
+        "Frame Spread",
+        "%",
+        percent_to_string,
+        string_to_percent,
+    ),
+    info(
+        WTOscParam::UnisonStack,
+        "Unison Stack",
+        "",
+        unison_stack_to_string,
+        string_to_unison_stack,
+    ),
+    info(
+        WTOscParam::PmAmount,
+        "Phase Modulation",
+        "%",
+        percent_to_string,
+        string_to_percent,
+    ),
+    info(
+        WTOscParam::Sync,
+        "Sync",
+        "x",
+        sync_to_string,
+        string_to_sync,
+    ),
+    info(
+        WTOscParam::DetuneCurve,
+        "Detune Curve",
+        "",
+        detune_curve_to_string,
+        string_to_detune_curve,
+    ),
+    info(
+        WTOscParam::Blend,
+        "Blend",
+        "%",
+        percent_to_string,
+        string_to_percent,
+    ),
+    info(
+        WTOscParam::Drift,
+        "Drift",
+        "ct",
+        drift_to_string,
+        string_to_drift,
+    ),
+    info(
+        WTOscParam::Glide,
+        "Glide",
+        "ms",
+        glide_to_string,
+        string_to_glide,
+    ),
+    info(
+        WTOscParam::VelocityLevel,
+        "Velocity > Level",
+        "%",
+        percent_to_string,
+        string_to_percent,
+    ),
+    info(
+        WTOscParam::VelocityFrame,
+        "Velocity > Frame",
+        "%",
+        percent_to_string,
+        string_to_percent,
+    ),
+    info(
+        WTOscParam::PressureFrame,
+        "Pressure > Frame",
+        "%",
+        percent_to_string,
+        string_to_percent,
+    ),
+    info(
+        WTOscParam::SlideLevel,
+        "Slide > Level",
+        "%",
+        percent_to_string,
+        string_to_percent,
+    ),
+    info(
+        WTOscParam::TableSelect,
+        "Table",
+        "%",
+        percent_to_string,
+        string_to_percent,
+    ),
+    info(
+        WTOscParam::Drive,
+        "Drive",
+        "dB",
+        drive_to_string,
+        string_to_drive,
+    ),
+    info(
+        WTOscParam::Humanize,
+        "Humanize",
+        "ct",
+        humanize_to_string,
+        string_to_humanize,
+    ),
+    info(
+        WTOscParam::DetuneSkew,
+        "Detune Skew",
+        "%",
+        percent_to_string,
+        string_to_percent,
+    ),
+    info(
+        WTOscParam::ScanRate,
+        "Scan Rate",
+        "Hz",
+        scan_rate_to_string,
+        string_to_scan_rate,
+    ),
+    info(
+        WTOscParam::ScanShape,
+        "Scan Shape",
+        "",
+        scan_shape_to_string,
+        string_to_scan_shape,
+    ),
+    info(
+        WTOscParam::ScanDepth,
+        "Scan Depth",
+        "%",
+        percent_to_string,
+        string_to_percent,
+    ),
+    info(
+        WTOscParam::Feedback,
+        "Feedback",
+        "%",
+        percent_to_string,
+        string_to_percent,
+    ),
+    info(
+        WTOscParam::RingRatio,
+        "Ring Ratio",
+        "",
+        ring_ratio_to_string,
+        string_to_ring_ratio,
+    ),
+    info(
+        WTOscParam::RingMix,
+        "Ring Mix",
+        "%",
+        percent_to_string,
+        string_to_percent,
+    ),
+    info(
+        WTOscParam::Fold,
+        "Fold",
+        "%",
+        percent_to_string,
+        string_to_percent,
+    ),
+    info(
+        WTOscParam::NoiseLevel,
+        "Noise",
+        "%",
+        percent_to_string,
+        string_to_percent,
+    ),
+];
+
+const fn info(
+    param: WTOscParam,
+    name: &'static str,
+    unit: &'static str,
+    to_string: fn(f32) -> String,
+    from_string: fn(&str) -> Option<f32>,
+) -> ParamInfo {
+    ParamInfo {
+        id: param as u64,
+        name,
+        unit,
+        to_string,
+        from_string,
+    }
+}
+
+const UNISON_STACK_NAMES: [&str; UNISON_STACK_RATIOS.len()] =
+    ["Off", "+1 oct", "-1 oct", "Fifth", "Oct + fifth"];
+
+const DETUNE_CURVE_NAMES: [&str; DetuneCurve::ALL.len()] =
+    ["Linear", "Exponential", "Outer weighted"];
+
+const SCAN_SHAPE_NAMES: [&str; FrameScanShape::ALL.len()] = ["Forward", "Ping-pong", "Random walk"];
+
+/// Parses a number, optionally followed by `unit`
+fn parse_with_unit(string: &str, unit: &str) -> Option<f32> {
+    let string = string.strip_suffix(unit).unwrap_or(string);
+    string.trim().parse().ok()
+}
+
+fn choice_to_norm(names: &[&str], string: &str) -> Option<f32> {
+    let last = (names.len() - 1) as f32;
+
+    names
+        .iter()
+        .position(|name| name.eq_ignore_ascii_case(string))
+        .map(|i| i as f32 / last)
+}
+
+fn norm_to_choice(names: &[&'static str], norm_val: f32) -> String {
+    let last = names.len() - 1;
+    names[((norm_val * last as f32).round() as usize).min(last)].into()
+}
+
+fn percent_to_string(norm_val: f32) -> String {
+    format!("{:.1}%", norm_val * 100.)
+}
+
+fn string_to_percent(string: &str) -> Option<f32> {
+    parse_with_unit(string, "%").map(|percent| (percent / 100.).clamp(0., 1.))
+}
+
+/// See `WTOscClusterNormParams::level_db_from_norm`
+fn level_to_string(norm_val: f32) -> String {
+    format!(
+        "{:.1} dB",
+        WTOscClusterNormParams::level_db_from_norm(norm_val)
+    )
+}
+
+fn string_to_level(string: &str) -> Option<f32> {
+    parse_with_unit(string, "dB").map(WTOscClusterNormParams::level_norm_from_db)
+}
+
+/// Always displays the voice count that's actually heard, see `VoiceParams::new_unchecked`
+fn num_voices_to_string(norm_val: f32) -> String {
+    match normalized_to_unison_count(norm_val) {
+        1 => "1 voice".into(),
+        n => format!("{n} voices"),
+    }
+}
+
+fn string_to_num_voices(string: &str) -> Option<f32> {
+    let string = string
+        .strip_suffix("voices")
+        .or_else(|| string.strip_suffix("voice"));
+    let num_voices: u32 = string?.trim().parse().ok()?;
+
+    (1..=MAX_UNISON as u32)
+        .contains(&num_voices)
+        .then(|| unison_count_to_normalized(num_voices))
+}
+
+fn string_to_transpose(string: &str) -> Option<f32> {
+    parse_with_unit(string, "st").map(|semitones| {
+        (semitones / WTOscClusterNormParams::TRANSPOSE_RANGE_SEMITONES)
+            .mul_add(0.5, 0.5)
+            .clamp(0., 1.)
+    })
+}
+
+fn detune_range_to_string(norm_val: f32) -> String {
+    format!("{:.2} st", norm_val * PITCH_RANGE_SEMITONES)
+}
+
+fn string_to_detune_range(string: &str) -> Option<f32> {
+    parse_with_unit(string, "st").map(|semitones| (semitones / PITCH_RANGE_SEMITONES).clamp(0., 1.))
+}
+
+fn unison_stack_to_string(norm_val: f32) -> String {
+    norm_to_choice(&UNISON_STACK_NAMES, norm_val)
+}
+
+fn string_to_unison_stack(string: &str) -> Option<f32> {
+    choice_to_norm(&UNISON_STACK_NAMES, string)
+}
+
+fn sync_to_string(norm_val: f32) -> String {
+    format!("x{:.2}", norm_val.mul_add(MAX_SYNC_RATIO - 1., 1.))
+}
+
+fn string_to_sync(string: &str) -> Option<f32> {
+    let ratio: f32 = string
+        .strip_prefix('x')
+        .unwrap_or(string)
+        .trim()
+        .parse()
+        .ok()?;
+    Some(((ratio - 1.) / (MAX_SYNC_RATIO - 1.)).clamp(0., 1.))
+}
+
+/// See `WTOscClusterNormParams::ring_ratio`
+fn ring_ratio_to_string(norm_val: f32) -> String {
+    format!(
+        "x{:.3}",
+        (norm_val.mul_add(2., -1.) * RING_RATIO_OCTAVES).exp2()
+    )
+}
+
+fn string_to_ring_ratio(string: &str) -> Option<f32> {
+    let ratio: f32 = string
+        .strip_prefix('x')
+        .unwrap_or(string)
+        .trim()
+        .parse()
+        .ok()?;
+    (ratio > 0.).then(|| {
+        (ratio.log2() / RING_RATIO_OCTAVES)
+            .mul_add(0.5, 0.5)
+            .clamp(0., 1.)
+    })
+}
+
+fn detune_curve_to_string(norm_val: f32) -> String {
+    norm_to_choice(&DETUNE_CURVE_NAMES, norm_val)
+}
+
+fn string_to_detune_curve(string: &str) -> Option<f32> {
+    choice_to_norm(&DETUNE_CURVE_NAMES, string)
+}
+
+/// In Hz, cycles per beat while a `Tempo` is set
+fn scan_rate_to_string(norm_val: f32) -> String {
+    format!("{:.2} Hz", norm_val * norm_val * MAX_SCAN_RATE)
+}
+
+fn string_to_scan_rate(string: &str) -> Option<f32> {
+    parse_with_unit(string, "Hz").map(|rate| (rate / MAX_SCAN_RATE).clamp(0., 1.).sqrt())
+}
+
+fn scan_shape_to_string(norm_val: f32) -> String {
+    norm_to_choice(&SCAN_SHAPE_NAMES, norm_val)
+}
+
+fn string_to_scan_shape(string: &str) -> Option<f32> {
+    choice_to_norm(&SCAN_SHAPE_NAMES, string)
+}
+
+fn drift_to_string(norm_val: f32) -> String {
+    format!("{:.1} ct", norm_val * MAX_DRIFT_CENTS)
+}
+
+fn string_to_drift(string: &str) -> Option<f32> {
+    parse_with_unit(string, "ct").map(|cents| (cents / MAX_DRIFT_CENTS).clamp(0., 1.))
+}
+
+fn humanize_to_string(norm_val: f32) -> String {
+    format!("{:.1} ct", norm_val * MAX_HUMANIZE_CENTS)
+}
+
+fn string_to_humanize(string: &str) -> Option<f32> {
+    parse_with_unit(string, "ct").map(|cents| (cents / MAX_HUMANIZE_CENTS).clamp(0., 1.))
+}
+
+/// All the way down is "Off", not 0 dB, see `WTOscClusterNormParams::drive_gain`
+fn drive_to_string(norm_val: f32) -> String {
+    if norm_val <= 0. {
+        "Off".into()
+    } else {
+        format!("{:.1} dB", norm_val * MAX_DRIVE_DB)
+    }
+}
+
+fn string_to_drive(string: &str) -> Option<f32> {
+    if string.eq_ignore_ascii_case("off") {
+        return Some(0.);
+    }
+
+    parse_with_unit(string, "dB").map(|db| (db / MAX_DRIVE_DB).clamp(0., 1.))
+}
+
+/// The glide parameter is quadratic, see `WTOscClusterNormParams::glide_secs`
+fn glide_to_string(norm_val: f32) -> String {
+    format!("{:.1} ms", norm_val * norm_val * MAX_GLIDE_SECS * 1000.)
+}
+
+fn string_to_glide(string: &str) -> Option<f32> {
+    parse_with_unit(string, "ms").map(|ms| (ms / 1000. / MAX_GLIDE_SECS).clamp(0., 1.).sqrt())
+}
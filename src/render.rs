@@ -0,0 +1,71 @@
+use super::*;
+use alloc::{boxed::Box, vec::Vec};
+use polygraph::{
+    buffer::{BufferHandleLocal, OutputBufferIndex},
+    processor::{new_vfloat_buffer, ParamsList},
+};
+
+const BLOCK_SIZE: usize = 64;
+const CLUSTER_IDX: usize = 0;
+
+/// Renders a single note, offline, and returns the left channel of the first voice.
+///
+/// `osc` is initialized, and its first voices reset, `params` are `(id, normalized
+/// value)` pairs overriding `DEFAULT_PARAMS`, unknown ids are ignored. This is also the
+/// sequence of calls needed to get sound out of a `WTOsc` in a realtime context
+pub fn render_mono(
+    osc: &mut WTOsc,
+    note: f32,
+    params: &[(u64, f32)],
+    sr: f32,
+    num_samples: usize,
+) -> Vec<f32> {
+    let voice_mask = TMask::splat(true);
+
+    osc.initialize(sr, BLOCK_SIZE, 1);
+
+    let mut values: Vec<_> = DEFAULT_PARAMS.iter().copied().map(splat_stereo).collect();
+
+    for &(id, norm_val) in params {
+        if let Some(value) = values.get_mut(id as usize) {
+            *value = Float::splat(norm_val);
+        }
+    }
+
+    let params = ParamsList(Box::new([values.into_boxed_slice()]));
+
+    osc.reset(CLUSTER_IDX, voice_mask);
+    osc.activate_voices_fractional(
+        CLUSTER_IDX,
+        voice_mask,
+        Float::splat(1.),
+        Float::splat(note),
+    );
+    osc.set_all_params(CLUSTER_IDX, voice_mask, &params);
+
+    render_more(osc, num_samples)
+}
+
+/// Keeps rendering the voices started by `render_mono`, e. g. after
+/// changing their notes, or parameters, in between
+pub fn render_more(osc: &mut WTOsc, num_samples: usize) -> Vec<f32> {
+    let voice_mask = TMask::splat(true);
+
+    let mut intermediate_buffers = Box::new([new_vfloat_buffer::<Float>(BLOCK_SIZE)]);
+    let mut output = Vec::with_capacity(num_samples);
+
+    while output.len() < num_samples {
+        let block_size = (num_samples - output.len()).min(BLOCK_SIZE);
+
+        let buffers = BufferHandleLocal::toplevel(intermediate_buffers.as_mut())
+            .with_indices(&[], &[Some(OutputBufferIndex::Local(0))])
+            .with_buffer_pos(0, NonZeroUsize::new(block_size).unwrap());
+
+        osc.process(buffers, CLUSTER_IDX, voice_mask);
+
+        let block = Cell::get_mut(intermediate_buffers[0].as_mut());
+        output.extend(block[..block_size].iter().map(|sample| sample[0]));
+    }
+
+    output
+}
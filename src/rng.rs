@@ -0,0 +1,105 @@
+use super::*;
+
+/// A tiny, lane-wise, seedable pseudo-random number generator (a PCG-style LCG),
+/// cheap enough to be used on the audio thread
+#[derive(Clone, Copy)]
+pub struct Rng {
+    state: UInt,
+}
+
+impl Default for Rng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl Rng {
+    #[inline]
+    pub fn new(seed: u32) -> Self {
+        let mut this = Self {
+            state: UInt::splat(0),
+        };
+        this.seed(seed);
+        this
+    }
+
+    /// Every lane gets a different sequence, for the same seed
+    #[inline]
+    pub fn seed(&mut self, seed: u32) {
+        let lanes = UInt::from_array(array::from_fn(|i| i as u32));
+        self.state = Self::mix(UInt::splat(seed) ^ Self::mix(lanes + UInt::splat(1)));
+    }
+
+    #[inline]
+    fn mix(mut x: UInt) -> UInt {
+        x ^= x >> UInt::splat(16);
+        x *= UInt::splat(0x45D9F3B);
+        x ^= x >> UInt::splat(16);
+        x *= UInt::splat(0x45D9F3B);
+        x ^ (x >> UInt::splat(16))
+    }
+
+    #[inline]
+    pub fn next_u32(&mut self) -> UInt {
+        // integer simd arithmetic wraps around
+        self.state = self.state * UInt::splat(1664525) + UInt::splat(1013904223);
+        Self::mix(self.state)
+    }
+
+    /// Uniformly distributed in `[0 ; 1)`
+    #[inline]
+    pub fn next_f32(&mut self) -> Float {
+        fxp_to_flp(self.next_u32())
+    }
+}
+
+/// Lane-wise xorshift32, fewer operations per number than `Rng`, for audio rate noise.
+/// Lower quality, but that can't be heard
+#[derive(Clone, Copy)]
+pub struct XorShift32 {
+    state: UInt,
+}
+
+impl Default for XorShift32 {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl XorShift32 {
+    #[inline]
+    pub fn new(seed: u32) -> Self {
+        let mut this = Self {
+            state: UInt::splat(1),
+        };
+        this.seed(seed);
+        this
+    }
+
+    /// Every lane gets a different sequence, for the same seed
+    #[inline]
+    pub fn seed(&mut self, seed: u32) {
+        let state = Rng::new(seed).next_u32();
+
+        // zero is xorshift's only fixed point
+        self.state = state
+            .simd_eq(UInt::splat(0))
+            .select(UInt::splat(0x9E37_79B9), state);
+    }
+
+    #[inline]
+    pub fn next_u32(&mut self) -> UInt {
+        let mut x = self.state;
+        x ^= x << UInt::splat(13);
+        x ^= x >> UInt::splat(17);
+        x ^= x << UInt::splat(5);
+        self.state = x;
+        x
+    }
+
+    /// Uniformly distributed in `[-1 ; 1)`
+    #[inline]
+    pub fn next_bipolar(&mut self) -> Float {
+        fxp_to_flp(self.next_u32()).mul_add(Simd::splat(2.), Simd::splat(-1.))
+    }
+}
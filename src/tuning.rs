@@ -0,0 +1,383 @@
+use super::*;
+use std::{error, fmt, fs, io, path::Path, str};
+
+pub const NUM_NOTES: usize = 128;
+
+/// Frequencies, in Hz, of every MIDI note, send an `Option<TuningTable>` through
+/// `WTOsc::custom_event` to install one, or `None` to go back to 12-TET
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TuningTable {
+    pub frequencies: [f64; NUM_NOTES],
+}
+
+impl Default for TuningTable {
+    fn default() -> Self {
+        Self::equal_temperament(440.)
+    }
+}
+
+impl TuningTable {
+    /// 12-TET, with MIDI note 69 at `a4_frequency`
+    pub fn equal_temperament(a4_frequency: f64) -> Self {
+        Self {
+            frequencies: array::from_fn(|note| a4_frequency * ((note as f64 - 69.) / 12.).exp2()),
+        }
+    }
+
+    /// `cents[n]` is how far MIDI note `n` is from its 12-TET pitch
+    pub fn from_cents_offsets(a4_frequency: f64, cents: &[f64; NUM_NOTES]) -> Self {
+        let mut this = Self::equal_temperament(a4_frequency);
+
+        for (frequency, &offset) in this.frequencies.iter_mut().zip(cents) {
+            *frequency *= (offset / 1200.).exp2();
+        }
+
+        this
+    }
+
+    /// Fractional notes are interpolated exponentially between the adjacent
+    /// entries, notes outside of the table are clamped
+    #[inline]
+    pub fn frequency(&self, note: f32) -> f32 {
+        let note = (note as f64).clamp(0., (NUM_NOTES - 1) as f64);
+        let lower = note.floor() as usize;
+        let upper = (lower + 1).min(NUM_NOTES - 1);
+
+        let (f0, f1) = (self.frequencies[lower], self.frequencies[upper]);
+
+        (f0 * (f1 / f0).powf(note.fract())) as f32
+    }
+
+    /// Unmapped keys get the frequency of the closest mapped key below them, or
+    /// above them, if there's none below
+    pub fn from_scala(scale: &Scale, mapping: &KeyboardMapping) -> Result<Self, TuningError> {
+        if scale.is_empty() {
+            return Err(TuningError::EmptyScale);
+        }
+
+        let reference_frequency = mapping.reference_frequency;
+
+        if !(reference_frequency.is_finite() && reference_frequency > 0.) {
+            return Err(TuningError::InvalidReferenceFrequency(reference_frequency));
+        }
+
+        let reference_degree = mapping
+            .degree(scale, mapping.reference_note)?
+            .ok_or(TuningError::UnmappedReference(mapping.reference_note))?;
+
+        let reference_cents = scale.cents(reference_degree);
+
+        let mut frequencies = [None; NUM_NOTES];
+
+        for (note, frequency) in (0..).zip(frequencies.iter_mut()) {
+            *frequency = mapping.degree(scale, note)?.map(|degree| {
+                let cents = scale.cents(degree) - reference_cents;
+                mapping.reference_frequency * (cents / 1200.).exp2()
+            });
+        }
+
+        let first_mapped = frequencies.iter().flatten().copied().next();
+        let mut previous = first_mapped.unwrap_or(mapping.reference_frequency);
+
+        Ok(Self {
+            frequencies: frequencies.map(|frequency| {
+                previous = frequency.unwrap_or(previous);
+                previous
+            }),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum TuningError {
+    Io(io::Error),
+    /// A line of a `.scl` or `.kbm` file couldn't be parsed, `line` starts at 1
+    Syntax {
+        line: usize,
+        reason: &'static str,
+    },
+    /// The file ended before all of its fields were read
+    UnexpectedEnd,
+    /// The keyboard mapping's reference note isn't mapped to a scale degree
+    UnmappedReference(u8),
+    /// A mapped scale degree, or the formal octave, isn't in the scale
+    DegreeOutOfRange {
+        degree: usize,
+        scale_len: usize,
+    },
+    /// The scale has no degrees
+    EmptyScale,
+    /// The keyboard mapping's reference frequency isn't finite and positive
+    InvalidReferenceFrequency(f64),
+}
+
+impl fmt::Display for TuningError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "IO error: {err}"),
+            Self::Syntax { line, reason } => write!(f, "line {line}: {reason}"),
+            Self::UnexpectedEnd => write!(f, "unexpected end of file"),
+            Self::UnmappedReference(note) => write!(f, "reference note {note} isn't mapped"),
+            Self::DegreeOutOfRange { degree, scale_len } => {
+                write!(
+                    f,
+                    "scale degree {degree} out of range for a {scale_len}-note scale"
+                )
+            }
+            Self::EmptyScale => write!(f, "scale contains no degrees"),
+            Self::InvalidReferenceFrequency(hz) => {
+                write!(f, "invalid reference frequency: {hz} Hz")
+            }
+        }
+    }
+}
+
+impl error::Error for TuningError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for TuningError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// Numbered non-comment lines of a Scala file
+fn scala_lines(string: &str) -> impl Iterator<Item = (usize, &str)> {
+    string
+        .lines()
+        .zip(1..)
+        .map(|(line, i)| (i, line.trim()))
+        .filter(|(_, line)| !line.starts_with('!'))
+}
+
+/// The first word of the next line, anything after it is a comment
+fn next_field<'a>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+) -> Result<(usize, &'a str), TuningError> {
+    let (i, line) = lines.next().ok_or(TuningError::UnexpectedEnd)?;
+
+    line.split_whitespace()
+        .next()
+        .map(|field| (i, field))
+        .ok_or(TuningError::Syntax {
+            line: i,
+            reason: "empty line",
+        })
+}
+
+fn parse_field<'a, T: str::FromStr>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+    reason: &'static str,
+) -> Result<T, TuningError> {
+    let (line, field) = next_field(lines)?;
+    field
+        .parse()
+        .map_err(|_| TuningError::Syntax { line, reason })
+}
+
+/// A Scala (`.scl`) scale, see <https://www.huygens-fokker.org/scala/scl_format.html>
+#[derive(Clone, PartialEq, Debug)]
+pub struct Scale {
+    pub description: String,
+    /// In cents, from the first degree above the unison, up to, and
+    /// including, the formal octave (the period of the scale)
+    pub degrees: Vec<f64>,
+}
+
+impl Scale {
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, TuningError> {
+        fs::read_to_string(path)?.parse()
+    }
+
+    /// Number of notes per period
+    pub fn len(&self) -> usize {
+        self.degrees.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.degrees.is_empty()
+    }
+
+    /// In cents, any degree, even negative, or past the formal octave.
+    /// Panics if the scale is empty
+    pub fn cents(&self, degree: i32) -> f64 {
+        let len = self.len() as i32;
+        let period = *self.degrees.last().unwrap_or(&0.);
+
+        let octaves = degree.div_euclid(len);
+        let degree = degree.rem_euclid(len) as usize;
+
+        let cents = degree.checked_sub(1).map_or(0., |i| self.degrees[i]);
+        f64::from(octaves).mul_add(period, cents)
+    }
+
+    /// Lines with a period are in cents, the others are ratios (`3/2`) or whole numbers (`2`)
+    fn parse_pitch(line: usize, field: &str) -> Result<f64, TuningError> {
+        let invalid = |reason| TuningError::Syntax { line, reason };
+
+        if field.contains('.') {
+            return field.parse().map_err(|_| invalid("invalid cents value"));
+        }
+
+        let (numerator, denominator) = field.split_once('/').unwrap_or((field, "1"));
+
+        let ratio = numerator
+            .parse::<u64>()
+            .ok()
+            .zip(denominator.parse::<u64>().ok())
+            .filter(|&(n, d)| n != 0 && d != 0)
+            .map(|(n, d)| n as f64 / d as f64)
+            .ok_or(invalid("invalid ratio"))?;
+
+        Ok(ratio.log2() * 1200.)
+    }
+}
+
+impl str::FromStr for Scale {
+    type Err = TuningError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut lines = scala_lines(string);
+
+        // may be empty
+        let (_, description) = lines.next().ok_or(TuningError::UnexpectedEnd)?;
+        let (line, field) = next_field(&mut lines)?;
+        let len: usize = field.parse().map_err(|_| TuningError::Syntax {
+            line,
+            reason: "invalid note count",
+        })?;
+
+        if len == 0 {
+            return Err(TuningError::Syntax {
+                line,
+                reason: "scales need at least one note",
+            });
+        }
+
+        let degrees = (0..len)
+            .map(|_| next_field(&mut lines).and_then(|(i, field)| Self::parse_pitch(i, field)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            description: description.into(),
+            degrees,
+        })
+    }
+}
+
+/// A Scala keyboard mapping (`.kbm`), see <https://www.huygens-fokker.org/scala/help.htm#mappings>
+#[derive(Clone, PartialEq, Debug)]
+pub struct KeyboardMapping {
+    /// Keys outside of this range are unmapped
+    pub first_note: u8,
+    pub last_note: u8,
+    /// Mapped to the first entry of `mapping`, or to the unison if it's empty
+    pub middle_note: u8,
+    pub reference_note: u8,
+    /// In Hz
+    pub reference_frequency: f64,
+    /// The scale degree the mapping repeats at, `0` for the scale's period
+    pub octave_degree: usize,
+    /// Scale degrees, one per key, repeating, `None` for unmapped keys.
+    /// If empty, every key is mapped to the scale degree next to that of the key below
+    pub mapping: Vec<Option<usize>>,
+}
+
+impl Default for KeyboardMapping {
+    fn default() -> Self {
+        Self {
+            first_note: 0,
+            last_note: (NUM_NOTES - 1) as u8,
+            middle_note: 60,
+            reference_note: 69,
+            reference_frequency: 440.,
+            octave_degree: 0,
+            mapping: Vec::new(),
+        }
+    }
+}
+
+impl KeyboardMapping {
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, TuningError> {
+        fs::read_to_string(path)?.parse()
+    }
+
+    /// The scale degree of `note`, counted from the middle note, if it's mapped
+    fn degree(&self, scale: &Scale, note: u8) -> Result<Option<i32>, TuningError> {
+        if !(self.first_note..=self.last_note).contains(&note) {
+            return Ok(None);
+        }
+
+        let offset = i32::from(note) - i32::from(self.middle_note);
+
+        if self.mapping.is_empty() {
+            return Ok(Some(offset));
+        }
+
+        let size = self.mapping.len() as i32;
+        let octave_degree = match self.octave_degree {
+            0 => scale.len(),
+            degree => degree,
+        };
+
+        let check = |degree: usize| {
+            (degree <= scale.len())
+                .then_some(degree as i32)
+                .ok_or(TuningError::DegreeOutOfRange {
+                    degree,
+                    scale_len: scale.len(),
+                })
+        };
+
+        let octave_degree = check(octave_degree)?;
+
+        self.mapping[offset.rem_euclid(size) as usize]
+            .map(check)
+            .transpose()
+            .map(|degree| degree.map(|d| offset.div_euclid(size) * octave_degree + d))
+    }
+}
+
+impl str::FromStr for KeyboardMapping {
+    type Err = TuningError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut lines = scala_lines(string).filter(|(_, line)| !line.is_empty());
+
+        let size: usize = parse_field(&mut lines, "invalid map size")?;
+        let first_note = parse_field(&mut lines, "invalid first note")?;
+        let last_note = parse_field(&mut lines, "invalid last note")?;
+        let middle_note = parse_field(&mut lines, "invalid middle note")?;
+        let reference_note = parse_field(&mut lines, "invalid reference note")?;
+        let reference_frequency = parse_field(&mut lines, "invalid reference frequency")?;
+        let octave_degree = parse_field(&mut lines, "invalid formal octave degree")?;
+
+        // missing trailing entries are unmapped
+        let mapping = (0..size)
+            .map(|_| match next_field(&mut lines) {
+                Ok((_, "x")) | Err(TuningError::UnexpectedEnd) => Ok(None),
+                Ok((line, field)) => field.parse().map(Some).map_err(|_| TuningError::Syntax {
+                    line,
+                    reason: "invalid scale degree",
+                }),
+                Err(err) => Err(err),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            first_note,
+            last_note,
+            middle_note,
+            reference_note,
+            reference_frequency,
+            octave_degree,
+            mapping,
+        })
+    }
+}
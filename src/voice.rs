@@ -1,4 +1,89 @@
 use super::*;
+use rng::Rng;
+
+/// How unison voices are distributed across the detune range
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum DetuneCurve {
+    /// Evenly spaced voices
+    Linear,
+    /// Offsets proportional to the square of the linear ones,
+    /// packing voices closer to the center
+    Exponential,
+    /// Packs voices closer to the edges of the detune range
+    OuterWeighted,
+}
+
+impl DetuneCurve {
+    pub const ALL: [Self; 3] = [Self::Linear, Self::Exponential, Self::OuterWeighted];
+
+    /// Remaps linear absolute detune offsets in `[0 ; 1]`, lane-wise, keeping `0` and `1` in place
+    #[inline]
+    pub fn apply(curves: UInt, abs_norm_detunes: Float) -> Float {
+        let x = abs_norm_detunes;
+        let exponential = x * x;
+        let outer_weighted = x * (Float::splat(2.) - x);
+
+        let select = |curve: Self, curved, rest| {
+            curves
+                .simd_eq(UInt::splat(curve as u32))
+                .select(curved, rest)
+        };
+
+        select(
+            Self::Exponential,
+            exponential,
+            select(Self::OuterWeighted, outer_weighted, x),
+        )
+    }
+}
+
+/// Which unison voices every lane of an oscillator renders, these only depend
+/// on the oscillator's index in its voice, so they're computed at compile time
+#[derive(Clone, Copy)]
+struct OscLanes {
+    voice_indices: UInt,
+    voice_pair_indices: UInt,
+    /// The sign bits of the voices detuned downwards
+    sign_mask: UInt,
+}
+
+impl OscLanes {
+    const fn new(index: usize) -> Self {
+        let mut voice_indices = [0; FLOATS_PER_VECTOR];
+        let mut voice_pair_indices = [0; FLOATS_PER_VECTOR];
+        let mut sign_mask = [0; FLOATS_PER_VECTOR];
+
+        let mut i = 0;
+        while i < FLOATS_PER_VECTOR {
+            let voice_index = (index * FLOATS_PER_VECTOR + i) as u32;
+
+            voice_indices[i] = voice_index;
+            voice_pair_indices[i] = voice_index >> 1;
+            sign_mask[i] = (voice_index ^ (voice_index >> 1)) << (u32::BITS - 1);
+
+            i += 1;
+        }
+
+        Self {
+            voice_indices: UInt::from_array(voice_indices),
+            voice_pair_indices: UInt::from_array(voice_pair_indices),
+            sign_mask: UInt::from_array(sign_mask),
+        }
+    }
+}
+
+static OSC_LANES: [OscLanes; OSCS_PER_VOICE] = {
+    let mut lanes = [OscLanes::new(0); OSCS_PER_VOICE];
+
+    let mut i = 1;
+    while i < OSCS_PER_VOICE {
+        lanes[i] = OscLanes::new(i);
+        i += 1;
+    }
+
+    lanes
+};
 
 pub struct VoiceParams {
     pub base_norm_frame: Float,
@@ -6,6 +91,21 @@ pub struct VoiceParams {
     pub detune: Float,
     pub num_voices: UInt,
     pub base_phase_delta: Float,
+    pub frame_spread: Float,
+    pub stack_ratio: Float,
+    pub sync_ratio: Float,
+    pub detune_curve: UInt,
+    pub blend: Float,
+    /// In semitones
+    pub drift: Float,
+    /// In semitones, see `WTOscClusterNormParams::humanize_semitones`
+    pub humanize: Float,
+    /// See `WTOscClusterNormParams::detune_skew`
+    pub detune_skew: Float,
+    /// See `FrameSnap`
+    pub frame_snap: bool,
+    /// In cycles per unit of output, see `WTOscClusterNormParams::feedback`
+    pub feedback: Float,
 }
 
 impl VoiceParams {
@@ -29,9 +129,9 @@ impl VoiceParams {
         let pitch_range_semitones = Simd::splat(PITCH_RANGE_SEMITONES);
 
         let detune = norm_detune_range * pitch_range_semitones * norm_detune;
-        let norm_transpose = split_stereo(&params.transpose.current).get_unchecked(i);
-        let transpose =
-            (Simd::splat(2.0) * norm_transpose - Simd::splat(1.0)) * pitch_range_semitones;
+        let transpose = WTOscClusterNormParams::transpose_semitones_from_norm(
+            *split_stereo(&params.transpose.current).get_unchecked(i),
+        );
 
         let num_voices = split_stereo(&params.num_voices_f()).get_unchecked(i).cast();
 
@@ -45,48 +145,72 @@ impl VoiceParams {
         (
             Self {
                 base_norm_frame: splat_stereo(
-                    *split_stereo(&params.frame.current).get_unchecked(i),
+                    *split_stereo(&params.modulated_frame()).get_unchecked(i),
                 ),
                 transpose: splat_stereo(transpose),
                 detune: splat_stereo(detune),
                 num_voices: splat_stereo(num_voices),
                 base_phase_delta: splat_stereo(*split_stereo(&params.phase_delta).get_unchecked(i)),
+                frame_spread: splat_stereo(
+                    *split_stereo(&params.frame_spread.current).get_unchecked(i),
+                ),
+                stack_ratio: splat_stereo(WTOscClusterNormParams::unison_stack_ratio_from_norm(
+                    *split_stereo(&params.unison_stack.current).get_unchecked(i),
+                )),
+                sync_ratio: splat_stereo(*split_stereo(&params.sync_ratio()).get_unchecked(i)),
+                detune_curve: splat_stereo(WTOscClusterNormParams::detune_curve_from_norm(
+                    *split_stereo(&params.detune_curve.current).get_unchecked(i),
+                )),
+                blend: splat_stereo(*split_stereo(&params.blend.current).get_unchecked(i)),
+                drift: splat_stereo(*split_stereo(&params.drift_semitones()).get_unchecked(i)),
+                humanize: splat_stereo(
+                    *split_stereo(&params.humanize_semitones()).get_unchecked(i),
+                ),
+                detune_skew: splat_stereo(*split_stereo(&params.detune_skew()).get_unchecked(i)),
+                frame_snap: params.frame_snap,
+                feedback: splat_stereo(*split_stereo(&params.feedback()).get_unchecked(i)),
             },
             // (panic) SAFETY: num_voices is garanteed to be nonzero
             NonZeroUsize::new(num_oscs_stereo.reduce_max() as usize).unwrap(),
         )
     }
 
+    /// Voice pairs are spread evenly over `[-1 ; 1]` times the detune, the outermost one at
+    /// the edges. With odd voice counts, the first pair is the center voice, at exactly zero
+    /// detune, rendered in both channels, so `num_voices + 1` lanes are enabled
     #[inline]
     pub fn get_params(&self, index: usize) -> (Float, Float, TMask) {
         let one_u = UInt::splat(1);
         let two_u = UInt::splat(2);
-        let last_voice_pair_idx =
-            UInt::splat((((MAX_UNISON + (MAX_UNISON & 1)) >> 1) - 1).max(1) as u32);
-        let last_voice_pair_idx_f = last_voice_pair_idx.cast::<f32>();
-        let max_float_bit_index = UInt::splat(mem::size_of::<f32>() as u32 * 8 - 1);
-        let counting = UInt::from_array(array::from_fn(|i| i as u32));
-        let counting_by2 = counting >> one_u;
-
-        let v_osc_index = UInt::splat((index * FLOATS_PER_VECTOR) as u32);
-        let voice_indices = v_osc_index + counting;
-        let voice_pair_indices = v_osc_index + counting_by2;
-        let sign_mask = (voice_indices ^ voice_pair_indices) << max_float_bit_index;
+
+        let OscLanes {
+            voice_indices,
+            voice_pair_indices,
+            sign_mask,
+        } = OSC_LANES[index];
 
         let num_voices = self.num_voices;
 
         let detune_step = (num_voices.simd_max(two_u) - one_u).cast::<f32>().recip();
         let start = (num_voices + one_u) & one_u;
-        let abs_norm_detunes = detune_step * (start + (voice_pair_indices << one_u)).cast::<f32>();
+        let linear_detunes = detune_step * (start + (voice_pair_indices << one_u)).cast::<f32>();
+        let abs_norm_detunes = DetuneCurve::apply(self.detune_curve, linear_detunes);
         let norm_detunes = Float::from_bits(abs_norm_detunes.to_bits() ^ sign_mask);
 
-        let detune_semitones = self.detune.mul_add(norm_detunes, self.transpose);
-        let detune_ratio = semitones_to_ratio(detune_semitones);
-        let phase_delta = self.unison_stack_mult(index) * detune_ratio;
+        // even lanes end up in the left channel, odd ones in the right, a zero skew
+        // leaves the detune bit-identical
+        let right = (voice_indices & one_u).simd_eq(one_u);
+        let skew = right.select(-self.detune_skew, self.detune_skew);
+        let detune = self.detune * (Float::splat(1.) + skew);
 
-        let norm_voice_spread = voice_pair_indices.cast::<f32>() / last_voice_pair_idx_f;
+        // adding a zero humanize offset keeps the transposition bit-identical
+        let transpose = self.transpose + self.humanize;
+        let detune_semitones = detune.mul_add(norm_detunes, transpose);
+        let detune_ratio = semitones_to_ratio(detune_semitones);
+        let phase_delta = self.unison_stack_mult(voice_pair_indices) * detune_ratio;
 
-        let norm_frame = norm_voice_spread.mul_add(self.frame_spread(index), self.base_norm_frame);
+        // unison voices spread across frames the same way they do across pitches
+        let norm_frame = norm_detunes.mul_add(self.frame_spread, self.base_norm_frame);
 
         let norm_frame_clamped = norm_frame.simd_clamp(Simd::splat(0.0001), Simd::splat(0.9999));
 
@@ -95,30 +219,78 @@ impl VoiceParams {
         (phase_delta, norm_frame_clamped, mask)
     }
 
+    /// Whether the oscillators at `index` and `other_index` get the exact same parameters,
+    /// e. g. when detune and frame spread are both at zero
     #[inline]
-    fn get_gather_mask(num_voices: UInt, voice_indices: UInt) -> TMask {
-        num_voices.simd_gt(voice_indices)
+    pub fn same_params(&self, index: usize, other_index: usize) -> bool {
+        let (phase_delta, frame, mask) = self.get_params(index);
+        let (other_phase_delta, other_frame, other_mask) = self.get_params(other_index);
+
+        mask == other_mask
+            && phase_delta.simd_eq(other_phase_delta).all()
+            && frame.simd_eq(other_frame).all()
+            && self
+                .get_gains(index)
+                .simd_eq(self.get_gains(other_index))
+                .all()
+    }
+
+    /// The center voice pair is always at full level, the others at `blend`'s
+    #[inline]
+    pub fn get_gains(&self, index: usize) -> Float {
+        OSC_LANES[index]
+            .voice_pair_indices
+            .simd_eq(UInt::splat(0))
+            .select(Float::splat(1.), self.blend)
     }
 
     #[inline]
-    fn unison_stack_mult(&self, _index: usize) -> Float {
-        Float::splat(1.)
+    fn get_gather_mask(num_voices: UInt, voice_indices: UInt) -> TMask {
+        num_voices.simd_gt(voice_indices)
     }
 
+    /// Every other voice pair is stacked
     #[inline]
-    fn frame_spread(&self, _index: usize) -> Float {
-        Float::splat(0.)
+    fn unison_stack_mult(&self, voice_pair_indices: UInt) -> Float {
+        let one = UInt::splat(1);
+        let stacked = (voice_pair_indices & one).simd_eq(one);
+
+        stacked.select(self.stack_ratio, Float::splat(1.))
     }
 }
 
 #[derive(Default, Clone, Copy)]
 pub struct Oscillator {
     phase: UInt,
+    /// The 32 bits below `phase`, only used with the `precise-phase` feature
+    phase_lo: UInt,
     frame: LinearSmoother,
     phase_delta: LogSmoother,
+    /// Hard sync is disabled for ratios less than or equal to 1
+    sync_ratio: LinearSmoother,
+    /// Not applied by `tick_all`, see `Self::get_gain`
+    gain: LinearSmoother,
+    /// Self phase modulation depth, in cycles per unit of output
+    feedback: LinearSmoother,
+    /// Of `tick_all`, before gain, fed back into the phase
+    last_output: Float,
+    /// Lanes that were audible after the last parameter update
+    active: TMask,
+    /// Slow random walk, in `[-1 ; 1]`, scaled by `drift_depth`
+    drift: LinearSmoother,
+    drift_target: Float,
+    drift_countdown: u32,
+    /// In semitones, drift is disabled when `0`
+    drift_depth: Float,
+    drift_rng: Rng,
 }
 
 impl Oscillator {
+    /// The drift random walk takes a step every `DRIFT_INTERVAL` samples, and the
+    /// oscillator's pitch glides linearly, in semitones, between two steps
+    const DRIFT_INTERVAL: u32 = 1024;
+    const DRIFT_STEP: f32 = 0.1;
+
     #[inline]
     pub fn scale_frame(&mut self, ratio: Float) {
         self.frame.scale(ratio);
@@ -139,6 +311,27 @@ impl Oscillator {
         self.phase_delta.set_target_recip(phase_delta, t_recip);
     }
 
+    /// The nearest whole frame, see `FrameSnap`
+    #[inline]
+    fn snap_frame(frame: Float, num_frames_f: Float) -> Float {
+        frame
+            .round()
+            .simd_min(num_frames_f - Float::splat(1.))
+            .simd_max(Float::splat(0.))
+    }
+
+    /// Keeps the frame in `[0 ; max_frame]`, e. g. after the table shrunk
+    #[inline]
+    pub fn clamp_frame(&mut self, max_frame: Float) {
+        let frame = self.frame.get_current();
+        self.set_frame(frame.simd_clamp(Float::splat(0.), max_frame));
+    }
+
+    #[inline]
+    pub fn frame(&self) -> Float {
+        self.frame.get_current()
+    }
+
     #[inline]
     pub fn set_frame(&mut self, frame: Float) {
         self.frame.set_all_vals_instantly(frame);
@@ -149,6 +342,43 @@ impl Oscillator {
         self.frame.set_target_recip(frame, t_recip);
     }
 
+    #[inline]
+    pub fn set_sync_ratio(&mut self, ratio: Float) {
+        self.sync_ratio.set_all_vals_instantly(ratio);
+    }
+
+    #[inline]
+    pub fn set_sync_ratio_smoothed(&mut self, ratio: Float, t_recip: Float) {
+        self.sync_ratio.set_target_recip(ratio, t_recip);
+    }
+
+    /// Drift is deterministic for a given seed
+    #[inline]
+    pub fn seed_drift(&mut self, seed: u32) {
+        self.drift_rng.seed(seed);
+    }
+
+    #[inline]
+    pub fn set_drift_depth(&mut self, semitones: Float) {
+        self.drift_depth = semitones;
+    }
+
+    #[inline]
+    pub fn set_gain(&mut self, gain: Float) {
+        self.gain.set_all_vals_instantly(gain);
+    }
+
+    #[inline]
+    pub fn set_gain_smoothed(&mut self, gain: Float, t_recip: Float) {
+        self.gain.set_target_recip(gain, t_recip);
+    }
+
+    /// The level the next sample should be scaled by before being mixed with other voices'
+    #[inline]
+    pub fn get_gain(&self) -> Float {
+        self.gain.get_current()
+    }
+
     #[inline]
     pub fn set_params_smoothed(
         &mut self,
@@ -159,10 +389,32 @@ impl Oscillator {
     ) -> TMask {
         let (total_detune, norm_frame, mask) = voice_params.get_params(voice_params_index);
 
-        self.set_frame_smoothed(num_frames_f * norm_frame, smooth_dt);
-        self.set_phase_delta_smoothed(voice_params.base_phase_delta * total_detune, smooth_dt);
+        let frame = num_frames_f * norm_frame;
 
-        mask
+        // snapped frames jump straight to their new positions, the
+        // oscillators' frame smoothers would otherwise morph through them
+        if voice_params.frame_snap {
+            self.set_frame(Self::snap_frame(frame, num_frames_f));
+        } else {
+            self.set_frame_smoothed(frame, smooth_dt);
+        }
+        self.set_phase_delta_smoothed(voice_params.base_phase_delta * total_detune, smooth_dt);
+        self.set_sync_ratio_smoothed(voice_params.sync_ratio, smooth_dt);
+        self.feedback
+            .set_target_recip(voice_params.feedback, smooth_dt);
+        self.set_drift_depth(voice_params.drift);
+
+        // when the voice count changes, lanes fade in from silence, and out to silence
+        // over the next buffer, instead of being switched on and off abruptly
+        let gains = voice_params.get_gains(voice_params_index);
+        self.gain
+            .set_val_instantly(Float::splat(0.), mask & !self.active);
+        self.set_gain_smoothed(mask.select(gains, Float::splat(0.)), smooth_dt);
+
+        let render_mask = mask | self.active;
+        self.active = mask;
+
+        render_mask
     }
 
     #[inline]
@@ -172,29 +424,225 @@ impl Oscillator {
         voice_params_index: usize,
         num_frames_f: Float,
     ) {
-        let (total_detune, norm_frame, _) = voice_params.get_params(voice_params_index);
+        let (total_detune, norm_frame, mask) = voice_params.get_params(voice_params_index);
+
+        let frame = num_frames_f * norm_frame;
 
-        self.set_frame(num_frames_f * norm_frame);
+        self.set_frame(if voice_params.frame_snap {
+            Self::snap_frame(frame, num_frames_f)
+        } else {
+            frame
+        });
         self.set_phase_delta(voice_params.base_phase_delta * total_detune);
+        self.set_sync_ratio(voice_params.sync_ratio);
+        self.feedback.set_all_vals_instantly(voice_params.feedback);
+        self.set_gain(voice_params.get_gains(voice_params_index));
+        self.set_drift_depth(voice_params.drift);
+        self.active = mask;
+    }
+
+    /// Whether any of this oscillator's lanes are (still) audible
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.active.any()
     }
 
     #[inline]
     pub fn set_phase(&mut self, phase: UInt) {
         self.phase = phase;
+        self.phase_lo = UInt::splat(0);
+        // restarted oscillators don't feed back the previous note's output
+        self.last_output = Float::splat(0.);
+    }
+
+    #[inline]
+    pub fn phase(&self) -> UInt {
+        self.phase
     }
 
     #[inline]
     pub fn tick_smoothers(&mut self) {
         self.frame.tick1();
         self.phase_delta.tick1();
+        self.sync_ratio.tick1();
+        self.feedback.tick1();
+        self.gain.tick1();
+        self.tick_drift();
     }
 
     #[inline]
-    pub unsafe fn tick_all(&mut self, table: &BandLimitedWaveTables, mask: TMask) -> Float {
-        let w = flp_to_fxp(self.phase_delta.get_current());
-        let frame = unsafe { self.frame.get_current().to_int_unchecked() };
-        let out = table.resample_select(w, frame, self.phase, mask);
-        self.phase += w;
+    fn tick_drift(&mut self) {
+        self.drift.tick1();
+
+        if self.drift_countdown == 0 {
+            self.drift_countdown = Self::DRIFT_INTERVAL;
+
+            let step = self
+                .drift_rng
+                .next_f32()
+                .mul_add(Float::splat(2.), Float::splat(-1.))
+                * Float::splat(Self::DRIFT_STEP);
+
+            self.drift_target =
+                (self.drift_target + step).simd_clamp(Float::splat(-1.), Float::splat(1.));
+            self.drift.set_target_recip(
+                self.drift_target,
+                Float::splat(1. / Self::DRIFT_INTERVAL as f32),
+            );
+        }
+
+        self.drift_countdown -= 1;
+    }
+
+    /// Whether `self` renders the exact same samples as `other`, as long as
+    /// they both get the same parameters, see `VoiceParams::same_params`
+    #[inline]
+    pub fn renders_like(&self, other: &Self) -> bool {
+        let no_drift = |osc: &Self| osc.drift_depth.simd_eq(Float::splat(0.)).all();
+
+        self.phase == other.phase
+            && self.phase_lo == other.phase_lo
+            && self.active == other.active
+            && self
+                .frame
+                .get_current()
+                .simd_eq(other.frame.get_current())
+                .all()
+            && self
+                .phase_delta
+                .get_current()
+                .simd_eq(other.phase_delta.get_current())
+                .all()
+            && self
+                .sync_ratio
+                .get_current()
+                .simd_eq(other.sync_ratio.get_current())
+                .all()
+            && self
+                .gain
+                .get_current()
+                .simd_eq(other.gain.get_current())
+                .all()
+            && self
+                .feedback
+                .get_current()
+                .simd_eq(other.feedback.get_current())
+                .all()
+            && self.last_output.simd_eq(other.last_output).all()
+            && no_drift(self)
+            && no_drift(other)
+    }
+
+    /// Catches up with `other`, which `self` was rendering like (see
+    /// `Self::renders_like`), `num_samples` ago, without rendering anything
+    #[inline]
+    pub fn follow(&mut self, other: &Self, num_samples: usize) {
+        *self = Self {
+            drift: self.drift,
+            drift_target: self.drift_target,
+            drift_countdown: self.drift_countdown,
+            drift_rng: self.drift_rng,
+            ..*other
+        };
+
+        for _ in 0..num_samples {
+            self.tick_drift();
+        }
+    }
+
+    #[inline]
+    fn drifted_phase_delta(&self) -> Float {
+        let phase_delta = self.phase_delta.get_current();
+        let drift = semitones_to_ratio(self.drift.get_current() * self.drift_depth);
+
+        // keep the output bit-identical when drift is disabled
+        self.drift_depth
+            .simd_eq(Float::splat(0.))
+            .select(phase_delta, phase_delta * drift)
+    }
+
+    /// `phase_delta` must be in `[0 ; 0.5]`. Truncated to a whole number of steps, the
+    /// increment's relative error grows as the frequency goes down (up to ~`0.005%` at
+    /// 0.25 Hz, at 48 kHz), with the `precise-phase` feature, the truncated fraction
+    /// is accumulated in `phase_lo`, carrying into `phase`, only `phase` is ever read
+    #[inline]
+    fn advance_phase(&mut self, phase_delta: Float) {
+        if !cfg!(feature = "precise-phase") {
+            self.phase += flp_to_fxp(phase_delta);
+            return;
+        }
+
+        // 2^32
+        const SCALE: Float = const_splat(4294967296.);
+
+        let steps = phase_delta * SCALE;
+        let w = steps.cast::<u32>();
+        let w_lo = flp_to_fxp(steps - steps.floor());
+
+        let phase_lo = self.phase_lo + w_lo;
+        let carry = phase_lo.simd_lt(self.phase_lo);
+
+        self.phase_lo = phase_lo;
+        self.phase += w + carry.select(UInt::splat(1), UInt::splat(0));
+    }
+
+    #[inline]
+    pub unsafe fn tick_all(
+        &mut self,
+        table: &BandLimitedWaveTables,
+        mask: TMask,
+        quality: Quality,
+    ) -> Float {
+        self.tick_all_pm(table, mask, quality, UInt::splat(0))
+    }
+
+    /// Like `tick_all`, but reads the table `phase_offset` ahead of the oscillator's
+    /// phase (e. g. for phase modulation), which is advanced as usual
+    #[inline]
+    pub unsafe fn tick_all_pm(
+        &mut self,
+        table: &BandLimitedWaveTables,
+        mask: TMask,
+        quality: Quality,
+        phase_offset: UInt,
+    ) -> Float {
+        const ZERO: Float = const_splat(0.);
+        const ONE: Float = const_splat(1.);
+        const NYQUIST: Float = const_splat(0.5);
+
+        let phase_delta = self.drifted_phase_delta();
+
+        // when hard syncing, `phase` is the master's, and the slave's phase,
+        // reset every time the master's wraps around, is derived from it
+        let sync_ratio = self.sync_ratio.get_current();
+        let synced = sync_ratio.simd_gt(ONE);
+
+        let slave_phase = fxp_to_flp(self.phase) * sync_ratio;
+        let phase = synced.select(flp_to_fxp(slave_phase - slave_phase.floor()), self.phase);
+
+        // mipmaps are selected according to the slave's frequency
+        let slave_phase_delta = synced.select(phase_delta * sync_ratio, phase_delta);
+
+        // silence oscillators above nyquist instead of letting
+        // their phase deltas wrap around (this also catches NaNs)
+        let audible = slave_phase_delta.simd_lt(NYQUIST);
+        let w = audible.select(phase_delta.simd_max(ZERO), ZERO);
+        let slave_w = flp_to_fxp(audible.select(slave_phase_delta.simd_max(ZERO), ZERO));
+
+        // self-FM, only offsets the phase the table is read at, wrapped to a single cycle,
+        // like phase modulation, a zero depth gives a zero offset, leaving the phase as is
+        let feedback = self.feedback.get_current() * self.last_output;
+        let feedback_offset = flp_to_fxp(feedback - feedback.floor());
+
+        let out = table.resample_frame_lerp(
+            slave_w,
+            self.frame.get_current(),
+            phase + phase_offset + feedback_offset,
+            mask & audible,
+            quality,
+        );
+        self.last_output = out;
+        self.advance_phase(w);
         self.tick_smoothers();
 
         out
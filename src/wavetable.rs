@@ -1,7 +1,378 @@
-use crate::{basic_shapes::WAVETABLES, *};
-use hound::{SampleFormat, WavReader};
-use realfft::{num_complex::Complex32, RealFftPlanner};
-use std::io;
+pub mod loader;
+#[cfg(feature = "serde")]
+mod serialize;
+
+use crate::*;
+use core::{
+    f32::consts::{FRAC_1_SQRT_2, FRAC_PI_2, TAU},
+    simd::SimdElement,
+};
+use hound::{SampleFormat, WavReader, WavSpec, WavWriter};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use realfft::{num_complex::Complex32, ComplexToReal, RealFftPlanner, RealToComplex};
+use std::{
+    error, fmt,
+    fs::{self, File},
+    io,
+    num::NonZeroUsize,
+    ops::Range,
+    path::Path,
+    str,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+#[derive(Debug)]
+pub enum WavetableError {
+    Io(io::Error),
+    /// The file isn't a valid WAV file
+    Wav(hound::Error),
+    UnsupportedFormat {
+        format: SampleFormat,
+        bits_per_sample: u16,
+    },
+    /// The number of samples isn't a multiple of the cycle length
+    WrongLength {
+        cycle_len: usize,
+        expected: usize,
+        actual: usize,
+    },
+    TooManyFrames {
+        max: usize,
+        actual: usize,
+    },
+    /// The table would contain no frames
+    Empty,
+    /// The file's header is malformed
+    InvalidHeader(&'static str),
+    /// A frame has partials above the Nyquist frequency
+    TooManyHarmonics {
+        max: usize,
+        actual: usize,
+    },
+    /// The requested frame range isn't within the table
+    FramesOutOfRange {
+        range: Range<usize>,
+        num_frames: usize,
+    },
+    /// The load was cancelled through `MipmapGenerator::with_cancel_flag`
+    Cancelled,
+}
+
+impl fmt::Display for WavetableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "IO error: {err}"),
+            Self::Wav(err) => write!(f, "invalid WAV file: {err}"),
+            Self::UnsupportedFormat {
+                format,
+                bits_per_sample,
+            } => write!(
+                f,
+                "unsupported sample format: {bits_per_sample}-bit {format:?}"
+            ),
+            Self::WrongLength {
+                cycle_len,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "expected a multiple of {cycle_len} samples (e. g. {expected}), found {actual}",
+            ),
+            Self::TooManyFrames { max, actual } => {
+                write!(f, "too many frames: {actual} (max {max})")
+            }
+            Self::Empty => write!(f, "wavetable contains no frames"),
+            Self::InvalidHeader(reason) => write!(f, "invalid header: {reason}"),
+            Self::TooManyHarmonics { max, actual } => {
+                write!(f, "too many harmonics: {actual} (max {max})")
+            }
+            Self::FramesOutOfRange { range, num_frames } => {
+                write!(
+                    f,
+                    "frames {range:?} out of range for a {num_frames}-frame table"
+                )
+            }
+            Self::Cancelled => write!(f, "load cancelled"),
+        }
+    }
+}
+
+impl error::Error for WavetableError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Wav(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Which channel(s) of a multichannel file end up in the wavetable
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelMode {
+    #[default]
+    Left,
+    /// The second channel, or the only one in mono files
+    Right,
+    /// The average of all channels
+    Mix,
+}
+
+/// How `BandLimitedWaveTables::interpolated` synthesizes intermediate frames
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FrameInterpolation {
+    /// Crossfade adjacent frames in the time domain, which
+    /// can cancel out partials whose phases differ between them
+    Linear,
+    /// Interpolate the magnitudes and phases of adjacent frames' partials
+    #[default]
+    Spectral,
+}
+
+/// How to even out the levels of a table's frames
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Normalize {
+    #[default]
+    None,
+    /// Scale all frames by the same amount, so that the loudest sample is at 0 dBFS
+    GlobalPeak,
+    /// Scale every frame so that its loudest sample is at 0 dBFS
+    PerFramePeak,
+    /// Scale every frame so that it is as loud (in RMS) as a full-scale sine
+    PerFrameRms,
+}
+
+/// How to rotate frames so that their phases line up
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PhaseAlignment {
+    #[default]
+    None,
+    /// Make every frame's fundamental start at phase zero (like a sine)
+    Fundamental,
+    /// Make every frame start at its highest sample
+    Peak,
+}
+
+/// How mipmaps' spectra end above their highest partial
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Transition {
+    /// Keep every partial below the mipmap's Nyquist frequency untouched
+    #[default]
+    Brickwall,
+    /// Gradually attenuate (with a half-cosine) the highest `width_bins` partials
+    /// of each mipmap, to soften the change in brightness between mipmaps. The
+    /// fundamental is never attenuated
+    RaisedCosine { width_bins: usize },
+}
+
+/// How oscillators read from wavetables, higher qualities cost more CPU
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Quality {
+    /// Play a single mipmap at a time (see `BandLimitedWaveTables::resample`)
+    #[default]
+    Standard,
+    /// Crossfade between neighbouring mipmaps (see `BandLimitedWaveTables::resample_xfade`)
+    High,
+    /// Play a single mipmap at a time, with cubic interpolation
+    /// (see `BandLimitedWaveTables::resample_cubic`)
+    Cubic,
+    /// Always play the top (unfiltered) mipmap, e. g. for LFOs, where band-limiting
+    /// would round off the corners of the shapes. Aliases at audio rates
+    Unfiltered,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MipmapOptions {
+    /// Remove any DC offset from every frame (including the top mipmap)
+    pub remove_dc: bool,
+    pub transition: Transition,
+}
+
+impl Default for MipmapOptions {
+    fn default() -> Self {
+        Self {
+            remove_dc: true,
+            transition: Transition::default(),
+        }
+    }
+}
+
+impl Transition {
+    /// The gain applied to every bin of a mipmap whose highest partial is `last_bin`
+    fn gains(self, last_bin: usize) -> impl Iterator<Item = (usize, f32)> {
+        let width = match self {
+            Self::Brickwall => 0,
+            Self::RaisedCosine { width_bins } => width_bins,
+        };
+
+        let start = last_bin.saturating_sub(width).max(1);
+        let step = core::f32::consts::PI / (width + 1) as f32;
+
+        (start + 1..=last_bin)
+            .map(move |bin| (bin, 0.5 + 0.5 * ((bin - start) as f32 * step).cos()))
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct LoadOptions {
+    pub channel_mode: ChannelMode,
+    pub normalize: Normalize,
+    pub phase_alignment: PhaseAlignment,
+    pub mipmaps: MipmapOptions,
+    /// The number of samples in each of the file's cycles, cycles of any
+    /// other length than `BandLimitedWaveTables::FRAME_LEN` are resampled.
+    ///
+    /// If `None`, it is read from the file's Serum `clm ` chunk, if there is one,
+    /// and defaults to `BandLimitedWaveTables::FRAME_LEN` otherwise
+    pub cycle_len: Option<NonZeroUsize>,
+}
+
+/// Iterate over the chunks of a RIFF/WAVE file, yielding
+/// their offsets in `bytes`, their ids, and their contents
+fn riff_chunks(bytes: &[u8]) -> impl Iterator<Item = (usize, &[u8], &[u8])> {
+    let is_wave = bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WAVE".as_slice());
+    let mut offset = if is_wave { 12 } else { bytes.len() };
+
+    iter::from_fn(move || {
+        let chunk_offset = offset;
+        let header = bytes.get(chunk_offset..chunk_offset + 8)?;
+        let len = u32::from_le_bytes(header[4..].try_into().unwrap()) as usize;
+        let contents = &bytes[chunk_offset + 8..];
+
+        // chunks are padded to an even number of bytes
+        offset += 8 + len + (len & 1);
+
+        Some((
+            chunk_offset,
+            &header[..4],
+            &contents[..len.min(contents.len())],
+        ))
+    })
+}
+
+/// Find the cycle length announced in the `clm ` chunk (e. g. `<!>2048 ...`)
+/// Serum writes in the wavetables it exports, if `bytes` is a WAV file that has one
+fn serum_cycle_len(bytes: &[u8]) -> Option<NonZeroUsize> {
+    let (_, _, contents) = riff_chunks(bytes).find(|&(_, id, _)| id == b"clm ")?;
+
+    let text = contents.strip_prefix(b"<!>")?;
+    let num_digits = text.iter().take_while(|c| c.is_ascii_digit()).count();
+    str::from_utf8(&text[..num_digits]).ok()?.parse().ok()
+}
+
+/// A copy of `bytes` (a WAV file) with only its `fmt ` and `data` chunks, and with a
+/// `WAVE_FORMAT_EXTENSIBLE` format replaced by its plain PCM or IEEE float equivalent.
+/// Some hound versions stumble on either, `None` if `bytes` can't be made
+/// into such a file, hound then reports the actual error
+fn plain_wav(bytes: &[u8]) -> Option<Vec<u8>> {
+    const WAVE_FORMAT_EXTENSIBLE: [u8; 2] = 0xfffe_u16.to_le_bytes();
+    // `KSDATAFORMAT_SUBTYPE_*` GUIDs only differ in their first two bytes, the format tag
+    const SUBFORMAT_GUID_SUFFIX: [u8; 14] = [
+        0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xaa, 0x00, 0x38, 0x9b, 0x71,
+    ];
+
+    let (_, _, fmt_contents) = riff_chunks(bytes).find(|&(_, id, _)| id == b"fmt ")?;
+    let (_, _, data) = riff_chunks(bytes).find(|&(_, id, _)| id == b"data")?;
+
+    let mut fmt: [u8; 16] = fmt_contents.get(..16)?.try_into().unwrap();
+
+    if fmt[..2] == WAVE_FORMAT_EXTENSIBLE {
+        let (format_tag, suffix) = fmt_contents.get(24..40)?.split_at(2);
+
+        if suffix != SUBFORMAT_GUID_SUFFIX {
+            return None;
+        }
+
+        fmt[..2].copy_from_slice(format_tag);
+    }
+
+    let data_len = u32::try_from(data.len()).ok()?;
+    let padding = data.len() & 1;
+
+    let mut wav = Vec::with_capacity(44 + data.len() + padding);
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_len + padding as u32).to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&fmt);
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_len.to_le_bytes());
+    wav.extend_from_slice(data);
+    wav.resize(wav.len() + padding, 0);
+
+    Some(wav)
+}
+
+impl From<hound::Error> for WavetableError {
+    fn from(err: hound::Error) -> Self {
+        match err {
+            hound::Error::IoError(err) => Self::Io(err),
+            err => Self::Wav(err),
+        }
+    }
+}
+
+/// Reads `table[idx]` one lane at a time, lanes whose `mask` value is disabled are
+/// zeroed. Where hardware gathers aren't available (SSE2-only x86, wasm32 SIMD128, some
+/// ARM cores) this beats portable_simd's own fallback, see the `scalar-gather` feature
+///
+/// # Safety
+///
+/// Every index in `idx` whose corresponding `mask` value is enabled must be in bounds of `table`
+#[inline]
+pub(crate) unsafe fn scalar_gather_select<T: SimdElement + Default>(
+    table: *const T,
+    idx: UInt,
+    mask: TMask,
+) -> Simd<T, FLOATS_PER_VECTOR> {
+    let (idx, mask) = (idx.to_array(), mask.to_array());
+
+    Simd::from_array(array::from_fn(|i| {
+        if mask[i] {
+            unsafe { *table.add(idx[i] as usize) }
+        } else {
+            T::default()
+        }
+    }))
+}
+
+/// `gather_select_unchecked`, or `scalar_gather_select` with the `scalar-gather` feature
+///
+/// # Safety
+///
+/// See `scalar_gather_select`
+#[inline]
+unsafe fn gather_select(table: *const f32, idx: UInt, mask: TMask) -> Float {
+    const ZERO_F: Float = const_splat(0.);
+
+    unsafe {
+        if cfg!(feature = "scalar-gather") {
+            scalar_gather_select(table, idx, mask)
+        } else {
+            gather_select_unchecked(table, idx, mask, ZERO_F)
+        }
+    }
+}
+
+/// Like `gather_select`, with every lane enabled
+///
+/// # Safety
+///
+/// Every index in `idx` must be in bounds of `table`
+#[inline]
+unsafe fn gather(table: *const f32, idx: UInt) -> Float {
+    unsafe {
+        if cfg!(feature = "scalar-gather") {
+            scalar_gather_select(table, idx, TMask::splat(true))
+        } else {
+            gather_unchecked(table, idx)
+        }
+    }
+}
 
 #[repr(transparent)]
 pub struct BandLimitedWaveTables {
@@ -32,7 +403,7 @@ impl BandLimitedWaveTables {
     }
 
     #[inline]
-    fn as_mut_slice(&mut self) -> &mut [[[f32; Self::FRAME_LEN]; Self::NUM_MIPMAPS]] {
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [[[f32; Self::FRAME_LEN]; Self::NUM_MIPMAPS]] {
         &mut self.data
     }
 
@@ -77,7 +448,29 @@ impl BandLimitedWaveTables {
 
     #[inline]
     pub fn basic_shapes() -> Box<Self> {
-        WAVETABLES.as_slice().into()
+        basic_shapes::generate()
+    }
+
+    /// 64 pulses, with duty cycles going from 50% to 5%
+    pub fn pwm_sweep() -> Box<Self> {
+        basic_shapes::pwm_sweep()
+    }
+
+    /// Organ-like frames, pulling out more drawbars with every frame,
+    /// see `basic_shapes::DRAWBAR_REGISTRATIONS`
+    pub fn harmonic_drawbars() -> Box<Self> {
+        basic_shapes::harmonic_drawbars()
+    }
+
+    /// 32 frames, morphing spectrally from a saw to a square
+    pub fn saw_to_square_morph() -> Box<Self> {
+        basic_shapes::saw_to_square_morph()
+    }
+
+    /// Formant frames, morphing through the vowels A, E, I, O and U,
+    /// see `basic_shapes::VOWEL_FORMANTS`
+    pub fn vowels() -> Box<Self> {
+        basic_shapes::vowels()
     }
 
     /// How many octaves of frequency content our wavetables have, this
@@ -91,14 +484,29 @@ impl BandLimitedWaveTables {
     const PHASE_MASK: UInt = const_splat(Self::FRAME_LEN as u32 - 1);
     pub const NUM_MIPMAPS: usize = Self::NUM_OCTAVES + 1;
     const V_NUM_MIPMAPS: UInt = const_splat(Self::NUM_OCTAVES as u32 + 1);
+    /// The maximum number of frames a table can hold, so that
+    /// sample indices still fit in 32 bits
+    pub const MAX_FRAMES: usize = u32::MAX as usize / (Self::FRAME_LEN * Self::NUM_MIPMAPS);
+
+    /// Index of the first sample of the given mipmap of the given frame, in the
+    /// `(frame * NUM_MIPMAPS + mipmap) * FRAME_LEN + phase` layout
+    #[inline]
+    fn mipmap_start(frame: UInt, mipmap: UInt) -> UInt {
+        (frame * Self::V_NUM_MIPMAPS + mipmap) << Self::V_NUM_OCTAVES
+    }
 
     #[inline]
     fn get_resample_data(phase: UInt, frame: UInt, phase_delta: UInt) -> (Float, UInt, UInt) {
         let octaves = map(phase_delta, u32::leading_zeros).simd_min(Self::V_NUM_OCTAVES);
 
+        Self::get_mipmap_resample_data(phase, frame, octaves)
+    }
+
+    #[inline]
+    fn get_mipmap_resample_data(phase: UInt, frame: UInt, octaves: UInt) -> (Float, UInt, UInt) {
         let fract = fxp_to_flp(phase << Self::V_NUM_OCTAVES);
 
-        let table_start = (octaves + frame * Self::V_NUM_MIPMAPS) << Self::V_NUM_OCTAVES;
+        let table_start = Self::mipmap_start(frame, octaves);
 
         const ONE: UInt = const_splat(1);
 
@@ -108,6 +516,72 @@ impl BandLimitedWaveTables {
         (fract, table_start + phase_a, table_start + phase_b)
     }
 
+    #[inline]
+    fn get_cubic_resample_data(phase: UInt, frame: UInt, phase_delta: UInt) -> (Float, [UInt; 4]) {
+        let octaves = map(phase_delta, u32::leading_zeros).simd_min(Self::V_NUM_OCTAVES);
+
+        let fract = fxp_to_flp(phase << Self::V_NUM_OCTAVES);
+
+        let table_start = Self::mipmap_start(frame, octaves);
+
+        const ONE: UInt = const_splat(1);
+        const TWO: UInt = const_splat(2);
+
+        let phase_b = phase >> Self::FRACT_BITS;
+        let phase_a = (phase_b - ONE) & Self::PHASE_MASK;
+        let phase_c = (phase_b + ONE) & Self::PHASE_MASK;
+        let phase_d = (phase_b + TWO) & Self::PHASE_MASK;
+
+        (
+            fract,
+            [phase_a, phase_b, phase_c, phase_d].map(|phase| table_start + phase),
+        )
+    }
+
+    /// Catmull-Rom interpolation between `b` and `c`
+    #[inline]
+    fn cubic_interp(a: Float, b: Float, c: Float, d: Float, t: Float) -> Float {
+        const HALF: Float = const_splat(0.5);
+        const ONE_AND_HALF: Float = const_splat(1.5);
+        const TWO: Float = const_splat(2.);
+        const TWO_AND_HALF: Float = const_splat(2.5);
+
+        let c1 = HALF * (c - a);
+        let c2 = TWO.mul_add(c, a) - TWO_AND_HALF.mul_add(b, HALF * d);
+        let c3 = HALF.mul_add(d - a, ONE_AND_HALF * (b - c));
+
+        c3.mul_add(t, c2).mul_add(t, c1).mul_add(t, b)
+    }
+
+    /// Returns the two mipmaps to crossfade between for the given `phase_delta`, and
+    /// the weight of the second one.
+    ///
+    /// Both mipmaps never alias at the given frequency, the first one being fully
+    /// selected at the top of the octave and the second one at its bottom
+    #[inline]
+    fn get_mipmap_xfade_data(phase_delta: UInt) -> (UInt, UInt, Float) {
+        const ONE: UInt = const_splat(1);
+        const MAX_SHIFT: UInt = const_splat(u32::BITS - 1);
+        const ONE_F: Float = const_splat(1.);
+        const TWO_F: Float = const_splat(2.);
+        const MANTISSA_SCALE: Float = const_splat(1. / (1u32 << 31) as f32);
+
+        let octaves = map(phase_delta, u32::leading_zeros);
+
+        let upper = octaves.simd_min(Self::V_NUM_OCTAVES);
+        let lower = upper.simd_max(ONE) - ONE;
+
+        // in [1, 2)
+        let mantissa = (phase_delta << octaves.simd_min(MAX_SHIFT)).cast::<f32>() * MANTISSA_SCALE;
+
+        // below the lowest octave, the top mipmap is played as is
+        let xfade = octaves
+            .simd_gt(Self::V_NUM_OCTAVES)
+            .select(ONE_F, TWO_F - mantissa);
+
+        (lower, upper, xfade)
+    }
+
     /// # Safety
     ///
     /// Every value in `frame` whose corresponding `mask` value is enabled must be
@@ -124,12 +598,10 @@ impl BandLimitedWaveTables {
 
         let this = self.as_ptr();
 
-        const ZERO_F: Float = const_splat(0.);
-
         let (a, b) = unsafe {
             (
-                gather_select_unchecked(this, start_idx, mask, ZERO_F),
-                gather_select_unchecked(this, end_idx, mask, ZERO_F),
+                gather_select(this, start_idx, mask),
+                gather_select(this, end_idx, mask),
             )
         };
 
@@ -146,84 +618,1213 @@ impl BandLimitedWaveTables {
 
         let this = self.as_ptr();
 
-        let (a, b) = unsafe {
+        let (a, b) = unsafe { (gather(this, start_idx), gather(this, end_idx)) };
+
+        lerp(a, b, fract)
+    }
+
+    /// Like `resample_select` (or its variants, depending on `quality`), but for
+    /// fractional frame positions, linearly interpolating between neighbouring
+    /// frames. Positions past the last frame read from the last frame only
+    ///
+    /// # Safety
+    ///
+    /// `self` must hold at least one frame
+    #[inline]
+    pub unsafe fn resample_frame_lerp(
+        &self,
+        phase_delta: UInt,
+        frame: Float,
+        phase: UInt,
+        mask: TMask,
+        quality: Quality,
+    ) -> Float {
+        const ONE: UInt = const_splat(1);
+
+        let last_frame = UInt::splat(self.num_frames() as u32 - 1);
+
+        // saturating cast, negative positions read from the first frame
+        let frame_a = frame.cast::<u32>().simd_min(last_frame);
+        let frame_b = (frame_a + ONE).simd_min(last_frame);
+        let fract = frame - frame_a.cast();
+
+        let resample = |frame| unsafe {
+            match quality {
+                Quality::Standard => self.resample_select(phase_delta, frame, phase, mask),
+                Quality::High => self.resample_select_xfade(phase_delta, frame, phase, mask),
+                Quality::Cubic => self.resample_select_cubic(phase_delta, frame, phase, mask),
+                // past the lowest octave, the top mipmap is selected
+                Quality::Unfiltered => self.resample_select(UInt::splat(0), frame, phase, mask),
+            }
+        };
+
+        lerp(resample(frame_a), resample(frame_b), fract)
+    }
+
+    /// Like `resample_select`, but uses cubic (Catmull-Rom) instead of linear
+    /// interpolation, lowering the interpolation noise floor, especially on low
+    /// notes, at the cost of twice as many gathers
+    ///
+    /// # Safety
+    ///
+    /// Every value in `frame` whose corresponding `mask` value is enabled must be
+    /// strictly less than `self.num_frames()`
+    #[inline]
+    pub unsafe fn resample_select_cubic(
+        &self,
+        phase_delta: UInt,
+        frame: UInt,
+        phase: UInt,
+        mask: TMask,
+    ) -> Float {
+        let (fract, indices) = Self::get_cubic_resample_data(phase, frame, phase_delta);
+
+        let this = self.as_ptr();
+
+        let [a, b, c, d] = indices.map(|idx| unsafe { gather_select(this, idx, mask) });
+
+        Self::cubic_interp(a, b, c, d, fract)
+    }
+
+    /// Like `resample`, but uses cubic interpolation, see `resample_select_cubic`
+    ///
+    /// # Safety
+    ///
+    /// Every value in `frame` must be strictly less than `self.num_frames()`
+    #[inline]
+    pub unsafe fn resample_cubic(&self, phase_delta: UInt, frame: UInt, phase: UInt) -> Float {
+        let (fract, indices) = Self::get_cubic_resample_data(phase, frame, phase_delta);
+
+        let this = self.as_ptr();
+
+        let [a, b, c, d] = indices.map(|idx| unsafe { gather(this, idx) });
+
+        Self::cubic_interp(a, b, c, d, fract)
+    }
+
+    /// Like `resample_select`, but crossfades between neighbouring mipmaps instead
+    /// of switching abruptly between them every octave, at the cost of twice as
+    /// many gathers. Since only mipmaps that can't alias are used, the output is
+    /// slightly duller than `resample_select`'s
+    ///
+    /// # Safety
+    ///
+    /// Every value in `frame` whose corresponding `mask` value is enabled must be
+    /// strictly less than `self.num_frames()`
+    #[inline]
+    pub unsafe fn resample_select_xfade(
+        &self,
+        phase_delta: UInt,
+        frame: UInt,
+        phase: UInt,
+        mask: TMask,
+    ) -> Float {
+        let (lower, upper, xfade) = Self::get_mipmap_xfade_data(phase_delta);
+        let (fract, lower_start, lower_end) = Self::get_mipmap_resample_data(phase, frame, lower);
+        let (_, upper_start, upper_end) = Self::get_mipmap_resample_data(phase, frame, upper);
+
+        let this = self.as_ptr();
+
+        let (a, b, c, d) = unsafe {
             (
-                gather_unchecked(this, start_idx),
-                gather_unchecked(this, end_idx),
+                gather_select(this, lower_start, mask),
+                gather_select(this, lower_end, mask),
+                gather_select(this, upper_start, mask),
+                gather_select(this, upper_end, mask),
             )
         };
 
-        lerp(a, b, fract)
+        lerp(lerp(a, b, fract), lerp(c, d, fract), xfade)
+    }
+
+    /// Like `resample`, but crossfades between neighbouring mipmaps,
+    /// see `resample_select_xfade`
+    ///
+    /// # Safety
+    ///
+    /// Every value in `frame` must be strictly less than `self.num_frames()`
+    #[inline]
+    pub unsafe fn resample_xfade(&self, phase_delta: UInt, frame: UInt, phase: UInt) -> Float {
+        let (lower, upper, xfade) = Self::get_mipmap_xfade_data(phase_delta);
+        let (fract, lower_start, lower_end) = Self::get_mipmap_resample_data(phase, frame, lower);
+        let (_, upper_start, upper_end) = Self::get_mipmap_resample_data(phase, frame, upper);
+
+        let this = self.as_ptr();
+
+        let (a, b, c, d) = unsafe {
+            (
+                gather(this, lower_start),
+                gather(this, lower_end),
+                gather(this, upper_start),
+                gather(this, upper_end),
+            )
+        };
+
+        lerp(lerp(a, b, fract), lerp(c, d, fract), xfade)
     }
 
-    pub fn from_wav_file(reader: impl io::Read) -> Box<Self> {
-        let reader = WavReader::new(reader).unwrap();
-        let num_samples = reader.len() as usize;
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Box<Self>, WavetableError> {
+        Self::from_file_with_options(path, LoadOptions::default())
+    }
 
-        assert!(num_samples % Self::FRAME_LEN == 0);
-        assert!(reader.spec().sample_format == SampleFormat::Float);
+    pub fn from_file_with_options(
+        path: impl AsRef<Path>,
+        options: LoadOptions,
+    ) -> Result<Box<Self>, WavetableError> {
+        Self::from_file_with_generator(path, options, None)
+    }
 
-        let num_frames = num_samples / Self::FRAME_LEN;
+    /// Like `from_file_with_options`, but generates mipmaps with `generator`, if any
+    pub fn from_file_with_generator(
+        path: impl AsRef<Path>,
+        options: LoadOptions,
+        generator: Option<&mut MipmapGenerator>,
+    ) -> Result<Box<Self>, WavetableError> {
+        let file = File::open(path).map_err(WavetableError::Io)?;
+        Self::from_reader_with_generator(io::BufReader::new(file), options, generator)
+    }
 
-        let mut table = Self::with_frame_count(num_frames);
+    pub fn from_file_with_cycle_len(
+        path: impl AsRef<Path>,
+        cycle_len: NonZeroUsize,
+    ) -> Result<Box<Self>, WavetableError> {
+        Self::from_file_with_options(
+            path,
+            LoadOptions {
+                cycle_len: Some(cycle_len),
+                ..Default::default()
+            },
+        )
+    }
 
-        for (output, input) in table
-            .as_mut_slice()
-            .iter_mut()
-            .flat_map(|mipmaps| mipmaps.last_mut().unwrap())
-            .zip(reader.into_samples().map(Result::unwrap))
-        {
-            *output = input;
+    /// Load a wavetable from any source of WAV data, e. g. a `Cursor`
+    /// over bytes embedded with `include_bytes!`
+    pub fn from_reader(reader: impl io::Read) -> Result<Box<Self>, WavetableError> {
+        Self::from_reader_with_options(reader, LoadOptions::default())
+    }
+
+    pub fn from_reader_with_options(
+        reader: impl io::Read,
+        options: LoadOptions,
+    ) -> Result<Box<Self>, WavetableError> {
+        Self::from_reader_with_generator(reader, options, None)
+    }
+
+    /// Like `from_reader_with_options`, but generates mipmaps with `generator`, if any
+    pub fn from_reader_with_generator(
+        mut reader: impl io::Read,
+        mut options: LoadOptions,
+        generator: Option<&mut MipmapGenerator>,
+    ) -> Result<Box<Self>, WavetableError> {
+        // hound doesn't expose chunks it doesn't know of, so
+        // we look for the `clm ` chunk in the raw bytes ourselves
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).map_err(WavetableError::Io)?;
+
+        if generator.as_ref().is_some_and(|g| g.is_cancelled()) {
+            return Err(WavetableError::Cancelled);
         }
 
-        table.create_mipmaps();
+        if options.cycle_len.is_none() {
+            options.cycle_len = serum_cycle_len(&bytes);
+        }
+
+        let plain = plain_wav(&bytes);
+        let samples = Self::decode_wav(
+            WavReader::new(plain.as_deref().unwrap_or(&bytes))?,
+            options.channel_mode,
+        )?;
 
-        table
+        let cycle_len = options
+            .cycle_len
+            .unwrap_or(NonZeroUsize::new(Self::FRAME_LEN).unwrap());
+
+        Self::from_cycles_with_options(&samples, cycle_len, &options, generator)
     }
 
-    #[inline]
-    pub fn create_mipmaps(&mut self) {
-        let mut fft = RealFftPlanner::<f32>::new();
+    pub fn from_directory(path: impl AsRef<Path>) -> Result<Box<Self>, WavetableError> {
+        Self::from_directory_with_options(path, LoadOptions::default())
+    }
 
-        let table_size: usize = 1 << Self::NUM_OCTAVES;
-        let normalisation_factor = 1. / table_size as f32;
+    /// Load every WAV file in a directory, in lexicographic order of their
+    /// names, as one frame each, e. g. for packs of single-cycle waveforms.
+    ///
+    /// A file's cycle length is that given in `options`, or in its `clm `
+    /// chunk, or its entire length, in that order of priority. Only the
+    /// first cycle of every file is kept
+    pub fn from_directory_with_options(
+        path: impl AsRef<Path>,
+        options: LoadOptions,
+    ) -> Result<Box<Self>, WavetableError> {
+        let mut paths = Vec::new();
 
-        let r2c = fft.plan_fft_forward(table_size);
+        for entry in fs::read_dir(path).map_err(WavetableError::Io)? {
+            let path = entry.map_err(WavetableError::Io)?.path();
 
-        let mut spectrum = r2c.make_output_vec();
-        let mut mipmap_scratch = spectrum.clone();
-        let mut spectrum_scratch = spectrum.clone();
-        let mut wave_scratch = r2c.make_input_vec();
+            let is_wav = path
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("wav"));
 
-        let c2r = fft.plan_fft_inverse(table_size);
+            if is_wav && path.is_file() {
+                paths.push(path);
+            }
+        }
 
-        for table in self.as_mut_slice() {
-            let (full_table, mipmaps) = table.split_last_mut().unwrap();
+        // don't rely on the platform-specific order of `read_dir`
+        paths.sort_unstable_by(|a, b| a.file_name().cmp(&b.file_name()));
 
-            wave_scratch.copy_from_slice(full_table);
+        Self::check_num_frames(paths.len())?;
 
-            r2c.process_with_scratch(&mut wave_scratch, &mut spectrum, &mut spectrum_scratch)
-                .unwrap();
+        let mut this = Self::with_frame_count(paths.len());
 
-            let mut partials = 1 << (Self::NUM_OCTAVES - 1);
+        for (frame, path) in this.as_mut_slice().chunks_exact_mut(1).zip(paths) {
+            let bytes = fs::read(path).map_err(WavetableError::Io)?;
+            let plain = plain_wav(&bytes);
+            let samples = Self::decode_wav(
+                WavReader::new(plain.as_deref().unwrap_or(&bytes))?,
+                options.channel_mode,
+            )?;
 
-            for mipmap in mipmaps.iter_mut().rev() {
-                let pass_band = &spectrum[..partials / 2 + 1];
+            let cycle_len = options
+                .cycle_len
+                .or_else(|| serum_cycle_len(&bytes))
+                .map_or(samples.len(), NonZeroUsize::get);
 
-                let (pb, sb) = spectrum_scratch.split_at_mut(partials / 2 + 1);
+            let cycle = samples
+                .get(..cycle_len)
+                .ok_or(WavetableError::WrongLength {
+                    cycle_len,
+                    expected: cycle_len,
+                    actual: samples.len(),
+                })?;
 
-                sb.fill(Complex32::new(0., 0.));
-                pb.copy_from_slice(pass_band);
+            Self::write_cycles(
+                frame,
+                cycle,
+                NonZeroUsize::new(cycle_len).ok_or(WavetableError::Empty)?,
+            );
+        }
 
-                c2r.process_with_scratch(&mut spectrum_scratch, mipmap, &mut mipmap_scratch)
-                    .unwrap();
+        this.preprocess(&options);
+        this.create_mipmaps_with_options(&options.mipmaps);
 
-                mipmap
-                    .iter_mut()
-                    .for_each(|sample| *sample *= normalisation_factor);
+        Ok(this)
+    }
 
-                partials /= 2;
-            }
+    pub fn from_surge_wt(path: impl AsRef<Path>) -> Result<Box<Self>, WavetableError> {
+        let file = File::open(path).map_err(WavetableError::Io)?;
+        Self::from_surge_wt_reader(io::BufReader::new(file))
+    }
+
+    /// Load a wavetable in Surge's `.wt` format: a 12-byte header (`vawt`, the
+    /// number of samples per wave as a `u32`, the number of waves as a `u16`, and
+    /// flags as a `u16`), followed by the waves' samples as `f32`s or `i16`s
+    pub fn from_surge_wt_reader(mut reader: impl io::Read) -> Result<Box<Self>, WavetableError> {
+        const INT16: u16 = 0x4;
+        const INT16_FULL_RANGE: u16 = 0x8;
+        /// Surge itself writes waves of at most 4096 samples
+        const MAX_WAVE_SIZE: usize = 1 << 16;
+
+        let mut header = [0; 12];
+        reader.read_exact(&mut header).map_err(WavetableError::Io)?;
+
+        if &header[..4] != b"vawt" {
+            return Err(WavetableError::InvalidHeader(
+                "expected the `vawt` magic number",
+            ));
+        }
+
+        let wave_size = u32::from_le_bytes(header[4..8].try_into().unwrap()) as usize;
+        let wave_count = u16::from_le_bytes(header[8..10].try_into().unwrap()) as usize;
+        let flags = u16::from_le_bytes(header[10..12].try_into().unwrap());
+
+        let cycle_len =
+            NonZeroUsize::new(wave_size).ok_or(WavetableError::InvalidHeader("zero wave size"))?;
+
+        if wave_size > MAX_WAVE_SIZE {
+            return Err(WavetableError::InvalidHeader("wave size too large"));
+        }
+
+        Self::check_num_frames(wave_count)?;
+
+        let int16 = flags & INT16 != 0;
+        let sample_size = if int16 {
+            mem::size_of::<i16>()
+        } else {
+            mem::size_of::<f32>()
+        };
+
+        let num_bytes = wave_size
+            .checked_mul(wave_count)
+            .and_then(|num_samples| num_samples.checked_mul(sample_size))
+            .ok_or(WavetableError::InvalidHeader("wave data too large"))?;
+
+        // the buffer only grows as data actually comes in, instead of
+        // being allocated upfront, with the size the header claims
+        let mut bytes = Vec::new();
+        reader
+            .take(num_bytes as u64)
+            .read_to_end(&mut bytes)
+            .map_err(WavetableError::Io)?;
+
+        if bytes.len() != num_bytes {
+            return Err(WavetableError::Io(io::ErrorKind::UnexpectedEof.into()));
+        }
+
+        let samples = if int16 {
+            // unless told otherwise, surge scales 16 bit samples so that 2^14 maps to 1.0
+            let scale = if flags & INT16_FULL_RANGE != 0 {
+                1. / 32768.
+            } else {
+                1. / 16384.
+            };
+
+            bytes
+                .chunks_exact(2)
+                .map(|b| i16::from_le_bytes([b[0], b[1]]) as f32 * scale)
+                .collect::<Vec<_>>()
+        } else {
+            bytes
+                .chunks_exact(4)
+                .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                .collect()
+        };
+
+        Self::from_cycles(&samples, cycle_len)
+    }
+
+    /// Build a table from frames already in memory, computing mipmaps
+    pub fn from_frames(frames: &[[f32; Self::FRAME_LEN]]) -> Result<Box<Self>, WavetableError> {
+        Self::check_num_frames(frames.len())?;
+        Ok(frames.into())
+    }
+
+    /// Build a table by evaluating `f(frame_index, norm_frame, phase)` at every sample
+    /// of each frame, where `norm_frame` goes from `0` (first frame) to `1` (last frame)
+    /// and `phase` goes from `0` (inclusive) to `1` (exclusive) over each frame.
+    ///
+    /// E. g. a pulse-width sweep:
+    ///
+    /// ```
+    /// use wt_osc::wavetable::BandLimitedWaveTables;
+    ///
+    /// let pwm = BandLimitedWaveTables::from_fn(64, |_, norm_frame, phase| {
+    ///     let width = 0.5 - 0.45 * norm_frame;
+    ///     if phase < width { 1. } else { -1. }
+    /// })
+    /// .unwrap();
+    ///
+    /// assert_eq!(pwm.num_frames(), 64);
+    /// ```
+    pub fn from_fn(
+        num_frames: usize,
+        mut f: impl FnMut(usize, f32, f32) -> f32,
+    ) -> Result<Box<Self>, WavetableError> {
+        Self::check_num_frames(num_frames)?;
+
+        let mut this = Self::with_frame_count(num_frames);
+
+        let frame_norm = 1. / (num_frames - 1).max(1) as f32;
+        let phase_norm = 1. / Self::FRAME_LEN as f32;
+
+        for (i, output) in this
+            .as_mut_slice()
+            .iter_mut()
+            .map(|mipmaps| mipmaps.last_mut().unwrap())
+            .enumerate()
+        {
+            let norm_frame = i as f32 * frame_norm;
+
+            for (j, sample) in output.iter_mut().enumerate() {
+                *sample = f(i, norm_frame, j as f32 * phase_norm);
+            }
+        }
+
+        this.create_mipmaps();
+
+        Ok(this)
+    }
+
+    /// Synthesize each frame from the complex amplitudes of its harmonics, starting
+    /// from the fundamental. A harmonic with amplitude `a` and phase `φ` contributes
+    /// `a * sin(k * t + φ)` to its frame, where `t` goes from `0` to `2π` over the frame
+    pub fn from_harmonics(frames: &[&[Complex32]]) -> Result<Box<Self>, WavetableError> {
+        // the nyquist bin can't hold a sine
+        const MAX_HARMONICS: usize = BandLimitedWaveTables::FRAME_LEN / 2 - 1;
+
+        Self::check_num_frames(frames.len())?;
+
+        if let Some(num_harmonics) = frames
+            .iter()
+            .map(|harmonics| harmonics.len())
+            .find(|&len| len > MAX_HARMONICS)
+        {
+            return Err(WavetableError::TooManyHarmonics {
+                max: MAX_HARMONICS,
+                actual: num_harmonics,
+            });
+        }
+
+        let mut this = Self::with_frame_count(frames.len());
+
+        let c2r = RealFftPlanner::<f32>::new().plan_fft_inverse(Self::FRAME_LEN);
+        let mut spectrum = c2r.make_input_vec();
+
+        // sin(x) = Re(-i * e^(ix)), and the inverse FFT isn't normalized
+        let scale = Complex32::new(0., -0.5);
+
+        for (output, harmonics) in this
+            .as_mut_slice()
+            .iter_mut()
+            .map(|mipmaps| mipmaps.last_mut().unwrap())
+            .zip(frames)
+        {
+            spectrum.fill(Complex32::new(0., 0.));
+
+            for (bin, &harmonic) in spectrum[1..].iter_mut().zip(harmonics.iter()) {
+                *bin = harmonic * scale;
+            }
+
+            c2r.process(&mut spectrum, output).unwrap();
+        }
+
+        this.create_mipmaps();
+
+        Ok(this)
+    }
+
+    /// A waveshaped sine, morphing from a pure sine (the first frame), to
+    /// `sin + Σ weights[n - 2] * T_n(sin)` (the last frame), where `T_n` is the `n`th
+    /// Chebyshev polynomial (of the first kind), which turns a sine into its `n`th
+    /// harmonic, the weights are faded in linearly across frames. Frames that
+    /// peak above `±1` are scaled down to `±1`
+    pub fn from_chebyshev(weights: &[f32], num_frames: usize) -> Result<Box<Self>, WavetableError> {
+        Self::check_num_frames(num_frames)?;
+
+        // `T_n(sin(t)) = cos(n * (t - π/2))`, i. e. `sin(n * t + (1 - n) * π/2)`
+        let harmonic = |n: usize, weight: f32| {
+            let phase = [(1., 0.), (0., -1.), (-1., 0.), (0., 1.)][(n - 1) % 4];
+            Complex32::new(phase.0, phase.1) * weight
+        };
+
+        let frame_norm = 1. / (num_frames - 1).max(1) as f32;
+
+        let frames: Vec<Vec<_>> = (0..num_frames)
+            .map(|i| {
+                let mix = i as f32 * frame_norm;
+
+                iter::once(Complex32::new(1., 0.))
+                    .chain(
+                        (2..)
+                            .zip(weights)
+                            .map(|(n, &weight)| harmonic(n, weight * mix)),
+                    )
+                    .collect()
+            })
+            .collect();
+
+        let frames: Vec<_> = frames.iter().map(Vec::as_slice).collect();
+
+        let mut this = Self::from_harmonics(&frames)?;
+        let mut rescaled = false;
+
+        for frame in this
+            .as_mut_slice()
+            .iter_mut()
+            .map(|mipmaps| mipmaps.last_mut().unwrap())
+        {
+            let peak = frame.iter().fold(0f32, |max, s| max.max(s.abs()));
+
+            if peak > 1. {
+                frame.iter_mut().for_each(|s| *s /= peak);
+                rescaled = true;
+            }
+        }
+
+        if rescaled {
+            this.create_mipmaps();
+        }
+
+        Ok(this)
+    }
+
+    /// Like `from_frames`, but takes the frames' samples concatenated in one slice
+    pub fn from_samples(samples: &[f32]) -> Result<Box<Self>, WavetableError> {
+        Self::from_cycles(samples, NonZeroUsize::new(Self::FRAME_LEN).unwrap())
+    }
+
+    /// Like `from_samples`, but for cycles of any length, which are resampled
+    /// to `FRAME_LEN` samples in the frequency domain, dropping any partials
+    /// above the resulting frames' Nyquist frequency
+    pub fn from_cycles(
+        samples: &[f32],
+        cycle_len: NonZeroUsize,
+    ) -> Result<Box<Self>, WavetableError> {
+        Self::from_cycles_with_options(samples, cycle_len, &LoadOptions::default(), None)
+    }
+
+    fn from_cycles_with_options(
+        samples: &[f32],
+        cycle_len: NonZeroUsize,
+        options: &LoadOptions,
+        mut generator: Option<&mut MipmapGenerator>,
+    ) -> Result<Box<Self>, WavetableError> {
+        let num_frames = Self::num_frames_from_len(samples.len(), cycle_len.get())?;
+
+        let mut this = Self::with_frame_count(num_frames);
+
+        Self::write_cycles(this.as_mut_slice(), samples, cycle_len);
+        this.preprocess(options);
+        this.create_mipmaps_with(&options.mipmaps, generator.as_deref_mut());
+
+        if generator.is_some_and(|g| g.is_cancelled()) {
+            return Err(WavetableError::Cancelled);
+        }
+
+        Ok(this)
+    }
+
+    /// Apply the processing `options` asks for to the top mipmaps of all frames
+    fn preprocess(&mut self, options: &LoadOptions) {
+        self.align_phases(options.phase_alignment);
+        self.normalize_frames(options.normalize);
+    }
+
+    /// Circularly shift the top mipmaps of all frames according to `mode`.
+    /// `create_mipmaps` must be called again afterwards
+    pub fn align_phases(&mut self, mode: PhaseAlignment) {
+        match mode {
+            PhaseAlignment::None => (),
+            PhaseAlignment::Peak => {
+                for frame in self
+                    .as_mut_slice()
+                    .iter_mut()
+                    .map(|mipmaps| mipmaps.last_mut().unwrap())
+                {
+                    let (peak_index, _) = frame.iter().enumerate().fold(
+                        (0, f32::NEG_INFINITY),
+                        |(i, max), (j, &s)| {
+                            if s > max {
+                                (j, s)
+                            } else {
+                                (i, max)
+                            }
+                        },
+                    );
+
+                    frame.rotate_left(peak_index);
+                }
+            }
+            PhaseAlignment::Fundamental => {
+                let mut fft = RealFftPlanner::<f32>::new();
+
+                let r2c = fft.plan_fft_forward(Self::FRAME_LEN);
+                let c2r = fft.plan_fft_inverse(Self::FRAME_LEN);
+
+                let mut wave_scratch = r2c.make_input_vec();
+                let mut spectrum = r2c.make_output_vec();
+                let normalisation_factor = 1. / Self::FRAME_LEN as f32;
+                let nyquist = Self::FRAME_LEN / 2;
+
+                for frame in self
+                    .as_mut_slice()
+                    .iter_mut()
+                    .map(|mipmaps| mipmaps.last_mut().unwrap())
+                {
+                    wave_scratch.copy_from_slice(frame);
+                    r2c.process(&mut wave_scratch, &mut spectrum).unwrap();
+
+                    let fundamental = spectrum[1];
+
+                    // the fundamental's phase is meaningless if it's (almost) absent
+                    if fundamental.norm() < f32::EPSILON {
+                        continue;
+                    }
+
+                    // a sine's fundamental has a phase of -π/2
+                    let shift = -FRAC_PI_2 - fundamental.arg();
+
+                    for (k, bin) in spectrum.iter_mut().enumerate() {
+                        *bin *= Complex32::from_polar(normalisation_factor, k as f32 * shift);
+                    }
+
+                    // the nyquist bin must stay real
+                    spectrum[nyquist].im = 0.;
+
+                    c2r.process(&mut spectrum, frame).unwrap();
+                }
+            }
+        }
+    }
+
+    /// Scale the top mipmaps of all frames according to `mode`.
+    /// `create_mipmaps` must be called again afterwards
+    pub fn normalize_frames(&mut self, mode: Normalize) {
+        // don't blow near-silent frames up to absurd levels
+        const MAX_GAIN: f32 = 16.;
+
+        let peak = |frame: &[f32]| frame.iter().fold(0f32, |max, s| max.max(s.abs()));
+        let rms =
+            |frame: &[f32]| (frame.iter().map(|s| s * s).sum::<f32>() / frame.len() as f32).sqrt();
+        let gain = |level: f32, target: f32| (target / level).min(MAX_GAIN);
+
+        let global_gain = gain(
+            self.as_slice()
+                .iter()
+                .map(|mipmaps| peak(mipmaps.last().unwrap()))
+                .fold(0., f32::max),
+            1.,
+        );
+
+        for frame in self
+            .as_mut_slice()
+            .iter_mut()
+            .map(|mipmaps| mipmaps.last_mut().unwrap())
+        {
+            let gain = match mode {
+                Normalize::None => return,
+                Normalize::GlobalPeak => global_gain,
+                Normalize::PerFramePeak => gain(peak(frame), 1.),
+                Normalize::PerFrameRms => gain(rms(frame), FRAC_1_SQRT_2),
+            };
+
+            frame.iter_mut().for_each(|s| *s *= gain);
+        }
+    }
+
+    /// Write `samples`, split in cycles of `cycle_len` samples each,
+    /// resampled if necessary, into the top mipmaps of `frames`
+    fn write_cycles(
+        frames: &mut [[[f32; Self::FRAME_LEN]; Self::NUM_MIPMAPS]],
+        samples: &[f32],
+        cycle_len: NonZeroUsize,
+    ) {
+        let cycle_len = cycle_len.get();
+
+        let top_mipmaps = frames
+            .iter_mut()
+            .map(|mipmaps| mipmaps.last_mut().unwrap())
+            .zip(samples.chunks_exact(cycle_len));
+
+        if cycle_len == Self::FRAME_LEN {
+            for (output, input) in top_mipmaps {
+                output.copy_from_slice(input);
+            }
+
+            return;
+        }
+
+        let mut fft = RealFftPlanner::<f32>::new();
+
+        let r2c = fft.plan_fft_forward(cycle_len);
+        let c2r = fft.plan_fft_inverse(Self::FRAME_LEN);
+
+        let mut cycle_scratch = r2c.make_input_vec();
+        let mut spectrum = r2c.make_output_vec();
+        let mut resampled_spectrum = c2r.make_input_vec();
+
+        // skip both the source's and the destination's nyquist bins, they are ambiguous
+        let num_bins = ((cycle_len + 1) / 2).min(Self::FRAME_LEN / 2);
+        let normalisation_factor = 1. / cycle_len as f32;
+
+        for (output, cycle) in top_mipmaps {
+            cycle_scratch.copy_from_slice(cycle);
+
+            r2c.process(&mut cycle_scratch, &mut spectrum).unwrap();
+
+            let (pb, sb) = resampled_spectrum.split_at_mut(num_bins);
+
+            sb.fill(Complex32::new(0., 0.));
+
+            for (output, &input) in pb.iter_mut().zip(spectrum.iter()) {
+                *output = input * normalisation_factor;
+            }
+
+            c2r.process(&mut resampled_spectrum, output).unwrap();
+        }
+    }
+
+    fn num_frames_from_len(num_samples: usize, cycle_len: usize) -> Result<usize, WavetableError> {
+        if num_samples % cycle_len != 0 {
+            return Err(WavetableError::WrongLength {
+                cycle_len,
+                expected: enclosing_div(num_samples, cycle_len) * cycle_len,
+                actual: num_samples,
+            });
+        }
+
+        let num_frames = num_samples / cycle_len;
+        Self::check_num_frames(num_frames)?;
+
+        Ok(num_frames)
+    }
+
+    fn check_num_frames(num_frames: usize) -> Result<(), WavetableError> {
+        if num_frames == 0 {
+            return Err(WavetableError::Empty);
+        }
+
+        if num_frames > Self::MAX_FRAMES {
+            return Err(WavetableError::TooManyFrames {
+                max: Self::MAX_FRAMES,
+                actual: num_frames,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Like `from_reader`, but panics if the file couldn't be loaded
+    pub fn from_reader_unchecked(reader: impl io::Read) -> Box<Self> {
+        Self::from_reader(reader).unwrap()
+    }
+
+    /// Copy the frames in `range` (and their mipmaps) into a new table
+    pub fn slice_frames(&self, range: Range<usize>) -> Result<Box<Self>, WavetableError> {
+        let frames =
+            self.as_slice()
+                .get(range.clone())
+                .ok_or(WavetableError::FramesOutOfRange {
+                    range,
+                    num_frames: self.num_frames(),
+                })?;
+
+        Self::check_num_frames(frames.len())?;
+
+        let mut this = Self::with_frame_count(frames.len());
+        this.as_mut_slice().copy_from_slice(frames);
+
+        Ok(this)
+    }
+
+    /// Build a table containing the frames (and mipmaps) of all `tables`, one after the other
+    pub fn concat(tables: &[&Self]) -> Result<Box<Self>, WavetableError> {
+        let num_frames = tables.iter().map(|table| table.num_frames()).sum();
+        Self::check_num_frames(num_frames)?;
+
+        let mut this = Self::with_frame_count(num_frames);
+        let mut frames = this.as_mut_slice();
+
+        for table in tables {
+            let (output, rest) = mem::take(&mut frames).split_at_mut(table.num_frames());
+            output.copy_from_slice(table.as_slice());
+            frames = rest;
+        }
+
+        Ok(this)
+    }
+
+    /// Build a table with `target_frames` frames, synthesizing the frames
+    /// between those of `self` using spectral interpolation, e. g. so that
+    /// sweeping through a table with only a handful of frames is smoother
+    pub fn interpolated(&self, target_frames: usize) -> Result<Box<Self>, WavetableError> {
+        self.interpolated_with_mode(target_frames, FrameInterpolation::default())
+    }
+
+    pub fn interpolated_with_mode(
+        &self,
+        target_frames: usize,
+        mode: FrameInterpolation,
+    ) -> Result<Box<Self>, WavetableError> {
+        Self::check_num_frames(self.num_frames())?;
+        Self::check_num_frames(target_frames)?;
+
+        let last_frame = self.num_frames() - 1;
+        let step = last_frame as f32 / (target_frames - 1).max(1) as f32;
+
+        let frame_positions = (0..target_frames).map(|i| {
+            let pos = i as f32 * step;
+            let a = (pos as usize).min(last_frame);
+            (a, (a + 1).min(last_frame), pos - a as f32)
+        });
+
+        let sources = self.as_slice();
+
+        let mut this = Self::with_frame_count(target_frames);
+
+        let outputs = this
+            .as_mut_slice()
+            .iter_mut()
+            .map(|mipmaps| mipmaps.last_mut().unwrap());
+
+        match mode {
+            FrameInterpolation::Linear => {
+                for (output, (a, b, t)) in outputs.zip(frame_positions) {
+                    let a = sources[a].last().unwrap();
+                    let b = sources[b].last().unwrap();
+
+                    for (output, (&a, &b)) in output.iter_mut().zip(a.iter().zip(b)) {
+                        *output = (b - a).mul_add(t, a);
+                    }
+                }
+            }
+
+            FrameInterpolation::Spectral => {
+                let mut fft = RealFftPlanner::<f32>::new();
+
+                let r2c = fft.plan_fft_forward(Self::FRAME_LEN);
+                let c2r = fft.plan_fft_inverse(Self::FRAME_LEN);
+
+                let mut wave_scratch = r2c.make_input_vec();
+
+                let spectra: Vec<_> = sources
+                    .iter()
+                    .map(|mipmaps| {
+                        let mut spectrum = r2c.make_output_vec();
+                        wave_scratch.copy_from_slice(mipmaps.last().unwrap());
+                        r2c.process(&mut wave_scratch, &mut spectrum).unwrap();
+                        spectrum
+                    })
+                    .collect();
+
+                let mut spectrum = c2r.make_input_vec();
+                let normalisation_factor = 1. / Self::FRAME_LEN as f32;
+
+                for (output, (a, b, t)) in outputs.zip(frame_positions) {
+                    for (bin, (a, b)) in spectrum.iter_mut().zip(spectra[a].iter().zip(&spectra[b]))
+                    {
+                        let (mag_a, phase_a) = a.to_polar();
+                        let (mag_b, phase_b) = b.to_polar();
+
+                        // take the shortest path between both phases
+                        let mut phase_diff = phase_b - phase_a;
+                        phase_diff -= TAU * (phase_diff / TAU).round();
+
+                        *bin = Complex32::from_polar(
+                            (mag_b - mag_a).mul_add(t, mag_a),
+                            phase_diff.mul_add(t, phase_a),
+                        ) * normalisation_factor;
+                    }
+
+                    // the DC and nyquist bins are real, their "phases" can only be 0 or π
+                    for i in [0, Self::FRAME_LEN / 2] {
+                        let (a, b) = (spectra[a][i].re, spectra[b][i].re);
+                        spectrum[i] =
+                            Complex32::new((b - a).mul_add(t, a) * normalisation_factor, 0.);
+                    }
+
+                    c2r.process(&mut spectrum, output).unwrap();
+                }
+            }
+        }
+
+        this.create_mipmaps();
+
+        Ok(this)
+    }
+
+    /// Write the top mipmap of every frame to a 32-bit float mono WAV file,
+    /// optionally with a Serum `clm ` chunk announcing the frames' length
+    pub fn write_wav(
+        &self,
+        path: impl AsRef<Path>,
+        serum_clm_chunk: bool,
+    ) -> Result<(), WavetableError> {
+        let file = File::create(path).map_err(WavetableError::Io)?;
+        self.write_wav_to(io::BufWriter::new(file), serum_clm_chunk)
+    }
+
+    pub fn write_wav_to(
+        &self,
+        mut writer: impl io::Write,
+        serum_clm_chunk: bool,
+    ) -> Result<(), WavetableError> {
+        let spec = WavSpec {
+            channels: 1,
+            sample_rate: 44100,
+            bits_per_sample: 32,
+            sample_format: SampleFormat::Float,
+        };
+
+        let mut cursor = io::Cursor::new(Vec::new());
+        let mut wav_writer = WavWriter::new(&mut cursor, spec)?;
+
+        for &sample in self
+            .as_slice()
+            .iter()
+            .flat_map(|mipmaps| mipmaps.last().unwrap())
+        {
+            wav_writer.write_sample(sample)?;
+        }
+
+        wav_writer.finalize()?;
+
+        let mut bytes = cursor.into_inner();
+
+        if serum_clm_chunk {
+            // hound can't write chunks it doesn't know of, so we insert it ourselves
+            let text = format!("<!>{} 00000000 wavetable (wt_osc)", Self::FRAME_LEN);
+
+            let mut chunk = b"clm ".to_vec();
+            chunk.extend_from_slice(&(text.len() as u32).to_le_bytes());
+            chunk.extend_from_slice(text.as_bytes());
+
+            if text.len() & 1 != 0 {
+                chunk.push(0);
+            }
+
+            let (data_offset, ..) = riff_chunks(&bytes)
+                .find(|&(_, id, _)| id == b"data")
+                .unwrap();
+
+            let data = bytes.split_off(data_offset);
+            bytes.extend_from_slice(&chunk);
+            bytes.extend_from_slice(&data);
+
+            let riff_len = bytes.len() as u32 - 8;
+            bytes[4..8].copy_from_slice(&riff_len.to_le_bytes());
+        }
+
+        writer.write_all(&bytes).map_err(WavetableError::Io)
+    }
+
+    /// Decode the samples of a WAV file, deinterleaving them according to `channel_mode`
+    fn decode_wav<R: io::Read>(
+        reader: WavReader<R>,
+        channel_mode: ChannelMode,
+    ) -> Result<Vec<f32>, WavetableError> {
+        let spec = reader.spec();
+        let num_channels = spec.channels as usize;
+
+        match (spec.sample_format, spec.bits_per_sample) {
+            (SampleFormat::Float, 32) | (SampleFormat::Int, 16 | 24 | 32) => (),
+            (format, bits_per_sample) => {
+                return Err(WavetableError::UnsupportedFormat {
+                    format,
+                    bits_per_sample,
+                })
+            }
+        }
+
+        match spec.sample_format {
+            SampleFormat::Float => {
+                Self::read_samples(reader.into_samples::<f32>(), num_channels, channel_mode)
+            }
+            SampleFormat::Int => {
+                // integer samples only have `bits_per_sample` significant bits,
+                // regardless of the width of the type hound reads them into
+                let scale = 1. / (1u32 << (spec.bits_per_sample - 1)) as f32;
+
+                Self::read_samples(
+                    reader
+                        .into_samples::<i32>()
+                        .map(|sample| sample.map(|sample| sample as f32 * scale)),
+                    num_channels,
+                    channel_mode,
+                )
+            }
+        }
+    }
+
+    fn read_samples(
+        samples: impl ExactSizeIterator<Item = Result<f32, hound::Error>>,
+        num_channels: usize,
+        channel_mode: ChannelMode,
+    ) -> Result<Vec<f32>, WavetableError> {
+        let channel = match channel_mode {
+            ChannelMode::Left => Some(0),
+            ChannelMode::Right => Some(1.min(num_channels - 1)),
+            ChannelMode::Mix => None,
+        };
+
+        let mix_normalisation = (num_channels as f32).recip();
+
+        let mut output = Vec::with_capacity(samples.len() / num_channels);
+        let mut acc = 0.;
+
+        for (i, sample) in samples.enumerate() {
+            let sample = sample?;
+            let i = i % num_channels;
+
+            match channel {
+                Some(channel) if channel == i => acc = sample,
+                None => acc += sample,
+                _ => (),
+            }
+
+            if i == num_channels - 1 {
+                output.push(if channel.is_some() {
+                    acc
+                } else {
+                    acc * mix_normalisation
+                });
+
+                acc = 0.;
+            }
+        }
+
+        Ok(output)
+    }
+
+    #[inline]
+    pub fn create_mipmaps(&mut self) {
+        self.create_mipmaps_with_options(&MipmapOptions::default());
+    }
+
+    pub fn create_mipmaps_with_options(&mut self, options: &MipmapOptions) {
+        MipmapGenerator::new().generate_with_options(self, options);
+    }
+
+    /// Like `create_mipmaps_with_options`, but processes frames in parallel
+    #[cfg(feature = "rayon")]
+    pub fn create_mipmaps_par_with_options(&mut self, options: &MipmapOptions) {
+        MipmapGenerator::new().generate_par_with_options(self, options);
+    }
+
+    /// Generate mipmaps with `generator` if there is one, or with a throwaway one otherwise
+    fn create_mipmaps_with(
+        &mut self,
+        options: &MipmapOptions,
+        generator: Option<&mut MipmapGenerator>,
+    ) {
+        match generator {
+            Some(generator) => generator.generate_with_options(self, options),
+            None => self.create_mipmaps_with_options(options),
+        }
+    }
+}
+
+/// FFT plans and buffers used to generate mipmaps, reusable across tables
+/// to avoid planning FFTs and allocating every time, e. g. when previewing
+/// many wavetables in a row
+#[derive(Clone)]
+pub struct MipmapGenerator {
+    r2c: Arc<dyn RealToComplex<f32>>,
+    c2r: Arc<dyn ComplexToReal<f32>>,
+    wave_scratch: Vec<f32>,
+    spectrum: Vec<Complex32>,
+    spectrum_scratch: Vec<Complex32>,
+    r2c_scratch: Vec<Complex32>,
+    c2r_scratch: Vec<Complex32>,
+    cancelled: Option<Arc<AtomicBool>>,
+}
+
+impl Default for MipmapGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MipmapGenerator {
+    pub fn new() -> Self {
+        let mut fft = RealFftPlanner::<f32>::new();
+
+        let r2c = fft.plan_fft_forward(BandLimitedWaveTables::FRAME_LEN);
+        let c2r = fft.plan_fft_inverse(BandLimitedWaveTables::FRAME_LEN);
+
+        Self {
+            wave_scratch: r2c.make_input_vec(),
+            spectrum: r2c.make_output_vec(),
+            spectrum_scratch: c2r.make_input_vec(),
+            r2c_scratch: r2c.make_scratch_vec(),
+            c2r_scratch: c2r.make_scratch_vec(),
+            r2c,
+            c2r,
+            cancelled: None,
+        }
+    }
+
+    /// Stop generating mipmaps, between frames, once `flag` is set. Loads
+    /// using this generator then fail with `WavetableError::Cancelled`
+    pub fn with_cancel_flag(mut self, flag: Arc<AtomicBool>) -> Self {
+        self.cancelled = Some(flag);
+        self
+    }
+
+    #[inline]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::Relaxed))
+    }
+
+    #[inline]
+    pub fn generate(&mut self, table: &mut BandLimitedWaveTables) {
+        self.generate_with_options(table, &MipmapOptions::default());
+    }
+
+    pub fn generate_with_options(
+        &mut self,
+        table: &mut BandLimitedWaveTables,
+        options: &MipmapOptions,
+    ) {
+        for mipmaps in table.as_mut_slice() {
+            if self.is_cancelled() {
+                return;
+            }
+
+            self.generate_frame(mipmaps, options);
+        }
+    }
+
+    /// Like `generate`, but processes frames in parallel, each worker
+    /// thread sharing `self`'s FFT plans, with its own buffers
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub fn generate_par(&self, table: &mut BandLimitedWaveTables) {
+        self.generate_par_with_options(table, &MipmapOptions::default());
+    }
+
+    #[cfg(feature = "rayon")]
+    pub fn generate_par_with_options(
+        &self,
+        table: &mut BandLimitedWaveTables,
+        options: &MipmapOptions,
+    ) {
+        table.as_mut_slice().par_iter_mut().for_each_init(
+            || self.clone(),
+            |this, mipmaps| this.generate_frame(mipmaps, options),
+        );
+    }
+
+    fn generate_frame(
+        &mut self,
+        mipmaps: &mut [[f32; BandLimitedWaveTables::FRAME_LEN]; BandLimitedWaveTables::NUM_MIPMAPS],
+        options: &MipmapOptions,
+    ) {
+        let normalisation_factor = 1. / BandLimitedWaveTables::FRAME_LEN as f32;
+
+        let (full_table, mipmaps) = mipmaps.split_last_mut().unwrap();
+
+        self.wave_scratch.copy_from_slice(full_table);
+
+        self.r2c
+            .process_with_scratch(
+                &mut self.wave_scratch,
+                &mut self.spectrum,
+                &mut self.r2c_scratch,
+            )
+            .unwrap();
+
+        if options.remove_dc {
+            let mean = self.spectrum[0].re * normalisation_factor;
+            full_table.iter_mut().for_each(|sample| *sample -= mean);
+            self.spectrum[0] = Complex32::new(0., 0.);
+        }
+
+        let mut partials = 1 << (BandLimitedWaveTables::NUM_OCTAVES - 1);
+
+        for mipmap in mipmaps.iter_mut().rev() {
+            let pass_band = &self.spectrum[..partials / 2 + 1];
+
+            let (pb, sb) = self.spectrum_scratch.split_at_mut(partials / 2 + 1);
+
+            sb.fill(Complex32::new(0., 0.));
+            pb.copy_from_slice(pass_band);
+
+            for (bin, gain) in options.transition.gains(partials / 2) {
+                pb[bin] *= gain;
+            }
+
+            self.c2r
+                .process_with_scratch(&mut self.spectrum_scratch, mipmap, &mut self.c2r_scratch)
+                .unwrap();
+
+            mipmap
+                .iter_mut()
+                .for_each(|sample| *sample *= normalisation_factor);
+
+            partials /= 2;
         }
     }
 }
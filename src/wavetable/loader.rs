@@ -0,0 +1,101 @@
+//! Loading wavetables off the audio thread
+//!
+//! Tables loaded here are handed back as an `Arc<BandLimitedWaveTables>`,
+//! ready to be sent to `WTOsc::custom_event`
+
+use super::*;
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    thread::{self, JoinHandle},
+};
+
+/// A wavetable being loaded in the background
+pub struct LoadHandle {
+    cancelled: Arc<AtomicBool>,
+    thread: JoinHandle<()>,
+}
+
+impl LoadHandle {
+    /// Stop the load as soon as possible, i. e. before the next frame's
+    /// mipmaps are generated. Its completion callback won't be called,
+    /// unless it already has been
+    #[inline]
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.thread.is_finished()
+    }
+
+    /// Block until the load is finished (or cancelled)
+    #[inline]
+    pub fn join(self) {
+        // the callback panicking is the caller's business
+        let _ = self.thread.join();
+    }
+}
+
+/// Load the wavetable at `path` (and create its mipmaps) on a new thread,
+/// then call `on_complete` with the result, from that thread
+pub fn load_async(
+    path: impl AsRef<Path>,
+    options: LoadOptions,
+    on_complete: impl FnOnce(Result<Arc<BandLimitedWaveTables>, WavetableError>) + Send + 'static,
+) -> LoadHandle {
+    let path = path.as_ref().to_path_buf();
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let is_cancelled = Arc::clone(&cancelled);
+
+    let thread = thread::spawn(move || {
+        if is_cancelled.load(Ordering::Relaxed) {
+            return;
+        }
+
+        let mut generator = MipmapGenerator::new().with_cancel_flag(Arc::clone(&is_cancelled));
+        let result =
+            BandLimitedWaveTables::from_file_with_generator(path, options, Some(&mut generator))
+                .map(Arc::from);
+
+        if !is_cancelled.load(Ordering::Relaxed) {
+            on_complete(result);
+        }
+    });
+
+    LoadHandle { cancelled, thread }
+}
+
+/// Loads one table at a time, starting a new load cancels the previous
+/// one, so that browsing through tables doesn't queue up loads
+#[derive(Default)]
+pub struct Loader {
+    current: Option<LoadHandle>,
+}
+
+impl Loader {
+    pub fn load(
+        &mut self,
+        path: impl AsRef<Path>,
+        options: LoadOptions,
+        on_complete: impl FnOnce(Result<Arc<BandLimitedWaveTables>, WavetableError>) + Send + 'static,
+    ) {
+        self.cancel();
+        self.current = Some(load_async(path, options, on_complete));
+    }
+
+    /// Cancel the current load, if any
+    #[inline]
+    pub fn cancel(&mut self) {
+        if let Some(handle) = self.current.take() {
+            handle.cancel();
+        }
+    }
+
+    #[inline]
+    pub fn is_loading(&self) -> bool {
+        self.current
+            .as_ref()
+            .is_some_and(|handle| !handle.is_finished())
+    }
+}
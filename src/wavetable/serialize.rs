@@ -0,0 +1,47 @@
+//! Tables are stored as their top mipmaps only, the others are regenerated when loading
+
+use super::*;
+use serde::{de, ser::SerializeSeq, Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for BandLimitedWaveTables {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut frames = serializer.serialize_seq(Some(self.num_frames()))?;
+
+        for mipmaps in self.as_slice() {
+            frames.serialize_element(mipmaps.last().unwrap().as_slice())?;
+        }
+
+        frames.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Box<BandLimitedWaveTables> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let frames = Vec::<Vec<f32>>::deserialize(deserializer)?;
+
+        if frames.len() > BandLimitedWaveTables::MAX_FRAMES {
+            return Err(de::Error::custom(WavetableError::TooManyFrames {
+                max: BandLimitedWaveTables::MAX_FRAMES,
+                actual: frames.len(),
+            }));
+        }
+
+        let mut this = BandLimitedWaveTables::with_frame_count(frames.len());
+
+        for (mipmaps, frame) in this.as_mut_slice().iter_mut().zip(&frames) {
+            if frame.len() != BandLimitedWaveTables::FRAME_LEN {
+                return Err(de::Error::invalid_length(frame.len(), &"a full frame"));
+            }
+
+            mipmaps.last_mut().unwrap().copy_from_slice(frame);
+        }
+
+        // the top mipmaps are kept as they were stored
+        this.create_mipmaps_with_options(&MipmapOptions {
+            remove_dc: false,
+            ..Default::default()
+        });
+
+        Ok(this)
+    }
+}